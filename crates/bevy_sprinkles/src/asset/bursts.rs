@@ -0,0 +1,84 @@
+use super::{EmitterData, EmitterTime, ParticleSystemAsset};
+use crate::random::hash_to_float;
+
+/// Maximum number of slices an emission cycle is split into when looking for bursts.
+const BURST_BINS: usize = 64;
+/// How many times more particles than steady emission a slice must spawn to count as
+/// part of a burst.
+const BURST_DENSITY: f32 = 4.0;
+
+impl EmitterData {
+    /// Returns the phases, from `0.0` to `1.0` of [`lifetime`](EmitterTime::lifetime),
+    /// where this emitter spawns a burst of particles within its emission cycle.
+    ///
+    /// Spawn phases follow the simulation shader, so they account for
+    /// [`explosiveness`](EmitterTime::explosiveness) and
+    /// [`spawn_time_randomness`](EmitterTime::spawn_time_randomness). Each burst
+    /// is reported once, at the phase where it starts. Steady emission and
+    /// [`PerDistance`](super::EmissionMode::PerDistance) emitters have no bursts.
+    ///
+    /// Emitters with a [`burst`](super::EmitterEmission::burst) report the phase of each
+    /// of its bursts within their lifetime.
+    pub fn burst_phases(&self) -> Vec<f32> {
+        let amount = self.emission.particles_amount;
+        if amount == 0 || self.emission.mode.spacing().is_some() {
+            return Vec::new();
+        }
+        if let Some(burst) = self.emission.active_burst() {
+            let lifetime = self.time.lifetime;
+            return burst
+                .times()
+                .filter(|t| *t < lifetime)
+                .map(|t| t / lifetime)
+                .collect();
+        }
+
+        // no more slices than particles, so steady emission never crowds a slice
+        let bin_count = (amount as usize).min(BURST_BINS);
+        let mut bins = vec![0u32; bin_count];
+        for idx in 0..amount {
+            bins[(spawn_phase(&self.time, idx, amount) * bin_count as f32) as usize] += 1;
+        }
+
+        let threshold = amount as f32 * BURST_DENSITY / bin_count as f32;
+        let mut phases = Vec::new();
+        let mut in_burst = false;
+        for (bin, count) in bins.iter().enumerate() {
+            let dense = *count as f32 >= threshold;
+            if dense && !in_burst {
+                phases.push(bin as f32 / bin_count as f32);
+            }
+            in_burst = dense;
+        }
+        phases
+    }
+}
+
+impl ParticleSystemAsset {
+    /// Returns the times within one playback, in seconds, where enabled emitters that
+    /// aren't sub-emitter targets spawn a burst of particles, sorted in ascending order.
+    ///
+    /// See [`EmitterData::burst_phases`]; each phase is offset by the emitter's
+    /// [`delay`](EmitterTime::delay) and scaled by its lifetime.
+    pub fn burst_times(&self) -> Vec<f32> {
+        let mut times: Vec<f32> = self
+            .source_emitters()
+            .filter(|e| e.enabled)
+            .flat_map(|e| {
+                e.burst_phases()
+                    .into_iter()
+                    .map(|phase| e.time.delay + phase * e.time.lifetime)
+            })
+            .collect();
+        times.sort_by(f32::total_cmp);
+        times
+    }
+}
+
+// mirrors the phase-based emission in `particle_simulate.wgsl`
+pub(super) fn spawn_phase(time: &EmitterTime, idx: u32, amount: u32) -> f32 {
+    let base_phase = idx as f32 / amount as f32;
+    let phase = base_phase + hash_to_float(idx) * time.spawn_time_randomness;
+    let adjusted = (phase * (1.0 - time.explosiveness)).fract();
+    adjusted.clamp(0.0, 1.0 - f32::EPSILON)
+}
@@ -0,0 +1,95 @@
+use bevy::reflect::{PartialReflect, ReflectMut};
+
+use super::ParticleSystemAsset;
+
+/// Number of decimals kept by [`ParticleSystemAsset::round_floats`] unless told otherwise.
+pub const DEFAULT_ROUNDING_DECIMALS: u32 = 4;
+
+impl ParticleSystemAsset {
+    /// Rounds every float in the emitters and colliders to `decimals` decimal places,
+    /// clearing out noise like `0.30000001` that accumulates when editing.
+    ///
+    /// Fields are found through reflection, so new float fields are covered without
+    /// changes here. Values only lose digits past `decimals`, and non-finite values are
+    /// left untouched. Returns whether any value changed.
+    pub fn round_floats(&mut self, decimals: u32) -> bool {
+        let factor = 10f64.powi(decimals.min(f64::DIGITS) as i32);
+        let mut changed = false;
+        for emitter in &mut self.emitters {
+            changed |= round_reflected(emitter, factor);
+        }
+        for collider in &mut self.colliders {
+            changed |= round_reflected(collider, factor);
+        }
+        changed
+    }
+}
+
+fn round_f64(value: f64, factor: f64) -> f64 {
+    if !value.is_finite() {
+        return value;
+    }
+    let rounded = (value * factor).round() / factor;
+    if rounded.is_finite() { rounded } else { value }
+}
+
+fn round_reflected(value: &mut dyn PartialReflect, factor: f64) -> bool {
+    let mut changed = false;
+    match value.reflect_mut() {
+        ReflectMut::Struct(s) => {
+            for i in 0..s.field_len() {
+                if let Some(field) = s.field_at_mut(i) {
+                    changed |= round_reflected(field, factor);
+                }
+            }
+        }
+        ReflectMut::TupleStruct(s) => {
+            for i in 0..s.field_len() {
+                if let Some(field) = s.field_mut(i) {
+                    changed |= round_reflected(field, factor);
+                }
+            }
+        }
+        ReflectMut::Tuple(t) => {
+            for i in 0..t.field_len() {
+                if let Some(field) = t.field_mut(i) {
+                    changed |= round_reflected(field, factor);
+                }
+            }
+        }
+        ReflectMut::List(list) => {
+            for i in 0..list.len() {
+                if let Some(item) = list.get_mut(i) {
+                    changed |= round_reflected(item, factor);
+                }
+            }
+        }
+        ReflectMut::Array(array) => {
+            for i in 0..array.len() {
+                if let Some(item) = array.get_mut(i) {
+                    changed |= round_reflected(item, factor);
+                }
+            }
+        }
+        ReflectMut::Enum(e) => {
+            for i in 0..e.field_len() {
+                if let Some(field) = e.field_at_mut(i) {
+                    changed |= round_reflected(field, factor);
+                }
+            }
+        }
+        ReflectMut::Opaque(opaque) => {
+            if let Some(v) = opaque.try_downcast_mut::<f32>() {
+                let rounded = round_f64(*v as f64, factor) as f32;
+                changed = rounded != *v;
+                *v = rounded;
+            } else if let Some(v) = opaque.try_downcast_mut::<f64>() {
+                let rounded = round_f64(*v, factor);
+                changed = rounded != *v;
+                *v = rounded;
+            }
+        }
+        _ => {}
+    }
+    changed
+}
@@ -1,9 +1,15 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
 use super::Range;
+use super::serde_helpers::is_false;
+use crate::textures::TEXTURE_WIDTH;
+
+/// Smallest gap kept between neighboring points when moving one of them.
+const POINT_SPACING: f32 = 0.001;
 
 /// Interpolation mode between two [`CurvePoint`]s.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default, Reflect)]
@@ -21,6 +27,16 @@ pub enum CurveMode {
     Stairs,
     /// Staircase interpolation with smooth transitions between steps.
     SmoothStairs,
+    /// Cubic Hermite (Catmull-Rom) interpolation, with tangents taken from the
+    /// neighboring points so consecutive segments join smoothly. May overshoot the
+    /// point values. Ignores tension and easing.
+    CatmullRom,
+}
+
+impl CurveMode {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
 }
 
 impl FromStr for CurveMode {
@@ -32,6 +48,7 @@ impl FromStr for CurveMode {
             "Hold" => Ok(Self::Hold),
             "Stairs" => Ok(Self::Stairs),
             "SmoothStairs" => Ok(Self::SmoothStairs),
+            "CatmullRom" => Ok(Self::CatmullRom),
             _ => Err(()),
         }
     }
@@ -51,6 +68,12 @@ pub enum CurveEasing {
     Circ,
 }
 
+impl CurveEasing {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 impl FromStr for CurveEasing {
     type Err = ();
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -68,6 +91,10 @@ fn default_tension() -> f64 {
     0.0
 }
 
+fn is_default_tension(tension: &f64) -> bool {
+    *tension == default_tension()
+}
+
 /// A single control point in a [`CurveTexture`].
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Reflect)]
 pub struct CurvePoint {
@@ -76,14 +103,17 @@ pub struct CurvePoint {
     /// The value at this point, typically in `[0.0, 1.0]`.
     pub value: f64,
     /// Interpolation mode for the segment leading to this point.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "CurveMode::is_default")]
     pub mode: CurveMode,
     /// Tension parameter that controls the curvature. The effect depends on the
     /// [`mode`](Self::mode) and [`easing`](Self::easing). Defaults to `0.0` (linear).
-    #[serde(default = "default_tension")]
+    #[serde(
+        default = "default_tension",
+        skip_serializing_if = "is_default_tension"
+    )]
     pub tension: f64,
     /// Easing function applied within this segment.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "CurveEasing::is_default")]
     pub easing: CurveEasing,
 }
 
@@ -122,6 +152,55 @@ fn is_empty_string(s: &Option<String>) -> bool {
     s.as_ref().is_none_or(|s| s.is_empty())
 }
 
+/// Common named shapes for values animated over a particle's lifetime.
+///
+/// Used as starting points by [`CurveTexture::from_preset`] and
+/// [`Gradient::from_preset`](super::Gradient::from_preset).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifetimePreset {
+    /// Rises linearly from `0.0` to `1.0`.
+    FadeIn,
+    /// Falls linearly from `1.0` to `0.0`.
+    FadeOut,
+    /// Rises from `0.0` to `1.0`, easing in and out of both ends.
+    EaseInOut,
+    /// Jumps up to `1.0` early on and falls back to `0.0` by the end.
+    Spike,
+}
+
+impl LifetimePreset {
+    /// Every preset, in the order editors list them.
+    pub const ALL: [Self; 4] = [Self::FadeIn, Self::FadeOut, Self::EaseInOut, Self::Spike];
+
+    /// Returns the display name of this preset, e.g. `"Fade Out"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::FadeIn => "Fade In",
+            Self::FadeOut => "Fade Out",
+            Self::EaseInOut => "Ease In-Out",
+            Self::Spike => "Spike",
+        }
+    }
+
+    pub(crate) fn points(self) -> Vec<CurvePoint> {
+        match self {
+            Self::FadeIn => vec![CurvePoint::new(0.0, 0.0), CurvePoint::new(1.0, 1.0)],
+            Self::FadeOut => vec![CurvePoint::new(0.0, 1.0), CurvePoint::new(1.0, 0.0)],
+            Self::EaseInOut => vec![
+                CurvePoint::new(0.0, 0.0),
+                CurvePoint::new(1.0, 1.0)
+                    .with_easing(CurveEasing::Sine)
+                    .with_tension(1.0),
+            ],
+            Self::Spike => vec![
+                CurvePoint::new(0.0, 0.0),
+                CurvePoint::new(0.1, 1.0),
+                CurvePoint::new(1.0, 0.0),
+            ],
+        }
+    }
+}
+
 /// A piecewise curve defined by control points, baked into a 1D texture for GPU sampling.
 ///
 /// Curve textures are used to animate particle properties (scale, alpha, velocity, etc.)
@@ -137,6 +216,22 @@ pub struct CurveTexture {
     /// The output range that the curve values are mapped to. Defaults to `0.0..1.0`.
     #[serde(default)]
     pub range: Range,
+    /// Flips the sampled values (`1.0 - value`) before they're mapped to the
+    /// [`range`](Self::range), without editing the points. Defaults to `false`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub invert: bool,
+    /// Optional bounds the sampled values are clamped to, applied after
+    /// [`invert`](Self::invert) and before mapping to the [`range`](Self::range).
+    /// Defaults to `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clamp: Option<Range>,
+    /// Precomputed samples uploaded as the curve's texture instead of baking it, for
+    /// platforms that can't afford baking at load time. Filled in by
+    /// [`embed_samples`](Self::embed_samples), and ignored unless it holds exactly
+    /// [`TEXTURE_WIDTH`] values. The samples aren't updated when the curve changes.
+    /// Defaults to `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub samples: Option<Vec<f32>>,
 }
 
 impl Default for CurveTexture {
@@ -145,6 +240,9 @@ impl Default for CurveTexture {
             name: Some("Constant".to_string()),
             points: vec![CurvePoint::new(0.0, 1.0), CurvePoint::new(1.0, 1.0)],
             range: Range::new(0.0, 1.0),
+            invert: false,
+            clamp: None,
+            samples: None,
         }
     }
 }
@@ -156,9 +254,17 @@ impl CurveTexture {
             name: None,
             points,
             range: Range::default(),
+            invert: false,
+            clamp: None,
+            samples: None,
         }
     }
 
+    /// Creates a curve shaped like the given preset, named after it.
+    pub fn from_preset(preset: LifetimePreset) -> Self {
+        Self::new(preset.points()).with_name(preset.name())
+    }
+
     /// Sets the display name for this curve.
     pub fn with_name(mut self, name: impl Into<String>) -> Self {
         self.name = Some(name.into());
@@ -171,6 +277,54 @@ impl CurveTexture {
         self
     }
 
+    /// Inverts the sampled values. See [`invert`](Self::invert).
+    pub fn inverted(mut self) -> Self {
+        self.invert = !self.invert;
+        self
+    }
+
+    /// Clamps the sampled values to the given bounds. See [`clamp`](Self::clamp).
+    pub fn with_clamp(mut self, clamp: Range) -> Self {
+        self.clamp = Some(clamp);
+        self
+    }
+
+    /// Removes the point at `index`, unless the curve would be left with fewer than two
+    /// points. Returns `true` if a point was removed.
+    pub fn remove_point(&mut self, index: usize) -> bool {
+        if self.points.len() <= 2 || index >= self.points.len() {
+            return false;
+        }
+        self.points.remove(index);
+        true
+    }
+
+    /// Moves the point at `index` by the given offsets. The position stays strictly between
+    /// its neighbors so the points remain sorted, and the value stays within the
+    /// [`range`](Self::range).
+    pub fn nudge_point(&mut self, index: usize, position_delta: f32, value_delta: f64) {
+        if index >= self.points.len() {
+            return;
+        }
+        let min_position = match index {
+            0 => 0.0,
+            _ => self.points[index - 1].position + POINT_SPACING,
+        };
+        let max_position = match self.points.get(index + 1) {
+            Some(next) => next.position - POINT_SPACING,
+            None => 1.0,
+        };
+        let low = self.range.min.min(self.range.max) as f64;
+        let high = self.range.min.max(self.range.max) as f64;
+
+        let point = &mut self.points[index];
+        // neighbors closer than the spacing leave no room to move
+        if min_position <= max_position {
+            point.position = (point.position + position_delta).clamp(min_position, max_position);
+        }
+        point.value = (point.value + value_delta).clamp(low, high);
+    }
+
     /// Computes a hash key for texture caching.
     pub fn cache_key(&self) -> u64 {
         let mut hasher = std::collections::hash_map::DefaultHasher::new();
@@ -182,11 +336,25 @@ impl CurveTexture {
         }
         self.range.min.to_bits().hash(&mut hasher);
         self.range.max.to_bits().hash(&mut hasher);
+        self.invert.hash(&mut hasher);
+        if let Some(clamp) = self.clamp {
+            clamp.min.to_bits().hash(&mut hasher);
+            clamp.max.to_bits().hash(&mut hasher);
+        }
+        if let Some(samples) = &self.samples {
+            for value in samples {
+                value.to_bits().hash(&mut hasher);
+            }
+        }
         hasher.finish()
     }
 
-    /// Returns `true` if all control points have the same value, meaning the curve is flat.
+    /// Returns `true` if all control points have the same value and neither
+    /// [`invert`](Self::invert) nor [`clamp`](Self::clamp) is set, meaning the curve is flat.
     pub fn is_constant(&self) -> bool {
+        if self.invert || self.clamp.is_some() {
+            return false;
+        }
         if self.points.len() < 2 {
             return true;
         }
@@ -196,8 +364,43 @@ impl CurveTexture {
             .all(|p| (p.value - first_value).abs() < f64::EPSILON)
     }
 
-    /// Samples the curve at position `t` (clamped to `[0.0, 1.0]`), returning the interpolated value.
+    /// Samples the curve at position `t` (clamped to `[0.0, 1.0]`), returning the interpolated value
+    /// with [`invert`](Self::invert) and [`clamp`](Self::clamp) applied.
     pub fn sample(&self, t: f32) -> f32 {
+        let mut value = self.sample_points(t);
+        if self.invert {
+            value = 1.0 - value;
+        }
+        if let Some(clamp) = self.clamp {
+            value = value.clamp(clamp.min.min(clamp.max), clamp.max.max(clamp.min));
+        }
+        value
+    }
+
+    /// Samples the curve at `count` evenly spaced positions from `0.0` to `1.0`, both
+    /// included, which is how it's baked into a texture.
+    pub fn to_samples(&self, count: usize) -> Vec<f32> {
+        (0..count)
+            .map(|i| self.sample(sample_position(i, count)))
+            .collect()
+    }
+
+    /// Stores the curve's baked values in [`samples`](Self::samples), so its texture is
+    /// uploaded from them instead of being baked.
+    pub fn embed_samples(&mut self) {
+        self.samples = Some(self.to_samples(TEXTURE_WIDTH as usize));
+    }
+
+    /// Returns the values baked into the curve's texture: the embedded
+    /// [`samples`](Self::samples) when usable, or else freshly computed ones.
+    pub(crate) fn baked_samples(&self) -> Cow<'_, [f32]> {
+        match &self.samples {
+            Some(samples) if samples.len() == TEXTURE_WIDTH as usize => Cow::Borrowed(samples),
+            _ => Cow::Owned(self.to_samples(TEXTURE_WIDTH as usize)),
+        }
+    }
+
+    fn sample_points(&self, t: f32) -> f32 {
         if self.points.is_empty() {
             return 1.0;
         }
@@ -234,8 +437,17 @@ impl CurveTexture {
             return left.value as f32;
         }
 
+        // held segments stay at the left value until the next point, whatever the tension
+        if right.mode == CurveMode::Hold {
+            return left.value as f32;
+        }
+
         let local_t = (t - left.position) / segment_range;
 
+        if right.mode == CurveMode::CatmullRom {
+            return self.sample_catmull_rom(left_idx, right_idx, local_t);
+        }
+
         let slope_sign = (right.value - left.value).signum() as f32;
         let effective_tension = right.tension as f32 * slope_sign;
         let curved_t = apply_curve(local_t, right.mode, right.easing, effective_tension);
@@ -244,6 +456,47 @@ impl CurveTexture {
     }
 }
 
+impl CurveTexture {
+    /// Evaluates a cubic Hermite segment between two adjacent points, using the slopes
+    /// through their neighbors as tangents. Endpoints use the segment's own slope.
+    fn sample_catmull_rom(&self, left_idx: usize, right_idx: usize, local_t: f32) -> f32 {
+        let left = &self.points[left_idx];
+        let right = &self.points[right_idx];
+        let before = &self.points[left_idx.saturating_sub(1)];
+        let after = &self.points[(right_idx + 1).min(self.points.len() - 1)];
+
+        let segment_range = (right.position - left.position) as f64;
+        let slope = |a: &CurvePoint, b: &CurvePoint| {
+            let run = (b.position - a.position) as f64;
+            if run <= 0.0 {
+                0.0
+            } else {
+                (b.value - a.value) / run
+            }
+        };
+        let left_tangent = slope(before, right) * segment_range;
+        let right_tangent = slope(left, after) * segment_range;
+
+        let t = local_t as f64;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let value = (2.0 * t3 - 3.0 * t2 + 1.0) * left.value
+            + (t3 - 2.0 * t2 + t) * left_tangent
+            + (-2.0 * t3 + 3.0 * t2) * right.value
+            + (t3 - t2) * right_tangent;
+        value as f32
+    }
+}
+
+/// Position of sample `index` out of `count` evenly spaced samples over `[0.0, 1.0]`.
+pub(crate) fn sample_position(index: usize, count: usize) -> f32 {
+    if count > 1 {
+        index as f32 / (count - 1) as f32
+    } else {
+        0.0
+    }
+}
+
 fn apply_curve(t: f32, mode: CurveMode, easing: CurveEasing, tension: f32) -> f32 {
     match mode {
         CurveMode::SingleCurve => apply_easing(t, easing, tension),
@@ -257,6 +510,8 @@ fn apply_curve(t: f32, mode: CurveMode, easing: CurveEasing, tension: f32) -> f3
             }
         }
         CurveMode::Hold => 0.0,
+        // needs the neighboring points, so it's evaluated in `CurveTexture::sample`
+        CurveMode::CatmullRom => t,
         CurveMode::Stairs => {
             let steps = tension_to_steps(tension);
             (t * steps as f32).floor() / (steps - 1).max(1) as f32
@@ -0,0 +1,645 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use thiserror::Error;
+
+use super::{
+    CurvePoint, CurveTexture, EmissionShape, EmitterData, Gradient, GradientInterpolation,
+    GradientStop, ParticleSystemAsset, ParticleSystemDimension, Range, SolidOrGradientColor,
+};
+
+const PROCESS_MATERIAL_TYPE: &str = "ParticleProcessMaterial";
+
+/// Errors that can occur when importing a Godot resource.
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum GodotImportError {
+    /// A property value or section header could not be parsed.
+    #[error("Could not parse line {line}: {message}")]
+    Syntax {
+        /// The 1-based line the error was found on.
+        line: usize,
+        /// What went wrong.
+        message: String,
+    },
+    /// The file doesn't contain a `ParticleProcessMaterial` resource.
+    #[error("No ParticleProcessMaterial found in the resource file")]
+    MissingProcessMaterial,
+}
+
+/// The result of [`import_process_material`].
+#[derive(Debug, Clone)]
+pub struct GodotImport {
+    /// A single-emitter particle system built from the process material.
+    pub asset: ParticleSystemAsset,
+    /// Names of the material's properties that have no equivalent here and were skipped.
+    pub unmapped: Vec<String>,
+}
+
+/// Converts a Godot 4 `ParticleProcessMaterial` resource (`.tres`) into a
+/// [`ParticleSystemAsset`] with a single emitter.
+///
+/// The material may be the file's main resource, or a sub-resource of a scene or
+/// another resource. These properties are mapped:
+///
+/// - `direction`, `spread`, `flatness`, `initial_velocity_min`/`max` and `gravity`
+/// - `scale_min`/`max` and `scale_curve`
+/// - `angle_min`/`max`
+/// - `color`, `color_initial_ramp`, `color_ramp` and `alpha_curve`
+/// - `emission_shape` with its radius, extents and ring settings, plus
+///   `emission_shape_offset` and `emission_shape_scale`
+///
+/// Curve tangents are not imported; points are joined linearly. Any other property is
+/// logged as a warning and listed in [`GodotImport::unmapped`]. Lifetime and amount
+/// live on Godot's particles node rather than the material, so they keep their defaults.
+pub fn import_process_material(
+    source: &str,
+    name: impl Into<String>,
+) -> Result<GodotImport, GodotImportError> {
+    let resource = parse_resource(source)?;
+    let material = resource
+        .sections
+        .iter()
+        .find(|s| s.kind == SectionKind::Resource && resource.main_type == PROCESS_MATERIAL_TYPE)
+        .or_else(|| {
+            resource.sections.iter().find(|s| {
+                s.kind == SectionKind::SubResource && s.type_name == PROCESS_MATERIAL_TYPE
+            })
+        })
+        .ok_or(GodotImportError::MissingProcessMaterial)?;
+
+    let mut importer = Importer {
+        resource: &resource,
+        emitter: godot_defaults(),
+        unmapped: Vec::new(),
+    };
+    for (key, value) in &material.properties {
+        if !importer.apply(key, value) {
+            warn!("Godot property \"{key}\" has no equivalent and was skipped");
+            importer.unmapped.push(key.clone());
+        }
+    }
+    importer.finish_emission_shape(material);
+
+    let asset = ParticleSystemAsset::new(
+        name.into(),
+        ParticleSystemDimension::D3,
+        vec![importer.emitter],
+        vec![],
+        None,
+    );
+    Ok(GodotImport {
+        asset,
+        unmapped: importer.unmapped,
+    })
+}
+
+/// An emitter set to the defaults of a freshly created Godot process material.
+fn godot_defaults() -> EmitterData {
+    let mut emitter = EmitterData::default();
+    emitter.velocities.initial_direction = Vec3::X;
+    emitter.velocities.spread = 45.0;
+    emitter.velocities.initial_velocity = Range::new(0.0, 0.0);
+    emitter.accelerations.gravity = Vec3::new(0.0, -9.8, 0.0);
+    emitter.scale.range = Range::new(1.0, 1.0);
+    emitter.colors.initial_color = SolidOrGradientColor::solid([1.0, 1.0, 1.0, 1.0]);
+    emitter.emission.shape = EmissionShape::Point;
+    emitter
+}
+
+struct Importer<'a> {
+    resource: &'a Resource,
+    emitter: EmitterData,
+    unmapped: Vec<String>,
+}
+
+impl Importer<'_> {
+    /// Applies a single property, returning `false` if it isn't mapped.
+    fn apply(&mut self, key: &str, value: &Value) -> bool {
+        let emitter = &mut self.emitter;
+        match key {
+            "direction" => value
+                .vec3()
+                .map(|v| emitter.velocities.initial_direction = v),
+            "spread" => value.f32().map(|v| emitter.velocities.spread = v),
+            "flatness" => value.f32().map(|v| emitter.velocities.flatness = v),
+            "initial_velocity_min" => value
+                .f32()
+                .map(|v| emitter.velocities.initial_velocity.min = v),
+            "initial_velocity_max" => value
+                .f32()
+                .map(|v| emitter.velocities.initial_velocity.max = v),
+            "gravity" => value.vec3().map(|v| emitter.accelerations.gravity = v),
+            "scale_min" => value.f32().map(|v| emitter.scale.range.min = v),
+            "scale_max" => value.f32().map(|v| emitter.scale.range.max = v),
+            "angle_min" => value.f32().map(|v| emitter.angle.range.min = v),
+            "angle_max" => value.f32().map(|v| emitter.angle.range.max = v),
+            "color" => value
+                .color()
+                .map(|c| emitter.colors.initial_color = SolidOrGradientColor::solid(c)),
+            "scale_curve" => {
+                let curve = self.curve(value);
+                curve.map(|c| self.emitter.scale.scale_over_lifetime = Some(c))
+            }
+            "alpha_curve" => {
+                let curve = self.curve(value);
+                curve.map(|c| self.emitter.colors.alpha_over_lifetime = Some(c))
+            }
+            "color_ramp" => {
+                let gradient = self.gradient(value);
+                gradient.map(|g| self.emitter.colors.color_over_lifetime = g)
+            }
+            "color_initial_ramp" => {
+                let gradient = self.gradient(value);
+                gradient.map(|gradient| {
+                    self.emitter.colors.initial_color = SolidOrGradientColor::Gradient { gradient }
+                })
+            }
+            "emission_shape_offset" => value.vec3().map(|v| emitter.emission.offset = v),
+            "emission_shape_scale" => value.vec3().map(|v| emitter.emission.scale = v),
+            // read together with the shape in `finish_emission_shape`
+            "emission_shape"
+            | "emission_sphere_radius"
+            | "emission_box_extents"
+            | "emission_ring_axis"
+            | "emission_ring_height"
+            | "emission_ring_radius"
+            | "emission_ring_inner_radius" => Some(()),
+            _ => None,
+        }
+        .is_some()
+    }
+
+    fn finish_emission_shape(&mut self, material: &Section) {
+        let property = |key: &str| material.property(key);
+        let float = |key: &str, default: f32| property(key).and_then(Value::f32).unwrap_or(default);
+
+        let shape = property("emission_shape")
+            .and_then(Value::f32)
+            .unwrap_or(0.0) as u32;
+        let radius = float("emission_sphere_radius", 1.0);
+        self.emitter.emission.shape = match shape {
+            0 => EmissionShape::Point,
+            1 => EmissionShape::Sphere { radius },
+            2 => EmissionShape::SphereSurface { radius },
+            3 => EmissionShape::Box {
+                extents: property("emission_box_extents")
+                    .and_then(Value::vec3)
+                    .unwrap_or(Vec3::ONE),
+                surface_only: false,
+            },
+            6 => EmissionShape::Ring {
+                axis: property("emission_ring_axis")
+                    .and_then(Value::vec3)
+                    .unwrap_or(Vec3::Z),
+                height: float("emission_ring_height", 1.0),
+                radius: float("emission_ring_radius", 1.0),
+                inner_radius: float("emission_ring_inner_radius", 0.0),
+                radial_bias: 0.0,
+            },
+            other => {
+                warn!("Godot emission shape {other} has no equivalent, emitting from a point");
+                self.unmapped.push("emission_shape".to_string());
+                EmissionShape::Point
+            }
+        };
+    }
+
+    /// Follows `SubResource` references (e.g. a `CurveTexture` wrapping a `Curve`) until
+    /// a section of the wanted type is found.
+    fn resolve(&self, value: &Value, type_name: &str, via: &str) -> Option<&Section> {
+        let section = self.resource.sub_resource(value.sub_resource_id()?)?;
+        if section.type_name == type_name {
+            return Some(section);
+        }
+        self.resolve(section.property(via)?, type_name, via)
+    }
+
+    fn curve(&self, value: &Value) -> Option<CurveTexture> {
+        let curve = self.resolve(value, "Curve", "curve")?;
+        let data = curve.property("_data")?.items()?;
+        let min = curve
+            .property("min_value")
+            .and_then(Value::f32)
+            .unwrap_or(0.0);
+        let max = curve
+            .property("max_value")
+            .and_then(Value::f32)
+            .unwrap_or(1.0);
+        let span = if (max - min).abs() < f32::EPSILON {
+            1.0
+        } else {
+            max - min
+        };
+
+        // each point is stored as `position, left_tangent, right_tangent, left_mode, right_mode`
+        let points: Vec<CurvePoint> = data
+            .iter()
+            .filter_map(Value::vec2)
+            .map(|p| CurvePoint::new(p.x, ((p.y - min) / span) as f64))
+            .collect();
+        if points.is_empty() {
+            return None;
+        }
+        Some(CurveTexture::new(points).with_range(Range::new(min, max)))
+    }
+
+    fn gradient(&self, value: &Value) -> Option<Gradient> {
+        let gradient = self.resolve(value, "Gradient", "gradient")?;
+        let offsets = gradient.property("offsets")?.floats()?;
+        let colors = gradient.property("colors")?.floats()?;
+        let stops: Vec<GradientStop> = offsets
+            .iter()
+            .zip(colors.chunks_exact(4))
+            .map(|(&position, c)| GradientStop {
+                color: [c[0], c[1], c[2], c[3]],
+                position,
+                midpoint: 0.5,
+            })
+            .collect();
+        if stops.is_empty() {
+            return None;
+        }
+
+        let interpolation = match gradient
+            .property("interpolation_mode")
+            .and_then(Value::f32)
+            .unwrap_or(0.0) as u32
+        {
+            1 => GradientInterpolation::Steps,
+            2 => GradientInterpolation::Smoothstep,
+            _ => GradientInterpolation::Linear,
+        };
+        Some(Gradient {
+            stops,
+            interpolation,
+            hdr: false,
+            samples: None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Number(f32),
+    Bool(bool),
+    String(String),
+    /// A constructor such as `Vector3(0, 1, 0)` or `SubResource("Curve_abc")`.
+    Call(String, Vec<Value>),
+    Array(Vec<Value>),
+    /// Anything else (dictionaries, `null`, ...), kept only so it can be reported.
+    Other,
+}
+
+impl Value {
+    fn f32(&self) -> Option<f32> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::Bool(b) => Some(*b as u32 as f32),
+            _ => None,
+        }
+    }
+
+    fn call(&self, name: &str) -> Option<&[Value]> {
+        match self {
+            Self::Call(n, args) if n == name => Some(args),
+            _ => None,
+        }
+    }
+
+    fn vec2(&self) -> Option<Vec2> {
+        match self.call("Vector2")? {
+            [x, y] => Some(Vec2::new(x.f32()?, y.f32()?)),
+            _ => None,
+        }
+    }
+
+    fn vec3(&self) -> Option<Vec3> {
+        match self.call("Vector3")? {
+            [x, y, z] => Some(Vec3::new(x.f32()?, y.f32()?, z.f32()?)),
+            _ => None,
+        }
+    }
+
+    fn color(&self) -> Option<[f32; 4]> {
+        match self.call("Color")? {
+            [r, g, b] => Some([r.f32()?, g.f32()?, b.f32()?, 1.0]),
+            [r, g, b, a] => Some([r.f32()?, g.f32()?, b.f32()?, a.f32()?]),
+            _ => None,
+        }
+    }
+
+    fn sub_resource_id(&self) -> Option<&str> {
+        match self.call("SubResource")? {
+            [Value::String(id)] => Some(id),
+            _ => None,
+        }
+    }
+
+    fn items(&self) -> Option<&[Value]> {
+        match self {
+            Self::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Flattens packed arrays like `PackedFloat32Array(...)` or `PackedColorArray(...)`.
+    fn floats(&self) -> Option<Vec<f32>> {
+        let items = match self {
+            Self::Call(_, args) | Self::Array(args) => args,
+            _ => return None,
+        };
+        items.iter().map(Value::f32).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SectionKind {
+    Header,
+    SubResource,
+    Resource,
+    Other,
+}
+
+#[derive(Debug)]
+struct Section {
+    kind: SectionKind,
+    type_name: String,
+    properties: Vec<(String, Value)>,
+}
+
+impl Section {
+    fn property(&self, key: &str) -> Option<&Value> {
+        self.properties
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+}
+
+#[derive(Debug)]
+struct Resource {
+    main_type: String,
+    sections: Vec<Section>,
+    sub_resources: HashMap<String, usize>,
+}
+
+impl Resource {
+    fn sub_resource(&self, id: &str) -> Option<&Section> {
+        self.sub_resources.get(id).map(|&i| &self.sections[i])
+    }
+}
+
+fn parse_resource(source: &str) -> Result<Resource, GodotImportError> {
+    let mut resource = Resource {
+        main_type: String::new(),
+        sections: Vec::new(),
+        sub_resources: HashMap::new(),
+    };
+
+    let mut lines = source.lines().enumerate();
+    while let Some((index, line)) = lines.next() {
+        let line_number = index + 1;
+        let syntax = |message: &str| GodotImportError::Syntax {
+            line: line_number,
+            message: message.to_string(),
+        };
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(';') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let header = header
+                .strip_suffix(']')
+                .ok_or_else(|| syntax("unterminated section header"))?;
+            let (tag, attributes) = header.split_once(' ').unwrap_or((header, ""));
+            let attributes = parse_attributes(attributes);
+            let kind = match tag {
+                "gd_resource" | "gd_scene" => SectionKind::Header,
+                "sub_resource" => SectionKind::SubResource,
+                "resource" => SectionKind::Resource,
+                _ => SectionKind::Other,
+            };
+            let type_name = attributes.get("type").cloned().unwrap_or_default();
+            if tag == "gd_resource" {
+                resource.main_type = type_name.clone();
+            }
+            if let (SectionKind::SubResource, Some(id)) = (kind, attributes.get("id")) {
+                resource
+                    .sub_resources
+                    .insert(id.clone(), resource.sections.len());
+            }
+            resource.sections.push(Section {
+                kind,
+                type_name,
+                properties: Vec::new(),
+            });
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(syntax("expected `key = value`"));
+        };
+
+        // values like arrays may continue over several lines
+        let mut text = value.trim().to_string();
+        while !is_balanced(&text) {
+            let Some((_, next)) = lines.next() else {
+                return Err(syntax("unterminated value"));
+            };
+            text.push(' ');
+            text.push_str(next.trim());
+        }
+
+        let value = Parser::new(&text)
+            .value()
+            .map_err(|message| syntax(&message))?;
+        if let Some(section) = resource.sections.last_mut() {
+            section.properties.push((key.trim().to_string(), value));
+        }
+    }
+
+    Ok(resource)
+}
+
+fn parse_attributes(attributes: &str) -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    let mut rest = attributes.trim();
+    while let Some((key, value)) = rest.split_once('=') {
+        let value = value.trim_start();
+        let (value, remainder) = match value.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"').unwrap_or((quoted, "")),
+            None => value.split_once(' ').unwrap_or((value, "")),
+        };
+        map.insert(key.trim().to_string(), value.to_string());
+        rest = remainder.trim();
+    }
+    map
+}
+
+fn is_balanced(text: &str) -> bool {
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in text.chars() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    depth <= 0 && !in_string
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let Some(first) = rest.chars().next() else {
+            return Err("missing value".to_string());
+        };
+
+        match first {
+            '"' => self.string(),
+            '[' => {
+                self.pos += 1;
+                self.list(']').map(Value::Array)
+            }
+            '{' => {
+                self.skip_group()?;
+                Ok(Value::Other)
+            }
+            c if c == '-' || c == '.' || c.is_ascii_digit() => self.number(),
+            c if c.is_ascii_alphabetic() || c == '&' => {
+                let len = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '&'))
+                    .unwrap_or(rest.len());
+                let ident = &rest[..len];
+                self.pos += len;
+                match ident {
+                    "true" => Ok(Value::Bool(true)),
+                    "false" => Ok(Value::Bool(false)),
+                    "inf" => Ok(Value::Number(f32::INFINITY)),
+                    "nan" => Ok(Value::Number(f32::NAN)),
+                    _ if self.eat('(') => {
+                        let args = self.list(')')?;
+                        Ok(Value::Call(ident.to_string(), args))
+                    }
+                    _ => Ok(Value::Other),
+                }
+            }
+            c => Err(format!("unexpected `{c}`")),
+        }
+    }
+
+    fn list(&mut self, close: char) -> Result<Vec<Value>, String> {
+        let mut items = Vec::new();
+        if self.eat(close) {
+            return Ok(items);
+        }
+        loop {
+            items.push(self.value()?);
+            if self.eat(close) {
+                return Ok(items);
+            }
+            if !self.eat(',') {
+                return Err(format!("expected `,` or `{close}`"));
+            }
+            // trailing comma
+            if self.eat(close) {
+                return Ok(items);
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<Value, String> {
+        let rest = &self.rest()[1..];
+        let mut value = String::new();
+        let mut chars = rest.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 2;
+                    return Ok(Value::String(value));
+                }
+                '\\' => {
+                    if let Some((_, escaped)) = chars.next() {
+                        value.push(escaped);
+                    }
+                }
+                _ => value.push(c),
+            }
+        }
+        Err("unterminated string".to_string())
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')))
+            .unwrap_or(rest.len());
+        let number = rest[..len]
+            .parse::<f32>()
+            .map_err(|_| format!("invalid number `{}`", &rest[..len]))?;
+        self.pos += len;
+        Ok(Value::Number(number))
+    }
+
+    fn skip_group(&mut self) -> Result<(), String> {
+        let rest = self.rest();
+        let mut depth = 0;
+        for (i, c) in rest.char_indices() {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.pos += i + 1;
+                        return Ok(());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Err("unterminated value".to_string())
+    }
+}
@@ -1,7 +1,25 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
 
+use super::LifetimePreset;
+use super::curve::sample_position;
+use super::serde_helpers::is_false;
+use crate::textures::TEXTURE_WIDTH;
+
+/// Smallest gap kept between neighboring stops when moving one of them.
+const STOP_SPACING: f32 = 0.001;
+
+/// Smallest change of a color channel, one 8-bit level.
+pub const COLOR_CHANNEL_STEP: f32 = 1.0 / 255.0;
+
+/// Moves a color channel by `steps` increments of [`COLOR_CHANNEL_STEP`], keeping it
+/// within `[0.0, 1.0]`.
+pub fn step_color_channel(value: f32, steps: f32) -> f32 {
+    (value + steps * COLOR_CHANNEL_STEP).clamp(0.0, 1.0)
+}
+
 /// Interpolation mode for sampling between gradient stops.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, Hash, Reflect)]
 pub enum GradientInterpolation {
@@ -21,12 +39,43 @@ impl GradientInterpolation {
 }
 
 /// A single color stop within a [`Gradient`].
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 pub struct GradientStop {
-    /// The color at this stop, as linear RGBA values in `[0.0, 1.0]`.
+    /// The color at this stop, as linear RGBA values in `[0.0, 1.0]`. Values above `1.0`
+    /// are only preserved when the gradient is [`hdr`](Gradient::hdr).
     pub color: [f32; 4],
     /// Position of this stop along the gradient, from `0.0` (start) to `1.0` (end).
     pub position: f32,
+    /// Where the blend toward the next stop reaches half way, as a fraction of the
+    /// distance between the two stops.
+    ///
+    /// Lower values move the blend toward the next stop's color sooner, higher values
+    /// hold this stop's color longer. Clamped to `[0.0, 1.0]` and ignored on the last
+    /// stop. Defaults to `0.5`, which blends evenly.
+    #[serde(
+        default = "default_midpoint",
+        skip_serializing_if = "is_default_midpoint"
+    )]
+    pub midpoint: f32,
+}
+
+fn default_midpoint() -> f32 {
+    0.5
+}
+
+fn is_default_midpoint(v: &f32) -> bool {
+    *v == default_midpoint()
+}
+
+impl GradientStop {
+    /// Creates a stop of `color` at `position` that blends evenly into the next stop.
+    pub fn new(color: [f32; 4], position: f32) -> Self {
+        Self {
+            color,
+            position,
+            midpoint: default_midpoint(),
+        }
+    }
 }
 
 /// A color gradient defined by a series of [`GradientStop`]s.
@@ -34,29 +83,37 @@ pub struct GradientStop {
 /// Gradients are baked into 1D textures for efficient GPU sampling. The
 /// [`interpolation`](Self::interpolation) mode controls how colors are blended
 /// between stops.
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 pub struct Gradient {
     /// The ordered list of color stops that define this gradient.
     pub stops: Vec<GradientStop>,
     /// Interpolation mode between stops. Defaults to [`GradientInterpolation::Linear`].
     #[serde(default, skip_serializing_if = "GradientInterpolation::is_default")]
     pub interpolation: GradientInterpolation,
+    /// Bakes this gradient into an `Rgba16Float` texture instead of `Rgba8UnormSrgb`, so
+    /// channel values above `1.0` survive. Enable it for emissive gradients that drive
+    /// bloom. Defaults to `false`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub hdr: bool,
+    /// Precomputed RGBA samples uploaded as the gradient's texture instead of baking it,
+    /// for platforms that can't afford baking at load time. Filled in by
+    /// [`embed_samples`](Self::embed_samples), and ignored unless it holds exactly
+    /// [`TEXTURE_WIDTH`] colors. The samples aren't updated when the gradient changes.
+    /// Defaults to `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub samples: Option<Vec<[f32; 4]>>,
 }
 
 impl Default for Gradient {
     fn default() -> Self {
         Self {
             stops: vec![
-                GradientStop {
-                    color: [0.0, 0.0, 0.0, 1.0],
-                    position: 0.0,
-                },
-                GradientStop {
-                    color: [1.0, 1.0, 1.0, 1.0],
-                    position: 1.0,
-                },
+                GradientStop::new([0.0, 0.0, 0.0, 1.0], 0.0),
+                GradientStop::new([1.0, 1.0, 1.0, 1.0], 1.0),
             ],
             interpolation: GradientInterpolation::Linear,
+            hdr: false,
+            samples: None,
         }
     }
 }
@@ -66,20 +123,60 @@ impl Gradient {
     pub fn white() -> Self {
         Self {
             stops: vec![
-                GradientStop {
-                    color: [1.0, 1.0, 1.0, 1.0],
-                    position: 0.0,
-                },
-                GradientStop {
-                    color: [1.0, 1.0, 1.0, 1.0],
-                    position: 1.0,
-                },
+                GradientStop::new([1.0, 1.0, 1.0, 1.0], 0.0),
+                GradientStop::new([1.0, 1.0, 1.0, 1.0], 1.0),
             ],
             interpolation: GradientInterpolation::Linear,
+            hdr: false,
+            samples: None,
+        }
+    }
+
+    /// Creates a gradient of the given linear RGB color whose alpha follows the preset.
+    ///
+    /// Stops are placed at the preset's key points. [`LifetimePreset::EaseInOut`] uses
+    /// [`GradientInterpolation::Smoothstep`]; the other presets are linear.
+    pub fn from_preset(preset: LifetimePreset, color: [f32; 3]) -> Self {
+        let [r, g, b] = color;
+        let interpolation = match preset {
+            LifetimePreset::EaseInOut => GradientInterpolation::Smoothstep,
+            _ => GradientInterpolation::Linear,
+        };
+        Self {
+            stops: preset
+                .points()
+                .into_iter()
+                .map(|point| GradientStop::new([r, g, b, point.value as f32], point.position))
+                .collect(),
+            interpolation,
+            hdr: false,
+            samples: None,
+        }
+    }
+
+    /// Returns `true` if the stops are in ascending order of position, as
+    /// [`sample`](Self::sample) and texture baking expect.
+    pub fn is_sorted(&self) -> bool {
+        self.stops.is_sorted_by(|a, b| a.position <= b.position)
+    }
+
+    /// Sorts the stops by position, keeping stops at the same position in their current
+    /// order. Returns `true` if any stop moved.
+    pub fn sort_stops(&mut self) -> bool {
+        if self.is_sorted() {
+            return false;
         }
+        self.stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+        true
+    }
+
+    pub(crate) fn is_white(&self) -> bool {
+        *self == Self::white()
     }
 
-    /// Computes a hash key for texture caching, based on all stops and the interpolation mode.
+    /// Computes a hash key for texture caching, based on all stops and their midpoints, the
+    /// interpolation mode, the [`hdr`](Self::hdr) flag and the embedded
+    /// [`samples`](Self::samples).
     pub fn cache_key(&self) -> u64 {
         let mut hasher = std::collections::hash_map::DefaultHasher::new();
         for stop in &self.stops {
@@ -87,10 +184,182 @@ impl Gradient {
                 c.to_bits().hash(&mut hasher);
             }
             stop.position.to_bits().hash(&mut hasher);
+            stop.midpoint.to_bits().hash(&mut hasher);
         }
         self.interpolation.hash(&mut hasher);
+        self.hdr.hash(&mut hasher);
+        if let Some(samples) = &self.samples {
+            for c in samples.iter().flatten() {
+                c.to_bits().hash(&mut hasher);
+            }
+        }
         hasher.finish()
     }
+
+    /// Samples the gradient at position `t` (clamped to `[0.0, 1.0]`), returning the
+    /// interpolated RGBA color.
+    pub fn sample(&self, t: f32) -> [f32; 4] {
+        let stops = &self.stops;
+
+        if stops.is_empty() {
+            return [1.0, 1.0, 1.0, 1.0];
+        }
+        if stops.len() == 1 {
+            return stops[0].color;
+        }
+
+        let t = t.clamp(0.0, 1.0);
+        let mut left_idx = 0;
+        let mut right_idx = stops.len() - 1;
+
+        for (i, stop) in stops.iter().enumerate() {
+            if stop.position <= t {
+                left_idx = i;
+            }
+        }
+        for (i, stop) in stops.iter().enumerate() {
+            if stop.position >= t {
+                right_idx = i;
+                break;
+            }
+        }
+
+        let left = &stops[left_idx];
+        let right = &stops[right_idx];
+
+        if left_idx == right_idx {
+            return left.color;
+        }
+
+        let range = right.position - left.position;
+        if range <= 0.0 {
+            return left.color;
+        }
+
+        let local_t = apply_midpoint((t - left.position) / range, left.midpoint);
+
+        match self.interpolation {
+            GradientInterpolation::Steps => left.color,
+            GradientInterpolation::Linear => lerp_color(left.color, right.color, local_t),
+            GradientInterpolation::Smoothstep => {
+                let smooth_t = local_t * local_t * (3.0 - 2.0 * local_t);
+                lerp_color(left.color, right.color, smooth_t)
+            }
+        }
+    }
+
+    /// Samples the gradient at `count` evenly spaced positions from `0.0` to `1.0`, both
+    /// included, which is how it's baked into a texture.
+    pub fn to_samples(&self, count: usize) -> Vec<[f32; 4]> {
+        (0..count)
+            .map(|i| self.sample(sample_position(i, count)))
+            .collect()
+    }
+
+    /// Stores the gradient's baked colors in [`samples`](Self::samples), so its texture
+    /// is uploaded from them instead of being baked.
+    pub fn embed_samples(&mut self) {
+        self.samples = Some(self.to_samples(TEXTURE_WIDTH as usize));
+    }
+
+    /// Returns the colors baked into the gradient's texture: the embedded
+    /// [`samples`](Self::samples) when usable, or else freshly computed ones.
+    pub(crate) fn baked_samples(&self) -> Cow<'_, [[f32; 4]]> {
+        match &self.samples {
+            Some(samples) if samples.len() == TEXTURE_WIDTH as usize => Cow::Borrowed(samples),
+            _ => Cow::Owned(self.to_samples(TEXTURE_WIDTH as usize)),
+        }
+    }
+
+    /// Returns this gradient flipped end to end: each stop moves to `1.0 - position` and
+    /// keeps its color, and each midpoint is mirrored within its segment.
+    pub fn reversed(&self) -> Gradient {
+        // a segment's midpoint lives on its left stop, which becomes the right one
+        let midpoints = self
+            .stops
+            .iter()
+            .rev()
+            .skip(1)
+            .map(|stop| 1.0 - stop.midpoint)
+            .chain(std::iter::once(default_midpoint()));
+        Self {
+            stops: self
+                .stops
+                .iter()
+                .rev()
+                .zip(midpoints)
+                .map(|(stop, midpoint)| GradientStop {
+                    color: stop.color,
+                    position: 1.0 - stop.position,
+                    midpoint,
+                })
+                .collect(),
+            interpolation: self.interpolation,
+            hdr: self.hdr,
+            samples: None,
+        }
+    }
+
+    /// Removes the stop at `index`, unless the gradient would be left with fewer than two
+    /// stops. Returns `true` if a stop was removed.
+    pub fn remove_stop(&mut self, index: usize) -> bool {
+        if self.stops.len() <= 2 || index >= self.stops.len() {
+            return false;
+        }
+        self.stops.remove(index);
+        true
+    }
+
+    /// Moves the stop at `index` by `delta`, keeping it strictly between its neighbors so
+    /// the stops remain sorted.
+    pub fn nudge_stop(&mut self, index: usize, delta: f32) {
+        if index >= self.stops.len() {
+            return;
+        }
+        let min_position = match index {
+            0 => 0.0,
+            _ => self.stops[index - 1].position + STOP_SPACING,
+        };
+        let max_position = match self.stops.get(index + 1) {
+            Some(next) => next.position - STOP_SPACING,
+            None => 1.0,
+        };
+
+        let stop = &mut self.stops[index];
+        // neighbors closer than the spacing leave no room to move
+        if min_position <= max_position {
+            stop.position = (stop.position + delta).clamp(min_position, max_position);
+        }
+    }
+
+    /// Spaces the stops uniformly across `[0.0, 1.0]`, keeping their order and colors.
+    ///
+    /// A single stop is moved to `0.0`.
+    pub fn distribute_evenly(&mut self) {
+        let last = self.stops.len().saturating_sub(1).max(1) as f32;
+        for (i, stop) in self.stops.iter_mut().enumerate() {
+            stop.position = i as f32 / last;
+        }
+    }
+}
+
+// remaps a segment's local position so that `midpoint` lands half way between its stops
+fn apply_midpoint(t: f32, midpoint: f32) -> f32 {
+    let midpoint = midpoint.clamp(f32::EPSILON, 1.0 - f32::EPSILON);
+    if t < midpoint {
+        0.5 * t / midpoint
+    } else {
+        0.5 + 0.5 * (t - midpoint) / (1.0 - midpoint)
+    }
+}
+
+fn lerp_color(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    [
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+        a[3] + (b[3] - a[3]) * t,
+    ]
 }
 
 /// A color that is either a single solid value or a gradient.
@@ -98,7 +367,7 @@ impl Gradient {
 /// When used as an initial particle color, [`Solid`](Self::Solid) applies the same color
 /// to every particle, while [`Gradient`](Self::Gradient) samples a random position along
 /// the gradient for each particle.
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 #[reflect(Clone)]
 pub enum SolidOrGradientColor {
     /// A single solid color, as linear RGBA values in `[0.0, 1.0]`.
@@ -122,6 +391,10 @@ impl Default for SolidOrGradientColor {
 }
 
 impl SolidOrGradientColor {
+    pub(crate) fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
     /// Creates a [`SolidOrGradientColor::Solid`] with the given linear RGBA color.
     pub fn solid(color: [f32; 4]) -> Self {
         Self::Solid { color }
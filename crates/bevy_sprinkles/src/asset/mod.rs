@@ -1,24 +1,45 @@
+mod bursts;
+mod cleanup;
 mod curve;
+/// Import of Godot particle process materials.
+pub mod godot;
 mod gradient;
+mod module;
 mod particle_material;
+mod peak;
+mod recovery;
+mod samples;
 pub(crate) mod serde_helpers;
+mod settle;
+mod validate;
 /// Asset format version tracking and compatibility validation.
 pub mod versioning;
 
-pub use curve::{CurveEasing, CurveMode, CurvePoint, CurveTexture};
-pub use gradient::{Gradient, GradientInterpolation, GradientStop, SolidOrGradientColor};
+pub use cleanup::DEFAULT_ROUNDING_DECIMALS;
+pub use curve::{CurveEasing, CurveMode, CurvePoint, CurveTexture, LifetimePreset};
+pub use gradient::{
+    COLOR_CHANNEL_STEP, Gradient, GradientInterpolation, GradientStop, SolidOrGradientColor,
+    step_color_channel,
+};
+pub use module::EmitterModule;
 pub use particle_material::{
-    DrawPassMaterial, SerializableAlphaMode, SerializableFace, StandardParticleMaterial,
+    BLOOM_HEADROOM, DrawPassMaterial, METALLIC_RANGE, PERCEPTUAL_ROUGHNESS_RANGE,
+    REFLECTANCE_RANGE, SerializableAlphaMode, SerializableFace, StandardParticleMaterial,
 };
+pub use recovery::EmitterLoadError;
+pub use validate::AssetWarning;
 
 use bevy::{
     asset::{AssetLoader, LoadContext, io::Reader},
+    math::bounding::Aabb3d,
     prelude::*,
 };
 use bitflags::bitflags;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::random::{hash, hash_to_float};
+use crate::textures::preset::TextureRef;
 use serde_helpers::*;
 use versioning::{VersionStatus, current_format_version};
 
@@ -26,6 +47,16 @@ use versioning::{VersionStatus, current_format_version};
 #[derive(Default, TypePath)]
 pub struct ParticleSystemAssetLoader;
 
+/// Settings for [`ParticleSystemAssetLoader`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParticleSystemAssetLoaderSettings {
+    /// If `true`, emitters that fail to deserialize are replaced with disabled ones
+    /// instead of failing the whole asset, logging a warning for each.
+    ///
+    /// See [`ParticleSystemAsset::from_ron_lenient`]. Defaults to `false`.
+    pub lenient: bool,
+}
+
 /// Errors that can occur when loading a [`ParticleSystemAsset`].
 #[non_exhaustive]
 #[derive(Debug, Error)]
@@ -53,36 +84,32 @@ pub enum ParticleSystemAssetLoaderError {
 
 impl AssetLoader for ParticleSystemAssetLoader {
     type Asset = ParticleSystemAsset;
-    type Settings = ();
+    type Settings = ParticleSystemAssetLoaderSettings;
     type Error = ParticleSystemAssetLoaderError;
 
     async fn load(
         &self,
         reader: &mut dyn Reader,
-        _settings: &(),
+        settings: &ParticleSystemAssetLoaderSettings,
         load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
-        let mut asset = ron::de::from_bytes::<ParticleSystemAsset>(&bytes)?;
-
-        match asset.try_upgrade_version() {
-            VersionStatus::Current => {}
-            VersionStatus::Outdated { found, current } => {
-                let path = load_context.path();
-                warn!(
-                    "{path:?}: loaded asset with sprinkles_version \"{found}\", current is \"{current}\""
-                );
-            }
-            VersionStatus::Incompatible { found, current } => {
-                return Err(ParticleSystemAssetLoaderError::IncompatibleVersion {
-                    found,
-                    current: current.to_string(),
-                });
-            }
-            VersionStatus::Unknown => {
-                return Err(ParticleSystemAssetLoaderError::UnknownVersion);
+        let path = load_context.path();
+        let (asset, status) = if settings.lenient {
+            let (asset, status, errors) = ParticleSystemAsset::from_ron_lenient(&bytes)?;
+            for error in errors {
+                warn!("{path:?}: {error}. Replaced it with a disabled emitter.");
             }
+            (asset, status)
+        } else {
+            ParticleSystemAsset::from_ron(&bytes)?
+        };
+
+        if let VersionStatus::Outdated { found, current } = status {
+            warn!(
+                "{path:?}: loaded asset with sprinkles_version \"{found}\", current is \"{current}\""
+            );
         }
 
         Ok(asset)
@@ -142,19 +169,24 @@ impl DrawOrder {
 }
 
 /// Timing and lifecycle configuration for an emitter.
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 pub struct EmitterTime {
     /// The amount of time each particle will exist, in seconds.
     ///
     /// The effective emission rate is `particles_amount / lifetime` particles per second.
     /// Defaults to `1.0`.
-    #[serde(default = "default_lifetime")]
+    #[serde(
+        default = "default_lifetime",
+        skip_serializing_if = "is_default_lifetime"
+    )]
     pub lifetime: f32,
     /// Particle lifetime randomness ratio.
     ///
     /// The actual lifetime of each particle is `lifetime * (1.0 - rand() * lifetime_randomness)`.
     /// For example, a value of `0.4` scales each particle's lifetime between 60% and 100% of
-    /// the configured [`lifetime`](Self::lifetime). Defaults to `0.0`.
+    /// the configured [`lifetime`](Self::lifetime). Curves and gradients over lifetime are
+    /// sampled against each particle's own lifetime, so short- and long-lived particles
+    /// both reach their end values when they die. Defaults to `0.0`.
     #[serde(default, skip_serializing_if = "is_zero_f32")]
     pub lifetime_randomness: f32,
     /// Time in seconds to wait before the emitter starts spawning particles.
@@ -162,12 +194,25 @@ pub struct EmitterTime {
     /// Defaults to `0.0`.
     #[serde(default, skip_serializing_if = "is_zero_f32")]
     pub delay: f32,
+    /// Random variation of the [`delay`](Self::delay) in seconds, picked again each cycle.
+    ///
+    /// Each cycle's delay is offset by a value in `[-delay_randomness, delay_randomness]`
+    /// derived from the emitter's random seed, and never goes below `0.0`.
+    /// Defaults to `0.0`.
+    #[serde(default, skip_serializing_if = "is_zero_f32")]
+    pub delay_randomness: f32,
     /// If `true`, only one emission cycle will occur: exactly `particles_amount` particles
     /// will be emitted, and then the emitter stops.
     ///
     /// Defaults to `false`.
     #[serde(default, skip_serializing_if = "is_false")]
     pub one_shot: bool,
+    /// If `true`, the emitter stays idle after spawning until a
+    /// [`TriggerEmitter`](crate::runtime::TriggerEmitter) event starts it.
+    ///
+    /// Defaults to `false`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub wait_for_trigger: bool,
     /// Time ratio between each emission, from `0.0` to `1.0`.
     ///
     /// If `0.0`, particles are emitted continuously over the lifetime. If `1.0`, all
@@ -199,13 +244,19 @@ fn default_lifetime() -> f32 {
     1.0
 }
 
+fn is_default_lifetime(v: &f32) -> bool {
+    *v == default_lifetime()
+}
+
 impl Default for EmitterTime {
     fn default() -> Self {
         Self {
             lifetime: 1.0,
             lifetime_randomness: 0.0,
             delay: 0.0,
+            delay_randomness: 0.0,
             one_shot: false,
+            wait_for_trigger: false,
             explosiveness: 0.0,
             spawn_time_randomness: 0.0,
             fixed_fps: 0,
@@ -215,9 +266,24 @@ impl Default for EmitterTime {
 }
 
 impl EmitterTime {
-    /// Returns the total duration of one emission cycle, including the delay.
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the total duration of one emission cycle, including the longest possible
+    /// delay.
     pub fn total_duration(&self) -> f32 {
-        self.delay + self.lifetime
+        self.delay + self.delay_randomness.max(0.0) + self.lifetime
+    }
+
+    /// Returns the delay before emission starts in the given cycle, applying
+    /// [`delay_randomness`](Self::delay_randomness) with the emitter's random seed.
+    pub fn cycle_delay(&self, seed: u32, cycle: u32) -> f32 {
+        if self.delay_randomness <= 0.0 {
+            return self.delay;
+        }
+        let rand = hash_to_float(seed.wrapping_add(hash(cycle)));
+        (self.delay + (rand * 2.0 - 1.0) * self.delay_randomness).max(0.0)
     }
 }
 
@@ -240,20 +306,60 @@ pub struct EmitterData {
     #[serde(default, skip_serializing_if = "is_zero_vec3")]
     pub position: Vec3,
 
+    /// If `true`, emission shape sizes, particle scales, and initial velocities are
+    /// multiplied by the emitter entity's world scale, so scaling the particle system
+    /// entity or one of its parents scales the effect with it.
+    ///
+    /// Particles take the scale they spawn with, so changing it only affects new ones.
+    /// Defaults to `false`, which keeps the effect at its authored size.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub inherit_scale: bool,
+
     /// Timing and lifecycle settings (lifetime, delay, one-shot, etc.).
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "EmitterTime::is_default")]
     pub time: EmitterTime,
 
+    /// Optional group of emitters whose emission cycles are synchronized.
+    ///
+    /// Emitters sharing a group within a particle system follow a single cycle clock,
+    /// driven by the group's first emitter, which wraps after the longest
+    /// [`EmitterTime::total_duration`] in the group. Shorter emitters stay idle until
+    /// the shared cycle restarts. Defaults to `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sync_group: Option<u32>,
+
+    /// If `true`, a [`one_shot`](EmitterTime::one_shot) emitter freezes its particles
+    /// when its cycle completes instead of letting them die.
+    ///
+    /// The simulation stops right before the cycle ends, so particles spawned at its
+    /// start hold their end-of-lifetime state. They stay rendered until the emitter is
+    /// cleared or restarted, or the particle system is despawned. Useful for decals
+    /// such as hit marks. Ignored by looping emitters. Defaults to `false`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub freeze_on_complete: bool,
+
     /// Draw pass configuration (mesh, material, draw order).
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "EmitterDrawPass::is_default")]
     pub draw_pass: EmitterDrawPass,
 
     /// Emission shape and particle count settings.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "EmitterEmission::is_default")]
     pub emission: EmitterEmission,
 
+    /// Share of the system's particle budget this emitter receives, relative to the
+    /// other emitters.
+    ///
+    /// Only used when [`ParticleSystemRuntime::particle_budget`](crate::runtime::ParticleSystemRuntime::particle_budget)
+    /// is set. An emitter with a weight of `3.0` gets three times the spawns of one
+    /// with `1.0`. Defaults to `1.0`.
+    #[serde(
+        default = "default_spawn_weight",
+        skip_serializing_if = "is_default_spawn_weight"
+    )]
+    pub spawn_weight: f32,
+
     /// Particle scale range and scale-over-lifetime curve.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "EmitterScale::is_default")]
     pub scale: EmitterScale,
 
     /// Initial particle rotation angle and angle-over-lifetime curve.
@@ -261,15 +367,15 @@ pub struct EmitterData {
     pub angle: EmitterAngle,
 
     /// Color and alpha settings, including gradients and curves over lifetime.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "EmitterColors::is_default")]
     pub colors: EmitterColors,
 
     /// Velocity settings (direction, spread, radial/angular velocity, etc.).
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "EmitterVelocities::is_default")]
     pub velocities: EmitterVelocities,
 
     /// Acceleration settings (gravity).
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "EmitterAccelerations::is_default")]
     pub accelerations: EmitterAccelerations,
 
     /// Turbulence noise settings for varying particle movement.
@@ -277,15 +383,31 @@ pub struct EmitterData {
     pub turbulence: EmitterTurbulence,
 
     /// Collision behavior settings.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "EmitterCollision::is_default")]
     pub collision: EmitterCollision,
 
     /// Optional sub-emitter configuration for spawning secondary particles.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sub_emitter: Option<SubEmitterConfig>,
 
+    /// Optional per-particle data written at spawn, for custom shaders to read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_data: Option<ParticleUserData>,
+
+    /// Optional region particles are confined to, in the emitter's local space.
+    ///
+    /// Particles that move outside of it are killed on the same frame, without
+    /// triggering sub-emitters. Defaults to `None`, which lets particles travel freely.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kill_box: Option<Aabb3d>,
+
+    /// Optional sphere particles are softly pulled back into when they stray past it,
+    /// centered on the emitter origin. Defaults to `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub containment: Option<Containment>,
+
     /// Bitflags controlling per-particle behavior (Y rotation, Z-axis disable, etc.).
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "ParticleFlags::is_empty")]
     #[reflect(ignore)]
     pub particle_flags: ParticleFlags,
 }
@@ -294,15 +416,27 @@ fn default_enabled() -> bool {
     true
 }
 
+fn default_spawn_weight() -> f32 {
+    1.0
+}
+
+fn is_default_spawn_weight(v: &f32) -> bool {
+    *v == default_spawn_weight()
+}
+
 impl Default for EmitterData {
     fn default() -> Self {
         Self {
             name: "Emitter".to_string(),
             enabled: true,
             position: Vec3::ZERO,
+            inherit_scale: false,
             time: EmitterTime::default(),
+            sync_group: None,
+            freeze_on_complete: false,
             draw_pass: EmitterDrawPass::default(),
             emission: EmitterEmission::default(),
+            spawn_weight: 1.0,
             scale: EmitterScale::default(),
             angle: EmitterAngle::default(),
             colors: EmitterColors::default(),
@@ -311,12 +445,113 @@ impl Default for EmitterData {
             turbulence: EmitterTurbulence::default(),
             collision: EmitterCollision::default(),
             sub_emitter: None,
+            user_data: None,
+            kill_box: None,
+            containment: None,
             particle_flags: ParticleFlags::empty(),
         }
     }
 }
 
+impl EmitterData {
+    /// Serializes this emitter on its own to pretty-printed RON, e.g. to share its
+    /// configuration in a bug report.
+    ///
+    /// Unlike [`ParticleSystemAsset::export_emitter_module`], the emitters it spawns as
+    /// sub-emitters aren't included.
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+
+    /// Returns the scale applied to this emitter's particles for an emitter entity with
+    /// the given world scale: `world_scale` if [`inherit_scale`](Self::inherit_scale) is
+    /// set, [`Vec3::ONE`] otherwise.
+    pub fn inherited_scale(&self, world_scale: Vec3) -> Vec3 {
+        if self.inherit_scale {
+            world_scale
+        } else {
+            Vec3::ONE
+        }
+    }
+}
+
+/// Per-particle data for custom shaders, exposed as the `user_data` field of each
+/// particle in the `sorted_particles` buffer.
+///
+/// Each component is picked uniformly between the matching components of
+/// [`min`](Self::min) and [`max`](Self::max) when the particle spawns, and stays
+/// constant over its lifetime.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default, Reflect)]
+pub struct ParticleUserData {
+    /// Lower bound for each component.
+    pub min: [f32; 4],
+    /// Upper bound for each component.
+    pub max: [f32; 4],
+}
+
+impl ParticleUserData {
+    /// Creates user data with the same value for every particle.
+    pub fn constant(value: [f32; 4]) -> Self {
+        Self {
+            min: value,
+            max: value,
+        }
+    }
+}
+
+fn default_containment_radius() -> f32 {
+    1.0
+}
+
+fn is_default_containment_radius(v: &f32) -> bool {
+    *v == default_containment_radius()
+}
+
+fn default_containment_strength() -> f32 {
+    10.0
+}
+
+fn is_default_containment_strength(v: &f32) -> bool {
+    *v == default_containment_strength()
+}
+
+/// A sphere around the emitter origin that keeps particles contained, e.g. inside a
+/// magic orb.
+///
+/// Particles beyond [`radius`](Self::radius) are accelerated back toward it in
+/// proportion to how far they strayed, and their outward motion is damped so they
+/// don't spring back out. Inside the sphere they move freely.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Reflect)]
+pub struct Containment {
+    /// Radius of the sphere, in the emitter's local space. Defaults to `1.0`.
+    #[serde(
+        default = "default_containment_radius",
+        skip_serializing_if = "is_default_containment_radius"
+    )]
+    pub radius: f32,
+    /// How hard particles are pulled back, as an acceleration per unit past the radius.
+    ///
+    /// Defaults to `10.0`.
+    #[serde(
+        default = "default_containment_strength",
+        skip_serializing_if = "is_default_containment_strength"
+    )]
+    pub strength: f32,
+}
+
+impl Default for Containment {
+    fn default() -> Self {
+        Self {
+            radius: default_containment_radius(),
+            strength: default_containment_strength(),
+        }
+    }
+}
+
 /// Controls how each particle's transform is aligned relative to the camera or its velocity.
+///
+/// Leaving [`EmitterDrawPass::transform_align`] unset renders particles as regular
+/// meshes instead, which suits 3D debris such as rocks or gibs.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, Reflect)]
 pub enum TransformAlign {
     /// Particles always face the camera (Z-billboard).
@@ -326,28 +561,87 @@ pub enum TransformAlign {
     YToVelocity,
     /// Particles face the camera and additionally align their Y axis to velocity.
     BillboardYToVelocity,
-    /// Particles face the camera with a fixed world-space Y axis.
+    /// Particles face the camera while keeping their Y axis along
+    /// [`EmitterDrawPass::billboard_axis`].
     BillboardFixedY,
+    /// Particles face the camera but ignore its roll, keeping their Y axis along the
+    /// world's up direction as seen on screen, for sprite-like 2.5D effects.
+    BillboardScreenAligned,
+}
+
+/// The axis particles keep as their Y axis with [`TransformAlign::BillboardFixedY`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default, Reflect)]
+pub enum BillboardAxis {
+    /// The world's +Y axis.
+    #[default]
+    WorldUp,
+    /// The emitter's +Y axis, following its rotation.
+    EmitterUp,
+    /// A custom world-space axis.
+    Custom {
+        /// The axis direction. Doesn't need to be normalized.
+        axis: Vec3,
+    },
+}
+
+impl BillboardAxis {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the axis as passed to the particle material: a normalized direction in
+    /// `xyz`, and `1.0` in `w` when it's in the emitter's local space.
+    ///
+    /// A zero custom axis falls back to +Y.
+    pub fn to_uniform(&self) -> Vec4 {
+        match self {
+            Self::WorldUp => Vec3::Y.extend(0.0),
+            Self::EmitterUp => Vec3::Y.extend(1.0),
+            Self::Custom { axis } => axis.normalize_or(Vec3::Y).extend(0.0),
+        }
+    }
 }
 
 /// Configuration for how particles are rendered in a single draw pass.
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 pub struct EmitterDrawPass {
     /// The order in which particles are drawn. Defaults to [`DrawOrder::Index`].
     #[serde(default, skip_serializing_if = "DrawOrder::is_default")]
     pub draw_order: DrawOrder,
-    /// The mesh shape used to render each particle.
+    /// The mesh shape used to render each particle. Defaults to a sphere of radius `1.0`.
+    #[serde(default, skip_serializing_if = "ParticleMesh::is_default")]
     pub mesh: ParticleMesh,
     /// The material applied to the particle mesh. Defaults to a standard PBR material.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "DrawPassMaterial::is_default")]
     pub material: DrawPassMaterial,
     /// Whether particles cast shadows. Defaults to `true`.
     #[serde(default = "default_shadow_caster", skip_serializing_if = "is_true")]
     pub shadow_caster: bool,
     /// Optional transform alignment mode for particles. When `None`, no special
-    /// alignment is applied.
+    /// alignment is applied: each particle is drawn as a full mesh, turned only by
+    /// its own rotation and never toward the camera.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transform_align: Option<TransformAlign>,
+    /// The axis kept upright by [`TransformAlign::BillboardFixedY`]; other alignment
+    /// modes ignore it. Defaults to [`BillboardAxis::WorldUp`].
+    #[serde(default, skip_serializing_if = "BillboardAxis::is_default")]
+    pub billboard_axis: BillboardAxis,
+    /// Sort keys closer than this are treated as equal, such as view depths that differ
+    /// only by floating-point noise. Equal keys are ordered by particle index, so they
+    /// stay in the same order from frame to frame instead of flickering.
+    ///
+    /// Ignored by [`DrawOrder::Index`]. Defaults to `0.0`, which compares keys exactly.
+    #[serde(default, skip_serializing_if = "is_zero_f32")]
+    pub sort_precision: f32,
+    /// Biases the render order between emitters of the same particle system. Emitters
+    /// with a lower priority are drawn first, so higher priorities end up on top, e.g.
+    /// sparks over smoke.
+    ///
+    /// Emitters sharing a priority are drawn in reverse order, so the first emitter
+    /// ends up on top. Only affects transparent materials, which are drawn back to front.
+    /// Defaults to `0`.
+    #[serde(default, skip_serializing_if = "is_zero_i32")]
+    pub draw_priority: i32,
 }
 
 fn default_shadow_caster() -> bool {
@@ -362,10 +656,19 @@ impl Default for EmitterDrawPass {
             material: DrawPassMaterial::default(),
             shadow_caster: true,
             transform_align: None,
+            billboard_axis: BillboardAxis::default(),
+            sort_precision: 0.0,
+            draw_priority: 0,
         }
     }
 }
 
+impl EmitterDrawPass {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// The axis a quad particle mesh faces by default.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Reflect)]
 pub enum QuadOrientation {
@@ -458,6 +761,12 @@ impl Default for ParticleMesh {
     }
 }
 
+impl ParticleMesh {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// A minimum/maximum range of `f32` values, used to randomize particle properties.
 ///
 /// When a particle is spawned, a random value between [`min`](Self::min) and
@@ -519,12 +828,18 @@ pub enum EmissionShape {
         /// Radius of the emission sphere surface.
         radius: f32,
     },
-    /// Particles are emitted within the volume of a box.
+    /// Particles are emitted within the volume of a box, or only on its faces.
     ///
     /// The extents define the half-size along each axis. The actual box is twice as large.
     Box {
         /// Half-extents of the emission box along each axis.
         extents: Vec3,
+        /// Emits only on the six faces of the box, for hollow shapes. Larger faces receive
+        /// proportionally more particles.
+        ///
+        /// Defaults to `false`, which fills the box's volume.
+        #[serde(default, skip_serializing_if = "is_false")]
+        surface_only: bool,
     },
     /// Particles are emitted within a ring or cylinder shape.
     Ring {
@@ -536,6 +851,12 @@ pub enum EmissionShape {
         radius: f32,
         /// The inner radius of the ring. A value of `0.0` fills the entire disc.
         inner_radius: f32,
+        /// Biases spawn positions across the ring's thickness, from `-1.0` (packed
+        /// against the inner radius) to `1.0` (packed against the outer radius).
+        ///
+        /// Defaults to `0.0`, which spreads particles evenly over the ring's area.
+        #[serde(default, skip_serializing_if = "is_zero_f32")]
+        radial_bias: f32,
     },
 }
 
@@ -545,6 +866,38 @@ impl EmissionShape {
     }
 }
 
+/// What drives the spawning of an emitter's particles.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default, Reflect)]
+pub enum EmissionMode {
+    /// Particles are spawned over time, spread across each emission cycle.
+    #[default]
+    Time,
+    /// One particle is spawned every `spacing` world units the emitter entity moves, for
+    /// effects like footprints or skid marks. Nothing is spawned while it stands still.
+    ///
+    /// [`particles_amount`](EmitterEmission::particles_amount) caps how many of these
+    /// particles are alive at once, with the oldest replaced first.
+    PerDistance {
+        /// Distance the emitter entity travels between two spawns, in world units.
+        spacing: f32,
+    },
+}
+
+impl EmissionMode {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the spacing between spawns for [`PerDistance`](Self::PerDistance), or
+    /// `None` for time-based emission or a spacing that isn't positive.
+    pub fn spacing(&self) -> Option<f32> {
+        match *self {
+            Self::Time => None,
+            Self::PerDistance { spacing } => (spacing > 0.0).then_some(spacing),
+        }
+    }
+}
+
 fn default_emission_scale() -> Vec3 {
     Vec3::ONE
 }
@@ -553,8 +906,12 @@ fn default_particles_amount() -> u32 {
     8
 }
 
+fn is_default_particles_amount(v: &u32) -> bool {
+    *v == default_particles_amount()
+}
+
 /// Emission configuration: shape, offset, scale, and particle count.
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 pub struct EmitterEmission {
     /// Position offset of the emission shape in local space. Defaults to [`Vec3::ZERO`].
     #[serde(default, skip_serializing_if = "is_zero_vec3")]
@@ -568,11 +925,32 @@ pub struct EmitterEmission {
     /// The shape of the emission region. Defaults to [`EmissionShape::Point`].
     #[serde(default, skip_serializing_if = "EmissionShape::is_default")]
     pub shape: EmissionShape,
+    /// Maximum random offset added to each particle's spawn position on each axis,
+    /// on top of the [`shape`](Self::shape) sampling. Defaults to [`Vec3::ZERO`].
+    #[serde(default, skip_serializing_if = "is_zero_vec3")]
+    pub position_jitter: Vec3,
+    /// Distance each particle is moved forward along its initial velocity when it spawns,
+    /// so particles of high spread emitters don't start bunched up at the same point.
+    ///
+    /// Particles spawned without velocity stay where they are. Defaults to `0.0`.
+    #[serde(default, skip_serializing_if = "is_zero_f32")]
+    pub spawn_offset_along_velocity: f32,
     /// The number of particles to emit in one emission cycle.
     ///
     /// Higher values will increase GPU load. Defaults to `8`.
-    #[serde(default = "default_particles_amount")]
+    #[serde(
+        default = "default_particles_amount",
+        skip_serializing_if = "is_default_particles_amount"
+    )]
     pub particles_amount: u32,
+    /// What drives the spawning of particles. Defaults to [`EmissionMode::Time`].
+    #[serde(default, skip_serializing_if = "EmissionMode::is_default")]
+    pub mode: EmissionMode,
+    /// Optional discrete bursts spawned instead of spreading particles over the cycle.
+    ///
+    /// Ignored by [`PerDistance`](EmissionMode::PerDistance) emitters. Defaults to `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub burst: Option<Burst>,
 }
 
 impl Default for EmitterEmission {
@@ -581,29 +959,140 @@ impl Default for EmitterEmission {
             offset: Vec3::ZERO,
             scale: Vec3::ONE,
             shape: EmissionShape::default(),
+            position_jitter: Vec3::ZERO,
+            spawn_offset_along_velocity: 0.0,
             particles_amount: 8,
+            mode: EmissionMode::default(),
+            burst: None,
+        }
+    }
+}
+
+impl EmitterEmission {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the [`burst`](Self::burst) this emitter spawns particles with, or `None`
+    /// when it has none, it spawns nothing, or the emitter spawns
+    /// [`PerDistance`](EmissionMode::PerDistance).
+    pub fn active_burst(&self) -> Option<&Burst> {
+        self.burst
+            .as_ref()
+            .filter(|burst| burst.count > 0 && burst.cycles > 0 && self.mode.spacing().is_none())
+    }
+}
+
+fn default_burst_count() -> u32 {
+    8
+}
+
+fn is_default_burst_count(v: &u32) -> bool {
+    *v == default_burst_count()
+}
+
+fn default_burst_cycles() -> u32 {
+    1
+}
+
+fn is_default_burst_cycles(v: &u32) -> bool {
+    *v == default_burst_cycles()
+}
+
+fn default_burst_interval() -> f32 {
+    0.0
+}
+
+fn is_default_burst_interval(v: &f32) -> bool {
+    *v == default_burst_interval()
+}
+
+/// Discrete bursts of particles spawned at fixed intervals in each emission cycle, e.g.
+/// for a single puff of smoke.
+///
+/// The first burst goes off when emission starts, after the cycle's
+/// [`delay`](EmitterTime::delay), and each following one [`interval`](Self::interval)
+/// seconds later. Bursts that would go off after the end of the cycle are skipped.
+/// [`particles_amount`](EmitterEmission::particles_amount) caps how many of these
+/// particles are alive at once, with the oldest replaced first.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Reflect)]
+pub struct Burst {
+    /// Number of particles spawned by each burst. Defaults to `8`.
+    #[serde(
+        default = "default_burst_count",
+        skip_serializing_if = "is_default_burst_count"
+    )]
+    pub count: u32,
+    /// Number of bursts in each emission cycle. Defaults to `1`.
+    #[serde(
+        default = "default_burst_cycles",
+        skip_serializing_if = "is_default_burst_cycles"
+    )]
+    pub cycles: u32,
+    /// Time between the start of two bursts, in seconds. Defaults to `0.0`.
+    #[serde(
+        default = "default_burst_interval",
+        skip_serializing_if = "is_default_burst_interval"
+    )]
+    pub interval: f32,
+}
+
+impl Default for Burst {
+    fn default() -> Self {
+        Self {
+            count: default_burst_count(),
+            cycles: default_burst_cycles(),
+            interval: default_burst_interval(),
         }
     }
 }
 
+impl Burst {
+    /// Returns when each burst goes off, in seconds after emission starts.
+    pub fn times(&self) -> impl Iterator<Item = f32> + '_ {
+        (0..self.cycles).map(|i| i as f32 * self.interval.max(0.0))
+    }
+
+    /// Returns how many particles the bursts going off from `start` up to, but not
+    /// including, `end` spawn, both in seconds after emission starts.
+    pub fn spawns_between(&self, start: f32, end: f32) -> u32 {
+        let bursts = self.times().filter(|t| *t >= start && *t < end).count() as u32;
+        bursts.saturating_mul(self.count)
+    }
+}
+
 fn default_scale_range() -> Range {
     Range { min: 1.0, max: 1.0 }
 }
 
+fn is_default_scale_range(v: &Range) -> bool {
+    *v == default_scale_range()
+}
+
 /// Particle scale configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 pub struct EmitterScale {
     /// The initial scale range applied to each particle.
     ///
     /// A random value between `min` and `max` is selected at spawn time.
     /// Defaults to `1.0..1.0`.
-    #[serde(default = "default_scale_range")]
+    #[serde(
+        default = "default_scale_range",
+        skip_serializing_if = "is_default_scale_range"
+    )]
     pub range: Range,
     /// Optional curve that modulates each particle's scale over its lifetime.
     ///
     /// The curve value is multiplied with the initial scale.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scale_over_lifetime: Option<CurveTexture>,
+    /// Fraction of each particle's lifetime over which its scale eases in from zero, so
+    /// particles don't pop in at full size.
+    ///
+    /// Multiplied with [`scale_over_lifetime`](Self::scale_over_lifetime). Clamped to
+    /// `0.0..=1.0`, where `0.0` disables it. Defaults to `0.0`.
+    #[serde(default, skip_serializing_if = "is_zero_f32")]
+    pub scale_in_time: f32,
 }
 
 impl Default for EmitterScale {
@@ -611,29 +1100,55 @@ impl Default for EmitterScale {
         Self {
             range: default_scale_range(),
             scale_over_lifetime: None,
+            scale_in_time: 0.0,
         }
     }
 }
 
+impl EmitterScale {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// Color and alpha configuration for particles.
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 pub struct EmitterColors {
     /// Each particle's initial color. Can be a solid color or a gradient from which a random
     /// color is sampled at spawn time. Defaults to opaque white.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "SolidOrGradientColor::is_default")]
     pub initial_color: SolidOrGradientColor,
     /// Gradient that modulates each particle's color over its lifetime.
     ///
     /// The particle's initial color is multiplied by the gradient value at the
     /// corresponding lifetime position. Defaults to a constant white gradient.
-    #[serde(default = "Gradient::white")]
+    ///
+    /// Ignored while [`color_channels_over_lifetime`](Self::color_channels_over_lifetime)
+    /// is set.
+    #[serde(
+        default = "Gradient::white",
+        skip_serializing_if = "Gradient::is_white"
+    )]
     pub color_over_lifetime: Gradient,
+    /// Optional red, green, blue and alpha curves that modulate each particle's color
+    /// over its lifetime independently.
+    ///
+    /// When set, these replace [`color_over_lifetime`](Self::color_over_lifetime)
+    /// entirely: the curves are baked into one texture in its place, with the color
+    /// channels read like gradient colors. [`alpha_over_lifetime`](Self::alpha_over_lifetime)
+    /// still applies on top. Defaults to `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color_channels_over_lifetime: Option<[CurveTexture; 4]>,
     /// Optional curve that modulates each particle's alpha over its lifetime.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub alpha_over_lifetime: Option<CurveTexture>,
     /// Optional curve that modulates the emissive intensity over each particle's lifetime.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub emission_over_lifetime: Option<CurveTexture>,
+    /// Optional noise that makes each particle's brightness flicker over time, for fire
+    /// and energy effects. Defaults to `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub brightness_flicker: Option<FlickerConfig>,
 }
 
 impl Default for EmitterColors {
@@ -641,8 +1156,63 @@ impl Default for EmitterColors {
         Self {
             initial_color: SolidOrGradientColor::default(),
             color_over_lifetime: Gradient::white(),
+            color_channels_over_lifetime: None,
             alpha_over_lifetime: None,
             emission_over_lifetime: None,
+            brightness_flicker: None,
+        }
+    }
+}
+
+impl EmitterColors {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+fn default_flicker_amplitude() -> f32 {
+    0.3
+}
+
+fn is_default_flicker_amplitude(v: &f32) -> bool {
+    *v == default_flicker_amplitude()
+}
+
+fn default_flicker_frequency() -> f32 {
+    10.0
+}
+
+fn is_default_flicker_frequency(v: &f32) -> bool {
+    *v == default_flicker_frequency()
+}
+
+/// Brightness noise applied to each particle's color, evaluated on the GPU.
+///
+/// Every particle follows its own stretch of smooth noise, so neighbours flicker out of
+/// sync. The color is scaled by `1.0 ± amplitude`, never going below black.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Reflect)]
+pub struct FlickerConfig {
+    /// How far the brightness strays from its unflickered value, as a fraction of it.
+    ///
+    /// Defaults to `0.3`.
+    #[serde(
+        default = "default_flicker_amplitude",
+        skip_serializing_if = "is_default_flicker_amplitude"
+    )]
+    pub amplitude: f32,
+    /// How many times per second the noise picks a new brightness. Defaults to `10.0`.
+    #[serde(
+        default = "default_flicker_frequency",
+        skip_serializing_if = "is_default_flicker_frequency"
+    )]
+    pub frequency: f32,
+}
+
+impl Default for FlickerConfig {
+    fn default() -> Self {
+        Self {
+            amplitude: default_flicker_amplitude(),
+            frequency: default_flicker_frequency(),
         }
     }
 }
@@ -651,12 +1221,28 @@ fn default_direction() -> Vec3 {
     Vec3::X
 }
 
+fn is_default_direction(v: &Vec3) -> bool {
+    *v == default_direction()
+}
+
 fn default_spread() -> f32 {
     45.0
 }
 
+fn is_default_spread(v: &f32) -> bool {
+    *v == default_spread()
+}
+
+fn default_inherit_velocity_max() -> f32 {
+    50.0
+}
+
+fn is_default_inherit_velocity_max(v: &f32) -> bool {
+    *v == default_inherit_velocity_max()
+}
+
 /// A velocity value with an optional curve for animation over a particle's lifetime.
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 pub struct AnimatedVelocity {
     /// The initial velocity range. A random value between `min` and `max` is
     /// selected at spawn time. Defaults to zero.
@@ -676,6 +1262,59 @@ impl Default for AnimatedVelocity {
     }
 }
 
+impl AnimatedVelocity {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+fn default_wobble_amplitude() -> Vec3 {
+    Vec3::splat(0.1)
+}
+
+fn is_default_wobble_amplitude(v: &Vec3) -> bool {
+    *v == default_wobble_amplitude()
+}
+
+fn default_wobble_frequency() -> f32 {
+    1.0
+}
+
+fn is_default_wobble_frequency(v: &f32) -> bool {
+    *v == default_wobble_frequency()
+}
+
+/// A sinusoidal positional offset applied to each particle, evaluated on the GPU.
+///
+/// Every particle starts at its own random phase on each axis, so neighbours sway out of
+/// sync. The offset doesn't feed back into the particle's velocity: particles oscillate
+/// around the path they would otherwise follow.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Reflect)]
+pub struct WobbleConfig {
+    /// Largest distance a particle strays from its path on each axis. Defaults to
+    /// `Vec3::splat(0.1)`.
+    #[serde(
+        default = "default_wobble_amplitude",
+        skip_serializing_if = "is_default_wobble_amplitude"
+    )]
+    pub amplitude: Vec3,
+    /// How many full oscillations a particle makes per second. Defaults to `1.0`.
+    #[serde(
+        default = "default_wobble_frequency",
+        skip_serializing_if = "is_default_wobble_frequency"
+    )]
+    pub frequency: f32,
+}
+
+impl Default for WobbleConfig {
+    fn default() -> Self {
+        Self {
+            amplitude: default_wobble_amplitude(),
+            frequency: default_wobble_frequency(),
+        }
+    }
+}
+
 /// Initial rotation angle and animated rotation for particles.
 ///
 /// Only applied when [`ParticleFlags::DISABLE_Z`] or [`ParticleFlags::ROTATE_Y`] are set,
@@ -707,15 +1346,18 @@ impl EmitterAngle {
 }
 
 /// Velocity settings for particles, including direction, spread, and animated velocities.
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 pub struct EmitterVelocities {
     /// Unit vector specifying the base emission direction. Defaults to `Vec3::X`.
-    #[serde(default = "default_direction")]
+    #[serde(
+        default = "default_direction",
+        skip_serializing_if = "is_default_direction"
+    )]
     pub initial_direction: Vec3,
     /// The angular spread in degrees. Each particle's initial direction varies from
     /// +spread to -spread relative to [`initial_direction`](Self::initial_direction).
     /// Defaults to `45.0`.
-    #[serde(default = "default_spread")]
+    #[serde(default = "default_spread", skip_serializing_if = "is_default_spread")]
     pub spread: f32,
     /// Amount of spread flattening along the Y axis.
     ///
@@ -729,13 +1371,13 @@ pub struct EmitterVelocities {
     pub initial_velocity: Range,
     /// Radial velocity that pushes particles away from (or toward, if negative) the
     /// [`pivot`](Self::pivot) point.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "AnimatedVelocity::is_default")]
     pub radial_velocity: AnimatedVelocity,
     /// Angular (rotation) velocity applied to each particle, in degrees per second.
     ///
     /// Only applied when [`ParticleFlags::DISABLE_Z`] or [`ParticleFlags::ROTATE_Y`] are set,
     /// or when using billboard rendering.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "AnimatedVelocity::is_default")]
     pub angular_velocity: AnimatedVelocity,
     /// The pivot point used to calculate radial and orbital velocity.
     ///
@@ -747,6 +1389,27 @@ pub struct EmitterVelocities {
     /// Defaults to `0.0`.
     #[serde(default, skip_serializing_if = "is_zero_f32")]
     pub inherit_ratio: f32,
+    /// Maximum speed a particle can inherit from the emitter entity, so sudden jumps of
+    /// the parent don't fling particles across the scene.
+    ///
+    /// Applied after [`inherit_ratio`](Self::inherit_ratio). Defaults to `50.0`.
+    #[serde(
+        default = "default_inherit_velocity_max",
+        skip_serializing_if = "is_default_inherit_velocity_max"
+    )]
+    pub inherit_velocity_max: f32,
+    /// Blends [`initial_direction`](Self::initial_direction) toward the outward surface
+    /// normal at each particle's spawn point.
+    ///
+    /// Only affects the surface emission shapes, [`EmissionShape::SphereSurface`] and
+    /// [`EmissionShape::Box`] with `surface_only` set. `0.0` keeps the initial direction,
+    /// `1.0` emits straight out of the surface. Defaults to `0.0`.
+    #[serde(default, skip_serializing_if = "is_zero_f32")]
+    pub emit_along_normal: f32,
+    /// Optional sinusoidal sway added on top of each particle's movement, for drifting
+    /// snow, bubbles or fireflies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wobble: Option<WobbleConfig>,
 }
 
 impl Default for EmitterVelocities {
@@ -760,44 +1423,111 @@ impl Default for EmitterVelocities {
             angular_velocity: AnimatedVelocity::default(),
             pivot: Vec3::ZERO,
             inherit_ratio: 0.0,
+            inherit_velocity_max: 50.0,
+            emit_along_normal: 0.0,
+            wobble: None,
         }
     }
 }
 
+impl EmitterVelocities {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 fn default_gravity() -> Vec3 {
     Vec3::new(0.0, -9.8, 0.0)
 }
 
+fn is_default_gravity(v: &Vec3) -> bool {
+    *v == default_gravity()
+}
+
+fn default_gravity_scale() -> f32 {
+    1.0
+}
+
+fn is_default_gravity_scale(v: &f32) -> bool {
+    *v == default_gravity_scale()
+}
+
 /// Acceleration forces applied to every particle.
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 pub struct EmitterAccelerations {
     /// Gravity vector applied to every particle, in units per second squared.
     ///
     /// Defaults to `(0.0, -9.8, 0.0)`.
-    #[serde(default = "default_gravity")]
+    #[serde(
+        default = "default_gravity",
+        skip_serializing_if = "is_default_gravity"
+    )]
     pub gravity: Vec3,
+    /// Multiplier applied to [`gravity`](Self::gravity), so emitters sharing the same
+    /// gravity can fall faster or float, like embers at `0.2`.
+    ///
+    /// `0.0` makes particles ignore gravity entirely. Defaults to `1.0`.
+    #[serde(
+        default = "default_gravity_scale",
+        skip_serializing_if = "is_default_gravity_scale"
+    )]
+    pub gravity_scale: f32,
+    /// Maximum magnitude of a constant random acceleration added to each particle on top of
+    /// [`gravity`](Self::gravity), per axis, in units per second squared.
+    ///
+    /// Every particle picks its own value within `-randomness..=randomness` from its seed
+    /// and keeps it for its whole lifetime, giving chaotic paths without the cost of
+    /// turbulence. Defaults to [`Vec3::ZERO`].
+    #[serde(default, skip_serializing_if = "is_zero_vec3")]
+    pub acceleration_randomness: Vec3,
 }
 
 impl Default for EmitterAccelerations {
     fn default() -> Self {
         Self {
             gravity: Vec3::new(0.0, -9.8, 0.0),
+            gravity_scale: 1.0,
+            acceleration_randomness: Vec3::ZERO,
         }
     }
 }
 
+impl EmitterAccelerations {
+    /// Returns [`gravity`](Self::gravity) multiplied by [`gravity_scale`](Self::gravity_scale),
+    /// the acceleration particles actually fall with.
+    pub fn scaled_gravity(&self) -> Vec3 {
+        self.gravity * self.gravity_scale
+    }
+
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 fn default_turbulence_noise_strength() -> f32 {
     1.0
 }
 
+fn is_default_turbulence_noise_strength(v: &f32) -> bool {
+    *v == default_turbulence_noise_strength()
+}
+
 fn default_turbulence_noise_scale() -> f32 {
     2.5
 }
 
+fn is_default_turbulence_noise_scale(v: &f32) -> bool {
+    *v == default_turbulence_noise_scale()
+}
+
 fn default_turbulence_influence() -> Range {
     Range { min: 0.0, max: 0.1 }
 }
 
+fn is_default_turbulence_influence(v: &Range) -> bool {
+    *v == default_turbulence_influence()
+}
+
 /// Turbulence noise settings for varying particle movement based on position.
 ///
 /// Turbulence uses a 3D noise pattern to displace particles, creating organic,
@@ -810,13 +1540,19 @@ pub struct EmitterTurbulence {
     pub enabled: bool,
     /// The turbulence noise strength. Higher values produce a stronger, more
     /// contrasting flow pattern. Defaults to `1.0`.
-    #[serde(default = "default_turbulence_noise_strength")]
+    #[serde(
+        default = "default_turbulence_noise_strength",
+        skip_serializing_if = "is_default_turbulence_noise_strength"
+    )]
     pub noise_strength: f32,
     /// Overall scale/frequency of the turbulence noise pattern.
     ///
     /// A small scale produces smaller features with more detail, while a large
     /// scale produces smoother noise with larger features. Defaults to `2.5`.
-    #[serde(default = "default_turbulence_noise_scale")]
+    #[serde(
+        default = "default_turbulence_noise_scale",
+        skip_serializing_if = "is_default_turbulence_noise_scale"
+    )]
     pub noise_scale: f32,
     /// Scrolling velocity for the turbulence field, setting a directional trend
     /// for the noise pattern over time. Defaults to [`Vec3::ZERO`] (no scrolling).
@@ -833,7 +1569,10 @@ pub struct EmitterTurbulence {
     /// A random value between `min` and `max` is selected per particle, then
     /// multiplied by [`influence_over_lifetime`](Self::influence_over_lifetime)
     /// if provided. Defaults to `0.0..0.1`.
-    #[serde(default = "default_turbulence_influence")]
+    #[serde(
+        default = "default_turbulence_influence",
+        skip_serializing_if = "is_default_turbulence_influence"
+    )]
     pub influence: Range,
     /// Optional curve that modulates turbulence influence over each particle's lifetime.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -874,8 +1613,12 @@ fn default_collision_base_size() -> f32 {
     0.01
 }
 
+fn is_default_collision_base_size(v: &f32) -> bool {
+    *v == default_collision_base_size()
+}
+
 /// How particles behave when they collide with a [`ParticlesCollider3D`](crate::ParticlesCollider3D).
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 pub enum EmitterCollisionMode {
     /// Rigid-body style collision. Particles bounce off surfaces.
     Rigid {
@@ -901,7 +1644,7 @@ impl Default for EmitterCollisionMode {
 }
 
 /// Particle collision configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 pub struct EmitterCollision {
     /// The collision mode. When `None`, collision is disabled and particles pass
     /// through colliders. Defaults to `None`.
@@ -916,7 +1659,10 @@ pub struct EmitterCollision {
     /// If particles appear to sink into the ground, increase this value. If they
     /// appear to float above surfaces, decrease it. Particles always use a spherical
     /// collision shape. Defaults to `0.01`.
-    #[serde(default = "default_collision_base_size")]
+    #[serde(
+        default = "default_collision_base_size",
+        skip_serializing_if = "is_default_collision_base_size"
+    )]
     pub base_size: f32,
 }
 
@@ -930,6 +1676,12 @@ impl Default for EmitterCollision {
     }
 }
 
+impl EmitterCollision {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// When a sub-emitter spawns its particles.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
 pub enum SubEmitterMode {
@@ -947,10 +1699,18 @@ fn default_sub_emitter_frequency() -> f32 {
     4.0
 }
 
+fn is_default_sub_emitter_frequency(v: &f32) -> bool {
+    *v == default_sub_emitter_frequency()
+}
+
 fn default_sub_emitter_amount() -> u32 {
     1
 }
 
+fn is_default_sub_emitter_amount(v: &u32) -> bool {
+    *v == default_sub_emitter_amount()
+}
+
 /// Configuration for a sub-emitter that spawns secondary particles from parent particles.
 ///
 /// Sub-emitters can be used to achieve effects such as fireworks, sparks on collision,
@@ -964,16 +1724,32 @@ pub struct SubEmitterConfig {
     /// How often particles are emitted from the sub-emitter, in seconds.
     ///
     /// Only used when [`mode`](Self::mode) is [`SubEmitterMode::Constant`]. Defaults to `4.0`.
-    #[serde(default = "default_sub_emitter_frequency")]
+    #[serde(
+        default = "default_sub_emitter_frequency",
+        skip_serializing_if = "is_default_sub_emitter_frequency"
+    )]
     pub frequency: f32,
     /// The number of particles to spawn per trigger event. Defaults to `1`.
-    #[serde(default = "default_sub_emitter_amount")]
+    #[serde(
+        default = "default_sub_emitter_amount",
+        skip_serializing_if = "is_default_sub_emitter_amount"
+    )]
     pub amount: u32,
     /// If `true`, the sub-emitted particles inherit the parent particle's velocity.
     ///
     /// Defaults to `false`.
     #[serde(default, skip_serializing_if = "is_false")]
     pub keep_velocity: bool,
+    /// If `true`, the sub-emitted particles follow the parent particle as it moves,
+    /// simulating in its local space instead of world space. Once the parent particle
+    /// dies they carry on from where they are, so this has no effect with
+    /// [`SubEmitterMode::AtEnd`].
+    ///
+    /// Only the parent's position is followed, not its rotation or scale. The parent's
+    /// velocity isn't inherited even with [`keep_velocity`](Self::keep_velocity), since
+    /// the particles already move along with it. Defaults to `false`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub keep_local: bool,
 }
 
 impl Default for SubEmitterConfig {
@@ -984,10 +1760,21 @@ impl Default for SubEmitterConfig {
             frequency: default_sub_emitter_frequency(),
             amount: default_sub_emitter_amount(),
             keep_velocity: false,
+            keep_local: false,
         }
     }
 }
 
+impl SubEmitterConfig {
+    /// Returns the most sub-emitter events a parent emitter with `parent_amount` particles
+    /// can produce in a single simulation step, when all of its particles trigger at once.
+    ///
+    /// Events beyond the target emitter's capacity are dropped on the GPU.
+    pub fn max_events_per_step(&self, parent_amount: u32) -> u32 {
+        parent_amount.saturating_mul(self.amount)
+    }
+}
+
 /// The 3D shape of a particle collider.
 #[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub enum ParticlesColliderShape3D {
@@ -1060,11 +1847,22 @@ pub struct ParticleSystemAsset {
     /// The list of emitters that make up this particle system.
     pub emitters: Vec<EmitterData>,
     /// Optional colliders that particles can interact with.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub colliders: Vec<ColliderData>,
     /// Optional attribution information.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub authors: Option<ParticleSystemAuthors>,
+    /// Axes the whole system is mirrored across, relative to the particle system entity.
+    ///
+    /// Mirroring an axis negates it on emitter and collider positions, on each particle's
+    /// spawn position and velocity, and on gravity, so an effect authored on one side
+    /// plays on the other. Defaults to no mirroring.
+    #[serde(default, skip_serializing_if = "is_unmirrored")]
+    pub mirror: BVec3,
+}
+
+fn is_unmirrored(mirror: &BVec3) -> bool {
+    !mirror.any()
 }
 
 impl ParticleSystemAsset {
@@ -1083,6 +1881,276 @@ impl ParticleSystemAsset {
             emitters,
             colliders,
             authors,
+            mirror: BVec3::FALSE,
+        }
+    }
+
+    /// Returns the per-axis scale applied by [`mirror`](Self::mirror): `-1.0` on mirrored
+    /// axes and `1.0` elsewhere.
+    pub fn mirror_scale(&self) -> Vec3 {
+        Vec3::select(self.mirror, Vec3::NEG_ONE, Vec3::ONE)
+    }
+
+    /// Returns the duration of the emission cycle followed by the emitter at `index`.
+    ///
+    /// This is the emitter's own [`EmitterTime::total_duration`], or the longest one in
+    /// its [`sync_group`](EmitterData::sync_group).
+    pub fn cycle_duration(&self, index: usize) -> f32 {
+        let Some(emitter) = self.emitters.get(index) else {
+            return 0.0;
+        };
+        let Some(group) = emitter.sync_group else {
+            return emitter.time.total_duration();
+        };
+        self.emitters
+            .iter()
+            .filter(|e| e.sync_group == Some(group))
+            .map(|e| e.time.total_duration())
+            .fold(0.0, f32::max)
+    }
+
+    /// Returns the length of one playback of the whole system, in seconds.
+    ///
+    /// This is the longest [`EmitterTime::total_duration`] among emitters that aren't
+    /// sub-emitter targets, or `0.0` if there are none.
+    pub fn playback_duration(&self) -> f32 {
+        self.source_emitters()
+            .map(|e| e.time.total_duration())
+            .fold(0.0, f32::max)
+    }
+
+    /// Returns `true` if any emitter that isn't a sub-emitter target keeps repeating
+    /// its emission cycle.
+    pub fn is_looping(&self) -> bool {
+        self.source_emitters().any(|e| !e.time.one_shot)
+    }
+
+    /// Splits a particle budget between the emitters of this system, returning the
+    /// number of particles each emitter may keep alive, indexed like
+    /// [`emitters`](Self::emitters).
+    ///
+    /// The budget is shared by enabled emitters that aren't sub-emitter targets, in
+    /// proportion to their [`EmitterData::spawn_weight`]. No emitter receives more than
+    /// its own [`particles_amount`](EmitterEmission::particles_amount); the remainder is
+    /// redistributed among the others. Sub-emitter targets keep their full amount, and
+    /// disabled emitters receive nothing.
+    pub fn budgeted_amounts(&self, budget: u32) -> Vec<u32> {
+        let is_target = |index: usize| {
+            self.emitters.iter().any(|e| {
+                e.sub_emitter
+                    .as_ref()
+                    .is_some_and(|s| s.target_emitter == index)
+            })
+        };
+
+        let mut amounts = vec![0; self.emitters.len()];
+        let mut pending = Vec::new();
+        for (index, emitter) in self.emitters.iter().enumerate() {
+            if !emitter.enabled {
+                continue;
+            }
+            if is_target(index) {
+                amounts[index] = emitter.emission.particles_amount;
+            } else if emitter.spawn_weight > 0.0 {
+                pending.push(index);
+            }
+        }
+
+        let mut remaining = budget as f32;
+        loop {
+            let total_weight: f32 = pending.iter().map(|&i| self.emitters[i].spawn_weight).sum();
+            if total_weight <= 0.0 {
+                break;
+            }
+            let share = |i: usize| remaining * self.emitters[i].spawn_weight / total_weight;
+            let (saturated, unsaturated): (Vec<usize>, Vec<usize>) = pending
+                .iter()
+                .partition(|&&i| share(i) >= self.emitters[i].emission.particles_amount as f32);
+
+            if saturated.is_empty() {
+                for &i in &pending {
+                    amounts[i] = share(i) as u32;
+                }
+                break;
+            }
+            for &i in &saturated {
+                amounts[i] = self.emitters[i].emission.particles_amount;
+                remaining -= amounts[i] as f32;
+            }
+            pending = unsaturated;
+        }
+
+        amounts
+    }
+
+    fn source_emitters(&self) -> impl Iterator<Item = &EmitterData> {
+        let is_target = |index: usize| {
+            self.emitters.iter().any(|e| {
+                e.sub_emitter
+                    .as_ref()
+                    .is_some_and(|s| s.target_emitter == index)
+            })
+        };
+        self.emitters
+            .iter()
+            .enumerate()
+            .filter(move |(index, _)| !is_target(*index))
+            .map(|(_, e)| e)
+    }
+
+    /// Inserts an emitter at `index`, shifting later emitters up.
+    ///
+    /// [`SubEmitterConfig::target_emitter`] indices, including the inserted emitter's
+    /// own, are updated so they keep pointing at the same emitters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > emitters.len()`.
+    pub fn insert_emitter(&mut self, index: usize, emitter: EmitterData) {
+        self.emitters.insert(index, emitter);
+        self.remap_sub_emitter_targets(|target| Some(target + (target >= index) as usize));
+    }
+
+    /// Removes and returns the emitter at `index`, or `None` if there is none.
+    ///
+    /// Sub-emitters targeting the removed emitter are cleared, and targets past it are
+    /// shifted down to follow their emitters.
+    pub fn remove_emitter(&mut self, index: usize) -> Option<EmitterData> {
+        if index >= self.emitters.len() {
+            return None;
+        }
+        let removed = self.emitters.remove(index);
+        self.remap_sub_emitter_targets(|target| match target.cmp(&index) {
+            std::cmp::Ordering::Less => Some(target),
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Greater => Some(target - 1),
+        });
+        Some(removed)
+    }
+
+    /// Moves the emitter at `from` to `to`, shifting the emitters in between and
+    /// updating [`SubEmitterConfig::target_emitter`] indices to match.
+    ///
+    /// Does nothing if either index is out of bounds.
+    pub fn move_emitter(&mut self, from: usize, to: usize) {
+        let len = self.emitters.len();
+        if from >= len || to >= len || from == to {
+            return;
+        }
+        let emitter = self.emitters.remove(from);
+        self.emitters.insert(to, emitter);
+        self.remap_sub_emitter_targets(|target| {
+            Some(if target == from {
+                to
+            } else if from < to && (from + 1..=to).contains(&target) {
+                target - 1
+            } else if to < from && (to..from).contains(&target) {
+                target + 1
+            } else {
+                target
+            })
+        });
+    }
+
+    // maps each sub-emitter target through `f`, clearing the sub-emitter when it returns `None`
+    fn remap_sub_emitter_targets(&mut self, f: impl Fn(usize) -> Option<usize>) {
+        for emitter in &mut self.emitters {
+            let Some(config) = emitter.sub_emitter.as_mut() else {
+                continue;
+            };
+            match f(config.target_emitter) {
+                Some(target) => config.target_emitter = target,
+                None => emitter.sub_emitter = None,
+            }
+        }
+    }
+
+    /// Returns the emitter indices in the order their particles are drawn, following
+    /// each emitter's [`draw_priority`](EmitterDrawPass::draw_priority).
+    pub fn emitter_draw_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.emitters.len()).rev().collect();
+        // stable, so equal priorities keep the reverse emitter order
+        order.sort_by_key(|&index| self.emitters[index].draw_pass.draw_priority);
+        order
+    }
+
+    /// Returns the index of the emitter driving the cycle clock of the emitter at
+    /// `index`, or `None` if it is not synchronized with an earlier emitter.
+    pub fn sync_leader(&self, index: usize) -> Option<usize> {
+        let group = self.emitters.get(index)?.sync_group?;
+        self.emitters
+            .iter()
+            .position(|e| e.sync_group == Some(group))
+            .filter(|&leader| leader != index)
+    }
+
+    /// Returns the textures referenced by the emitters' materials, without duplicates.
+    ///
+    /// Textures are listed in the order they are first used: by emitter, then by
+    /// material slot (base color, emissive, metallic/roughness, normal map, occlusion).
+    /// Useful for tooling that needs to package or scan an asset's dependencies.
+    pub fn referenced_textures(&self) -> Vec<TextureRef> {
+        let mut textures: Vec<TextureRef> = Vec::new();
+        for texture in self
+            .emitters
+            .iter()
+            .flat_map(|emitter| emitter.draw_pass.material.textures())
+        {
+            if !textures.contains(texture) {
+                textures.push(texture.clone());
+            }
+        }
+        textures
+    }
+
+    /// Serializes this asset to RON that only depends on its contents, for keeping
+    /// assets under version control.
+    ///
+    /// Fields left at their default are omitted and the rest are written in a fixed
+    /// order, with `\n` line endings and four-space indents whatever the platform, so
+    /// equal assets always produce the same bytes.
+    pub fn to_optimized_ron(&self) -> Result<String, ron::Error> {
+        let config = ron::ser::PrettyConfig::new()
+            .new_line("\n")
+            .indentor("    ");
+        ron::ser::to_string_pretty(self, config)
+    }
+
+    /// Parses an asset from RON bytes, upgrading its `sprinkles_version` if it's outdated.
+    ///
+    /// This is what [`ParticleSystemAssetLoader`] runs, exposed for tools that read
+    /// asset files themselves, e.g. to reload one after it changed on disk. Returns the
+    /// asset along with its original [`VersionStatus`], which is either
+    /// [`Current`](VersionStatus::Current) or [`Outdated`](VersionStatus::Outdated).
+    ///
+    /// Gradient stops out of order are sorted with
+    /// [`sort_gradient_stops`](Self::sort_gradient_stops), logging a warning for each
+    /// gradient.
+    pub fn from_ron(bytes: &[u8]) -> Result<(Self, VersionStatus), ParticleSystemAssetLoaderError> {
+        ron::de::from_bytes::<ParticleSystemAsset>(bytes)?.finish_loading()
+    }
+
+    // the steps shared by strict and lenient parsing once the RON has been read
+    fn finish_loading(mut self) -> Result<(Self, VersionStatus), ParticleSystemAssetLoaderError> {
+        for warning in self.sort_gradient_stops() {
+            let emitter = &self.emitters[warning.emitter()];
+            warn!(
+                "Particle system \"{}\": sorted the stops of {} in emitter \"{}\" by position",
+                self.name,
+                warning.field(),
+                emitter.name
+            );
+        }
+
+        match self.try_upgrade_version() {
+            VersionStatus::Incompatible { found, current } => {
+                Err(ParticleSystemAssetLoaderError::IncompatibleVersion {
+                    found,
+                    current: current.to_string(),
+                })
+            }
+            VersionStatus::Unknown => Err(ParticleSystemAssetLoaderError::UnknownVersion),
+            status => Ok((self, status)),
         }
     }
 
@@ -1097,4 +2165,24 @@ impl ParticleSystemAsset {
         }
         status
     }
+
+    /// Returns how long the last particle spawned by the emitter at `index` can take to
+    /// die, along with every particle its chain of sub-emitters spawns from it.
+    ///
+    /// This is the emitter's lifetime plus that of its sub-emitter target, of the target's
+    /// own target, and so on. Emitters already in the chain aren't counted twice.
+    pub fn sub_emitter_chain_lifetime(&self, index: usize) -> f32 {
+        let mut visited = Vec::new();
+        let mut total = 0.0;
+        let mut current = Some(index);
+        while let Some(index) = current.filter(|index| !visited.contains(index)) {
+            let Some(emitter) = self.emitters.get(index) else {
+                break;
+            };
+            visited.push(index);
+            total += emitter.time.lifetime;
+            current = emitter.sub_emitter.as_ref().map(|s| s.target_emitter);
+        }
+        total
+    }
 }
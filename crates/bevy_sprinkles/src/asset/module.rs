@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+use super::versioning::{self, VersionStatus, current_format_version};
+use super::{EmitterData, ParticleSystemAsset, ParticleSystemAssetLoaderError};
+
+/// An emitter saved on its own, to be reused across particle systems.
+///
+/// Holds the exported emitter first, followed by the emitters it spawns as
+/// sub-emitters, directly or through other sub-emitters. Their
+/// [`target_emitter`](super::SubEmitterConfig::target_emitter) indices point into
+/// [`emitters`](Self::emitters) rather than into the particle system they came from.
+/// Modules are stored as RON, in files ending with [`EXTENSION`](Self::EXTENSION).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmitterModule {
+    sprinkles_version: String,
+    /// The exported emitter and its sub-emitter targets.
+    pub emitters: Vec<EmitterData>,
+}
+
+impl EmitterModule {
+    /// File extension of emitter modules, without the leading dot.
+    pub const EXTENSION: &str = "emitter.ron";
+
+    /// Serializes this module to pretty-printed RON.
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+
+    /// Parses a module from RON bytes, upgrading its `sprinkles_version` if it's outdated.
+    ///
+    /// Returns the module along with its original [`VersionStatus`], like
+    /// [`ParticleSystemAsset::from_ron`].
+    pub fn from_ron(bytes: &[u8]) -> Result<(Self, VersionStatus), ParticleSystemAssetLoaderError> {
+        let mut module = ron::de::from_bytes::<EmitterModule>(bytes)?;
+
+        match versioning::validate_version(&module.sprinkles_version) {
+            VersionStatus::Incompatible { found, current } => {
+                Err(ParticleSystemAssetLoaderError::IncompatibleVersion {
+                    found,
+                    current: current.to_string(),
+                })
+            }
+            VersionStatus::Unknown => Err(ParticleSystemAssetLoaderError::UnknownVersion),
+            status => {
+                module.sprinkles_version = current_format_version().to_string();
+                Ok((module, status))
+            }
+        }
+    }
+}
+
+impl ParticleSystemAsset {
+    /// Exports the emitter at `index` as an [`EmitterModule`], along with the emitters it
+    /// spawns as sub-emitters. Returns `None` if there is no emitter at `index`.
+    pub fn export_emitter_module(&self, index: usize) -> Option<EmitterModule> {
+        self.emitters.get(index)?;
+
+        // follow the sub-emitter chain, stopping at targets that are missing or already taken
+        let mut indices = vec![index];
+        while let Some(target) = self.emitters[*indices.last().unwrap()]
+            .sub_emitter
+            .as_ref()
+            .map(|config| config.target_emitter)
+            .filter(|target| *target < self.emitters.len() && !indices.contains(target))
+        {
+            indices.push(target);
+        }
+
+        let emitters = indices
+            .iter()
+            .map(|&i| {
+                let mut emitter = self.emitters[i].clone();
+                if let Some(config) = emitter.sub_emitter.as_mut() {
+                    match indices.iter().position(|&j| j == config.target_emitter) {
+                        Some(target) => config.target_emitter = target,
+                        None => emitter.sub_emitter = None,
+                    }
+                }
+                emitter
+            })
+            .collect();
+
+        Some(EmitterModule {
+            sprinkles_version: current_format_version().to_string(),
+            emitters,
+        })
+    }
+
+    /// Appends the emitters of `module` and returns the index of the first one.
+    ///
+    /// Their [`target_emitter`](super::SubEmitterConfig::target_emitter) indices are
+    /// shifted to point at the appended emitters, and their
+    /// [`sync_group`](EmitterData::sync_group)s are renumbered so they don't join the
+    /// groups already in this asset.
+    pub fn import_emitter_module(&mut self, module: EmitterModule) -> usize {
+        let offset = self.emitters.len();
+        let next_group = self
+            .emitters
+            .iter()
+            .filter_map(|emitter| emitter.sync_group)
+            .max()
+            .map_or(0, |group| group + 1);
+        let mut groups: Vec<u32> = Vec::new();
+
+        for mut emitter in module.emitters {
+            if let Some(config) = emitter.sub_emitter.as_mut() {
+                config.target_emitter += offset;
+            }
+            if let Some(group) = emitter.sync_group {
+                let position = groups.iter().position(|&g| g == group).unwrap_or_else(|| {
+                    groups.push(group);
+                    groups.len() - 1
+                });
+                emitter.sync_group = Some(next_group + position as u32);
+            }
+            self.emitters.push(emitter);
+        }
+
+        offset
+    }
+}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use std::hash::{Hash, Hasher};
 
 use super::serde_helpers::{is_false, is_true, is_zero_f32};
-use crate::textures::preset::TextureRef;
+use crate::textures::preset::{CustomPresetRegistry, TextureRef};
 
 /// Sets how a material's base color alpha channel is used for transparency, copied from Bevy's [`AlphaMode`](bevy::render::alpha::AlphaMode).
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Reflect)]
@@ -113,22 +113,58 @@ impl From<Face> for SerializableFace {
     }
 }
 
+/// Valid range of [`StandardParticleMaterial::perceptual_roughness`].
+pub const PERCEPTUAL_ROUGHNESS_RANGE: (f32, f32) = (0.089, 1.0);
+/// Valid range of [`StandardParticleMaterial::metallic`].
+pub const METALLIC_RANGE: (f32, f32) = (0.0, 1.0);
+/// Valid range of [`StandardParticleMaterial::reflectance`].
+pub const REFLECTANCE_RANGE: (f32, f32) = (0.0, 1.0);
+
+/// How far [`StandardParticleMaterial::with_bloom`] pushes the emissive luminance past the
+/// bloom threshold.
+pub const BLOOM_HEADROOM: f32 = 1.0;
+
+// Rec. 709 luminance, as computed by Bevy's tonemapping and bloom shaders
+fn luminance(color: [f32; 4]) -> f32 {
+    0.2126 * color[0] + 0.7152 * color[1] + 0.0722 * color[2]
+}
+
+fn clamp_to(value: f32, (min, max): (f32, f32)) -> f32 {
+    value.clamp(min, max)
+}
+
 fn default_base_color() -> [f32; 4] {
     [1.0, 1.0, 1.0, 1.0]
 }
 
+fn is_default_base_color(v: &[f32; 4]) -> bool {
+    *v == default_base_color()
+}
+
 fn default_perceptual_roughness() -> f32 {
     0.5
 }
 
+fn is_default_perceptual_roughness(v: &f32) -> bool {
+    *v == default_perceptual_roughness()
+}
+
 fn default_alpha_mode() -> SerializableAlphaMode {
     SerializableAlphaMode::Opaque
 }
 
+fn is_default_alpha_mode(v: &SerializableAlphaMode) -> bool {
+    *v == default_alpha_mode()
+}
+
 fn default_reflectance() -> f32 {
     0.5
 }
 
+fn is_default_reflectance(v: &f32) -> bool {
+    *v == default_reflectance()
+}
+
 fn default_fog_enabled() -> bool {
     true
 }
@@ -166,7 +202,7 @@ fn color_to_array(c: LinearRgba) -> [f32; 4] {
 }
 
 /// A serializable PBR material for particles, copied from Bevy's [`StandardMaterial`](bevy::pbr::StandardMaterial).
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 #[reflect(Clone)]
 pub struct StandardParticleMaterial {
     /// The color of the surface of the material before lighting.
@@ -176,7 +212,10 @@ pub struct StandardParticleMaterial {
     /// is factored into the final base color as `base_color * base_color_texture_value`.
     ///
     /// Defaults to white `[1.0, 1.0, 1.0, 1.0]`.
-    #[serde(default = "default_base_color")]
+    #[serde(
+        default = "default_base_color",
+        skip_serializing_if = "is_default_base_color"
+    )]
     pub base_color: [f32; 4],
 
     /// The actual pre-lighting color is `base_color * this_texture`.
@@ -220,17 +259,23 @@ pub struct StandardParticleMaterial {
     ///
     /// See [`SerializableAlphaMode`] for details. Defaults to
     /// [`SerializableAlphaMode::Opaque`].
-    #[serde(default = "default_alpha_mode")]
+    #[serde(
+        default = "default_alpha_mode",
+        skip_serializing_if = "is_default_alpha_mode"
+    )]
     pub alpha_mode: SerializableAlphaMode,
 
-    /// Linear perceptual roughness, clamped to `[0.089, 1.0]` in the shader.
+    /// Linear perceptual roughness, clamped to `[0.089, 1.0]` when applied.
     ///
     /// Defaults to `0.5`. Low values result in a "glossy" material with specular
     /// highlights, while values close to `1.0` result in rough materials.
     ///
     /// If used together with a roughness/metallic texture, this is factored into
     /// the final base color as `roughness * roughness_texture_value`.
-    #[serde(default = "default_perceptual_roughness")]
+    #[serde(
+        default = "default_perceptual_roughness",
+        skip_serializing_if = "is_default_perceptual_roughness"
+    )]
     pub perceptual_roughness: f32,
 
     /// How "metallic" the material appears, within `[0.0, 1.0]`.
@@ -250,7 +295,10 @@ pub struct StandardParticleMaterial {
     /// physical property "reflectance."
     ///
     /// Defaults to `0.5` which is mapped to 4% reflectance in the shader.
-    #[serde(default = "default_reflectance")]
+    #[serde(
+        default = "default_reflectance",
+        skip_serializing_if = "is_default_reflectance"
+    )]
     pub reflectance: f32,
 
     /// The blue channel contains metallic values, and the green channel contains
@@ -480,10 +528,68 @@ impl Default for StandardParticleMaterial {
 }
 
 impl StandardParticleMaterial {
+    /// Creates an unlit, additive material that only emits light, for glowing particles.
+    ///
+    /// The emitted color is `color * intensity`, so values above `1.0` push the particles
+    /// into HDR range for bloom. The base color is fully transparent and blended with
+    /// [`SerializableAlphaMode::Premultiplied`], so the emission is added on top of
+    /// whatever is behind the particles without darkening it.
+    pub fn glow(color: [f32; 4], intensity: f32) -> Self {
+        Self {
+            base_color: [0.0, 0.0, 0.0, 0.0],
+            emissive: [
+                color[0] * intensity,
+                color[1] * intensity,
+                color[2] * intensity,
+                1.0,
+            ],
+            alpha_mode: SerializableAlphaMode::Premultiplied,
+            unlit: true,
+            ..default()
+        }
+    }
+
+    /// Creates a [`glow`](Self::glow) material whose emissive color is bright enough to
+    /// bloom under Bevy's `Bloom` with the given `prefilter.threshold`.
+    ///
+    /// The color keeps its hue and is scaled so its luminance is [`BLOOM_HEADROOM`] above
+    /// `above_threshold`. Bevy's prefilter compares the brightest channel against the
+    /// threshold, which is never below the luminance, so the particles always get past
+    /// it. A black `color` is replaced with white, which can't be scaled into range.
+    pub fn with_bloom(color: [f32; 4], above_threshold: f32) -> Self {
+        let color = if luminance(color) > 0.0 {
+            color
+        } else {
+            [1.0, 1.0, 1.0, color[3]]
+        };
+        let target = above_threshold.max(0.0) + BLOOM_HEADROOM;
+        Self::glow(color, target / luminance(color))
+    }
+
     /// Converts this serializable material into a Bevy [`StandardMaterial`],
     /// loading any referenced textures via the provided [`AssetServer`].
+    ///
+    /// [`TextureRef::Custom`] textures load as the default handle; use
+    /// [`to_standard_material_with_presets`](Self::to_standard_material_with_presets) to
+    /// resolve them.
     pub fn to_standard_material(&self, asset_server: &AssetServer) -> StandardMaterial {
-        let load_tex = |tex: &Option<TextureRef>| tex.as_ref().map(|t| t.load(asset_server));
+        self.to_standard_material_with_presets(asset_server, &CustomPresetRegistry::default())
+    }
+
+    /// Like [`to_standard_material`](Self::to_standard_material), resolving
+    /// [`TextureRef::Custom`] references through `custom_presets`.
+    ///
+    /// Roughness, metallic and reflectance values are clamped to
+    /// [`PERCEPTUAL_ROUGHNESS_RANGE`], [`METALLIC_RANGE`] and [`REFLECTANCE_RANGE`].
+    pub fn to_standard_material_with_presets(
+        &self,
+        asset_server: &AssetServer,
+        custom_presets: &CustomPresetRegistry,
+    ) -> StandardMaterial {
+        let load_tex = |tex: &Option<TextureRef>| {
+            tex.as_ref()
+                .map(|t| t.load_with_presets(asset_server, custom_presets))
+        };
 
         StandardMaterial {
             base_color: color_from_array(self.base_color),
@@ -492,9 +598,9 @@ impl StandardParticleMaterial {
             emissive_texture: load_tex(&self.emissive_texture),
             emissive_exposure_weight: self.emissive_exposure_weight,
             alpha_mode: self.alpha_mode.into(),
-            perceptual_roughness: self.perceptual_roughness,
-            metallic: self.metallic,
-            reflectance: self.reflectance,
+            perceptual_roughness: clamp_to(self.perceptual_roughness, PERCEPTUAL_ROUGHNESS_RANGE),
+            metallic: clamp_to(self.metallic, METALLIC_RANGE),
+            reflectance: clamp_to(self.reflectance, REFLECTANCE_RANGE),
             metallic_roughness_texture: load_tex(&self.metallic_roughness_texture),
             normal_map_texture: load_tex(&self.normal_map_texture),
             flip_normal_map_y: self.flip_normal_map_y,
@@ -507,7 +613,10 @@ impl StandardParticleMaterial {
             attenuation_distance: self.attenuation_distance,
             attenuation_color: color_from_array(self.attenuation_color),
             clearcoat: self.clearcoat,
-            clearcoat_perceptual_roughness: self.clearcoat_perceptual_roughness,
+            clearcoat_perceptual_roughness: clamp_to(
+                self.clearcoat_perceptual_roughness,
+                PERCEPTUAL_ROUGHNESS_RANGE,
+            ),
             anisotropy_strength: self.anisotropy_strength,
             anisotropy_rotation: self.anisotropy_rotation,
             double_sided: self.double_sided,
@@ -605,11 +714,16 @@ impl StandardParticleMaterial {
 }
 
 /// The material used for a draw pass, either a standard PBR material or custom shaders.
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Reflect)]
 pub enum DrawPassMaterial {
     /// A standard PBR material for particles.
     Standard(StandardParticleMaterial),
     /// Custom vertex and/or fragment shaders.
+    ///
+    /// Each shader is loaded from its asset path and replaces the built-in particle
+    /// shader for that stage in the main pass, on top of a default [`StandardMaterial`].
+    /// See [`ParticleMaterialExtension`](crate::ParticleMaterialExtension) for the
+    /// bindings they can read.
     CustomShader {
         /// Optional path to a custom vertex shader.
         vertex_shader: Option<String>,
@@ -625,6 +739,25 @@ impl Default for DrawPassMaterial {
 }
 
 impl DrawPassMaterial {
+    pub(crate) fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns every texture this material samples, in field order.
+    pub(crate) fn textures(&self) -> impl Iterator<Item = &TextureRef> {
+        let textures = match self {
+            Self::Standard(mat) => vec![
+                &mat.base_color_texture,
+                &mat.emissive_texture,
+                &mat.metallic_roughness_texture,
+                &mat.normal_map_texture,
+                &mat.occlusion_texture,
+            ],
+            Self::CustomShader { .. } => Vec::new(),
+        };
+        textures.into_iter().flatten()
+    }
+
     /// Computes a hash key for material caching.
     pub fn cache_key(&self) -> u64 {
         let mut hasher = std::collections::hash_map::DefaultHasher::new();
@@ -0,0 +1,79 @@
+use super::{ParticleSystemAsset, bursts::spawn_phase};
+use crate::random::{hash, hash_to_float};
+
+/// Number of cycles swept for looping emitters, so particles from one cycle overlap the
+/// next one with different random delays.
+const LOOPING_CYCLES: u32 = 3;
+
+impl ParticleSystemAsset {
+    /// Estimates the largest number of particles the emitter at `index` keeps alive at
+    /// once, over a full emission cycle.
+    ///
+    /// Spawn times and particle lifetimes follow the simulation shader, so the estimate
+    /// accounts for [`explosiveness`](super::EmitterTime::explosiveness),
+    /// [`spawn_time_randomness`](super::EmitterTime::spawn_time_randomness),
+    /// [`lifetime_randomness`](super::EmitterTime::lifetime_randomness) and the
+    /// randomized delay of looping emitters, without running the simulation or reading
+    /// particles back from the GPU. Randomness uses the emitter's
+    /// [`fixed_seed`](super::EmitterTime::fixed_seed), or `0` when it has none.
+    ///
+    /// Sub-emitter targets, [`PerDistance`](super::EmissionMode::PerDistance) emitters
+    /// and emitters with a [`burst`](super::EmitterEmission::burst) don't spread their
+    /// particles over the cycle, so their
+    /// [`particles_amount`](super::EmitterEmission::particles_amount) is returned as
+    /// an upper bound. Returns `0` for disabled emitters or an out-of-range `index`.
+    pub fn peak_particle_count(&self, index: usize) -> u32 {
+        let Some(emitter) = self.emitters.get(index) else {
+            return 0;
+        };
+        let time = &emitter.time;
+        let amount = emitter.emission.particles_amount;
+        if !emitter.enabled || amount == 0 || time.lifetime <= 0.0 {
+            return 0;
+        }
+        let is_target = self.emitters.iter().any(|e| {
+            e.sub_emitter
+                .as_ref()
+                .is_some_and(|s| s.target_emitter == index)
+        });
+        let discrete =
+            emitter.emission.mode.spacing().is_some() || emitter.emission.active_burst().is_some();
+        if is_target || discrete {
+            return amount;
+        }
+
+        let seed = time.fixed_seed.unwrap_or(0);
+        let cycle_duration = self.cycle_duration(index);
+        let cycles = if time.one_shot { 1 } else { LOOPING_CYCLES };
+
+        // +1 when a particle spawns, -1 when it dies
+        let mut events = Vec::with_capacity((cycles * amount * 2) as usize);
+        for cycle in 0..cycles {
+            let start = cycle as f32 * cycle_duration + time.cycle_delay(seed, cycle);
+            for idx in 0..amount {
+                let spawn = start + spawn_phase(time, idx, amount) * time.lifetime;
+                // mirrors `spawn_particle` in `particle_simulate.wgsl`
+                let particle_seed = hash(
+                    seed.wrapping_add(1)
+                        .wrapping_add(idx)
+                        .wrapping_add(cycle.wrapping_mul(amount)),
+                );
+                let rand = hash_to_float(particle_seed.wrapping_add(4));
+                let lifetime = time.lifetime * (1.0 - rand * time.lifetime_randomness);
+                events.push((spawn, 1));
+                events.push((spawn + lifetime.max(0.0), -1));
+            }
+        }
+        // particles dying as others spawn don't overlap them
+        events.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+
+        let mut alive = 0i32;
+        let mut peak = 0i32;
+        for (_, change) in events {
+            alive += change;
+            peak = peak.max(alive);
+        }
+        // a slot never holds two particles, but rounding can overlap its spawn and death
+        (peak as u32).min(amount)
+    }
+}
@@ -0,0 +1,99 @@
+use ron::value::RawValue;
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::{
+    EmitterData, ParticleSystemAsset, ParticleSystemAssetLoaderError, versioning::VersionStatus,
+};
+
+// placeholder written over an emitter that fails to parse, keeping later indices stable
+const PLACEHOLDER_EMITTER: &[u8] = b"(name: \"\", enabled: false)";
+
+/// An emitter that [`ParticleSystemAsset::from_ron_lenient`] couldn't deserialize.
+#[derive(Debug, Error)]
+#[error("Could not parse emitter {index}: {error}")]
+pub struct EmitterLoadError {
+    /// Index of the emitter in the asset's emitter list.
+    pub index: usize,
+    /// Name of the emitter, if it could be read.
+    pub name: Option<String>,
+    /// The error, with a position relative to the start of the emitter.
+    pub error: ron::error::SpannedError,
+}
+
+// only the emitters are read, so the rest of the file can't fail to parse here
+#[derive(Deserialize)]
+#[serde(rename = "ParticleSystemAsset")]
+struct RawEmitters<'a> {
+    #[serde(borrow, default)]
+    emitters: Vec<&'a RawValue>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "EmitterData")]
+struct EmitterName {
+    name: String,
+}
+
+impl ParticleSystemAsset {
+    /// Like [`from_ron`](Self::from_ron), but a malformed emitter doesn't fail the whole
+    /// asset.
+    ///
+    /// If the asset doesn't parse as a whole, each emitter is parsed on its own. Those
+    /// that fail are replaced with a disabled default emitter that keeps their name when
+    /// it can be read, so sub-emitter targets still point at the right emitters. The
+    /// errors are returned alongside the asset.
+    ///
+    /// The file itself still has to be valid RON, and anything wrong outside of the
+    /// emitters fails the load like with [`from_ron`](Self::from_ron).
+    pub fn from_ron_lenient(
+        bytes: &[u8],
+    ) -> Result<(Self, VersionStatus, Vec<EmitterLoadError>), ParticleSystemAssetLoaderError> {
+        let strict_error = match ron::de::from_bytes::<ParticleSystemAsset>(bytes) {
+            Ok(asset) => {
+                let (asset, status) = asset.finish_loading()?;
+                return Ok((asset, status, Vec::new()));
+            }
+            Err(error) => error,
+        };
+        let Ok(raw) = ron::de::from_bytes::<RawEmitters>(bytes) else {
+            return Err(strict_error.into());
+        };
+
+        let mut errors = Vec::new();
+        let mut patched = Vec::with_capacity(bytes.len());
+        let mut copied = 0;
+        for (index, emitter) in raw.emitters.iter().enumerate() {
+            let Err(error) = emitter.into_rust::<EmitterData>() else {
+                continue;
+            };
+            // raw values borrow from `bytes`, so their offset locates them in the file
+            let source = emitter.get_ron();
+            let start = source.as_ptr() as usize - bytes.as_ptr() as usize;
+            patched.extend_from_slice(&bytes[copied..start]);
+            patched.extend_from_slice(PLACEHOLDER_EMITTER);
+            copied = start + source.len();
+
+            errors.push(EmitterLoadError {
+                index,
+                name: emitter.into_rust::<EmitterName>().ok().map(|e| e.name),
+                error,
+            });
+        }
+        if errors.is_empty() {
+            return Err(strict_error.into());
+        }
+        patched.extend_from_slice(&bytes[copied..]);
+
+        let mut asset = ron::de::from_bytes::<ParticleSystemAsset>(&patched)?;
+        for error in &errors {
+            if let Some(name) = &error.name {
+                asset.emitters[error.index].name.clone_from(name);
+            } else {
+                asset.emitters[error.index].name = EmitterData::default().name;
+            }
+        }
+        let (asset, status) = asset.finish_loading()?;
+        Ok((asset, status, errors))
+    }
+}
@@ -0,0 +1,93 @@
+use bevy::reflect::{PartialReflect, ReflectMut};
+
+use super::{CurveTexture, Gradient, ParticleSystemAsset};
+
+impl ParticleSystemAsset {
+    /// Embeds precomputed samples in every curve and gradient of the emitters, so the
+    /// runtime uploads their textures as-is instead of baking them. Meant for platforms
+    /// where baking at load time is too slow.
+    ///
+    /// Like [`round_floats`](Self::round_floats), curves and gradients are found through
+    /// reflection. Run it again after editing the asset, since stale samples are uploaded
+    /// as they are.
+    pub fn embed_samples(&mut self) {
+        for emitter in &mut self.emitters {
+            visit_sampled(emitter, &mut |sampled| match sampled {
+                Sampled::Curve(curve) => curve.embed_samples(),
+                Sampled::Gradient(gradient) => gradient.embed_samples(),
+            });
+        }
+    }
+
+    /// Removes the samples added by [`embed_samples`](Self::embed_samples), so curves and
+    /// gradients are baked again.
+    pub fn clear_embedded_samples(&mut self) {
+        for emitter in &mut self.emitters {
+            visit_sampled(emitter, &mut |sampled| match sampled {
+                Sampled::Curve(curve) => curve.samples = None,
+                Sampled::Gradient(gradient) => gradient.samples = None,
+            });
+        }
+    }
+}
+
+enum Sampled<'a> {
+    Curve(&'a mut CurveTexture),
+    Gradient(&'a mut Gradient),
+}
+
+fn visit_sampled(value: &mut dyn PartialReflect, visit: &mut dyn FnMut(Sampled)) {
+    if let Some(curve) = value.try_downcast_mut::<CurveTexture>() {
+        visit(Sampled::Curve(curve));
+        return;
+    }
+    if let Some(gradient) = value.try_downcast_mut::<Gradient>() {
+        visit(Sampled::Gradient(gradient));
+        return;
+    }
+    match value.reflect_mut() {
+        ReflectMut::Struct(s) => {
+            for i in 0..s.field_len() {
+                if let Some(field) = s.field_at_mut(i) {
+                    visit_sampled(field, visit);
+                }
+            }
+        }
+        ReflectMut::TupleStruct(s) => {
+            for i in 0..s.field_len() {
+                if let Some(field) = s.field_mut(i) {
+                    visit_sampled(field, visit);
+                }
+            }
+        }
+        ReflectMut::Tuple(t) => {
+            for i in 0..t.field_len() {
+                if let Some(field) = t.field_mut(i) {
+                    visit_sampled(field, visit);
+                }
+            }
+        }
+        ReflectMut::List(list) => {
+            for i in 0..list.len() {
+                if let Some(item) = list.get_mut(i) {
+                    visit_sampled(item, visit);
+                }
+            }
+        }
+        ReflectMut::Array(array) => {
+            for i in 0..array.len() {
+                if let Some(item) = array.get_mut(i) {
+                    visit_sampled(item, visit);
+                }
+            }
+        }
+        ReflectMut::Enum(e) => {
+            for i in 0..e.field_len() {
+                if let Some(field) = e.field_at_mut(i) {
+                    visit_sampled(field, visit);
+                }
+            }
+        }
+        _ => {}
+    }
+}
@@ -16,6 +16,10 @@ pub(crate) fn is_zero_u32(v: &u32) -> bool {
     *v == 0
 }
 
+pub(crate) fn is_zero_i32(v: &i32) -> bool {
+    *v == 0
+}
+
 pub(crate) fn is_zero_vec2(v: &Vec2) -> bool {
     *v == Vec2::ZERO
 }
@@ -0,0 +1,148 @@
+use bevy::prelude::*;
+
+use super::{EmitterCollisionMode, ParticleSystemAsset, ParticlesColliderShape3D};
+
+/// Time step of the simulated run, matching a 60 FPS simulation.
+const SETTLE_STEP: f32 = 1.0 / 60.0;
+/// Simulated runs give up after this many seconds.
+const MAX_SETTLE_TIME: f32 = 30.0;
+/// Matches `COLLISION_EPSILON` in `particle_simulate.wgsl`.
+const COLLISION_EPSILON: f32 = 0.001;
+
+impl ParticleSystemAsset {
+    /// Estimates how long the particles of the emitter at `index` take to come to
+    /// rest, in seconds, by simulating a few probe particles on the CPU.
+    ///
+    /// Probes are launched at the slowest and fastest
+    /// [`initial_velocity`](super::EmitterVelocities::initial_velocity), along the
+    /// initial direction and the edges of its spread, and fall under gravity against
+    /// the asset's enabled colliders. A probe settles once its speed drops below
+    /// `speed_threshold` while resting on a collider (or at any point when there is no
+    /// gravity), or when it's hidden on contact. The slowest probe decides the result.
+    ///
+    /// Turbulence, radial velocity and animated velocities aren't simulated. Returns
+    /// `None` if the emitter doesn't exist or a probe never settles, e.g. when it
+    /// falls forever with nothing to land on.
+    pub fn settle_time(&self, index: usize, speed_threshold: f32) -> Option<f32> {
+        let emitter = self.emitters.get(index)?;
+        let velocities = &emitter.velocities;
+        let gravity = emitter.accelerations.scaled_gravity();
+        let origin = emitter.position + emitter.emission.offset;
+
+        let mut radius = emitter.collision.base_size * 0.5;
+        if emitter.collision.use_scale {
+            radius *= emitter.scale.range.max;
+        }
+
+        let direction = velocities
+            .initial_direction
+            .try_normalize()
+            .unwrap_or(Vec3::X);
+        let spread = velocities.spread.clamp(0.0, 180.0).to_radians();
+        let (side, up) = direction.any_orthonormal_pair();
+        let directions = [
+            direction,
+            Quat::from_axis_angle(side, spread) * direction,
+            Quat::from_axis_angle(side, -spread) * direction,
+            Quat::from_axis_angle(up, spread) * direction,
+            Quat::from_axis_angle(up, -spread) * direction,
+        ];
+        let speeds = [
+            velocities.initial_velocity.min,
+            velocities.initial_velocity.max,
+        ];
+
+        let mut settle_time: f32 = 0.0;
+        for direction in directions {
+            for speed in speeds {
+                let probe = self.simulate_probe(
+                    origin,
+                    direction * speed,
+                    gravity,
+                    radius,
+                    emitter.collision.mode.as_ref(),
+                    speed_threshold,
+                )?;
+                settle_time = settle_time.max(probe);
+            }
+        }
+        Some(settle_time)
+    }
+
+    fn simulate_probe(
+        &self,
+        mut position: Vec3,
+        mut velocity: Vec3,
+        gravity: Vec3,
+        radius: f32,
+        mode: Option<&EmitterCollisionMode>,
+        speed_threshold: f32,
+    ) -> Option<f32> {
+        let free_fall = gravity.length() >= speed_threshold;
+        let mut time = 0.0;
+        while time <= MAX_SETTLE_TIME {
+            let contact = mode.and_then(|_| self.probe_contact(position, radius));
+            match (mode, contact) {
+                (Some(EmitterCollisionMode::HideOnContact), Some(_)) => return Some(time),
+                (Some(EmitterCollisionMode::Rigid { friction, bounce }), Some((normal, depth))) => {
+                    // mirrors the rigid response in `particle_simulate.wgsl`
+                    let response = normal.dot(velocity);
+                    let (friction, bounce) = (*friction, *bounce);
+                    let bounce_threshold = 2.0 / (bounce + 1.0).clamp(1.0, 2.0);
+                    let should_bounce = (response.abs() >= bounce_threshold) as u32 as f32;
+                    position += normal * depth;
+                    velocity -= normal * response;
+                    velocity = velocity.lerp(Vec3::ZERO, friction.clamp(0.0, 1.0));
+                    velocity -= normal * response * bounce * should_bounce;
+                }
+                _ => {}
+            }
+
+            let resting = contact.is_some() || !free_fall;
+            if resting && velocity.length() < speed_threshold {
+                return Some(time);
+            }
+
+            velocity += gravity * SETTLE_STEP;
+            position += velocity * SETTLE_STEP;
+            time += SETTLE_STEP;
+        }
+        None
+    }
+
+    // returns the deepest contact's normal and penetration depth, like `process_collisions`
+    fn probe_contact(&self, position: Vec3, radius: f32) -> Option<(Vec3, f32)> {
+        self.colliders
+            .iter()
+            .filter(|collider| collider.enabled)
+            .filter_map(|collider| {
+                let local = position - collider.position;
+                match collider.shape {
+                    ParticlesColliderShape3D::Sphere {
+                        radius: collider_radius,
+                    } => {
+                        let penetration = local.length() - (radius + collider_radius);
+                        (penetration <= COLLISION_EPSILON)
+                            .then(|| (local.try_normalize().unwrap_or(Vec3::Y), -penetration))
+                    }
+                    ParticlesColliderShape3D::Box { size } => {
+                        let extents = size * 0.5;
+                        let outside = local.abs() - extents;
+                        if outside.max_element() > 0.0 {
+                            let rel = outside.max(Vec3::ZERO);
+                            let penetration = rel.length() - radius;
+                            let normal = (rel * local.signum()).try_normalize();
+                            (penetration <= COLLISION_EPSILON)
+                                .then(|| (normal.unwrap_or(Vec3::Y), -penetration))
+                        } else {
+                            let depth = -outside;
+                            let axis = depth.min_position();
+                            let normal = Vec3::AXES[axis] * local[axis].signum();
+                            Some((normal, depth[axis] + radius))
+                        }
+                    }
+                }
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
@@ -0,0 +1,197 @@
+use thiserror::Error;
+
+use super::{EmitterColors, Gradient, ParticleSystemAsset, SolidOrGradientColor};
+use crate::runtime::FAR_FROM_ORIGIN_DISTANCE;
+
+/// A problem with a [`ParticleSystemAsset`] that doesn't stop it from loading, but
+/// likely makes it behave differently than intended.
+///
+/// Returned by [`ParticleSystemAsset::validate`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum AssetWarning {
+    /// The emitter spawns no particles.
+    #[error("Emitter spawns no particles")]
+    NoParticles {
+        /// Index of the emitter.
+        emitter: usize,
+    },
+    /// The emitter's particles die as soon as they spawn.
+    #[error("Particle lifetime is {lifetime}s, so particles are never visible")]
+    NonPositiveLifetime {
+        /// Index of the emitter.
+        emitter: usize,
+        /// The emitter's lifetime, in seconds.
+        lifetime: f32,
+    },
+    /// The emitter's sub-emitter targets an emitter that doesn't exist.
+    #[error("Sub-emitter targets emitter #{target}, which doesn't exist")]
+    MissingSubEmitterTarget {
+        /// Index of the emitter.
+        emitter: usize,
+        /// Index of the missing target emitter.
+        target: usize,
+    },
+    /// The emitter's sub-emitter targets the emitter itself.
+    #[error("Sub-emitter targets its own emitter")]
+    SelfSubEmitterTarget {
+        /// Index of the emitter.
+        emitter: usize,
+    },
+    /// The emitter's sub-emitter can produce more events in one step than its target holds.
+    #[error(
+        "Sub-emitter can produce up to {max_events} events per step, but its target holds {capacity} particles"
+    )]
+    SubEmitterOverflow {
+        /// Index of the emitter.
+        emitter: usize,
+        /// Most events the sub-emitter can produce in a single step.
+        max_events: u32,
+        /// Number of particles the target emitter holds.
+        capacity: u32,
+    },
+    /// The emitter is placed so far from the origin that its particles lose precision.
+    ///
+    /// See [`FAR_FROM_ORIGIN_DISTANCE`].
+    #[error("Emitter is {distance} units from the origin, where particles lose precision")]
+    FarFromOrigin {
+        /// Index of the emitter.
+        emitter: usize,
+        /// Distance of the emitter from the origin.
+        distance: f32,
+    },
+    /// One of the emitter's gradients has stops that are not sorted by position.
+    #[error("Gradient stops are out of order")]
+    UnsortedGradientStops {
+        /// Index of the emitter.
+        emitter: usize,
+        /// Path of the gradient, relative to [`EmitterData`](super::EmitterData).
+        field: &'static str,
+    },
+}
+
+impl AssetWarning {
+    /// Returns the index of the emitter the warning is about.
+    pub fn emitter(&self) -> usize {
+        match self {
+            Self::NoParticles { emitter }
+            | Self::NonPositiveLifetime { emitter, .. }
+            | Self::MissingSubEmitterTarget { emitter, .. }
+            | Self::SelfSubEmitterTarget { emitter }
+            | Self::SubEmitterOverflow { emitter, .. }
+            | Self::FarFromOrigin { emitter, .. }
+            | Self::UnsortedGradientStops { emitter, .. } => *emitter,
+        }
+    }
+
+    /// Returns the path of the emitter field to fix, relative to
+    /// [`EmitterData`](super::EmitterData), e.g. `"time.lifetime"`.
+    pub fn field(&self) -> &'static str {
+        match self {
+            Self::NoParticles { .. } => "emission.particles_amount",
+            Self::NonPositiveLifetime { .. } => "time.lifetime",
+            Self::MissingSubEmitterTarget { .. } | Self::SelfSubEmitterTarget { .. } => {
+                "sub_emitter.target_emitter"
+            }
+            Self::SubEmitterOverflow { .. } => "sub_emitter.amount",
+            Self::FarFromOrigin { .. } => "position",
+            Self::UnsortedGradientStops { field, .. } => field,
+        }
+    }
+}
+
+/// Returns the emitter's gradients, along with their path relative to
+/// [`EmitterData`](super::EmitterData).
+fn gradients(colors: &EmitterColors) -> Vec<(&'static str, &Gradient)> {
+    let mut gradients = Vec::new();
+    if let SolidOrGradientColor::Gradient { gradient } = &colors.initial_color {
+        gradients.push(("colors.initial_color", gradient));
+    }
+    gradients.push(("colors.color_over_lifetime", &colors.color_over_lifetime));
+    gradients
+}
+
+impl ParticleSystemAsset {
+    /// Sorts the stops of every gradient by position, and returns an
+    /// [`AssetWarning::UnsortedGradientStops`] for each gradient that had to be reordered.
+    ///
+    /// [`from_ron`](Self::from_ron) runs this, so hand-edited files with stops out of
+    /// order still sample as expected.
+    pub fn sort_gradient_stops(&mut self) -> Vec<AssetWarning> {
+        let warnings = self
+            .validate()
+            .into_iter()
+            .filter(|warning| matches!(warning, AssetWarning::UnsortedGradientStops { .. }))
+            .collect();
+
+        for emitter in &mut self.emitters {
+            if let SolidOrGradientColor::Gradient { gradient } = &mut emitter.colors.initial_color {
+                gradient.sort_stops();
+            }
+            emitter.colors.color_over_lifetime.sort_stops();
+        }
+        warnings
+    }
+
+    /// Checks the asset for settings that are valid but most likely mistakes, such as
+    /// sub-emitters targeting missing emitters.
+    ///
+    /// Disabled emitters are checked too. Warnings are ordered by emitter.
+    pub fn validate(&self) -> Vec<AssetWarning> {
+        let mut warnings = Vec::new();
+
+        for (index, emitter) in self.emitters.iter().enumerate() {
+            if emitter.emission.particles_amount == 0 {
+                warnings.push(AssetWarning::NoParticles { emitter: index });
+            }
+            if emitter.time.lifetime <= 0.0 {
+                warnings.push(AssetWarning::NonPositiveLifetime {
+                    emitter: index,
+                    lifetime: emitter.time.lifetime,
+                });
+            }
+            for (field, gradient) in gradients(&emitter.colors) {
+                if !gradient.is_sorted() {
+                    warnings.push(AssetWarning::UnsortedGradientStops {
+                        emitter: index,
+                        field,
+                    });
+                }
+            }
+            let distance = emitter.position.length();
+            if distance > FAR_FROM_ORIGIN_DISTANCE {
+                warnings.push(AssetWarning::FarFromOrigin {
+                    emitter: index,
+                    distance,
+                });
+            }
+
+            let Some(config) = &emitter.sub_emitter else {
+                continue;
+            };
+            let Some(target) = self.emitters.get(config.target_emitter) else {
+                warnings.push(AssetWarning::MissingSubEmitterTarget {
+                    emitter: index,
+                    target: config.target_emitter,
+                });
+                continue;
+            };
+            if config.target_emitter == index {
+                warnings.push(AssetWarning::SelfSubEmitterTarget { emitter: index });
+                continue;
+            }
+
+            let max_events = config.max_events_per_step(emitter.emission.particles_amount);
+            let capacity = target.emission.particles_amount;
+            if max_events > capacity {
+                warnings.push(AssetWarning::SubEmitterOverflow {
+                    emitter: index,
+                    max_events,
+                    capacity,
+                });
+            }
+        }
+
+        warnings
+    }
+}
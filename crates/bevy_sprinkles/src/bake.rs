@@ -0,0 +1,185 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::mesh::{Indices, PrimitiveTopology, VertexAttributeValues};
+use bevy::prelude::*;
+use std::fmt::Write;
+
+#[cfg(feature = "readback")]
+use crate::runtime::ParticleBufferHandle;
+use crate::runtime::ParticleData;
+
+const QUAD_CORNERS: [(Vec2, [f32; 2]); 4] = [
+    (Vec2::new(-0.5, -0.5), [0.0, 1.0]),
+    (Vec2::new(0.5, -0.5), [1.0, 1.0]),
+    (Vec2::new(0.5, 0.5), [1.0, 0.0]),
+    (Vec2::new(-0.5, 0.5), [0.0, 0.0]),
+];
+
+/// Freezes particles into a single static [`Mesh`], one quad per active particle.
+///
+/// Each quad is centered on the particle, sized by its scale and turned to face
+/// `facing` (typically the camera's rotation, so billboards look the same as when
+/// captured). Vertices carry positions, normals, UVs and the particle's color.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_sprinkles::bake::ParticleMeshBaker;
+///
+/// let mut baker = ParticleMeshBaker::new(Quat::IDENTITY);
+/// baker.add_particles(&[], Mat4::IDENTITY);
+/// let mesh = baker.build();
+/// assert_eq!(mesh.count_vertices(), 0);
+/// ```
+pub struct ParticleMeshBaker {
+    facing: Quat,
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    uvs: Vec<[f32; 2]>,
+    colors: Vec<[f32; 4]>,
+    indices: Vec<u32>,
+}
+
+impl ParticleMeshBaker {
+    /// Creates an empty baker whose quads face along `facing`'s forward axis.
+    pub fn new(facing: Quat) -> Self {
+        Self {
+            facing,
+            positions: Vec::new(),
+            normals: Vec::new(),
+            uvs: Vec::new(),
+            colors: Vec::new(),
+            indices: Vec::new(),
+        }
+    }
+
+    /// Adds a quad for every active particle, moving emitter-local positions into
+    /// the mesh's space with `transform`. Inactive particles are skipped.
+    pub fn add_particles(&mut self, particles: &[ParticleData], transform: Mat4) -> &mut Self {
+        let right = self.facing * Vec3::X;
+        let up = self.facing * Vec3::Y;
+        let normal = (self.facing * Vec3::Z).to_array();
+
+        for particle in particles.iter().filter(|p| p.is_active()) {
+            let [x, y, z, scale] = particle.position;
+            let center = transform.transform_point3(Vec3::new(x, y, z));
+            let base = self.positions.len() as u32;
+
+            for (corner, uv) in QUAD_CORNERS {
+                let position = center + (right * corner.x + up * corner.y) * scale;
+                self.positions.push(position.to_array());
+                self.normals.push(normal);
+                self.uvs.push(uv);
+                self.colors.push(particle.color);
+            }
+            self.indices
+                .extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+        self
+    }
+
+    /// Returns the number of particles baked so far.
+    pub fn particle_count(&self) -> usize {
+        self.positions.len() / QUAD_CORNERS.len()
+    }
+
+    /// Builds the baked mesh. With no active particles the mesh has no vertices.
+    pub fn build(self) -> Mesh {
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, self.positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, self.normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, self.uvs)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, self.colors)
+        .with_inserted_indices(Indices::U32(self.indices))
+    }
+}
+
+/// Returns a [`Readback`](bevy::render::gpu_readback::Readback) that copies an emitter's
+/// particle buffer back to the CPU.
+///
+/// Spawn it and observe [`ReadbackComplete`](bevy::render::gpu_readback::ReadbackComplete),
+/// then decode the data with [`particles_from_bytes`]. The readback repeats every frame
+/// until the entity is despawned.
+#[cfg(feature = "readback")]
+pub fn particle_readback(buffers: &ParticleBufferHandle) -> bevy::render::gpu_readback::Readback {
+    bevy::render::gpu_readback::Readback::buffer(buffers.particle_buffer.clone())
+}
+
+/// Reinterprets a particle buffer read back from the GPU as [`ParticleData`].
+///
+/// Trailing bytes that don't make up a whole particle are ignored.
+pub fn particles_from_bytes(bytes: &[u8]) -> Vec<ParticleData> {
+    let stride = size_of::<ParticleData>();
+    bytes
+        .chunks_exact(stride)
+        .map(bytemuck::pod_read_unaligned)
+        .collect()
+}
+
+/// Writes a triangle mesh as Wavefront OBJ text.
+///
+/// Positions, UVs and normals are written when present. Vertex colors use the
+/// widely supported `v x y z r g b` extension.
+pub fn mesh_to_obj(mesh: &Mesh) -> String {
+    let mut obj = String::from("# baked with bevy_sprinkles\n");
+
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        return obj;
+    };
+    let colors = match mesh.attribute(Mesh::ATTRIBUTE_COLOR) {
+        Some(VertexAttributeValues::Float32x4(colors)) => Some(colors),
+        _ => None,
+    };
+    let uvs = match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+        Some(VertexAttributeValues::Float32x2(uvs)) => Some(uvs),
+        _ => None,
+    };
+    let normals = match mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+        Some(VertexAttributeValues::Float32x3(normals)) => Some(normals),
+        _ => None,
+    };
+
+    for (i, [x, y, z]) in positions.iter().enumerate() {
+        match colors.and_then(|c| c.get(i)) {
+            Some([r, g, b, _]) => writeln!(obj, "v {x} {y} {z} {r} {g} {b}"),
+            None => writeln!(obj, "v {x} {y} {z}"),
+        }
+        .ok();
+    }
+    for [u, v] in uvs.into_iter().flatten() {
+        // OBJ texture coordinates have their origin at the bottom left
+        writeln!(obj, "vt {u} {}", 1.0 - v).ok();
+    }
+    for [x, y, z] in normals.into_iter().flatten() {
+        writeln!(obj, "vn {x} {y} {z}").ok();
+    }
+
+    let vertex = |i: usize| {
+        let i = i + 1;
+        match (uvs.is_some(), normals.is_some()) {
+            (true, true) => format!("{i}/{i}/{i}"),
+            (true, false) => format!("{i}/{i}"),
+            (false, true) => format!("{i}//{i}"),
+            (false, false) => i.to_string(),
+        }
+    };
+    let indices: Vec<usize> = match mesh.indices() {
+        Some(indices) => indices.iter().collect(),
+        None => (0..positions.len()).collect(),
+    };
+    for face in indices.chunks_exact(3) {
+        writeln!(
+            obj,
+            "f {} {} {}",
+            vertex(face[0]),
+            vertex(face[1]),
+            vertex(face[2])
+        )
+        .ok();
+    }
+
+    obj
+}
@@ -0,0 +1,121 @@
+use bevy::{prelude::*, render::storage::ShaderStorageBuffer};
+use thiserror::Error;
+
+use crate::{
+    asset::ParticleSystemAsset,
+    runtime::{
+        EmitterEntity, EmitterRuntime, ParticleBufferHandle, ParticleData, ParticleSystem3D,
+    },
+};
+
+/// A mismatch between what an emitter dispatches and what its GPU buffers can hold.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum BufferBoundsError {
+    /// The emitter would simulate more particles than its buffers were sized for.
+    #[error("Emitter dispatches {amount} particles but its buffers hold {max_particles}")]
+    AmountExceedsCapacity {
+        /// Number of particles the compute pass would simulate.
+        amount: u32,
+        /// [`ParticleBufferHandle::max_particles`] of the emitter.
+        max_particles: u32,
+    },
+    /// One of the emitter's buffers is smaller than `max_particles` requires.
+    #[error(
+        "The {buffer} buffer holds {len} bytes but {required} are needed for {max_particles} particles"
+    )]
+    BufferTooSmall {
+        /// Which buffer is undersized.
+        buffer: &'static str,
+        /// Size of the buffer in bytes.
+        len: u64,
+        /// Size the buffer needs to be, in bytes.
+        required: u64,
+        /// [`ParticleBufferHandle::max_particles`] of the emitter.
+        max_particles: u32,
+    },
+}
+
+fn buffer_len(buffer: &ShaderStorageBuffer) -> u64 {
+    buffer
+        .data
+        .as_ref()
+        .map_or(buffer.buffer_description.size, |data| data.len() as u64)
+}
+
+/// Checks that `amount` particles fit in the buffers behind `handle`.
+///
+/// Buffers missing from `buffers`, e.g. ones only kept in the render world, are skipped.
+pub fn check_buffer_bounds(
+    handle: &ParticleBufferHandle,
+    amount: u32,
+    buffers: &Assets<ShaderStorageBuffer>,
+) -> Result<(), BufferBoundsError> {
+    if amount > handle.max_particles {
+        return Err(BufferBoundsError::AmountExceedsCapacity {
+            amount,
+            max_particles: handle.max_particles,
+        });
+    }
+
+    let particle_bytes = handle.max_particles as u64 * size_of::<ParticleData>() as u64;
+    let index_bytes = handle.max_particles as u64 * size_of::<u32>() as u64;
+    for (buffer, asset, required) in [
+        ("particle", &handle.particle_buffer, particle_bytes),
+        ("indices", &handle.indices_buffer, index_bytes),
+        (
+            "sorted particles",
+            &handle.sorted_particles_buffer,
+            particle_bytes,
+        ),
+    ] {
+        let Some(len) = buffers.get(asset).map(buffer_len) else {
+            continue;
+        };
+        if len < required {
+            return Err(BufferBoundsError::BufferTooSmall {
+                buffer,
+                len,
+                required,
+                max_particles: handle.max_particles,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Validates every emitter's particle count against its buffers before they are extracted
+/// for dispatch.
+///
+/// Runs after the buffers are grown, so an amount above
+/// [`ParticleBufferHandle::max_particles`] means they were capped by
+/// [`ParticleBufferGrowth`](crate::runtime::ParticleBufferGrowth) and would drop particles.
+///
+/// Only added with the `bounds-checks` feature. Panics on overflow in debug builds and
+/// logs an error otherwise.
+pub fn validate_particle_buffers(
+    emitters: Query<(&EmitterEntity, &EmitterRuntime, &ParticleBufferHandle)>,
+    particle_systems: Query<&ParticleSystem3D>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    buffers: Res<Assets<ShaderStorageBuffer>>,
+) {
+    for (emitter, runtime, handle) in &emitters {
+        let Some(emitter_data) = particle_systems
+            .get(emitter.parent_system)
+            .ok()
+            .and_then(|system| assets.get(&system.handle))
+            .and_then(|asset| asset.emitters.get(runtime.emitter_index))
+        else {
+            continue;
+        };
+
+        // `extract_particle_systems` clamps the amount to fit, so the requested amount is
+        // checked to catch spawns the buffers silently drop
+        let amount = emitter_data.emission.particles_amount;
+        if let Err(error) = check_buffer_bounds(handle, amount, &buffers) {
+            if cfg!(debug_assertions) {
+                panic!("Emitter \"{}\": {error}", emitter_data.name);
+            }
+            error!("Emitter \"{}\": {error}", emitter_data.name);
+        }
+    }
+}
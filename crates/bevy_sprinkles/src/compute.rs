@@ -2,6 +2,7 @@ use bevy::{
     prelude::*,
     render::{
         Render, RenderApp, RenderStartup, RenderSystems,
+        diagnostic::RecordDiagnostics,
         render_asset::RenderAssets,
         render_graph::{self, RenderGraph, RenderLabel},
         render_resource::{
@@ -28,7 +29,7 @@ use crate::extract::{
     ColliderUniform, EmitterUniforms, ExtractedColliders, ExtractedEmitterData,
     ExtractedParticleSystem, MAX_COLLIDERS,
 };
-use crate::runtime::ParticleData;
+use crate::runtime::{EmitterStatsBuffer, ParticleData};
 use crate::textures::{FallbackCurveTexture, FallbackGradientTexture};
 
 #[derive(Clone, Copy, Default, bytemuck::Pod, bytemuck::Zeroable, ShaderType)]
@@ -38,6 +39,8 @@ pub struct ColliderArray {
 }
 
 const SHADER_ASSET_PATH: &str = "embedded://bevy_sprinkles/shaders/particle_simulate.wgsl";
+// recorded by Bevy's `RenderDiagnosticsPlugin`, surfaced through the `profiling` feature
+const SIMULATE_SPAN: &str = "particle_simulate";
 const WORKGROUP_SIZE: u32 = 64;
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
@@ -83,6 +86,8 @@ pub fn init_particle_compute_pipeline(
                 storage_buffer_read_only::<ColliderArray>(false),
                 storage_buffer_sized(false, None),
                 storage_buffer_sized(false, None),
+                storage_buffer_sized(false, None),
+                storage_buffer_read_only::<ParticleData>(false),
             ),
         ),
     );
@@ -122,6 +127,24 @@ pub fn init_particle_compute_pipeline(
         },
     );
 
+    // written to by emitters whose stats aren't tracked
+    let fallback_stats_buffer = render_device.create_buffer_with_data(
+        &bevy::render::render_resource::BufferInitDescriptor {
+            label: Some("fallback_emitter_stats_buffer"),
+            contents: &[0u8; EmitterStatsBuffer::SIZE as usize],
+            usage: BufferUsages::STORAGE,
+        },
+    );
+
+    // a single inactive particle, bound when no parent is followed
+    let fallback_parent_buffer = render_device.create_buffer_with_data(
+        &bevy::render::render_resource::BufferInitDescriptor {
+            label: Some("fallback_parent_particle_buffer"),
+            contents: bytemuck::bytes_of(&ParticleData::default()),
+            usage: BufferUsages::STORAGE,
+        },
+    );
+
     commands.insert_resource(ParticleComputePipeline {
         bind_group_layout,
         simulate_pipeline,
@@ -129,6 +152,8 @@ pub fn init_particle_compute_pipeline(
     commands.insert_resource(GradientSampler(gradient_sampler));
     commands.insert_resource(CurveSampler(curve_sampler));
     commands.insert_resource(FallbackEmissionBuffer(fallback_emission_buffer));
+    commands.insert_resource(FallbackStatsBuffer(fallback_stats_buffer));
+    commands.insert_resource(FallbackParentBuffer(fallback_parent_buffer));
 }
 
 #[derive(Resource)]
@@ -140,6 +165,12 @@ pub struct CurveSampler(pub bevy::render::render_resource::Sampler);
 #[derive(Resource)]
 pub struct FallbackEmissionBuffer(pub Buffer);
 
+#[derive(Resource)]
+pub struct FallbackStatsBuffer(pub Buffer);
+
+#[derive(Resource)]
+pub struct FallbackParentBuffer(pub Buffer);
+
 #[derive(Resource, Default)]
 pub struct EmissionBufferClearList {
     pub buffers: Vec<Buffer>,
@@ -148,6 +179,11 @@ pub struct EmissionBufferClearList {
 #[derive(Resource, Default)]
 pub struct ParticleComputeBindGroups {
     pub bind_groups: Vec<(Entity, Vec<BindGroup>)>,
+    /// Stats buffers of the emitters tracking them, cleared before they are simulated.
+    pub stats_buffers: Vec<(Entity, Buffer)>,
+    /// Particles to carry over from replaced buffers into grown ones, as source,
+    /// destination and size in bytes.
+    pub particle_copies: Vec<(Buffer, Buffer, u64)>,
 }
 
 pub fn prepare_particle_compute_bind_groups(
@@ -163,10 +199,14 @@ pub fn prepare_particle_compute_bind_groups(
     fallback_gradient_texture: Option<Res<FallbackGradientTexture>>,
     fallback_curve_texture: Option<Res<FallbackCurveTexture>>,
     fallback_emission_buffer: Res<FallbackEmissionBuffer>,
+    fallback_stats_buffer: Res<FallbackStatsBuffer>,
+    fallback_parent_buffer: Res<FallbackParentBuffer>,
     gradient_sampler: Res<GradientSampler>,
     curve_sampler: Res<CurveSampler>,
 ) {
     let mut bind_groups = Vec::new();
+    let mut stats_buffers = Vec::new();
+    let mut particle_copies = Vec::new();
 
     let fallback_gradient_gpu_image = fallback_gradient_texture
         .as_ref()
@@ -204,6 +244,18 @@ pub fn prepare_particle_compute_bind_groups(
             continue;
         };
 
+        if let Some((previous_buffer, previous_max_particles)) = emitter_data
+            .previous_particle_buffer
+            .as_ref()
+            .and_then(|(h, max)| gpu_storage_buffers.get(h).map(|b| (&b.buffer, max)))
+        {
+            particle_copies.push((
+                previous_buffer.clone(),
+                gpu_buffer.buffer.clone(),
+                *previous_max_particles as u64 * size_of::<ParticleData>() as u64,
+            ));
+        }
+
         fn resolve_texture<'a>(
             handle: &Option<Handle<Image>>,
             gpu_images: &'a RenderAssets<GpuImage>,
@@ -292,10 +344,25 @@ pub fn prepare_particle_compute_bind_groups(
 
         let dst_binding = dst_buffer.unwrap_or(&fallback_emission_buffer.0);
         let src_binding = src_buffer.unwrap_or(&fallback_emission_buffer.0);
+        let parent_binding = emitter_data
+            .parent_particle_buffer_handle
+            .as_ref()
+            .and_then(|h| gpu_storage_buffers.get(h))
+            .map_or(&fallback_parent_buffer.0, |b| &b.buffer);
+
+        let stats_buffer = emitter_data
+            .stats_buffer_handle
+            .as_ref()
+            .and_then(|h| gpu_storage_buffers.get(h))
+            .map(|b| &b.buffer);
+        let stats_binding = stats_buffer.unwrap_or(&fallback_stats_buffer.0);
 
         if let Some(buf) = dst_buffer {
             emission_clear_list.push(buf.clone());
         }
+        if let Some(buf) = stats_buffer {
+            stats_buffers.push((*entity, buf.clone()));
+        }
 
         let step_bind_groups: Vec<BindGroup> = emitter_data
             .uniform_steps
@@ -339,6 +406,8 @@ pub fn prepare_particle_compute_bind_groups(
                         colliders_buffer.as_entire_binding(),
                         dst_binding.as_entire_binding(),
                         src_binding.as_entire_binding(),
+                        stats_binding.as_entire_binding(),
+                        parent_binding.as_entire_binding(),
                     )),
                 )
             })
@@ -354,7 +423,11 @@ pub fn prepare_particle_compute_bind_groups(
         }
     }
 
-    commands.insert_resource(ParticleComputeBindGroups { bind_groups });
+    commands.insert_resource(ParticleComputeBindGroups {
+        bind_groups,
+        stats_buffers,
+        particle_copies,
+    });
     commands.insert_resource(EmissionBufferClearList {
         buffers: unique_buffers,
     });
@@ -424,6 +497,19 @@ impl render_graph::Node for ParticleComputeNode {
             .map(|(e, data)| (*e, data))
             .collect();
 
+        let diagnostics = render_context.diagnostic_recorder();
+        let time_span = diagnostics.time_span(render_context.command_encoder(), SIMULATE_SPAN);
+
+        for (source, destination, size) in &bind_groups.particle_copies {
+            render_context.command_encoder().copy_buffer_to_buffer(
+                source,
+                0,
+                destination,
+                0,
+                *size,
+            );
+        }
+
         let has_sub_emitter_targets = emitter_map.values().any(|data| data.is_sub_emitter_target);
         let pass_labels: &[&str] = if has_sub_emitter_targets {
             &["particle_compute_pass", "particle_sub_emitter_pass"]
@@ -438,6 +524,16 @@ impl render_graph::Node for ParticleComputeNode {
                     .clear_buffer(buf, 0, Some(4));
             }
 
+            // live particles are recounted every step
+            for (entity, buf) in &bind_groups.stats_buffers {
+                if emitter_map
+                    .get(entity)
+                    .is_some_and(|data| step_index < data.uniform_steps.len())
+                {
+                    render_context.command_encoder().clear_buffer(buf, 0, None);
+                }
+            }
+
             for (pass_index, label) in pass_labels.iter().enumerate() {
                 let is_target_pass = pass_index == 1;
 
@@ -471,6 +567,8 @@ impl render_graph::Node for ParticleComputeNode {
             }
         }
 
+        time_span.end(render_context.command_encoder());
+
         Ok(())
     }
 }
@@ -11,9 +11,9 @@ use crate::{
         SubEmitterMode,
     },
     runtime::{
-        EmitterEntity, EmitterRuntime, ParticleBufferHandle, ParticleSystem3D,
-        ParticleSystemRuntime, ParticlesCollider3D, SubEmitterBufferHandle, compute_phase,
-        is_past_delay,
+        EmitterEntity, EmitterRuntime, EmitterStatsBuffer, ParticleBufferHandle, ParticleSystem3D,
+        ParticleSystemRuntime, ParticlesCollider3D, PreviousParticleBuffer, SubEmitterBufferHandle,
+        compute_cycle_phase, is_past_cycle_delay,
     },
     textures::{CurveTextureCache, GradientTextureCache},
 };
@@ -23,6 +23,7 @@ pub const EMISSION_SHAPE_SPHERE: u32 = 1;
 pub const EMISSION_SHAPE_SPHERE_SURFACE: u32 = 2;
 pub const EMISSION_SHAPE_BOX: u32 = 3;
 pub const EMISSION_SHAPE_RING: u32 = 4;
+pub const EMISSION_SHAPE_BOX_SURFACE: u32 = 5;
 
 pub const COLLIDER_TYPE_SPHERE: u32 = 0;
 pub const COLLIDER_TYPE_BOX: u32 = 1;
@@ -38,6 +39,10 @@ pub const SUB_EMITTER_MODE_AT_END: u32 = 2;
 pub const SUB_EMITTER_MODE_AT_COLLISION: u32 = 3;
 pub const SUB_EMITTER_MODE_AT_START: u32 = 4;
 
+pub const EMISSION_MODE_TIME: u32 = 0;
+pub const EMISSION_MODE_PER_DISTANCE: u32 = 1;
+pub const EMISSION_MODE_BURST: u32 = 2;
+
 #[derive(Clone, Copy, Default, Pod, Zeroable, ShaderType)]
 #[repr(C)]
 pub struct CurveUniform {
@@ -127,7 +132,7 @@ pub struct EmitterUniforms {
     pub _pad3: f32,
 
     pub emission_ring_axis: [f32; 3],
-    pub _pad4: f32,
+    pub emission_ring_radial_bias: f32,
 
     pub direction: [f32; 3],
     pub _pad5: f32,
@@ -145,7 +150,7 @@ pub struct EmitterUniforms {
     pub use_initial_color_gradient: u32,
     pub turbulence_enabled: u32,
     pub particle_flags: u32,
-    pub _pad7: u32,
+    pub count_alive_particles: u32,
 
     pub initial_color: [f32; 4],
 
@@ -189,9 +194,67 @@ pub struct EmitterUniforms {
     pub sub_emitter_keep_velocity: u32,
 
     pub is_sub_emitter_target: u32,
-    pub _sub_emitter_pad0: u32,
+    pub sub_emitter_keep_local: u32,
     pub _sub_emitter_pad1: u32,
     pub _sub_emitter_pad2: u32,
+
+    pub user_data_min: [f32; 4],
+    pub user_data_max: [f32; 4],
+
+    pub kill_box_min: [f32; 3],
+    pub kill_box_enabled: u32,
+    pub kill_box_max: [f32; 3],
+    pub _kill_box_pad0: f32,
+
+    pub fade: f32,
+    pub spawn_ratio: f32,
+    pub _fade_pad1: f32,
+    pub _fade_pad2: f32,
+
+    pub color_tint: [f32; 4],
+
+    pub mirror: [f32; 3],
+    pub _mirror_pad0: f32,
+
+    pub position_jitter: [f32; 3],
+    pub spawn_offset_along_velocity: f32,
+
+    pub acceleration_randomness: [f32; 3],
+    pub _acceleration_randomness_pad0: f32,
+
+    pub inherit_velocity: [f32; 3],
+    pub inherit_velocity_max: f32,
+
+    pub wobble_amplitude: [f32; 3],
+    pub wobble_frequency: f32,
+
+    pub flicker_enabled: u32,
+    pub flicker_amplitude: f32,
+    pub flicker_frequency: f32,
+    pub _flicker_pad0: f32,
+
+    pub containment_enabled: u32,
+    pub containment_radius: f32,
+    pub containment_strength: f32,
+    pub _containment_pad0: f32,
+
+    pub emit_along_normal: f32,
+    pub _normal_pad0: f32,
+    pub _normal_pad1: f32,
+    pub _normal_pad2: f32,
+
+    pub scale_in_time: f32,
+    pub _scale_in_pad0: f32,
+    pub _scale_in_pad1: f32,
+    pub _scale_in_pad2: f32,
+
+    pub emission_mode: u32,
+    pub scheduled_spawn_start: u32,
+    pub scheduled_spawns: u32,
+    pub _emission_mode_pad0: u32,
+
+    pub inherited_scale: [f32; 3],
+    pub inherited_size: f32,
 }
 
 #[derive(Resource, Default)]
@@ -211,6 +274,8 @@ pub struct ExtractedEmitterData {
     pub sorted_particles_buffer_handle: Handle<ShaderStorageBuffer>,
     pub amount: u32,
     pub draw_order: u32,
+    pub sort_precision: f32,
+    pub extrapolate_time: f32,
     pub camera_position: [f32; 3],
     pub camera_forward: [f32; 3],
     pub emitter_transform: Mat4,
@@ -226,6 +291,10 @@ pub struct ExtractedEmitterData {
     pub is_sub_emitter_target: bool,
     pub emission_buffer_handle: Option<Handle<ShaderStorageBuffer>>,
     pub source_buffer_handle: Option<Handle<ShaderStorageBuffer>>,
+    pub stats_buffer_handle: Option<Handle<ShaderStorageBuffer>>,
+    pub parent_particle_buffer_handle: Option<Handle<ShaderStorageBuffer>>,
+    /// Particle buffer replaced by a grown one this frame and how many particles it holds.
+    pub previous_particle_buffer: Option<(Handle<ShaderStorageBuffer>, u32)>,
 }
 
 fn curve_uniform_from(curve: &Option<CurveTexture>) -> CurveUniform {
@@ -279,6 +348,7 @@ struct EmissionShapeUniforms {
     ring_height: f32,
     ring_radius: f32,
     ring_inner_radius: f32,
+    ring_radial_bias: f32,
 }
 
 fn emission_shape_uniforms_from(shape: &EmissionShape) -> EmissionShapeUniforms {
@@ -291,6 +361,7 @@ fn emission_shape_uniforms_from(shape: &EmissionShape) -> EmissionShapeUniforms
             ring_height: 0.0,
             ring_radius: 0.0,
             ring_inner_radius: 0.0,
+            ring_radial_bias: 0.0,
         },
         EmissionShape::Sphere { radius } => EmissionShapeUniforms {
             shape: EMISSION_SHAPE_SPHERE,
@@ -300,6 +371,7 @@ fn emission_shape_uniforms_from(shape: &EmissionShape) -> EmissionShapeUniforms
             ring_height: 0.0,
             ring_radius: 0.0,
             ring_inner_radius: 0.0,
+            ring_radial_bias: 0.0,
         },
         EmissionShape::SphereSurface { radius } => EmissionShapeUniforms {
             shape: EMISSION_SHAPE_SPHERE_SURFACE,
@@ -309,21 +381,31 @@ fn emission_shape_uniforms_from(shape: &EmissionShape) -> EmissionShapeUniforms
             ring_height: 0.0,
             ring_radius: 0.0,
             ring_inner_radius: 0.0,
+            ring_radial_bias: 0.0,
         },
-        EmissionShape::Box { extents } => EmissionShapeUniforms {
-            shape: EMISSION_SHAPE_BOX,
+        EmissionShape::Box {
+            extents,
+            surface_only,
+        } => EmissionShapeUniforms {
+            shape: if surface_only {
+                EMISSION_SHAPE_BOX_SURFACE
+            } else {
+                EMISSION_SHAPE_BOX
+            },
             sphere_radius: 0.0,
             box_extents: extents,
             ring_axis: Vec3::Z,
             ring_height: 0.0,
             ring_radius: 0.0,
             ring_inner_radius: 0.0,
+            ring_radial_bias: 0.0,
         },
         EmissionShape::Ring {
             axis,
             height,
             radius,
             inner_radius,
+            radial_bias,
         } => EmissionShapeUniforms {
             shape: EMISSION_SHAPE_RING,
             sphere_radius: 0.0,
@@ -332,6 +414,7 @@ fn emission_shape_uniforms_from(shape: &EmissionShape) -> EmissionShapeUniforms
             ring_height: height,
             ring_radius: radius,
             ring_inner_radius: inner_radius,
+            ring_radial_bias: radial_bias.clamp(-1.0, 1.0),
         },
     }
 }
@@ -352,9 +435,13 @@ fn build_base_uniforms(
     draw_order: u32,
     es: &EmissionShapeUniforms,
     collision: &CollisionUniforms,
-    sub_emitter_uniforms: (u32, f32, u32, u32),
+    sub_emitter_uniforms: (u32, f32, u32, u32, u32),
 ) -> EmitterUniforms {
     let turbulence = &emitter.turbulence;
+    let kill_box = emitter.kill_box;
+    let containment = emitter.containment;
+    let flicker = emitter.colors.brightness_flicker;
+    let wobble = emitter.velocities.wobble;
 
     EmitterUniforms {
         delta_time: 0.0,
@@ -367,7 +454,7 @@ fn build_base_uniforms(
         lifetime_randomness: emitter.time.lifetime_randomness,
         emitting: 0,
 
-        gravity: emitter.accelerations.gravity.into(),
+        gravity: emitter.accelerations.scaled_gravity().into(),
         random_seed: runtime.random_seed,
 
         emission_shape: es.shape,
@@ -395,7 +482,7 @@ fn build_base_uniforms(
         _pad3: 0.0,
 
         emission_ring_axis: es.ring_axis.into(),
-        _pad4: 0.0,
+        emission_ring_radial_bias: es.ring_radial_bias,
 
         direction: emitter.velocities.initial_direction.into(),
         _pad5: 0.0,
@@ -416,7 +503,7 @@ fn build_base_uniforms(
         },
         turbulence_enabled: if turbulence.enabled { 1 } else { 0 },
         particle_flags: emitter.particle_flags.bits(),
-        _pad7: 0,
+        count_alive_particles: 0,
 
         initial_color: match &emitter.colors.initial_color {
             SolidOrGradientColor::Solid { color } => *color,
@@ -461,9 +548,71 @@ fn build_base_uniforms(
         sub_emitter_amount: sub_emitter_uniforms.2,
         sub_emitter_keep_velocity: sub_emitter_uniforms.3,
         is_sub_emitter_target: 0,
-        _sub_emitter_pad0: 0,
+        sub_emitter_keep_local: sub_emitter_uniforms.4,
         _sub_emitter_pad1: 0,
         _sub_emitter_pad2: 0,
+
+        user_data_min: emitter.user_data.unwrap_or_default().min,
+        user_data_max: emitter.user_data.unwrap_or_default().max,
+
+        kill_box_min: kill_box.map_or([0.0; 3], |b| b.min.to_array()),
+        kill_box_enabled: kill_box.is_some() as u32,
+        kill_box_max: kill_box.map_or([0.0; 3], |b| b.max.to_array()),
+        _kill_box_pad0: 0.0,
+
+        fade: runtime.alpha_multiplier(),
+        spawn_ratio: 1.0,
+        _fade_pad1: 0.0,
+        _fade_pad2: 0.0,
+        color_tint: runtime.tint(),
+        mirror: [1.0; 3],
+        _mirror_pad0: 0.0,
+
+        position_jitter: emitter.emission.position_jitter.into(),
+        spawn_offset_along_velocity: emitter.emission.spawn_offset_along_velocity,
+
+        acceleration_randomness: emitter.accelerations.acceleration_randomness.into(),
+        _acceleration_randomness_pad0: 0.0,
+
+        inherit_velocity: runtime.velocity.into(),
+        inherit_velocity_max: emitter.velocities.inherit_velocity_max.max(0.0),
+
+        wobble_amplitude: wobble.map_or([0.0; 3], |w| w.amplitude.into()),
+        wobble_frequency: wobble.map_or(0.0, |w| w.frequency.max(0.0)),
+
+        flicker_enabled: flicker.is_some() as u32,
+        flicker_amplitude: flicker.map_or(0.0, |f| f.amplitude.max(0.0)),
+        flicker_frequency: flicker.map_or(0.0, |f| f.frequency.max(0.0)),
+        _flicker_pad0: 0.0,
+
+        containment_enabled: containment.is_some() as u32,
+        containment_radius: containment.map_or(0.0, |c| c.radius.max(0.0)),
+        containment_strength: containment.map_or(0.0, |c| c.strength.max(0.0)),
+        _containment_pad0: 0.0,
+
+        emit_along_normal: emitter.velocities.emit_along_normal.clamp(0.0, 1.0),
+        _normal_pad0: 0.0,
+        _normal_pad1: 0.0,
+        _normal_pad2: 0.0,
+
+        scale_in_time: emitter.scale.scale_in_time.clamp(0.0, 1.0),
+        _scale_in_pad0: 0.0,
+        _scale_in_pad1: 0.0,
+        _scale_in_pad2: 0.0,
+
+        emission_mode: if emitter.emission.mode.spacing().is_some() {
+            EMISSION_MODE_PER_DISTANCE
+        } else if emitter.emission.active_burst().is_some() {
+            EMISSION_MODE_BURST
+        } else {
+            EMISSION_MODE_TIME
+        },
+        scheduled_spawn_start: 0,
+        scheduled_spawns: 0,
+        _emission_mode_pad0: 0,
+
+        inherited_scale: [1.0; 3],
+        inherited_size: 1.0,
     }
 }
 
@@ -477,6 +626,8 @@ pub fn extract_particle_systems(
             &ParticleBufferHandle,
             &GlobalTransform,
             Option<&SubEmitterBufferHandle>,
+            Option<&EmitterStatsBuffer>,
+            Option<&PreviousParticleBuffer>,
         )>,
     >,
     system_query: Extract<Query<(&ParticleSystem3D, &ParticleSystemRuntime)>>,
@@ -497,8 +648,21 @@ pub fn extract_particle_systems(
         (Entity, usize),
         Handle<ShaderStorageBuffer>,
     > = std::collections::HashMap::new();
-    for (_entity, emitter_entity, runtime, _buffer_handle, _global_transform, sub_emitter_buf) in
-        emitter_query.iter()
+    // particle buffers of parents whose children follow them, by the same key
+    let mut parent_particle_buffer_map: std::collections::HashMap<
+        (Entity, usize),
+        Handle<ShaderStorageBuffer>,
+    > = std::collections::HashMap::new();
+    for (
+        _entity,
+        emitter_entity,
+        runtime,
+        buffer_handle,
+        _global_transform,
+        sub_emitter_buf,
+        _,
+        _,
+    ) in emitter_query.iter()
     {
         let Some(sub_buf) = sub_emitter_buf else {
             continue;
@@ -519,12 +683,26 @@ pub fn extract_particle_systems(
             (emitter_entity.parent_system, sub_config.target_emitter),
             sub_buf.buffer.clone(),
         );
+        if sub_config.keep_local {
+            parent_particle_buffer_map.insert(
+                (emitter_entity.parent_system, sub_config.target_emitter),
+                buffer_handle.particle_buffer.clone(),
+            );
+        }
     }
 
-    for (entity, emitter_entity, runtime, buffer_handle, global_transform, sub_emitter_buf) in
-        emitter_query.iter()
+    for (
+        entity,
+        emitter_entity,
+        runtime,
+        buffer_handle,
+        global_transform,
+        sub_emitter_buf,
+        stats_buffer,
+        previous_buffer,
+    ) in emitter_query.iter()
     {
-        let Ok((particle_system, _system_runtime)) = system_query.get(emitter_entity.parent_system)
+        let Ok((particle_system, system_runtime)) = system_query.get(emitter_entity.parent_system)
         else {
             continue;
         };
@@ -564,38 +742,93 @@ pub fn extract_particle_systems(
                 } else {
                     1.0
                 };
-                (mode, freq, config.amount, config.keep_velocity as u32)
+                // a single parent particle can't emit more than the target holds
+                let amount =
+                    sub_emitter_buf.map_or(config.amount, |b| config.amount.min(b.max_particles));
+                (
+                    mode,
+                    freq,
+                    amount,
+                    config.keep_velocity as u32,
+                    config.keep_local as u32,
+                )
             }
-            None => (SUB_EMITTER_MODE_DISABLED, 1.0, 1, 0),
+            None => (SUB_EMITTER_MODE_DISABLED, 1.0, 1, 0, 0),
         };
 
-        let base_uniforms = build_base_uniforms(
-            emitter,
-            runtime,
-            draw_order,
-            &es,
-            &collision,
-            sub_emitter_uniforms,
-        );
+        // the buffers may not have grown to the requested amount yet, or may be capped
+        let amount = emitter
+            .emission
+            .particles_amount
+            .min(buffer_handle.max_particles);
+        let spawn_ratio = match system_runtime.particle_budget {
+            Some(budget) if amount > 0 => {
+                let budgeted = asset.budgeted_amounts(budget)[runtime.emitter_index];
+                (budgeted as f32 / amount as f32).min(1.0)
+            }
+            _ => 1.0,
+        };
+        let mirror = asset.mirror_scale();
+        let inherited_scale = emitter.inherited_scale(global_transform.scale());
+        let base_uniforms = EmitterUniforms {
+            amount,
+            spawn_ratio,
+            // only emitters whose stats are read back pay for the count
+            count_alive_particles: stats_buffer.is_some() as u32,
+            mirror: mirror.to_array(),
+            inherited_scale: inherited_scale.to_array(),
+            inherited_size: inherited_scale.abs().element_sum() / 3.0,
+            // particles simulate in the emitter's local space
+            inherit_velocity: (global_transform.rotation().inverse() * runtime.velocity).into(),
+            gravity: (emitter.accelerations.scaled_gravity() * mirror).into(),
+            ..build_base_uniforms(
+                emitter,
+                runtime,
+                draw_order,
+                &es,
+                &collision,
+                sub_emitter_uniforms,
+            )
+        };
 
         let is_sub_emitter_target = emission_buffer_map
             .contains_key(&(emitter_entity.parent_system, runtime.emitter_index));
 
+        let cycle_duration = asset.cycle_duration(runtime.emitter_index);
         let uniform_steps: Vec<EmitterUniforms> = runtime
             .simulation_steps
             .iter()
             .map(|step| {
+                let delay = emitter.time.cycle_delay(runtime.random_seed, step.cycle);
+                let prev_cycle = if step.prev_system_time > step.system_time {
+                    step.cycle.saturating_sub(1)
+                } else {
+                    step.cycle
+                };
+                let prev_delay = emitter.time.cycle_delay(runtime.random_seed, prev_cycle);
                 let should_emit = if is_sub_emitter_target {
                     false
                 } else {
-                    runtime.emitting && is_past_delay(step.system_time, &emitter.time)
+                    runtime.emitting && is_past_cycle_delay(step.system_time, cycle_duration, delay)
                 };
                 EmitterUniforms {
                     delta_time: step.delta_time,
-                    system_phase: compute_phase(step.system_time, &emitter.time),
-                    prev_system_phase: compute_phase(step.prev_system_time, &emitter.time),
+                    system_phase: compute_cycle_phase(
+                        step.system_time,
+                        &emitter.time,
+                        cycle_duration,
+                        delay,
+                    ),
+                    prev_system_phase: compute_cycle_phase(
+                        step.prev_system_time,
+                        &emitter.time,
+                        cycle_duration,
+                        prev_delay,
+                    ),
                     cycle: step.cycle,
                     emitting: if should_emit { 1 } else { 0 },
+                    scheduled_spawn_start: step.scheduled_spawn_start,
+                    scheduled_spawns: step.scheduled_spawns,
                     clear_particles: if step.clear_requested { 1 } else { 0 },
                     is_sub_emitter_target: if is_sub_emitter_target { 1 } else { 0 },
                     ..base_uniforms
@@ -609,7 +842,7 @@ pub fn extract_particle_systems(
         };
 
         let color_over_lifetime_texture_handle =
-            gradient_cache.get(&emitter.colors.color_over_lifetime);
+            gradient_cache.get_color_over_lifetime(&emitter.colors);
 
         let scale_over_lifetime_texture_handle =
             resolve_curve_texture(&emitter.scale.scale_over_lifetime, &curve_cache);
@@ -638,6 +871,9 @@ pub fn extract_particle_systems(
         } else {
             None
         };
+        let parent_particle_buffer_handle = parent_particle_buffer_map
+            .get(&(emitter_entity.parent_system, runtime.emitter_index))
+            .cloned();
 
         extracted.emitters.push((
             entity,
@@ -646,8 +882,16 @@ pub fn extract_particle_systems(
                 particle_buffer_handle: buffer_handle.particle_buffer.clone(),
                 indices_buffer_handle: buffer_handle.indices_buffer.clone(),
                 sorted_particles_buffer_handle: buffer_handle.sorted_particles_buffer.clone(),
-                amount: emitter.emission.particles_amount,
+                amount,
                 draw_order,
+                sort_precision: emitter.draw_pass.sort_precision.max(0.0),
+                // frames skipped under a reduced simulation rate are covered by moving
+                // particles along their velocity, fixed-FPS emitters keep their steps
+                extrapolate_time: if emitter.time.fixed_fps == 0 {
+                    runtime.accumulated_delta
+                } else {
+                    0.0
+                },
                 camera_position: camera_position.into(),
                 camera_forward: camera_forward.into(),
                 emitter_transform: global_transform.to_matrix(),
@@ -663,6 +907,10 @@ pub fn extract_particle_systems(
                 is_sub_emitter_target,
                 emission_buffer_handle,
                 source_buffer_handle,
+                stats_buffer_handle: stats_buffer.map(|b| b.buffer.clone()),
+                parent_particle_buffer_handle,
+                previous_particle_buffer: previous_buffer
+                    .map(|previous| (previous.buffer.clone(), previous.max_particles)),
             },
         ));
     }
@@ -27,7 +27,7 @@
 //!
 //! fn main() {
 //!     App::new()
-//!         .add_plugins((DefaultPlugins, SprinklesPlugin))
+//!         .add_plugins((DefaultPlugins, SprinklesPlugin::default()))
 //!         // ...your other plugins, systems and resources
 //!         .run();
 //! }
@@ -49,9 +49,7 @@
 //! use bevy_sprinkles::prelude::*;
 //!
 //! fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
-//!     commands.spawn(ParticleSystem3D {
-//!         handle: asset_server.load("my_effect.ron"),
-//!     });
+//!     commands.spawn(ParticleSystem3D::new(asset_server.load("my_effect.ron")));
 //! }
 //! ```
 //!
@@ -83,7 +81,7 @@
 //!         None,
 //!     ));
 //!
-//!     commands.spawn(ParticleSystem3D { handle });
+//!     commands.spawn(ParticleSystem3D::new(handle));
 //! }
 //! ```
 //!
@@ -157,6 +155,11 @@
 
 /// Particle system asset definitions, emitter data, and serialization types.
 pub mod asset;
+/// Freezing particles into static meshes.
+pub mod bake;
+/// Development checks of particle counts against GPU buffer sizes.
+#[cfg(feature = "bounds-checks")]
+pub mod bounds;
 mod compute;
 mod extract;
 /// Particle material extension for GPU-driven particle rendering.
@@ -164,10 +167,19 @@ pub mod material;
 mod mesh;
 /// Convenience re-exports for common particle system types.
 pub mod prelude;
+/// GPU timings for the particle compute and sort dispatches.
+#[cfg(feature = "profiling")]
+pub mod profiling;
+mod random;
 /// Runtime components and state for active particle systems.
 pub mod runtime;
 mod sort;
 mod spawning;
+/// Internal systems and helpers exposed to the integration and editor tests. Not part of the
+/// public API.
+#[cfg(feature = "test-utils")]
+#[doc(hidden)]
+pub mod test_utils;
 /// Texture baking and caching for gradients and curves.
 pub mod textures;
 
@@ -185,9 +197,12 @@ use compute::ParticleComputePlugin;
 use extract::{extract_colliders, extract_particle_systems};
 use sort::ParticleSortPlugin;
 use spawning::{
-    cleanup_particle_entities, setup_particle_systems, sync_collider_data,
-    sync_emitter_mesh_transforms, sync_emitter_transform, sync_particle_material,
-    sync_particle_mesh, update_particle_time,
+    cleanup_particle_entities, clear_simulation_steps, despawn_finished_particle_systems,
+    flag_far_emitters, grow_particle_buffers, read_emitter_stats, setup_particle_systems,
+    sync_collider_data, sync_emitter_mesh_transforms, sync_emitter_stats_readbacks,
+    sync_emitter_transform, sync_particle_material, sync_particle_mesh, sync_particle_shadows,
+    trigger_emitter, update_emitter_fades, update_emitter_velocities, update_particle_time,
+    warn_sub_emitter_overflow,
 };
 use textures::{
     CurveTextureCache, FallbackCurveTexture, FallbackGradientTexture, GradientTextureCache,
@@ -195,11 +210,98 @@ use textures::{
     prepare_gradient_textures,
 };
 
+/// System sets of [`SprinklesPlugin`] that other systems can be ordered against.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SprinklesSystems {
+    /// Spawns the emitters of new particle systems, in [`Update`].
+    Setup,
+    /// Advances particle time, in the schedule picked by [`ParticleSystemSchedule`].
+    Time,
+}
+
 /// Plugin that adds GPU particle system support to a Bevy app.
 ///
 /// Registers asset loaders, compute pipelines, material plugins, texture caches,
 /// and all the systems needed to simulate and render particles.
-pub struct SprinklesPlugin;
+///
+/// By default particle time is advanced once per frame in [`Update`]. For games that
+/// need deterministic stepping, simulate on the fixed timestep instead:
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_sprinkles::prelude::*;
+///
+/// App::new().add_plugins((
+///     DefaultPlugins,
+///     SprinklesPlugin::default().with_schedule(ParticleSystemSchedule::FixedUpdate),
+/// ));
+/// ```
+#[derive(Default)]
+#[non_exhaustive]
+pub struct SprinklesPlugin {
+    /// The schedule particle time is advanced in.
+    pub schedule: ParticleSystemSchedule,
+    /// Caps the particle buffer memory of all particle systems combined, in bytes.
+    ///
+    /// Sets [`ParticleBufferGrowth::max_buffer_bytes`] when given.
+    pub max_buffer_bytes: Option<u64>,
+    /// The GPU algorithm particles are sorted with for their draw order.
+    pub sort_algorithm: SortAlgorithm,
+}
+
+/// The schedule in which [`SprinklesPlugin`] advances particle simulation time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ParticleSystemSchedule {
+    /// Step once per frame by the frame delta.
+    #[default]
+    Update,
+    /// Step once per fixed tick by the fixed timestep, as configured by [`Time<Fixed>`].
+    ///
+    /// Steps from every tick run during a frame are dispatched to the GPU together.
+    FixedUpdate,
+}
+
+/// The GPU algorithm [`SprinklesPlugin`] sorts particles with, for emitters whose
+/// [`DrawOrder`] isn't [`DrawOrder::Index`].
+///
+/// Both algorithms order particles by their sort key, then by particle index, so they
+/// draw particles in exactly the same order.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SortAlgorithm {
+    /// Compare and swap passes, one dispatch per pass. The number of passes grows with
+    /// the square of the logarithm of the particle count.
+    #[default]
+    Bitonic,
+    /// Least significant digit radix sort on the sort key, whose work grows linearly with
+    /// the particle count. Each of its eight passes sorts a 4-bit digit with three
+    /// dispatches: per-workgroup digit histograms, a prefix sum over all of them, then a
+    /// stable scatter.
+    ///
+    /// Falls back to [`Bitonic`](Self::Bitonic), with a warning, on devices whose
+    /// compute workgroups are too small, lack the workgroup memory it needs, or can't
+    /// bind four storage buffers.
+    Radix,
+}
+
+impl SprinklesPlugin {
+    /// Sets the [`schedule`](Self::schedule) particle time is advanced in.
+    pub fn with_schedule(mut self, schedule: ParticleSystemSchedule) -> Self {
+        self.schedule = schedule;
+        self
+    }
+
+    /// Sets the [`max_buffer_bytes`](Self::max_buffer_bytes) cap on particle buffer memory.
+    pub fn with_max_buffer_bytes(mut self, max_buffer_bytes: u64) -> Self {
+        self.max_buffer_bytes = Some(max_buffer_bytes);
+        self
+    }
+
+    /// Sets the [`sort_algorithm`](Self::sort_algorithm) particles are sorted with.
+    pub fn with_sort_algorithm(mut self, sort_algorithm: SortAlgorithm) -> Self {
+        self.sort_algorithm = sort_algorithm;
+        self
+    }
+}
 
 impl Plugin for SprinklesPlugin {
     fn build(&self, app: &mut App) {
@@ -224,27 +326,62 @@ impl Plugin for SprinklesPlugin {
 
         app.add_plugins(MaterialPlugin::<runtime::ParticleMaterial>::default());
 
+        app.init_resource::<ParticleBufferGrowth>()
+            .init_resource::<ParticlesGlobalControl>()
+            .init_resource::<CustomPresetRegistry>();
+        if let Some(max_buffer_bytes) = self.max_buffer_bytes {
+            app.world_mut()
+                .resource_mut::<ParticleBufferGrowth>()
+                .max_buffer_bytes = Some(max_buffer_bytes);
+        }
+
         app.add_systems(
             Update,
             (
-                setup_particle_systems,
+                setup_particle_systems.in_set(SprinklesSystems::Setup),
+                grow_particle_buffers.after(setup_particle_systems),
+                warn_sub_emitter_overflow.after(grow_particle_buffers),
+                flag_far_emitters,
+                update_emitter_fades,
+                update_emitter_velocities,
                 sync_particle_mesh,
                 sync_particle_material,
+                sync_particle_shadows.after(setup_particle_systems),
                 sync_emitter_mesh_transforms,
                 sync_emitter_transform,
                 sync_collider_data,
-                update_particle_time,
+                sync_emitter_stats_readbacks.after(setup_particle_systems),
                 cleanup_particle_entities,
             ),
         );
+        app.add_observer(read_emitter_stats);
+
+        let time_systems = (
+            update_particle_time.in_set(SprinklesSystems::Time),
+            despawn_finished_particle_systems.after(update_particle_time),
+        );
+        app.add_systems(First, clear_simulation_steps)
+            .add_observer(trigger_emitter);
+        match self.schedule {
+            ParticleSystemSchedule::Update => app.add_systems(Update, time_systems),
+            ParticleSystemSchedule::FixedUpdate => app.add_systems(FixedUpdate, time_systems),
+        };
 
         app.add_plugins((
             ParticleComputePlugin,
-            ParticleSortPlugin,
+            ParticleSortPlugin {
+                algorithm: self.sort_algorithm,
+            },
             ExtractResourcePlugin::<FallbackGradientTexture>::default(),
             ExtractResourcePlugin::<FallbackCurveTexture>::default(),
         ));
 
+        #[cfg(feature = "profiling")]
+        app.add_plugins(profiling::ParticleProfilingPlugin);
+
+        #[cfg(feature = "bounds-checks")]
+        app.add_systems(PostUpdate, bounds::validate_particle_buffers);
+
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app.add_systems(
                 ExtractSchedule,
@@ -255,18 +392,21 @@ impl Plugin for SprinklesPlugin {
 }
 
 pub use asset::{
-    ColliderData, DrawOrder, DrawPassMaterial, EmitterAccelerations, EmitterCollision,
-    EmitterCollisionMode, EmitterColors, EmitterData, EmitterDrawPass, EmitterEmission,
-    EmitterScale, EmitterTime, EmitterTurbulence, EmitterVelocities, ParticleFlags, ParticleMesh,
-    ParticleSystemDimension, ParticlesColliderShape3D, QuadOrientation, SerializableAlphaMode,
-    StandardParticleMaterial, TransformAlign,
+    BillboardAxis, ColliderData, DrawOrder, DrawPassMaterial, EmissionMode, EmitterAccelerations,
+    EmitterCollision, EmitterCollisionMode, EmitterColors, EmitterData, EmitterDrawPass,
+    EmitterEmission, EmitterScale, EmitterTime, EmitterTurbulence, EmitterVelocities,
+    ParticleFlags, ParticleMesh, ParticleSystemDimension, ParticlesColliderShape3D,
+    QuadOrientation, SerializableAlphaMode, StandardParticleMaterial, TransformAlign,
 };
-pub use material::ParticleMaterialExtension;
+pub use material::{ParticleMaterialExtension, ParticleMaterialKey};
 pub use runtime::{
-    ColliderEntity, EmitterEntity, EmitterMeshEntity, EmitterRuntime, ParticleBufferHandle,
-    ParticleData, ParticleMaterial, ParticleMaterialHandle, ParticleSystem2D, ParticleSystem3D,
-    ParticleSystemRuntime, ParticlesCollider3D,
+    ColliderEntity, CrossFade, DespawnOnFinish, EmitterEntity, EmitterFade, EmitterMeshEntity,
+    EmitterRuntime, EmitterStats, EmitterStatsBuffer, FAR_FROM_ORIGIN_DISTANCE, FarFromOrigin,
+    MIN_SIMULATION_RATE, ParticleBufferGrowth, ParticleBufferHandle, ParticleData,
+    ParticleMaterial, ParticleMaterialHandle, ParticleSystem2D, ParticleSystem3D,
+    ParticleSystemRuntime, ParticlesCollider3D, ParticlesGlobalControl, PreviousParticleBuffer,
+    TriggerEmitter,
 };
 #[cfg(feature = "preset-textures")]
 pub use textures::preset::PresetTexture;
-pub use textures::preset::TextureRef;
+pub use textures::preset::{CustomPresetRegistry, CustomPresetSource, TextureRef};
@@ -18,7 +18,14 @@ const SHADER_ASSET_PATH: &str = "embedded://bevy_sprinkles/shaders/particle_mate
 /// This extension binds particle data buffers to the shader pipeline so that
 /// the vertex shader can read per-particle state (position, color, scale, etc.)
 /// and transform each mesh instance accordingly.
+///
+/// [`vertex_shader`](Self::vertex_shader) and [`fragment_shader`](Self::fragment_shader)
+/// replace the built-in particle shader in the main pass. They see the same bindings, so
+/// they can read every [`ParticleData`](crate::runtime::ParticleData) field, including
+/// `user_data`, from `sorted_particles`. Prepass and shadow pipelines keep the built-in
+/// shader.
 #[derive(Asset, AsBindGroup, Reflect, Debug, Clone)]
+#[bind_group_data(ParticleMaterialKey)]
 pub struct ParticleMaterialExtension {
     /// Handle to the sorted particle data buffer, read by the vertex shader.
     #[storage(100, read_only)]
@@ -29,6 +36,31 @@ pub struct ParticleMaterialExtension {
     /// Particle behavior flags (see [`ParticleFlags`](crate::ParticleFlags)).
     #[uniform(102)]
     pub particle_flags: u32,
+    /// Fixed Y axis for [`TransformAlign::BillboardFixedY`](crate::asset::TransformAlign::BillboardFixedY),
+    /// see [`BillboardAxis::to_uniform`](crate::asset::BillboardAxis::to_uniform).
+    #[uniform(103)]
+    pub billboard_axis: Vec4,
+    /// Custom vertex shader used instead of the built-in one.
+    pub vertex_shader: Option<Handle<Shader>>,
+    /// Custom fragment shader used instead of the built-in one.
+    pub fragment_shader: Option<Handle<Shader>>,
+}
+
+/// Pipeline key of a [`ParticleMaterialExtension`], telling materials with different
+/// custom shaders apart.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParticleMaterialKey {
+    vertex_shader: Option<Handle<Shader>>,
+    fragment_shader: Option<Handle<Shader>>,
+}
+
+impl From<&ParticleMaterialExtension> for ParticleMaterialKey {
+    fn from(extension: &ParticleMaterialExtension) -> Self {
+        Self {
+            vertex_shader: extension.vertex_shader.clone(),
+            fragment_shader: extension.fragment_shader.clone(),
+        }
+    }
 }
 
 impl MaterialExtension for ParticleMaterialExtension {
@@ -68,6 +100,22 @@ impl MaterialExtension for ParticleMaterialExtension {
             depth_stencil.depth_compare = CompareFunction::GreaterEqual;
         }
 
+        let is_prepass = descriptor
+            .vertex
+            .shader_defs
+            .contains(&"PREPASS_PIPELINE".into());
+        if !is_prepass {
+            if let Some(shader) = &key.bind_group_data.vertex_shader {
+                descriptor.vertex.shader = shader.clone();
+            }
+            if let (Some(shader), Some(fragment)) = (
+                &key.bind_group_data.fragment_shader,
+                &mut descriptor.fragment,
+            ) {
+                fragment.shader = shader.clone();
+            }
+        }
+
         Ok(())
     }
 }
@@ -339,6 +339,7 @@ fn create_subdivided_quad(size: Vec2, subdivisions_x: u32, subdivisions_y: u32)
 
     let mut positions = Vec::with_capacity(vertex_count);
     let mut normals = Vec::with_capacity(vertex_count);
+    let mut tangents = Vec::with_capacity(vertex_count);
     let mut uvs = Vec::with_capacity(vertex_count);
     let mut indices = Vec::with_capacity(index_count);
 
@@ -353,6 +354,8 @@ fn create_subdivided_quad(size: Vec2, subdivisions_x: u32, subdivisions_y: u32)
             let x = -half_w + tx * size.x;
             positions.push([x, y, 0.0]);
             normals.push([0.0, 0.0, 1.0]);
+            // u grows along +X, so normal maps work without generating tangents
+            tangents.push([1.0, 0.0, 0.0, 1.0]);
             uvs.push([tx, 1.0 - ty]);
         }
     }
@@ -379,6 +382,7 @@ fn create_subdivided_quad(size: Vec2, subdivisions_x: u32, subdivisions_y: u32)
     );
     mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
     mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, tangents);
     mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
     mesh.insert_indices(Indices::U32(indices));
     mesh
@@ -418,6 +422,14 @@ fn create_base_mesh(config: &ParticleMesh) -> Mesh {
                         *normal = v.to_array();
                     }
                 }
+                if let Some(VertexAttributeValues::Float32x4(tangents)) =
+                    mesh.attribute_mut(Mesh::ATTRIBUTE_TANGENT)
+                {
+                    for tangent in tangents.iter_mut() {
+                        let v = rot * Vec3::from_slice(tangent);
+                        *tangent = v.extend(tangent[3]).to_array();
+                    }
+                }
             }
 
             mesh
@@ -466,6 +478,14 @@ pub(crate) fn create_particle_mesh(
     let base_normals: Vec<[f32; 3]> = extract_float32x3(&base_mesh, Mesh::ATTRIBUTE_NORMAL)
         .unwrap_or_else(|| vec![[0.0, 0.0, 1.0]; base_positions.len()]);
 
+    // quads carry tangents so their normal maps can be sampled
+    let base_tangents: Option<Vec<[f32; 4]>> = base_mesh
+        .attribute(Mesh::ATTRIBUTE_TANGENT)
+        .and_then(|attr| match attr {
+            VertexAttributeValues::Float32x4(v) => Some(v.clone()),
+            _ => None,
+        });
+
     let base_uvs: Vec<[f32; 2]> = base_mesh
         .attribute(Mesh::ATTRIBUTE_UV_0)
         .and_then(|attr| match attr {
@@ -487,6 +507,8 @@ pub(crate) fn create_particle_mesh(
 
     let mut positions: Vec<[f32; 3]> = Vec::with_capacity(total_vertices);
     let mut normals: Vec<[f32; 3]> = Vec::with_capacity(total_vertices);
+    let mut tangents: Vec<[f32; 4]> =
+        Vec::with_capacity(base_tangents.as_ref().map_or(0, |_| total_vertices));
     let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(total_vertices);
     let mut uv_bs: Vec<[f32; 2]> = Vec::with_capacity(total_vertices);
     let mut indices: Vec<u32> = Vec::with_capacity(total_indices);
@@ -498,6 +520,9 @@ pub(crate) fn create_particle_mesh(
         for i in 0..vertices_per_mesh {
             positions.push(base_positions[i]);
             normals.push(base_normals[i]);
+            if let Some(base_tangents) = &base_tangents {
+                tangents.push(base_tangents[i]);
+            }
             uvs.push(base_uvs[i]);
             uv_bs.push([particle_index_f32, 0.0]);
         }
@@ -514,6 +539,9 @@ pub(crate) fn create_particle_mesh(
     mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
     mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
     mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    if base_tangents.is_some() {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, tangents);
+    }
     mesh.insert_attribute(Mesh::ATTRIBUTE_UV_1, uv_bs);
     mesh.insert_indices(Indices::U32(indices));
 
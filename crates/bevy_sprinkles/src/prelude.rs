@@ -1,21 +1,24 @@
-pub use crate::SprinklesPlugin;
+pub use crate::{ParticleSystemSchedule, SortAlgorithm, SprinklesPlugin, SprinklesSystems};
 
 pub use crate::asset::{
-    AnimatedVelocity, ColliderData, CurveEasing, CurveMode, CurvePoint, CurveTexture, DrawOrder,
-    DrawPassMaterial, EmissionShape, EmitterAccelerations, EmitterCollision, EmitterCollisionMode,
-    EmitterColors, EmitterData, EmitterDrawPass, EmitterEmission, EmitterScale, EmitterTime,
-    EmitterTurbulence, EmitterVelocities, Gradient as ParticleGradient, GradientInterpolation,
-    GradientStop, ParticleFlags, ParticleMesh, ParticleSystemAsset, ParticleSystemAuthors,
-    ParticleSystemDimension, ParticlesColliderShape3D, QuadOrientation, Range as ParticleRange,
-    SerializableAlphaMode, SerializableFace, SolidOrGradientColor, StandardParticleMaterial,
-    SubEmitterConfig, SubEmitterMode, TransformAlign,
+    AnimatedVelocity, AssetWarning, BillboardAxis, Burst, ColliderData, Containment, CurveEasing,
+    CurveMode, CurvePoint, CurveTexture, DrawOrder, DrawPassMaterial, EmissionMode, EmissionShape,
+    EmitterAccelerations, EmitterCollision, EmitterCollisionMode, EmitterColors, EmitterData,
+    EmitterDrawPass, EmitterEmission, EmitterModule, EmitterScale, EmitterTime, EmitterTurbulence,
+    EmitterVelocities, FlickerConfig, Gradient as ParticleGradient, GradientInterpolation,
+    GradientStop, LifetimePreset, ParticleFlags, ParticleMesh, ParticleSystemAsset,
+    ParticleSystemAuthors, ParticleSystemDimension, ParticleUserData, ParticlesColliderShape3D,
+    QuadOrientation, Range as ParticleRange, SerializableAlphaMode, SerializableFace,
+    SolidOrGradientColor, StandardParticleMaterial, SubEmitterConfig, SubEmitterMode,
+    TransformAlign, WobbleConfig,
 };
 #[cfg(feature = "preset-textures")]
 pub use crate::textures::preset::PresetTexture;
-pub use crate::textures::preset::TextureRef;
+pub use crate::textures::preset::{CustomPresetRegistry, TextureRef};
 
 pub use crate::runtime::{
-    ColliderEntity, EmitterEntity, EmitterRuntime, ParticleMaterial, ParticleMaterialHandle,
-    ParticleSystem2D, ParticleSystem3D, ParticleSystemRuntime, ParticlesCollider3D,
-    SubEmitterBufferHandle,
+    ColliderEntity, CrossFade, DespawnOnFinish, EmitterEntity, EmitterFade, EmitterRuntime,
+    EmitterStats, ParticleMaterial, ParticleMaterialHandle, ParticleSystem2D, ParticleSystem3D,
+    ParticleSystemRuntime, ParticlesCollider3D, ParticlesGlobalControl, SubEmitterBufferHandle,
+    TriggerEmitter,
 };
@@ -0,0 +1,59 @@
+use bevy::diagnostic::{DiagnosticPath, DiagnosticsStore};
+use bevy::prelude::*;
+use bevy::render::diagnostic::RenderDiagnosticsPlugin;
+
+/// Diagnostic holding the GPU time spent simulating particles, in milliseconds.
+pub const SIMULATE_GPU_TIME: DiagnosticPath =
+    DiagnosticPath::const_new("render/particle_simulate/elapsed_gpu");
+/// Diagnostic holding the GPU time spent sorting particles, in milliseconds.
+pub const SORT_GPU_TIME: DiagnosticPath =
+    DiagnosticPath::const_new("render/particle_sort/elapsed_gpu");
+
+/// GPU timings of the most recent particle dispatches, across every particle system.
+///
+/// Timings are measured with GPU timestamp queries, which are only available on some
+/// backends (currently Vulkan and DX12). Each field is `None` until a timing has been
+/// read back, and stays `None` when the backend has no timestamp support.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct ParticleProfileStats {
+    /// Milliseconds spent in the particle simulation compute passes.
+    pub sim_ms: Option<f32>,
+    /// Milliseconds spent in the particle sort compute passes.
+    pub sort_ms: Option<f32>,
+}
+
+/// Collects [`ParticleProfileStats`] from the render diagnostics.
+///
+/// [`SprinklesPlugin`](crate::SprinklesPlugin) adds this when the `profiling` feature is
+/// enabled, along with Bevy's [`RenderDiagnosticsPlugin`] if it isn't present yet.
+pub struct ParticleProfilingPlugin;
+
+impl Plugin for ParticleProfilingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiagnosticsStore>()
+            .init_resource::<ParticleProfileStats>()
+            .add_systems(Update, update_particle_profile_stats);
+
+        if !app.is_plugin_added::<RenderDiagnosticsPlugin>() {
+            app.add_plugins(RenderDiagnosticsPlugin);
+        }
+    }
+}
+
+/// Copies the latest particle GPU timings from the [`DiagnosticsStore`] into
+/// [`ParticleProfileStats`].
+pub fn update_particle_profile_stats(
+    store: Res<DiagnosticsStore>,
+    mut stats: ResMut<ParticleProfileStats>,
+) {
+    let latest = |path: &DiagnosticPath| {
+        store
+            .get(path)
+            .and_then(|diagnostic| diagnostic.value())
+            .map(|ms| ms as f32)
+    };
+    stats.set_if_neq(ParticleProfileStats {
+        sim_ms: latest(&SIMULATE_GPU_TIME),
+        sort_ms: latest(&SORT_GPU_TIME),
+    });
+}
@@ -0,0 +1,13 @@
+/// Integer hash shared with `hash` in `common.wgsl`, so the CPU can reproduce the
+/// random values the simulation shader derives from particle and emitter seeds.
+pub fn hash(n: u32) -> u32 {
+    let mut x = n;
+    x = ((x >> 16) ^ x).wrapping_mul(0x45d9f3b);
+    x = ((x >> 16) ^ x).wrapping_mul(0x45d9f3b);
+    (x >> 16) ^ x
+}
+
+/// Maps [`hash`] to `[0, 1]`, like `hash_to_float` in `common.wgsl`.
+pub fn hash_to_float(n: u32) -> f32 {
+    hash(n) as f32 / u32::MAX as f32
+}
@@ -3,10 +3,14 @@ use bevy::prelude::*;
 use bevy::render::render_resource::{Buffer, ShaderType};
 use bevy::render::storage::ShaderStorageBuffer;
 use bytemuck::{Pod, Zeroable};
+use std::mem::offset_of;
 
 use crate::asset::{DrawPassMaterial, ParticleMesh, ParticleSystemAsset, ParticlesColliderShape3D};
 use crate::material::ParticleMaterialExtension;
 
+// mixed into the global seed before picking a system's spawn rotation
+const SPAWN_ROTATION_SALT: u32 = 0x5eed_0707;
+
 /// Component that spawns a 2D particle system from a [`ParticleSystemAsset`].
 ///
 /// # TODO
@@ -20,19 +24,192 @@ pub struct ParticleSystem2D {
 }
 
 /// Component that spawns a 3D particle system from a [`ParticleSystemAsset`].
+///
+/// Create it with [`ParticleSystem3D::new`] or [`ParticleSystem3D::oneshot`].
 #[derive(Component)]
+#[non_exhaustive]
 pub struct ParticleSystem3D {
     /// Handle to the particle system asset that defines this effect.
+    ///
+    /// Assigning a different handle at runtime respawns the emitters from the new asset
+    /// once it has loaded. Add [`CrossFade`] to blend between the two effects instead of
+    /// swapping them on the same frame.
     pub handle: Handle<ParticleSystemAsset>,
+    /// Optional range of a random rotation given to the system when it spawns, e.g. so
+    /// muzzle flashes don't all look the same.
+    ///
+    /// Each component is the largest angle, in radians, around the X, Y and Z axes. Angles
+    /// are picked in `[-range, range]` from the
+    /// [`global_seed`](ParticleSystemRuntime::global_seed) and applied once on top of the
+    /// entity's [`Transform`] rotation. Defaults to `None`.
+    pub spawn_rotation_randomness: Option<Vec3>,
+}
+
+impl ParticleSystem3D {
+    /// Creates a particle system that plays the asset behind `handle`.
+    pub fn new(handle: Handle<ParticleSystemAsset>) -> Self {
+        Self {
+            handle,
+            spawn_rotation_randomness: None,
+        }
+    }
+
+    /// Returns a bundle that plays the particle system once and despawns it when finished.
+    ///
+    /// This is meant for fire-and-forget effects such as hits and explosions. Every emitter
+    /// must be [`one_shot`](crate::asset::EmitterTime::one_shot); see [`DespawnOnFinish`].
+    pub fn oneshot(handle: Handle<ParticleSystemAsset>) -> impl Bundle {
+        (Self::new(handle), DespawnOnFinish::default())
+    }
+
+    /// Sets [`spawn_rotation_randomness`](Self::spawn_rotation_randomness).
+    pub fn with_spawn_rotation_randomness(mut self, ranges: Vec3) -> Self {
+        self.spawn_rotation_randomness = Some(ranges);
+        self
+    }
+
+    /// Returns the rotation given to this system when it spawns with the given
+    /// [`global_seed`](ParticleSystemRuntime::global_seed), or [`Quat::IDENTITY`] without
+    /// [`spawn_rotation_randomness`](Self::spawn_rotation_randomness).
+    pub fn spawn_rotation(&self, seed: u32) -> Quat {
+        let Some(ranges) = self.spawn_rotation_randomness else {
+            return Quat::IDENTITY;
+        };
+        let hash = crate::random::hash;
+        // hashed once more so the angles don't follow the emitter seeds
+        let base = hash(seed ^ SPAWN_ROTATION_SALT);
+        let angle = |axis: u32, range: f32| {
+            let t = crate::random::hash_to_float(base.wrapping_add(axis));
+            (t * 2.0 - 1.0) * range
+        };
+        Quat::from_euler(
+            EulerRot::XYZ,
+            angle(0, ranges.x),
+            angle(1, ranges.y),
+            angle(2, ranges.z),
+        )
+    }
+}
+
+/// Component that cross-fades a [`ParticleSystem3D`] whenever its
+/// [`handle`](ParticleSystem3D::handle) is swapped.
+///
+/// The emitters of the previous asset keep simulating while their particles fade out,
+/// and the new emitters fade in over the same [`duration`](Self::duration).
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_sprinkles::prelude::*;
+/// fn intensify(mut fires: Query<&mut ParticleSystem3D, With<CrossFade>>, intense: Res<IntenseFire>) {
+///     for mut fire in &mut fires {
+///         fire.handle = intense.0.clone();
+///     }
+/// }
+/// # #[derive(Resource)]
+/// # struct IntenseFire(Handle<ParticleSystemAsset>);
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CrossFade {
+    /// How long the transition lasts, in seconds.
+    pub duration: f32,
+}
+
+/// Component that fades an emitter's particles in or out over time.
+///
+/// Inserted on emitter entities while a [`CrossFade`] is in progress. Once an emitter
+/// has faded out, the particle system holding it is despawned.
+#[derive(Component, Debug, Clone)]
+pub struct EmitterFade {
+    /// How long the fade lasts, in seconds.
+    pub duration: f32,
+    /// Time elapsed since the fade started, in seconds.
+    pub elapsed: f32,
+    /// Whether the emitter is fading out rather than in.
+    pub fade_out: bool,
+}
+
+impl EmitterFade {
+    /// Creates a fade from transparent to fully opaque.
+    pub fn fade_in(duration: f32) -> Self {
+        Self {
+            duration,
+            elapsed: 0.0,
+            fade_out: false,
+        }
+    }
+
+    /// Creates a fade from fully opaque to transparent.
+    pub fn fade_out(duration: f32) -> Self {
+        Self {
+            duration,
+            elapsed: 0.0,
+            fade_out: true,
+        }
+    }
+
+    /// Returns `true` once the fade has run for its whole duration.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Returns the current opacity multiplier, from `0.0` to `1.0`.
+    pub fn opacity(&self) -> f32 {
+        let t = if self.duration > 0.0 {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        if self.fade_out { 1.0 - t } else { t }
+    }
+}
+
+/// Component that despawns a particle system once all of its one-shot emitters have
+/// finished and their last particles have died.
+///
+/// Every emitter that is not a sub-emitter target must be
+/// [`one_shot`](crate::asset::EmitterTime::one_shot). If a looping emitter is found, a
+/// warning is logged and this component is removed, leaving the particle system alive.
+///
+/// Live particles are counted from each emitter's [`EmitterStats`]. Where those aren't
+/// read back, e.g. without a GPU, the system waits out the longest lifetime along each
+/// chain of sub-emitters instead.
+#[derive(Component, Default)]
+pub struct DespawnOnFinish {
+    drain_elapsed: f32,
+    idle_frames: u32,
+}
+
+impl DespawnOnFinish {
+    /// Frames in a row every emitter must report no live particles before the system is
+    /// despawned, since readbacks arrive a few frames after the GPU work they describe.
+    pub(crate) const IDLE_FRAMES: u32 = 4;
+
+    /// Advances the time spent waiting for the remaining particles to die and returns
+    /// `true` once `drain_time` has been reached.
+    pub(crate) fn tick(&mut self, delta: f32, drain_time: f32) -> bool {
+        self.drain_elapsed += delta;
+        self.drain_elapsed >= drain_time
+    }
+
+    /// Records whether the emitters reported live particles this frame and returns `true`
+    /// once they have reported none for [`IDLE_FRAMES`](Self::IDLE_FRAMES) frames in a row.
+    pub(crate) fn tick_idle(&mut self, idle: bool) -> bool {
+        self.idle_frames = if idle { self.idle_frames + 1 } else { 0 };
+        self.idle_frames >= Self::IDLE_FRAMES
+    }
 }
 
 /// GPU-side per-particle data, packed into `[f32; 4]` vectors for shader alignment.
+///
+/// Mirrors the `Particle` struct in `bevy_sprinkles::common`, which custom shaders can
+/// import to read the `sorted_particles` buffer. Each particle takes 96 bytes: six
+/// `vec4<f32>` fields in declaration order.
 #[derive(Clone, Copy, Default, Pod, Zeroable, ShaderType)]
 #[repr(C)]
 pub struct ParticleData {
     /// XYZ position and W = scale.
     pub position: [f32; 4],
-    /// XYZ velocity and W = remaining lifetime.
+    /// XYZ velocity and W = lifetime.
     pub velocity: [f32; 4],
     /// RGBA color.
     pub color: [f32; 4],
@@ -40,17 +217,90 @@ pub struct ParticleData {
     pub custom: [f32; 4],
     /// XYZ direction for velocity-aligned transforms, W unused.
     pub alignment_dir: [f32; 4],
+    /// Free-form data for custom shaders, set at spawn from
+    /// [`EmitterData::user_data`](crate::asset::EmitterData::user_data). Unused by Sprinkles.
+    pub user_data: [f32; 4],
 }
 
 impl ParticleData {
     /// Bit flag indicating that a particle is alive and should be rendered.
     pub const FLAG_ACTIVE: u32 = 1;
 
+    /// Bit flag indicating that a sub-emitted particle follows its parent particle, see
+    /// [`SubEmitterConfig::keep_local`](crate::asset::SubEmitterConfig::keep_local).
+    pub const FLAG_FOLLOWS_PARENT: u32 = 2;
+
+    /// Bit offset of the parent particle's index within the flags, set along with
+    /// [`FLAG_FOLLOWS_PARENT`](Self::FLAG_FOLLOWS_PARENT).
+    pub const PARENT_INDEX_SHIFT: u32 = 8;
+
+    /// Name and byte offset of each field, in declaration order.
+    pub const FIELDS: [(&'static str, usize); 6] = [
+        ("position", offset_of!(ParticleData, position)),
+        ("velocity", offset_of!(ParticleData, velocity)),
+        ("color", offset_of!(ParticleData, color)),
+        ("custom", offset_of!(ParticleData, custom)),
+        ("alignment_dir", offset_of!(ParticleData, alignment_dir)),
+        ("user_data", offset_of!(ParticleData, user_data)),
+    ];
+
+    /// Returns a WGSL `Particle` struct matching this layout, annotated with each field's
+    /// byte offset and size, ready to paste into a custom shader.
+    ///
+    /// Shaders built with Sprinkles can import the same struct from
+    /// `bevy_sprinkles::common` instead.
+    pub fn wgsl_struct() -> String {
+        let field_size = size_of::<[f32; 4]>();
+        let mut wgsl = format!(
+            "// {} bytes per particle\nstruct Particle {{\n",
+            size_of::<Self>()
+        );
+        for (name, offset) in Self::FIELDS {
+            let field = format!("{name}: vec4<f32>,");
+            wgsl.push_str(&format!(
+                "    {field:<27}// offset {offset}, size {field_size}\n"
+            ));
+        }
+        wgsl.push_str("}\n");
+        wgsl
+    }
+
     /// Returns `true` if this particle is currently active.
     pub fn is_active(&self) -> bool {
         let flags = self.custom[3].to_bits();
         (flags & Self::FLAG_ACTIVE) != 0
     }
+
+    /// Returns the index of the parent particle this particle follows, if it follows one.
+    pub fn parent_index(&self) -> Option<u32> {
+        let flags = self.custom[3].to_bits();
+        ((flags & Self::FLAG_FOLLOWS_PARENT) != 0).then_some(flags >> Self::PARENT_INDEX_SHIFT)
+    }
+
+    /// Returns the time in seconds since this particle spawned.
+    pub fn age(&self) -> f32 {
+        self.custom[0]
+    }
+
+    /// Returns this particle's total lifetime in seconds, after
+    /// [`EmitterTime::lifetime_randomness`](crate::asset::EmitterTime::lifetime_randomness)
+    /// has been applied.
+    pub fn lifetime(&self) -> f32 {
+        self.velocity[3]
+    }
+
+    /// Returns how far this particle is through its own lifetime, from `0.0` to `1.0`.
+    ///
+    /// This is the position at which the `*_over_lifetime` curves and gradients are
+    /// sampled, so every particle dies with the end value regardless
+    /// of how long it lives.
+    pub fn lifetime_fraction(&self) -> f32 {
+        let lifetime = self.lifetime();
+        if lifetime <= 0.0 {
+            return 1.0;
+        }
+        (self.age() / lifetime).clamp(0.0, 1.0)
+    }
 }
 
 /// Runtime state for a particle system entity, controlling playback.
@@ -62,6 +312,13 @@ pub struct ParticleSystemRuntime {
     pub force_loop: bool,
     /// Global random seed for all emitters in this system.
     pub global_seed: u32,
+    /// Optional cap on the number of particles alive across all emitters.
+    ///
+    /// When set, each emitter's spawn rate is scaled down so the system stays under
+    /// the budget, shared by [`EmitterData::spawn_weight`](crate::asset::EmitterData::spawn_weight).
+    /// See [`ParticleSystemAsset::budgeted_amounts`](crate::asset::ParticleSystemAsset::budgeted_amounts).
+    /// Defaults to `None`.
+    pub particle_budget: Option<u32>,
 }
 
 impl Default for ParticleSystemRuntime {
@@ -70,6 +327,7 @@ impl Default for ParticleSystemRuntime {
             paused: false,
             force_loop: true,
             global_seed: rand_seed(),
+            particle_budget: None,
         }
     }
 }
@@ -89,6 +347,28 @@ impl ParticleSystemRuntime {
     pub fn toggle(&mut self) {
         self.paused = !self.paused;
     }
+
+    /// Replaces the [`global_seed`](Self::global_seed) with a fresh random one, which always
+    /// differs from the previous seed, and returns it.
+    ///
+    /// Emitters keep their current seed until they're restarted, e.g. with
+    /// [`emitter_seed`](Self::emitter_seed).
+    pub fn reseed(&mut self) -> u32 {
+        let mut seed = crate::random::hash(self.global_seed ^ rand_seed());
+        if seed == self.global_seed {
+            seed = seed.wrapping_add(1);
+        }
+        self.global_seed = seed;
+        seed
+    }
+
+    /// Returns a seed for the emitter at `emitter_index`, derived from the
+    /// [`global_seed`](Self::global_seed) so emitters of one system don't share
+    /// random sequences.
+    pub fn emitter_seed(&self, emitter_index: usize) -> u32 {
+        let hash = crate::random::hash;
+        hash(self.global_seed.wrapping_add(hash(emitter_index as u32)))
+    }
 }
 
 /// A single simulation step to be processed by the compute shader.
@@ -104,6 +384,13 @@ pub struct SimulationStep {
     pub delta_time: f32,
     /// Whether to clear all particles before this step.
     pub clear_requested: bool,
+    /// Number of particles spawned in this step by
+    /// [`EmissionMode::PerDistance`](crate::asset::EmissionMode::PerDistance) or by a
+    /// [`Burst`](crate::asset::Burst).
+    pub scheduled_spawns: u32,
+    /// Running spawn count of the first of those particles, which picks the slots they
+    /// take. See [`EmitterRuntime::spawn_cursor`].
+    pub scheduled_spawn_start: u32,
 }
 
 /// Runtime state for a single emitter within a particle system.
@@ -111,14 +398,23 @@ pub struct SimulationStep {
 pub struct EmitterRuntime {
     /// Whether this emitter is actively spawning particles.
     pub emitting: bool,
+    /// Whether this emitter is held idle until a [`TriggerEmitter`] event.
+    ///
+    /// While set, simulation time doesn't advance and no particles are spawned.
+    pub awaiting_trigger: bool,
     /// Current simulation time in seconds.
     pub system_time: f32,
     /// Simulation time from the previous frame.
     pub prev_system_time: f32,
     /// Current emission cycle index (increments each time the lifetime wraps).
     pub cycle: u32,
-    /// Accumulated time delta for fixed-FPS stepping.
+    /// Time elapsed since the last simulation step, not simulated yet. Accumulated for
+    /// fixed-FPS stepping and for frames skipped by
+    /// [`ParticlesGlobalControl::simulation_rate`].
     pub accumulated_delta: f32,
+    /// Fraction of a simulation step earned so far under
+    /// [`ParticlesGlobalControl::simulation_rate`]; a step runs once it reaches `1.0`.
+    pub simulation_credit: f32,
     /// Random seed for this emitter's particle generation.
     pub random_seed: u32,
     /// Whether a one-shot emission cycle has completed.
@@ -129,6 +425,33 @@ pub struct EmitterRuntime {
     pub emitter_index: usize,
     /// Pending simulation steps to be dispatched to the GPU.
     pub simulation_steps: Vec<SimulationStep>,
+    /// Opacity multiplier applied to every particle, driven by [`EmitterFade`].
+    /// Defaults to `1.0`.
+    pub fade: f32,
+    /// Opacity multiplier applied to every particle on top of [`fade`](Self::fade), for
+    /// dimming a whole emitter from gameplay code without touching its asset.
+    /// Defaults to `1.0`.
+    pub opacity: f32,
+    /// Optional RGBA multiplier applied to every particle's color, for tinting a live
+    /// effect from gameplay code (team colors, status effects) without touching its
+    /// asset. The alpha stacks with [`alpha_multiplier`](Self::alpha_multiplier).
+    /// Defaults to `None`.
+    pub color_tint: Option<[f32; 4]>,
+    /// World-space velocity of the emitter entity, measured from its movement over the
+    /// last frame. Particles inherit it according to
+    /// [`EmitterVelocities::inherit_ratio`](crate::asset::EmitterVelocities::inherit_ratio).
+    pub velocity: Vec3,
+    /// World-space position of the emitter entity when [`velocity`](Self::velocity) was
+    /// last measured.
+    pub prev_position: Option<Vec3>,
+    /// World-space distance the emitter entity moved since the last particle spawned by
+    /// [`EmissionMode::PerDistance`](crate::asset::EmissionMode::PerDistance).
+    pub distance_travelled: f32,
+    /// Total number of particles spawned by
+    /// [`EmissionMode::PerDistance`](crate::asset::EmissionMode::PerDistance) or by
+    /// [`Burst`](crate::asset::Burst)s, wrapping around. Each spawn takes the slot after
+    /// the previous one.
+    pub spawn_cursor: u32,
 }
 
 impl EmitterRuntime {
@@ -140,36 +463,104 @@ impl EmitterRuntime {
         let random_seed = fixed_seed.unwrap_or_else(rand_seed);
         Self {
             emitting: true,
+            awaiting_trigger: false,
             system_time: 0.0,
             prev_system_time: 0.0,
             cycle: 0,
             accumulated_delta: 0.0,
+            simulation_credit: 0.0,
             random_seed,
             one_shot_completed: false,
             clear_requested: false,
             emitter_index,
             simulation_steps: Vec::new(),
+            fade: 1.0,
+            opacity: 1.0,
+            color_tint: None,
+            velocity: Vec3::ZERO,
+            prev_position: None,
+            distance_travelled: 0.0,
+            spawn_cursor: 0,
         }
     }
 
+    /// Returns the multiplier applied to every particle's alpha, which is
+    /// [`fade`](Self::fade) times [`opacity`](Self::opacity) clamped to `0.0..=1.0`.
+    pub fn alpha_multiplier(&self) -> f32 {
+        (self.fade * self.opacity).clamp(0.0, 1.0)
+    }
+
+    /// Returns the RGBA multiplier applied to every particle's color, which is
+    /// [`color_tint`](Self::color_tint) or white when there is none.
+    pub fn tint(&self) -> [f32; 4] {
+        self.color_tint.unwrap_or([1.0; 4])
+    }
+
     /// Returns the current phase within the emission cycle, from `0.0` to `1.0`.
+    ///
+    /// This assumes the cycle lasts the emitter's own
+    /// [`total_duration`](crate::asset::EmitterTime::total_duration); see
+    /// [`system_phase_in_cycle`](Self::system_phase_in_cycle) for emitters in a sync group.
     pub fn system_phase(&self, time: &crate::asset::EmitterTime) -> f32 {
-        compute_phase(self.system_time, time)
+        self.system_phase_in_cycle(time, time.total_duration())
+    }
+
+    /// Returns the current phase within an emission cycle lasting `cycle_duration`, from
+    /// `0.0` to `1.0`.
+    ///
+    /// The cycle is longer than the emitter's own total duration in a sync group. See
+    /// [`ParticleSystemAsset::cycle_duration`].
+    pub fn system_phase_in_cycle(
+        &self,
+        time: &crate::asset::EmitterTime,
+        cycle_duration: f32,
+    ) -> f32 {
+        let delay = time.cycle_delay(self.random_seed, self.cycle);
+        compute_cycle_phase(self.system_time, time, cycle_duration, delay)
     }
 
     /// Returns the phase from the previous frame.
     pub fn prev_system_phase(&self, time: &crate::asset::EmitterTime) -> f32 {
-        compute_phase(self.prev_system_time, time)
+        self.prev_system_phase_in_cycle(time, time.total_duration())
+    }
+
+    /// Like [`prev_system_phase`](Self::prev_system_phase), for an emission cycle lasting
+    /// `cycle_duration`.
+    pub fn prev_system_phase_in_cycle(
+        &self,
+        time: &crate::asset::EmitterTime,
+        cycle_duration: f32,
+    ) -> f32 {
+        let cycle = if self.prev_system_time > self.system_time {
+            self.cycle.saturating_sub(1)
+        } else {
+            self.cycle
+        };
+        let delay = time.cycle_delay(self.random_seed, cycle);
+        compute_cycle_phase(self.prev_system_time, time, cycle_duration, delay)
     }
 
     /// Returns `true` if the emitter has passed its initial delay within the current cycle.
     pub fn is_past_delay(&self, time: &crate::asset::EmitterTime) -> bool {
-        is_past_delay(self.system_time, time)
+        self.is_past_delay_in_cycle(time, time.total_duration())
+    }
+
+    /// Like [`is_past_delay`](Self::is_past_delay), for an emission cycle lasting
+    /// `cycle_duration`.
+    pub fn is_past_delay_in_cycle(
+        &self,
+        time: &crate::asset::EmitterTime,
+        cycle_duration: f32,
+    ) -> bool {
+        let delay = time.cycle_delay(self.random_seed, self.cycle);
+        is_past_cycle_delay(self.system_time, cycle_duration, delay)
     }
 
-    /// Starts or resumes emission, resetting the one-shot completed flag.
+    /// Starts or resumes emission, resetting the one-shot completed flag and releasing
+    /// the [trigger gate](Self::awaiting_trigger).
     pub fn play(&mut self) {
         self.emitting = true;
+        self.awaiting_trigger = false;
         self.one_shot_completed = false;
     }
 
@@ -180,16 +571,20 @@ impl EmitterRuntime {
         self.prev_system_time = 0.0;
         self.cycle = 0;
         self.accumulated_delta = 0.0;
+        self.simulation_credit = 0.0;
         self.random_seed = fixed_seed.unwrap_or_else(rand_seed);
         self.one_shot_completed = false;
         self.clear_requested = true;
         self.simulation_steps.clear();
+        self.distance_travelled = 0.0;
+        self.spawn_cursor = 0;
     }
 
     /// Stops and immediately restarts emission from the beginning.
     pub fn restart(&mut self, fixed_seed: Option<u32>) {
         self.stop(fixed_seed);
         self.emitting = true;
+        self.awaiting_trigger = false;
     }
 
     /// Jumps the emitter's simulation time to the given value.
@@ -197,32 +592,144 @@ impl EmitterRuntime {
         self.system_time = time;
         self.prev_system_time = time;
     }
+
+    /// Clears the emitter and queues the simulation steps that play its current cycle
+    /// from the start up to `time`, `step` seconds at a time.
+    ///
+    /// Unlike [`seek`](Self::seek), particles end up where they'd be after playing up to
+    /// `time`, so pausing the particle system right after shows that moment. All steps
+    /// are dispatched on the next frame.
+    pub fn simulate_to(&mut self, time: f32, step: f32) {
+        let step = step.max(1e-3);
+        let mut prev_time = 0.0;
+        let mut clear_requested = true;
+        loop {
+            let next_time = (prev_time + step).min(time.max(0.0));
+            self.simulation_steps.push(SimulationStep {
+                prev_system_time: prev_time,
+                system_time: next_time,
+                cycle: self.cycle,
+                delta_time: next_time - prev_time,
+                clear_requested,
+                scheduled_spawns: 0,
+                scheduled_spawn_start: self.spawn_cursor,
+            });
+            clear_requested = false;
+            if next_time >= time {
+                break;
+            }
+            prev_time = next_time;
+        }
+        self.clear_requested = false;
+        self.prev_system_time = prev_time;
+        self.system_time = time.max(0.0);
+    }
+
+    /// Returns the time since the first cycle started, in seconds, given the
+    /// length of one cycle (see [`ParticleSystemAsset::cycle_duration`]).
+    pub fn elapsed_time(&self, cycle_duration: f32) -> f32 {
+        self.cycle as f32 * cycle_duration + self.system_time
+    }
+
+    /// Jumps to the start of the given emission cycle, before its delay.
+    ///
+    /// Boundaries are at `cycle * cycle_duration` in [elapsed time](Self::elapsed_time),
+    /// so the cycle's delay plays out again after seeking.
+    pub fn seek_cycle(&mut self, cycle: u32) {
+        self.cycle = cycle;
+        self.seek(0.0);
+    }
+
+    /// Jumps to the start of the next emission cycle.
+    pub fn seek_next_cycle(&mut self) {
+        self.seek_cycle(self.cycle.saturating_add(1));
+    }
+
+    /// Jumps back to the start of the current emission cycle, or to the previous
+    /// one if the current cycle hasn't passed its delay yet.
+    pub fn seek_prev_cycle(&mut self, time: &crate::asset::EmitterTime) {
+        let delay = time.cycle_delay(self.random_seed, self.cycle);
+        let cycle = if self.system_time <= delay {
+            self.cycle.saturating_sub(1)
+        } else {
+            self.cycle
+        };
+        self.seek_cycle(cycle);
+    }
+}
+
+/// Starts an emitter that is [waiting for a trigger](crate::asset::EmitterTime::wait_for_trigger).
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_sprinkles::runtime::TriggerEmitter;
+/// fn start_sparks(commands: &mut Commands, system: Entity) {
+///     commands.trigger(TriggerEmitter {
+///         system,
+///         emitter_index: 0,
+///     });
+/// }
+/// ```
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TriggerEmitter {
+    /// The particle system entity owning the emitter.
+    pub system: Entity,
+    /// Index of the emitter within the system's [`ParticleSystemAsset::emitters`].
+    pub emitter_index: usize,
 }
 
 /// Computes the emission phase (0.0–1.0) for the given time and emitter timing config.
+///
+/// This uses the emitter's base [`delay`](crate::asset::EmitterTime::delay); see
+/// [`compute_cycle_phase`] to apply a per-cycle delay.
 pub fn compute_phase(time: f32, emitter_time: &crate::asset::EmitterTime) -> f32 {
+    compute_cycle_phase(
+        time,
+        emitter_time,
+        emitter_time.total_duration(),
+        emitter_time.delay,
+    )
+}
+
+/// Computes the emission phase (0.0–1.0) for an emitter whose cycle wraps after
+/// `cycle_duration` and starts emitting after `delay`.
+///
+/// The cycle may be longer than the emitter's own total duration when it belongs to a
+/// [`sync_group`](crate::asset::EmitterData::sync_group), and `delay` may differ from the
+/// base delay when [`delay_randomness`](crate::asset::EmitterTime::delay_randomness) is set.
+/// The phase holds at `1.0` for the remainder of the cycle.
+pub fn compute_cycle_phase(
+    time: f32,
+    emitter_time: &crate::asset::EmitterTime,
+    cycle_duration: f32,
+    delay: f32,
+) -> f32 {
     if emitter_time.lifetime <= 0.0 {
         return 0.0;
     }
-    let total_duration = emitter_time.total_duration();
-    if total_duration <= 0.0 {
+    if cycle_duration <= 0.0 {
         return 0.0;
     }
-    let time_in_cycle = time % total_duration;
-    if time_in_cycle < emitter_time.delay {
+    let time_in_cycle = time % cycle_duration;
+    if time_in_cycle < delay {
         return 0.0;
     }
-    (time_in_cycle - emitter_time.delay) / emitter_time.lifetime
+    ((time_in_cycle - delay) / emitter_time.lifetime).min(1.0)
 }
 
 /// Returns `true` if the given time is past the emitter's initial delay within the current cycle.
 pub fn is_past_delay(time: f32, emitter_time: &crate::asset::EmitterTime) -> bool {
-    let total_duration = emitter_time.total_duration();
-    if total_duration <= 0.0 {
+    is_past_cycle_delay(time, emitter_time.total_duration(), emitter_time.delay)
+}
+
+/// Like [`is_past_delay`], for a cycle that wraps after `cycle_duration` and starts
+/// emitting after `delay`.
+pub fn is_past_cycle_delay(time: f32, cycle_duration: f32, delay: f32) -> bool {
+    if cycle_duration <= 0.0 {
         return true;
     }
-    let time_in_cycle = time % total_duration;
-    time_in_cycle >= emitter_time.delay
+    let time_in_cycle = time % cycle_duration;
+    time_in_cycle >= delay
 }
 
 /// Marker component linking an emitter entity back to its parent particle system.
@@ -262,6 +769,176 @@ pub struct ParticleBufferHandle {
     pub max_particles: u32,
 }
 
+impl ParticleBufferHandle {
+    /// Bytes of GPU storage each particle takes up: one [`ParticleData`] in both the
+    /// particle and sorted buffers, plus its sort index.
+    pub const BYTES_PER_PARTICLE: u64 =
+        2 * size_of::<ParticleData>() as u64 + size_of::<u32>() as u64;
+
+    /// Total size of these buffers in bytes.
+    pub fn size_in_bytes(&self) -> u64 {
+        self.max_particles as u64 * Self::BYTES_PER_PARTICLE
+    }
+}
+
+/// The particle buffer an emitter used before its buffers grew to fit a larger
+/// [`particles_amount`](crate::asset::EmitterEmission::particles_amount).
+///
+/// Its contents are copied into the start of the new
+/// [`ParticleBufferHandle::particle_buffer`] before the next simulation step, so particles
+/// alive at the time of the resize carry over. The component is removed once the copy has
+/// been extracted.
+#[derive(Component)]
+pub struct PreviousParticleBuffer {
+    /// Handle to the old particle buffer.
+    pub buffer: Handle<ShaderStorageBuffer>,
+    /// Number of particles the old buffer holds.
+    pub max_particles: u32,
+}
+
+/// Handle to the GPU buffer an emitter's compute pass writes its [`EmitterStats`] to.
+///
+/// The buffer holds a single `u32`: the live particle count at the end of the last step.
+/// Only emitters of particle systems with [`DespawnOnFinish`] have one.
+#[derive(Component)]
+pub struct EmitterStatsBuffer {
+    /// Handle to the stats buffer.
+    pub buffer: Handle<ShaderStorageBuffer>,
+}
+
+impl EmitterStatsBuffer {
+    /// Size of the stats buffer in bytes.
+    pub const SIZE: u64 = size_of::<u32>() as u64;
+}
+
+/// Counters read back from an emitter's [`EmitterStatsBuffer`] after the GPU simulated it.
+///
+/// Only emitters of particle systems with [`DespawnOnFinish`] are read back. The values
+/// lag a few frames behind the simulation.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmitterStats {
+    /// Number of live particles after the emitter's last simulation step.
+    pub alive_particles: u32,
+}
+
+impl EmitterStats {
+    /// Decodes the contents of an [`EmitterStatsBuffer`], or returns `None` if `bytes` is
+    /// too short.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let alive_particles = bytes.get(..4)?.try_into().ok()?;
+        Some(Self {
+            alive_particles: u32::from_le_bytes(alive_particles),
+        })
+    }
+}
+
+/// Controls how far an emitter's particle buffers may grow at runtime.
+///
+/// Buffers are sized to [`EmitterEmission::particles_amount`](crate::asset::EmitterEmission::particles_amount)
+/// when the particle system is spawned. If the amount is raised afterwards, the buffers are
+/// reallocated to fit, up to [`max_particles`](Self::max_particles). Particles alive at the
+/// time are copied over on the GPU (see [`PreviousParticleBuffer`]), while sub-emitter
+/// events still waiting to be spawned are dropped. Insert this resource with a different
+/// value to change the cap.
+#[derive(Resource, Debug, Clone)]
+pub struct ParticleBufferGrowth {
+    /// Upper bound on the number of particles a single emitter's buffers can hold.
+    ///
+    /// Emitters asking for more particles than this are clamped to it.
+    pub max_particles: u32,
+    /// Upper bound on the particle buffer memory of all emitters combined, in bytes.
+    ///
+    /// When spawning a particle system would go over it, the capacity of each of its
+    /// emitters is reduced by the same proportion to fit what's left, and a warning is
+    /// logged. Buffer growth is limited the same way. `None` means no limit.
+    pub max_buffer_bytes: Option<u64>,
+}
+
+impl Default for ParticleBufferGrowth {
+    fn default() -> Self {
+        Self {
+            max_particles: 1 << 20,
+            max_buffer_bytes: None,
+        }
+    }
+}
+
+/// Global playback control shared by every particle system.
+///
+/// While [`paused`](Self::paused) is set, no particle system advances its simulation
+/// time, dispatches simulation steps, ticks [`EmitterFade`]s or counts down
+/// [`DespawnOnFinish`]. Particles stay where they are and resume once it's cleared,
+/// which makes it a good fit for a game pause menu. Pausing a single system is done
+/// through [`ParticleSystemRuntime::pause`] instead.
+///
+/// Clearing [`shadows_enabled`](Self::shadows_enabled) stops every particle mesh from
+/// casting shadows, whatever their emitters'
+/// [`shadow_caster`](crate::asset::EmitterDrawPass::shadow_caster) says.
+///
+/// Lowering [`simulation_rate`](Self::simulation_rate) cuts the cost of every particle
+/// system at once, e.g. on battery or low-end hardware.
+#[derive(Resource, Debug, Clone)]
+pub struct ParticlesGlobalControl {
+    /// Whether every particle system is paused.
+    pub paused: bool,
+    /// Whether particle meshes may cast shadows. When `false`, no particle mesh casts
+    /// shadows, without editing any asset. Defaults to `true`.
+    pub shadows_enabled: bool,
+    /// How often simulation steps are dispatched, relative to normal.
+    ///
+    /// At `0.5`, emitters simulate every other frame over the time of both frames, and
+    /// particles are moved along their velocity in between so motion stays smooth.
+    /// Emitters with a [`fixed_fps`](crate::asset::EmitterTime::fixed_fps) run at that
+    /// rate scaled by this one, without smoothing. Clamped between
+    /// [`MIN_SIMULATION_RATE`] and `1.0`. Defaults to `1.0`.
+    pub simulation_rate: f32,
+}
+
+impl Default for ParticlesGlobalControl {
+    fn default() -> Self {
+        Self {
+            paused: false,
+            shadows_enabled: true,
+            simulation_rate: 1.0,
+        }
+    }
+}
+
+/// Lowest [`ParticlesGlobalControl::simulation_rate`] taken into account.
+pub const MIN_SIMULATION_RATE: f32 = 0.1;
+
+impl ParticlesGlobalControl {
+    /// Returns [`simulation_rate`](Self::simulation_rate) clamped to the supported range.
+    pub fn effective_simulation_rate(&self) -> f32 {
+        if self.simulation_rate.is_nan() {
+            return 1.0;
+        }
+        self.simulation_rate.clamp(MIN_SIMULATION_RATE, 1.0)
+    }
+}
+
+/// Distance from the world origin, in units, past which emitters are flagged with
+/// [`FarFromOrigin`].
+///
+/// Particles are simulated with 32-bit floats, which hold about 7 significant digits.
+/// Positions 10,000 units away are only precise to about a thousandth of a unit, so
+/// slow particles visibly snap between positions and small per-step velocity changes
+/// get lost, and it only gets worse from there. Keep effects close to the origin, e.g.
+/// by moving the world around the camera, to avoid it.
+pub const FAR_FROM_ORIGIN_DISTANCE: f32 = 10_000.0;
+
+/// Marks an emitter whose world position is further than [`FAR_FROM_ORIGIN_DISTANCE`]
+/// from the origin.
+///
+/// Inserted and removed by [`SprinklesPlugin`](crate::SprinklesPlugin), which also logs
+/// a warning when an emitter first moves out. Emitters keep simulating where they
+/// are; this only points out where precision issues come from.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct FarFromOrigin {
+    /// Distance of the emitter from the origin.
+    pub distance: f32,
+}
+
 /// Raw GPU buffer references for an emitter, used during compute dispatch.
 #[derive(Component)]
 pub struct ParticleGpuBuffers {
@@ -1,11 +1,13 @@
 #define_import_path bevy_sprinkles::common
 
+// mirrors ParticleData: 96 bytes per particle, so index particle buffers with a stride of 96
 struct Particle {
     position: vec4<f32>,       // xyz, scale
     velocity: vec4<f32>,       // xyz, lifetime
     color: vec4<f32>,
     custom: vec4<f32>,         // age, spawn_index, seed, flags
     alignment_dir: vec4<f32>,  // xyz direction for ALIGN_Y_TO_VELOCITY, w = angle (radians)
+    user_data: vec4<f32>,      // set at spawn from EmitterData::user_data, free for custom shaders
 }
 
 struct CurveUniform {
@@ -17,6 +19,9 @@ struct CurveUniform {
 
 // per-particle flags (stored in particle.custom.w)
 const PARTICLE_FLAG_ACTIVE: u32 = 1u;
+// sub-emitted particle following its parent, whose index is stored from PARTICLE_PARENT_SHIFT up
+const PARTICLE_FLAG_FOLLOWS_PARENT: u32 = 2u;
+const PARTICLE_PARENT_SHIFT: u32 = 8u;
 
 // emitter-level particle flags (from EmitterParams.particle_flags)
 const EMITTER_FLAG_ROTATE_Y: u32 = 2u;
@@ -30,16 +35,19 @@ const TRANSFORM_ALIGN_BILLBOARD: u32 = 1u;
 const TRANSFORM_ALIGN_Y_TO_VELOCITY: u32 = 2u;
 const TRANSFORM_ALIGN_BILLBOARD_Y_TO_VELOCITY: u32 = 3u;
 const TRANSFORM_ALIGN_BILLBOARD_FIXED_Y: u32 = 4u;
+const TRANSFORM_ALIGN_BILLBOARD_SCREEN_ALIGNED: u32 = 5u;
 
 // sub emitter emission buffer
 struct SubEmissionEntry {
     position: vec4<f32>,    // xyz + scale
     velocity: vec4<f32>,    // xyz + w unused
     flags: u32,
+    parent: u32,            // index of the emitting particle
 }
 
 const EMISSION_FLAG_HAS_POSITION: u32 = 1u;
 const EMISSION_FLAG_HAS_VELOCITY: u32 = 2u;
+const EMISSION_FLAG_KEEP_LOCAL: u32 = 4u;
 
 // sub emitter mode constants
 const SUB_EMITTER_MODE_DISABLED: u32 = 0u;
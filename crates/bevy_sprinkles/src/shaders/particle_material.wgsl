@@ -8,6 +8,7 @@
     TRANSFORM_ALIGN_Y_TO_VELOCITY,
     TRANSFORM_ALIGN_BILLBOARD_Y_TO_VELOCITY,
     TRANSFORM_ALIGN_BILLBOARD_FIXED_Y,
+    TRANSFORM_ALIGN_BILLBOARD_SCREEN_ALIGNED,
 }
 #import bevy_pbr::{
     mesh_functions,
@@ -38,6 +39,8 @@ const STANDARD_MATERIAL_FLAGS_UNLIT_BIT: u32 = 1u << 5u;
 @group(#{MATERIAL_BIND_GROUP}) @binding(100) var<storage, read> sorted_particles: array<Particle>;
 @group(#{MATERIAL_BIND_GROUP}) @binding(101) var<uniform> max_particles: u32;
 @group(#{MATERIAL_BIND_GROUP}) @binding(102) var<uniform> particle_flags: u32;
+// fixed y axis for TRANSFORM_ALIGN_BILLBOARD_FIXED_Y, in emitter space when w is 1
+@group(#{MATERIAL_BIND_GROUP}) @binding(103) var<uniform> billboard_axis: vec4<f32>;
 
 // computes a rotation matrix that aligns Y axis to a direction
 fn align_y_to_direction(dir: vec3<f32>) -> mat3x3<f32> {
@@ -77,6 +80,9 @@ fn vertex(vertex: Vertex) -> VertexOutput {
 #ifdef VERTEX_NORMALS
     var rotated_normal = vertex.normal;
 #endif
+#ifdef VERTEX_TANGENTS
+    var rotated_tangent = vertex.tangent.xyz;
+#endif
 
     let transform_align = (particle_flags >> TRANSFORM_ALIGN_SHIFT) & TRANSFORM_ALIGN_MASK;
 
@@ -88,6 +94,9 @@ fn vertex(vertex: Vertex) -> VertexOutput {
             rotated_position = rotation_matrix * vertex.position;
 #ifdef VERTEX_NORMALS
             rotated_normal = rotation_matrix * vertex.normal;
+#endif
+#ifdef VERTEX_TANGENTS
+            rotated_tangent = rotation_matrix * vertex.tangent.xyz;
 #endif
         }
     }
@@ -106,6 +115,9 @@ fn vertex(vertex: Vertex) -> VertexOutput {
             rotated_position = angle_matrix * rotated_position;
 #ifdef VERTEX_NORMALS
             rotated_normal = angle_matrix * rotated_normal;
+#endif
+#ifdef VERTEX_TANGENTS
+            rotated_tangent = angle_matrix * rotated_tangent;
 #endif
         } else {
             let angle_matrix = mat3x3<f32>(
@@ -116,17 +128,30 @@ fn vertex(vertex: Vertex) -> VertexOutput {
             rotated_position = angle_matrix * rotated_position;
 #ifdef VERTEX_NORMALS
             rotated_normal = angle_matrix * rotated_normal;
+#endif
+#ifdef VERTEX_TANGENTS
+            rotated_tangent = angle_matrix * rotated_tangent;
 #endif
         }
     }
 
     var world_from_local = mesh_functions::get_world_from_local(vertex.instance_index);
 
-    if transform_align == TRANSFORM_ALIGN_BILLBOARD || transform_align == TRANSFORM_ALIGN_BILLBOARD_Y_TO_VELOCITY || transform_align == TRANSFORM_ALIGN_BILLBOARD_FIXED_Y {
-        let cam_right = normalize(view.world_from_view[0].xyz);
-        let cam_up = normalize(view.world_from_view[1].xyz);
+    if transform_align == TRANSFORM_ALIGN_BILLBOARD || transform_align == TRANSFORM_ALIGN_BILLBOARD_Y_TO_VELOCITY || transform_align == TRANSFORM_ALIGN_BILLBOARD_FIXED_Y || transform_align == TRANSFORM_ALIGN_BILLBOARD_SCREEN_ALIGNED {
+        var cam_right = normalize(view.world_from_view[0].xyz);
+        var cam_up = normalize(view.world_from_view[1].xyz);
         let cam_forward = normalize(view.world_from_view[2].xyz);
 
+        if transform_align == TRANSFORM_ALIGN_BILLBOARD_SCREEN_ALIGNED {
+            // world up projected onto the screen plane, so camera roll doesn't turn the quad
+            let screen_up = vec3(0.0, 1.0, 0.0) - cam_forward * cam_forward.y;
+            // looking straight up or down, keep the camera's own up
+            if length(screen_up) > 0.001 {
+                cam_up = normalize(screen_up);
+                cam_right = cross(cam_up, cam_forward);
+            }
+        }
+
         let particle_world_pos = (world_from_local * vec4(particle_position, 1.0)).xyz;
 
         let scale = vec3(particle_scale) * vec3(
@@ -161,9 +186,18 @@ fn vertex(vertex: Vertex) -> VertexOutput {
                 + cam_forward * rotated_normal.z;
 #endif
         } else if transform_align == TRANSFORM_ALIGN_BILLBOARD_FIXED_Y {
-            // y-axis locked to world up, rotates around vertical axis to face camera
-            let world_up = vec3(0.0, 1.0, 0.0);
-            let right = normalize(cross(world_up, cam_forward));
+            // y-axis locked to the fixed axis, rotates around it to face camera
+            var world_up = billboard_axis.xyz;
+            if billboard_axis.w > 0.5 {
+                world_up = (world_from_local * vec4(world_up, 0.0)).xyz;
+            }
+            world_up = normalize(world_up);
+            var right = cross(world_up, cam_forward);
+            // looking along the axis, keep the camera's right instead
+            if length(right) < 0.001 {
+                right = cam_right;
+            }
+            right = normalize(right);
             let forward = cross(right, world_up);
 
             let scaled_vertex = rotated_position * scale;
@@ -218,7 +252,8 @@ fn vertex(vertex: Vertex) -> VertexOutput {
 #endif
 
 #ifdef VERTEX_TANGENTS
-    out.world_tangent = mesh_functions::mesh_tangent_local_to_world(world_from_local, vertex.tangent, vertex.instance_index);
+    // follows the particle's rotation so normal maps stay lit correctly on rotating meshes
+    out.world_tangent = mesh_functions::mesh_tangent_local_to_world(world_from_local, vec4(rotated_tangent, vertex.tangent.w), vertex.instance_index);
 #endif
 
 #ifdef VERTEX_COLORS
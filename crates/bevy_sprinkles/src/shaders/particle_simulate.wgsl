@@ -4,9 +4,12 @@
     CurveUniform,
     SubEmissionEntry,
     PARTICLE_FLAG_ACTIVE,
+    PARTICLE_FLAG_FOLLOWS_PARENT,
+    PARTICLE_PARENT_SHIFT,
     EMITTER_FLAG_DISABLE_Z,
     EMISSION_FLAG_HAS_POSITION,
     EMISSION_FLAG_HAS_VELOCITY,
+    EMISSION_FLAG_KEEP_LOCAL,
     SUB_EMITTER_MODE_DISABLED,
     SUB_EMITTER_MODE_CONSTANT,
     SUB_EMITTER_MODE_AT_END,
@@ -14,6 +17,7 @@
     SUB_EMITTER_MODE_AT_START,
     hash,
     hash_to_float,
+    random_vec3,
 }
 
 struct AnimatedVelocity {
@@ -63,7 +67,7 @@ struct EmitterParams {
     _pad3: f32,
 
     emission_ring_axis: vec3<f32>,
-    _pad4: f32,
+    emission_ring_radial_bias: f32,
 
     direction: vec3<f32>,
     _pad5: f32,
@@ -81,7 +85,7 @@ struct EmitterParams {
     use_initial_color_gradient: u32,
     turbulence_enabled: u32,
     particle_flags: u32,
-    _pad7: u32,
+    count_alive_particles: u32,
 
     initial_color: vec4<f32>,
 
@@ -129,9 +133,83 @@ struct EmitterParams {
     sub_emitter_keep_velocity: u32,
 
     is_sub_emitter_target: u32,
-    _sub_emitter_pad0: u32,
+    sub_emitter_keep_local: u32,
     _sub_emitter_pad1: u32,
     _sub_emitter_pad2: u32,
+
+    // custom shader data
+    user_data_min: vec4<f32>,
+    user_data_max: vec4<f32>,
+
+    // kill box
+    kill_box_min: vec3<f32>,
+    kill_box_enabled: u32,
+    kill_box_max: vec3<f32>,
+    _kill_box_pad0: f32,
+
+    // cross-fade opacity times the emitter runtime opacity
+    fade: f32,
+    // fraction of slots allowed to respawn under the system's particle budget
+    spawn_ratio: f32,
+    _fade_pad1: f32,
+    _fade_pad2: f32,
+
+    // rgba multiplier from the emitter runtime's color tint
+    color_tint: vec4<f32>,
+
+    // -1 on axes the system is mirrored across, 1 elsewhere
+    mirror: vec3<f32>,
+    _mirror_pad0: f32,
+
+    position_jitter: vec3<f32>,
+    // distance spawned particles are moved forward along their initial velocity
+    spawn_offset_along_velocity: f32,
+
+    acceleration_randomness: vec3<f32>,
+    _acceleration_randomness_pad0: f32,
+
+    // emitter entity velocity, in the emitter's local space
+    inherit_velocity: vec3<f32>,
+    inherit_velocity_max: f32,
+
+    // sinusoidal positional sway, zero amplitude when disabled
+    wobble_amplitude: vec3<f32>,
+    wobble_frequency: f32,
+
+    // brightness flicker
+    flicker_enabled: u32,
+    flicker_amplitude: f32,
+    flicker_frequency: f32,
+    _flicker_pad0: f32,
+
+    // containment
+    containment_enabled: u32,
+    containment_radius: f32,
+    containment_strength: f32,
+    _containment_pad0: f32,
+
+    // blend of the emission direction toward the surface normal, surface shapes only
+    emit_along_normal: f32,
+    _normal_pad0: f32,
+    _normal_pad1: f32,
+    _normal_pad2: f32,
+
+    // fraction of the lifetime over which the scale eases in from zero
+    scale_in_time: f32,
+    _scale_in_pad0: f32,
+    _scale_in_pad1: f32,
+    _scale_in_pad2: f32,
+
+    // per-distance and burst emission spawn scheduled_spawns slots starting at scheduled_spawn_start
+    emission_mode: u32,
+    scheduled_spawn_start: u32,
+    scheduled_spawns: u32,
+    _emission_mode_pad0: u32,
+
+    // emitter world scale when inherit_scale is set, 1 elsewhere
+    inherited_scale: vec3<f32>,
+    // uniform factor applied to particle sizes, the mean of inherited_scale
+    inherited_size: f32,
 }
 
 struct Collider {
@@ -150,9 +228,14 @@ const EMISSION_SHAPE_SPHERE: u32 = 1u;
 const EMISSION_SHAPE_SPHERE_SURFACE: u32 = 2u;
 const EMISSION_SHAPE_BOX: u32 = 3u;
 const EMISSION_SHAPE_RING: u32 = 4u;
+const EMISSION_SHAPE_BOX_SURFACE: u32 = 5u;
 
 const DRAW_ORDER_INDEX: u32 = 0u;
 
+const EMISSION_MODE_TIME: u32 = 0u;
+const EMISSION_MODE_PER_DISTANCE: u32 = 1u;
+const EMISSION_MODE_BURST: u32 = 2u;
+
 // collision constants
 const COLLIDER_TYPE_SPHERE: u32 = 0u;
 const COLLIDER_TYPE_BOX: u32 = 1u;
@@ -192,6 +275,16 @@ struct SubEmissionBuffer {
 @group(0) @binding(21) var<storage, read_write> dst_emission_buffer: SubEmissionBuffer;
 @group(0) @binding(22) var<storage, read_write> src_emission_buffer: SubEmissionBuffer;
 
+// read back by the CPU, see `EmitterStatsBuffer`
+struct EmitterStats {
+    alive_particles: atomic<u32>,
+}
+
+@group(0) @binding(23) var<storage, read_write> stats: EmitterStats;
+
+// particles of the emitter feeding this one, for children that follow their parent
+@group(0) @binding(24) var<storage, read> parent_particles: array<Particle>;
+
 @compute @workgroup_size(64)
 fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
     let idx = global_id.x;
@@ -214,6 +307,7 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
     // when delta is zero (paused), write cleared data and stop
     if (params.delta_time <= 0.0) {
         particles[idx] = p;
+        count_alive(p);
         return;
     }
 
@@ -244,14 +338,22 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
                         p.alignment_dir = vec4(normalize(entry.velocity.xyz), p.alignment_dir.w);
                     }
                 }
+
+                if ((entry.flags & EMISSION_FLAG_KEEP_LOCAL) != 0u) {
+                    let follow = PARTICLE_FLAG_FOLLOWS_PARENT | (entry.parent << PARTICLE_PARENT_SHIFT);
+                    p.custom.w = bitcast<f32>(bitcast<u32>(p.custom.w) | follow);
+                }
             }
         } else {
-            p = update_particle(p);
+            p = follow_parent(update_particle(p, idx));
         }
     } else {
         // normal phase-based emission
         var should_restart = false;
-        if (params.emitting != 0u) {
+        if (params.emission_mode != EMISSION_MODE_TIME) {
+            should_restart = params.emitting != 0u &&
+                           scheduled_spawn_offset(idx) < min(params.scheduled_spawns, params.amount);
+        } else if (params.emitting != 0u) {
             if (params.system_phase < params.prev_system_phase) {
                 // phase wrapped around
                 should_restart = adjusted_phase >= params.prev_system_phase ||
@@ -260,16 +362,38 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
                 should_restart = adjusted_phase >= params.prev_system_phase &&
                                adjusted_phase < params.system_phase;
             }
+            should_restart = should_restart && is_budgeted_slot(idx);
         }
 
         if (should_restart) {
             p = spawn_particle(idx);
         } else if (is_active) {
-            p = update_particle(p);
+            p = update_particle(p, idx);
         }
     }
 
     particles[idx] = p;
+    count_alive(p);
+}
+
+fn count_alive(p: Particle) {
+    if (params.count_alive_particles == 0u) {
+        return;
+    }
+    if ((bitcast<u32>(p.custom.w) & PARTICLE_FLAG_ACTIVE) != 0u) {
+        atomicAdd(&stats.alive_particles, 1u);
+    }
+}
+
+// how many spawns after scheduled_spawn_start this slot is taken, since spawns go round the slots in order
+fn scheduled_spawn_offset(idx: u32) -> u32 {
+    let start = params.scheduled_spawn_start % params.amount;
+    return (idx + params.amount - start) % params.amount;
+}
+
+// picks every (1 / spawn_ratio)-th slot, so the kept slots stay evenly spread over the cycle
+fn is_budgeted_slot(idx: u32) -> bool {
+    return floor(f32(idx + 1u) * params.spawn_ratio) > floor(f32(idx) * params.spawn_ratio);
 }
 
 fn get_emission_offset(seed: u32) -> vec3<f32> {
@@ -317,6 +441,23 @@ fn get_emission_offset(seed: u32) -> vec3<f32> {
             let w = hash_to_float(seed + 2u) * 2.0 - 1.0;
             pos = vec3(u, v, w) * params.emission_box_extents;
         }
+        case EMISSION_SHAPE_BOX_SURFACE: {
+            // picks a face pair weighted by its area, then a uniform point on one of the two
+            let e = params.emission_box_extents;
+            let areas = vec3(e.y * e.z, e.x * e.z, e.x * e.y);
+            let pick = hash_to_float(seed + 3u) * (areas.x + areas.y + areas.z);
+            let u = hash_to_float(seed) * 2.0 - 1.0;
+            let v = hash_to_float(seed + 1u) * 2.0 - 1.0;
+            let side = select(-1.0, 1.0, hash_to_float(seed + 2u) < 0.5);
+
+            if (pick < areas.x) {
+                pos = vec3(side, u, v) * e;
+            } else if (pick < areas.x + areas.y) {
+                pos = vec3(u, side, v) * e;
+            } else {
+                pos = vec3(u, v, side) * e;
+            }
+        }
         case EMISSION_SHAPE_RING: {
             let u = hash_to_float(seed);
             let v = hash_to_float(seed + 1u);
@@ -324,7 +465,9 @@ fn get_emission_offset(seed: u32) -> vec3<f32> {
 
             let theta = 2.0 * PI * u;
             let r_range = params.emission_ring_radius - params.emission_ring_inner_radius;
-            let r = params.emission_ring_inner_radius + sqrt(v) * r_range;
+            // bias of +1 pulls samples toward the outer radius, -1 toward the inner one
+            let biased_v = pow(v, exp2(-2.0 * params.emission_ring_radial_bias));
+            let r = params.emission_ring_inner_radius + sqrt(biased_v) * r_range;
             let height_offset = (h - 0.5) * params.emission_ring_height;
 
             // ring local space (ring lies in XY plane, axis is Z)
@@ -337,6 +480,7 @@ fn get_emission_offset(seed: u32) -> vec3<f32> {
     }
 
     var result = pos * params.emission_scale + params.emission_offset;
+    result += random_vec3(seed + 90u, params.position_jitter);
 
     // disable z for 2d mode
     if ((params.particle_flags & EMITTER_FLAG_DISABLE_Z) != 0u) {
@@ -367,12 +511,50 @@ fn rotate_to_axis(v: vec3<f32>, axis: vec3<f32>) -> vec3<f32> {
     return v * cos_angle + cross(rot_axis, v) * sin_angle + rot_axis * dot(rot_axis, v) * (1.0 - cos_angle);
 }
 
-fn get_emission_velocity(seed: u32) -> vec3<f32> {
+// outward normal at the spawn point of surface shapes, zero for every other shape.
+// reuses the hashes of get_emission_offset so it matches the spawn position
+fn get_emission_normal(seed: u32) -> vec3<f32> {
+    switch params.emission_shape {
+        case EMISSION_SHAPE_SPHERE_SURFACE: {
+            let theta = 2.0 * PI * hash_to_float(seed);
+            let phi = acos(2.0 * hash_to_float(seed + 1u) - 1.0);
+            return vec3(sin(phi) * cos(theta), sin(phi) * sin(theta), cos(phi));
+        }
+        case EMISSION_SHAPE_BOX_SURFACE: {
+            let e = params.emission_box_extents;
+            let areas = vec3(e.y * e.z, e.x * e.z, e.x * e.y);
+            let pick = hash_to_float(seed + 3u) * (areas.x + areas.y + areas.z);
+            let side = select(-1.0, 1.0, hash_to_float(seed + 2u) < 0.5);
+
+            if (pick < areas.x) {
+                return vec3(side, 0.0, 0.0);
+            } else if (pick < areas.x + areas.y) {
+                return vec3(0.0, side, 0.0);
+            }
+            return vec3(0.0, 0.0, side);
+        }
+        default: {
+            return vec3(0.0);
+        }
+    }
+}
+
+fn get_emission_velocity(seed: u32, normal: vec3<f32>) -> vec3<f32> {
     var dir = normalize(params.direction);
     if (length(params.direction) < 0.0001) {
         dir = vec3(1.0, 0.0, 0.0);
     }
 
+    // bend the base direction toward the surface normal before the spread is applied
+    if (params.emit_along_normal > 0.0 && length(normal) > 0.0) {
+        let blended = mix(dir, normal, params.emit_along_normal);
+        if (length(blended) > 0.0001) {
+            dir = normalize(blended);
+        } else {
+            dir = normal;
+        }
+    }
+
     // randomize direction within a cone based on spread angle
     let spread_rad = radians(params.spread);
     if (spread_rad > 0.0001) {
@@ -416,7 +598,7 @@ fn get_emission_velocity(seed: u32) -> vec3<f32> {
 
 fn get_initial_scale(seed: u32) -> f32 {
     let t = hash_to_float(seed);
-    return mix(params.scale_min, params.scale_max, t);
+    return mix(params.scale_min, params.scale_max, t) * params.inherited_size;
 }
 
 fn get_initial_angle(seed: u32) -> f32 {
@@ -598,18 +780,28 @@ fn get_turbulence_influence_at_lifetime(base_influence: f32, age: f32, lifetime:
     return base_influence * curve_value;
 }
 
+// ease-out from 0 to 1 over the first scale_in_time fraction of the lifetime
+fn get_scale_in(t: f32) -> f32 {
+    if (params.scale_in_time <= 0.0) {
+        return 1.0;
+    }
+    let x = clamp(t / params.scale_in_time, 0.0, 1.0);
+    return 1.0 - (1.0 - x) * (1.0 - x);
+}
+
 fn get_scale_at_lifetime(initial_scale: f32, age: f32, lifetime: f32) -> f32 {
+    let t = clamp(age / lifetime, 0.0, 1.0);
+    let scale = initial_scale * get_scale_in(t);
     if (params.scale_over_lifetime.enabled == 0u) {
-        return initial_scale;
+        return scale;
     }
-    let t = clamp(age / lifetime, 0.0, 1.0);
     let curve_value = sample_spline_curve(
         scale_over_lifetime_texture,
         scale_over_lifetime_sampler,
         params.scale_over_lifetime,
         t
     );
-    return initial_scale * curve_value;
+    return scale * curve_value;
 }
 
 fn get_initial_alpha(seed: u32) -> f32 {
@@ -658,6 +850,31 @@ fn get_emission_at_lifetime(age: f32, lifetime: f32) -> f32 {
     return 1.0 + curve_value;
 }
 
+fn get_wobble_offset(seed: u32, age: f32) -> vec3<f32> {
+    // each axis starts at its own random phase so neighbours sway out of sync
+    let phase = vec3(
+        hash_to_float(seed + 120u),
+        hash_to_float(seed + 121u),
+        hash_to_float(seed + 122u)
+    );
+    return params.wobble_amplitude * sin(2.0 * PI * (age * params.wobble_frequency + phase));
+}
+
+fn get_brightness_flicker(seed: u32, age: f32) -> f32 {
+    if (params.flicker_enabled == 0u) {
+        return 1.0;
+    }
+    // smooth 1d value noise, offset per particle so neighbours flicker out of sync
+    let n = age * params.flicker_frequency + hash_to_float(seed + 110u) * 256.0;
+    let cell = u32(floor(n));
+    let f = fract(n);
+    let base = hash(seed + 111u);
+    let a = hash_to_float(base + cell);
+    let b = hash_to_float(base + cell + 1u);
+    let noise = mix(a, b, f * f * (3.0 - 2.0 * f)) * 2.0 - 1.0;
+    return max(1.0 + params.flicker_amplitude * noise, 0.0);
+}
+
 fn get_color_over_lifetime(age: f32, lifetime: f32) -> vec4<f32> {
     let t = clamp(age / lifetime, 0.0, 1.0);
     return textureSampleLevel(color_over_lifetime_texture, color_over_lifetime_sampler, vec2(t, 0.5), 0.0);
@@ -886,7 +1103,7 @@ fn process_collisions(
     return final_result;
 }
 
-fn emit_subparticle(position: vec3<f32>, scale: f32, velocity: vec3<f32>, flags: u32) -> bool {
+fn emit_subparticle(position: vec3<f32>, scale: f32, velocity: vec3<f32>, parent: u32) -> bool {
     if (params.sub_emitter_mode == SUB_EMITTER_MODE_DISABLED) {
         return false;
     }
@@ -899,25 +1116,73 @@ fn emit_subparticle(position: vec3<f32>, scale: f32, velocity: vec3<f32>, flags:
 
     dst_emission_buffer.data[dst_index].position = vec4(position, scale);
     dst_emission_buffer.data[dst_index].velocity = vec4(velocity, 0.0);
-    dst_emission_buffer.data[dst_index].flags = flags;
+    dst_emission_buffer.data[dst_index].flags = sub_emission_flags();
+    dst_emission_buffer.data[dst_index].parent = parent;
 
     return true;
 }
 
-fn emit_sub_particles(position: vec3<f32>, scale: f32, velocity: vec3<f32>) {
-    var flags = EMISSION_FLAG_HAS_POSITION;
+// children following their parent already move with it, so they don't inherit its velocity
+fn sub_emission_flags() -> u32 {
+    if (params.sub_emitter_keep_local != 0u) {
+        return EMISSION_FLAG_HAS_POSITION | EMISSION_FLAG_KEEP_LOCAL;
+    }
     if (params.sub_emitter_keep_velocity != 0u) {
-        flags |= EMISSION_FLAG_HAS_VELOCITY;
+        return EMISSION_FLAG_HAS_POSITION | EMISSION_FLAG_HAS_VELOCITY;
     }
+    return EMISSION_FLAG_HAS_POSITION;
+}
+
+fn emit_sub_particles(position: vec3<f32>, scale: f32, velocity: vec3<f32>, parent: u32) {
     for (var i = 0u; i < params.sub_emitter_amount; i++) {
-        emit_subparticle(position, scale, velocity, flags);
+        emit_subparticle(position, scale, velocity, parent);
     }
 }
 
+// moves a child by its parent's last step while the parent is alive; a parent born over a
+// step after the child has died and respawned since, so the child stops following it
+fn follow_parent(p_in: Particle) -> Particle {
+    var p = p_in;
+    let flags = bitcast<u32>(p.custom.w);
+    if ((flags & PARTICLE_FLAG_ACTIVE) == 0u || (flags & PARTICLE_FLAG_FOLLOWS_PARENT) == 0u) {
+        return p;
+    }
+
+    let parent_index = flags >> PARTICLE_PARENT_SHIFT;
+    var following = parent_index < arrayLength(&parent_particles);
+    if (following) {
+        let parent = parent_particles[parent_index];
+        let parent_flags = bitcast<u32>(parent.custom.w);
+        following = (parent_flags & PARTICLE_FLAG_ACTIVE) != 0u &&
+                    parent.custom.x + params.delta_time >= p.custom.x;
+        if (following) {
+            p.position = vec4(p.position.xyz + parent.velocity.xyz * params.delta_time, p.position.w);
+        }
+    }
+    if (!following) {
+        p.custom.w = bitcast<f32>(flags & PARTICLE_FLAG_ACTIVE);
+    }
+    return p;
+}
+
+fn get_inherited_velocity() -> vec3<f32> {
+    let inherited = params.inherit_velocity * params.inherit_velocity_ratio;
+    let speed = length(inherited);
+    if (speed <= params.inherit_velocity_max) {
+        return inherited;
+    }
+    return inherited * (params.inherit_velocity_max / speed);
+}
+
 fn spawn_particle(idx: u32) -> Particle {
     var p: Particle;
     // per-particle seed: base_seed + 1 + index + (cycle * amount)
-    let seed = hash(params.random_seed + 1u + idx + params.cycle * params.amount);
+    var cycle = params.cycle;
+    if (params.emission_mode != EMISSION_MODE_TIME) {
+        // slots are reused once per lap of the spawn cursor rather than once per cycle
+        cycle = (params.scheduled_spawn_start + scheduled_spawn_offset(idx)) / params.amount;
+    }
+    let seed = hash(params.random_seed + 1u + idx + cycle * params.amount);
 
     let emission_pos = get_emission_offset(seed);
     let initial_scale = get_initial_scale(seed + 20u);
@@ -925,7 +1190,7 @@ fn spawn_particle(idx: u32) -> Particle {
     let scale = get_scale_at_lifetime(initial_scale, 0.0, 1.0);
     p.position = vec4(emission_pos, scale);
 
-    var vel = get_emission_velocity(seed + 10u);
+    var vel = get_emission_velocity(seed + 10u, get_emission_normal(seed));
     let lifetime = params.lifetime * (1.0 - hash_to_float(seed + 4u) * params.lifetime_randomness);
 
     // include radial velocity at spawn for correct initial alignment
@@ -943,6 +1208,14 @@ fn spawn_particle(idx: u32) -> Particle {
     }
     vel = vel + radial_displacement;
 
+    p.position = vec4(p.position.xyz * params.mirror * params.inherited_scale, p.position.w);
+    vel = vel * params.mirror * params.inherited_scale + get_inherited_velocity();
+
+    if (params.spawn_offset_along_velocity != 0.0 && length(vel) > 0.0) {
+        let nudged = p.position.xyz + normalize(vel) * params.spawn_offset_along_velocity;
+        p.position = vec4(nudged, p.position.w);
+    }
+
     p.velocity = vec4(vel, lifetime);
 
     if (params.use_initial_color_gradient == 0u) {
@@ -955,14 +1228,14 @@ fn spawn_particle(idx: u32) -> Particle {
     let initial_alpha = p.color.a;
     p.color.a = get_alpha_at_lifetime(initial_alpha, 0.0, 1.0);
 
-    let emission = get_emission_at_lifetime(0.0, 1.0);
+    let emission = get_emission_at_lifetime(0.0, 1.0) * get_brightness_flicker(seed, 0.0);
     let col_life = get_color_over_lifetime(0.0, 1.0);
-    p.color = vec4(p.color.rgb * emission * col_life.rgb, p.color.a * col_life.a);
+    p.color = vec4(p.color.rgb * emission * col_life.rgb, p.color.a * col_life.a * params.fade) * params.color_tint;
 
     // spawn_index tracks total spawns across all cycles for depth ordering
     var spawn_index = 0.0;
     if (params.draw_order == DRAW_ORDER_INDEX) {
-        spawn_index = f32(params.cycle * params.amount + idx);
+        spawn_index = f32(cycle * params.amount + idx);
     }
     p.custom = vec4(0.0, spawn_index, bitcast<f32>(seed), bitcast<f32>(PARTICLE_FLAG_ACTIVE));
 
@@ -975,48 +1248,44 @@ fn spawn_particle(idx: u32) -> Particle {
         p.alignment_dir = vec4(0.0, 1.0, 0.0, angle);
     }
 
+    let user_data_t = vec4(
+        hash_to_float(seed + 80u),
+        hash_to_float(seed + 81u),
+        hash_to_float(seed + 82u),
+        hash_to_float(seed + 83u),
+    );
+    p.user_data = mix(params.user_data_min, params.user_data_max, user_data_t);
+
     // sub emitter: at start trigger
     if (params.sub_emitter_mode == SUB_EMITTER_MODE_AT_START) {
-        emit_sub_particles(p.position.xyz, p.position.w, vel);
+        emit_sub_particles(p.position.xyz, p.position.w, vel, idx);
     }
 
     return p;
 }
 
-fn update_particle(p_in: Particle) -> Particle {
+fn update_particle(p_in: Particle, idx: u32) -> Particle {
     var p = p_in;
     let dt = params.delta_time;
-    let age = p.custom.x + dt;
-    p.custom.x = age;
-
+    let prev_age = p.custom.x;
     let lifetime = p.velocity.w;
 
+    // clamp so the particle's final state samples every curve at exactly t = 1.0
+    // of its own (randomized) lifetime
+    let age = min(prev_age + dt, lifetime);
+    p.custom.x = age;
+
     // sub emitter: constant mode - emit once per frequency interval
     if (params.sub_emitter_mode == SUB_EMITTER_MODE_CONSTANT) {
-        let prev_age = age - dt;
         let interval = params.sub_emitter_frequency;
         if (interval > 0.0) {
             let interval_rem = interval - fract(prev_age / interval) * interval;
             if (dt >= interval_rem) {
-                emit_subparticle(
-                    p.position.xyz,
-                    p.position.w,
-                    p.velocity.xyz,
-                    EMISSION_FLAG_HAS_POSITION | select(0u, EMISSION_FLAG_HAS_VELOCITY, params.sub_emitter_keep_velocity != 0u)
-                );
+                emit_subparticle(p.position.xyz, p.position.w, p.velocity.xyz, idx);
             }
         }
     }
 
-    if (age >= lifetime) {
-        // sub emitter: at end trigger
-        if (params.sub_emitter_mode == SUB_EMITTER_MODE_AT_END) {
-            emit_sub_particles(p.position.xyz, p.position.w, p.velocity.xyz);
-        }
-        p.custom.w = bitcast<f32>(0u); // deactivate
-        return p;
-    }
-
     let seed = bitcast<u32>(p.custom.z);
     let initial_radial_velocity = get_initial_radial_velocity(seed + 60u);
 
@@ -1025,9 +1294,8 @@ fn update_particle(p_in: Particle) -> Particle {
 
     // on first frame, stored velocity is pure physics (no radial yet)
     var physics_velocity = stored_velocity;
-    if (age > dt) {
+    if (prev_age > 0.0) {
         let prev_position = p.position.xyz - stored_velocity * dt;
-        let prev_age = age - dt;
 
         var prev_radial = get_radial_displacement(
             prev_position,
@@ -1044,12 +1312,25 @@ fn update_particle(p_in: Particle) -> Particle {
         physics_velocity = stored_velocity - prev_radial;
     }
 
-    var gravity = params.gravity;
+    // each particle keeps the same random acceleration for its whole life
+    var gravity = params.gravity + random_vec3(seed + 100u, params.acceleration_randomness);
     if ((params.particle_flags & EMITTER_FLAG_DISABLE_Z) != 0u) {
         gravity.z = 0.0;
     }
     physics_velocity = physics_velocity + gravity * dt;
 
+    // containment pulls stray particles back toward the sphere, damping outward motion
+    if (params.containment_enabled != 0u) {
+        let distance = length(p.position.xyz);
+        if (distance > params.containment_radius) {
+            let dir = p.position.xyz / distance;
+            let overshoot = distance - params.containment_radius;
+            let outward_speed = max(dot(physics_velocity, dir), 0.0);
+            let damping = min(outward_speed * 2.0 * sqrt(params.containment_strength) * dt, outward_speed);
+            physics_velocity -= dir * (overshoot * params.containment_strength * dt + damping);
+        }
+    }
+
     var radial_displacement = get_radial_displacement(
         p.position.xyz,
         initial_radial_velocity,
@@ -1093,6 +1374,7 @@ fn update_particle(p_in: Particle) -> Particle {
     }
 
     var new_position = p.position.xyz + effective_velocity * dt;
+    new_position += get_wobble_offset(seed, age) - get_wobble_offset(seed, prev_age);
 
     if ((params.particle_flags & EMITTER_FLAG_DISABLE_Z) != 0u) {
         new_position.z = 0.0;
@@ -1103,6 +1385,14 @@ fn update_particle(p_in: Particle) -> Particle {
 
     p.position = vec4(new_position, scale);
 
+    // particles leaving the kill box die immediately
+    if (params.kill_box_enabled != 0u) {
+        if (any(new_position < params.kill_box_min) || any(new_position > params.kill_box_max)) {
+            p.custom.w = bitcast<f32>(0u);
+            return p;
+        }
+    }
+
     // collision handling
     if (params.collision_mode != COLLISION_MODE_DISABLED && params.collider_count > 0u) {
         let particle_radius = get_particle_collision_size(scale);
@@ -1111,7 +1401,7 @@ fn update_particle(p_in: Particle) -> Particle {
         if (collision.collided) {
             // sub emitter: at collision trigger
             if (params.sub_emitter_mode == SUB_EMITTER_MODE_AT_COLLISION) {
-                emit_sub_particles(p.position.xyz, p.position.w, p.velocity.xyz);
+                emit_sub_particles(p.position.xyz, p.position.w, p.velocity.xyz, idx);
             }
 
             if (params.collision_mode == COLLISION_MODE_HIDE_ON_CONTACT) {
@@ -1156,9 +1446,18 @@ fn update_particle(p_in: Particle) -> Particle {
     p.color.a = get_alpha_at_lifetime(initial_alpha, age, lifetime);
 
     let initial_rgb = get_initial_color_rgb(seed);
-    let emission = get_emission_at_lifetime(age, lifetime);
+    let emission = get_emission_at_lifetime(age, lifetime) * get_brightness_flicker(seed, age);
     let col_life = get_color_over_lifetime(age, lifetime);
-    p.color = vec4(initial_rgb * emission * col_life.rgb, p.color.a * col_life.a);
+    p.color = vec4(initial_rgb * emission * col_life.rgb, p.color.a * col_life.a * params.fade) * params.color_tint;
+
+    // particles die on the frame they reach the end of their lifetime
+    if (age >= lifetime) {
+        // sub emitter: at end trigger
+        if (params.sub_emitter_mode == SUB_EMITTER_MODE_AT_END) {
+            emit_sub_particles(p.position.xyz, p.position.w, p.velocity.xyz, idx);
+        }
+        p.custom.w = bitcast<f32>(0u); // deactivate
+    }
 
     return p;
 }
@@ -11,17 +11,25 @@ struct SortParams {
     stage: u32,
     step: u32,
     camera_position: vec3<f32>,
-    _pad1: f32,
+    // keys closer than this compare as equal, 0 compares them exactly
+    sort_precision: f32,
     camera_forward: vec3<f32>,
-    _pad2: f32,
+    // time not simulated yet, see `ParticlesGlobalControl::simulation_rate`
+    extrapolate_time: f32,
     emitter_transform: mat4x4<f32>,
 }
 
 @group(0) @binding(0) var<uniform> params: SortParams;
 @group(0) @binding(1) var<storage, read_write> particles: array<Particle>;
 @group(0) @binding(2) var<storage, read_write> indices: array<u32>;
+#ifdef RADIX_SORT
+// the sorted particles buffer, free until `copy_sorted` fills it, holds the indices
+// between radix passes
+@group(0) @binding(3) var<storage, read_write> scratch: array<u32>;
+#else
 // output buffer: particle data written in sorted order for rendering
 @group(0) @binding(3) var<storage, read_write> sorted_particles: array<Particle>;
+#endif
 
 fn get_sort_key(particle_index: u32) -> f32 {
     let particle = particles[particle_index];
@@ -67,7 +75,167 @@ fn get_sort_key(particle_index: u32) -> f32 {
     }
 }
 
+fn quantize_key(key: f32) -> f32 {
+    if (params.sort_precision > 0.0) {
+        return round(key / params.sort_precision);
+    }
+    return key;
+}
+
+// orders by key, then by particle index so equal keys keep the same order every frame
+fn sorts_after(key_a: f32, index_a: u32, key_b: f32, index_b: u32) -> bool {
+    if (key_a != key_b) {
+        return key_a > key_b;
+    }
+    return index_a > index_b;
+}
+
+#ifdef RADIX_SORT
+const RADIX_WORKGROUP_SIZE: u32 = 256u;
+// keys are sorted 4 bits at a time, so 8 passes cover the 32 bit keys
+const RADIX_BITS: u32 = 4u;
+const RADIX_BINS: u32 = 16u;
+
+// how many keys of each digit every block holds, digit major, then where each block
+// writes its keys of each digit once `radix_scan` has summed them
+@group(0) @binding(4) var<storage, read_write> histograms: array<u32>;
+
+var<workgroup> bin_counts: array<atomic<u32>, RADIX_BINS>;
+var<workgroup> block_digits: array<u32, RADIX_WORKGROUP_SIZE>;
+var<workgroup> scan: array<u32, RADIX_WORKGROUP_SIZE>;
+
+// maps a key to bits that order like the float, with -0.0 and 0.0 equal like in
+// `sorts_after`
+fn sortable_bits(key: f32) -> u32 {
+    let bits = bitcast<u32>(select(key, 0.0, key == 0.0));
+    if ((bits & 0x80000000u) != 0u) {
+        return ~bits;
+    }
+    return bits | 0x80000000u;
+}
+
+fn radix_blocks() -> u32 {
+    return (params.amount + RADIX_WORKGROUP_SIZE - 1u) / RADIX_WORKGROUP_SIZE;
+}
+
+// the digit of the particle's key sorted by pass `params.step`
+fn key_digit(particle_index: u32) -> u32 {
+    let bits = sortable_bits(quantize_key(get_sort_key(particle_index)));
+    return (bits >> (params.step * RADIX_BITS)) & (RADIX_BINS - 1u);
+}
+
+// passes alternate between reading `indices` and `scratch`, so the even number of passes
+// leaves the result in `indices`
+fn read_index(i: u32) -> u32 {
+    if ((params.step & 1u) == 0u) {
+        return indices[i];
+    }
+    return scratch[i];
+}
+
+fn write_index(i: u32, value: u32) {
+    if ((params.step & 1u) == 0u) {
+        scratch[i] = value;
+    } else {
+        indices[i] = value;
+    }
+}
+
+// first step of a pass: counts the digits of each block of keys
+@compute @workgroup_size(256)
+fn radix_histogram(
+    @builtin(workgroup_id) block: vec3<u32>,
+    @builtin(local_invocation_index) local: u32,
+) {
+    if (local < RADIX_BINS) {
+        atomicStore(&bin_counts[local], 0u);
+    }
+    workgroupBarrier();
+
+    let i = block.x * RADIX_WORKGROUP_SIZE + local;
+    if (i < params.amount) {
+        atomicAdd(&bin_counts[key_digit(read_index(i))], 1u);
+    }
+    workgroupBarrier();
+
+    if (local < RADIX_BINS) {
+        histograms[local * radix_blocks() + block.x] = atomicLoad(&bin_counts[local]);
+    }
+}
+
+// second step, run by a single workgroup: an exclusive prefix sum over every histogram.
+// with digit major counts, this puts all keys of a digit after those of smaller digits,
+// and each block's keys after those of the blocks before it
+@compute @workgroup_size(256)
+fn radix_scan(@builtin(local_invocation_index) local: u32) {
+    let count = RADIX_BINS * radix_blocks();
+    let chunks = (count + RADIX_WORKGROUP_SIZE - 1u) / RADIX_WORKGROUP_SIZE;
+
+    var total = 0u;
+    for (var chunk = 0u; chunk < chunks; chunk++) {
+        let i = chunk * RADIX_WORKGROUP_SIZE + local;
+        var value = 0u;
+        if (i < count) {
+            value = histograms[i];
+        }
+
+        // inclusive prefix sum of this chunk
+        scan[local] = value;
+        workgroupBarrier();
+        for (var offset = 1u; offset < RADIX_WORKGROUP_SIZE; offset <<= 1u) {
+            var sum = scan[local];
+            if (local >= offset) {
+                sum += scan[local - offset];
+            }
+            workgroupBarrier();
+            scan[local] = sum;
+            workgroupBarrier();
+        }
+
+        if (i < count) {
+            histograms[i] = total + scan[local] - value;
+        }
+        total += scan[RADIX_WORKGROUP_SIZE - 1u];
+        workgroupBarrier();
+    }
+}
+
+// last step: moves each key to its block's offset for its digit, after the keys of the
+// block with the same digit that come before it. that keeps the sort stable, so equal
+// keys stay ordered by particle index like in the bitonic sort
+@compute @workgroup_size(256)
+fn radix_scatter(
+    @builtin(workgroup_id) block: vec3<u32>,
+    @builtin(local_invocation_index) local: u32,
+) {
+    let i = block.x * RADIX_WORKGROUP_SIZE + local;
+    var particle_index = 0u;
+    // past the last digit, so keys past the end never match
+    var digit = RADIX_BINS;
+    if (i < params.amount) {
+        particle_index = read_index(i);
+        digit = key_digit(particle_index);
+    }
+    block_digits[local] = digit;
+    workgroupBarrier();
+
+    if (i >= params.amount) {
+        return;
+    }
+    var rank = 0u;
+    for (var j = 0u; j < local; j++) {
+        if (block_digits[j] == digit) {
+            rank += 1u;
+        }
+    }
+    write_index(histograms[digit * radix_blocks() + block.x] + rank, particle_index);
+}
+#else
 // bitonic sort: compare and swap based on current stage and step
+//
+// every pass sorts ascending: the first step of a stage compares mirrored pairs across
+// each block, merging its two sorted halves, and later steps compare elements d apart.
+// particles past the end then act as keys sorted after every other, so any amount sorts
 @compute @workgroup_size(256)
 fn sort(@builtin(global_invocation_id) global_id: vec3<u32>) {
     let idx = global_id.x;
@@ -86,33 +254,23 @@ fn sort(@builtin(global_invocation_id) global_id: vec3<u32>) {
         return;
     }
 
-    let partner = idx + d;
+    var partner = idx + d;
+    if (params.step == params.stage) {
+        // the stage block is the comparison block here, 2^(stage+1) elements
+        partner = idx - within_block + block_2d - 1u - within_block;
+    }
     if (partner >= params.amount) {
         return;
     }
 
-    // determine sort direction based on which stage block we're in
-    // stage_block_size = 2^(stage+1)
-    let stage_block_size = 2u << params.stage;
-    let stage_block_idx = idx / stage_block_size;
-    let ascending = (stage_block_idx % 2u) == 0u;
-
     let idx_a = indices[idx];
     let idx_b = indices[partner];
 
-    let key_a = get_sort_key(idx_a);
-    let key_b = get_sort_key(idx_b);
-
-    // for ascending blocks: we want smaller keys at lower indices
-    // for descending blocks: we want larger keys at lower indices
-    var should_swap = false;
-    if (ascending) {
-        should_swap = key_a > key_b;
-    } else {
-        should_swap = key_a < key_b;
-    }
+    let key_a = quantize_key(get_sort_key(idx_a));
+    let key_b = quantize_key(get_sort_key(idx_b));
 
-    if (should_swap) {
+    // smaller keys at lower indices
+    if (sorts_after(key_a, idx_a, key_b, idx_b)) {
         indices[idx] = idx_b;
         indices[partner] = idx_a;
     }
@@ -138,5 +296,15 @@ fn copy_sorted(@builtin(global_invocation_id) global_id: vec3<u32>) {
 
     // indices[idx] contains the original particle index for sorted position idx
     let particle_index = indices[idx];
-    sorted_particles[idx] = particles[particle_index];
+    var particle = particles[particle_index];
+
+    // move particles along their velocity through frames the simulation skipped
+    let is_active = (bitcast<u32>(particle.custom.w) & PARTICLE_FLAG_ACTIVE) != 0u;
+    if (params.extrapolate_time > 0.0 && is_active) {
+        let position = particle.position.xyz + particle.velocity.xyz * params.extrapolate_time;
+        particle.position = vec4(position, particle.position.w);
+    }
+
+    sorted_particles[idx] = particle;
 }
+#endif
@@ -2,12 +2,14 @@ use bevy::{
     prelude::*,
     render::{
         Render, RenderApp, RenderStartup, RenderSystems,
+        diagnostic::RecordDiagnostics,
         render_asset::RenderAssets,
         render_graph::{self, RenderGraph, RenderLabel},
         render_resource::{
             BindGroupEntries, BindGroupLayoutDescriptor, BindGroupLayoutEntries, Buffer,
-            CachedComputePipelineId, CachedPipelineState, ComputePassDescriptor,
-            ComputePipelineDescriptor, PipelineCache, ShaderStages, ShaderType, UniformBuffer,
+            BufferDescriptor, BufferUsages, CachedComputePipelineId, CachedPipelineState,
+            ComputePassDescriptor, ComputePipelineDescriptor, PipelineCache, ShaderStages,
+            ShaderType, UniformBuffer,
             binding_types::{storage_buffer, uniform_buffer},
         },
         renderer::{RenderContext, RenderDevice, RenderQueue},
@@ -16,12 +18,24 @@ use bevy::{
 };
 use std::borrow::Cow;
 
+use crate::SortAlgorithm;
 use crate::compute::ParticleComputeLabel;
 use crate::extract::ExtractedParticleSystem;
 use crate::runtime::ParticleData;
 
 const SHADER_ASSET_PATH: &str = "embedded://bevy_sprinkles/shaders/particle_sort.wgsl";
 const WORKGROUP_SIZE: u32 = 256;
+/// Digits of the radix sort's keys, 4 bits each, sorted one per pass.
+const RADIX_BINS: u32 = 16;
+const RADIX_PASSES: u32 = 8;
+// the radix sort's digit counts, plus the digits and prefix sum of a block
+const RADIX_WORKGROUP_STORAGE_BYTES: u32 = RADIX_BINS * 4 + WORKGROUP_SIZE * 4 * 2;
+// particles, indices, scratch indices and histograms
+const RADIX_STORAGE_BUFFERS: u32 = 4;
+// `SortParams::stage` of radix passes, which the shader ignores; it picks their bind group
+const RADIX_STAGE: u32 = u32::MAX;
+// recorded by Bevy's `RenderDiagnosticsPlugin`, surfaced through the `profiling` feature
+const SORT_SPAN: &str = "particle_sort";
 
 #[derive(Clone, Copy, Default, ShaderType)]
 pub struct SortParams {
@@ -30,9 +44,9 @@ pub struct SortParams {
     pub stage: u32,
     pub step: u32,
     pub camera_position: Vec3,
-    pub _pad1: f32,
+    pub sort_precision: f32,
     pub camera_forward: Vec3,
-    pub _pad2: f32,
+    pub extrapolate_time: f32,
     pub emitter_transform: Mat4,
 }
 
@@ -45,12 +59,34 @@ pub struct ParticleSortPipeline {
     pub init_pipeline: CachedComputePipelineId,
     pub sort_pipeline: CachedComputePipelineId,
     pub copy_pipeline: CachedComputePipelineId,
+    /// Replaces the bitonic passes of `sort_pipeline` when [`SortAlgorithm::Radix`] is
+    /// picked and supported.
+    pub radix: Option<RadixSortPipelines>,
+}
+
+/// Pipelines of the three steps of each radix sort pass, which also bind the histograms.
+pub struct RadixSortPipelines {
+    pub bind_group_layout: BindGroupLayoutDescriptor,
+    pub histogram_pipeline: CachedComputePipelineId,
+    pub scan_pipeline: CachedComputePipelineId,
+    pub scatter_pipeline: CachedComputePipelineId,
+}
+
+/// Whether the device can run the radix sort's workgroups.
+fn supports_radix_sort(render_device: &RenderDevice) -> bool {
+    let limits = render_device.limits();
+    limits.max_compute_invocations_per_workgroup >= WORKGROUP_SIZE
+        && limits.max_compute_workgroup_size_x >= WORKGROUP_SIZE
+        && limits.max_compute_workgroup_storage_size >= RADIX_WORKGROUP_STORAGE_BYTES
+        && limits.max_storage_buffers_per_shader_stage >= RADIX_STORAGE_BUFFERS
 }
 
 pub fn init_particle_sort_pipeline(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     pipeline_cache: Res<PipelineCache>,
+    render_device: Res<RenderDevice>,
+    algorithm: Res<SortAlgorithm>,
 ) {
     let bind_group_layout = BindGroupLayoutDescriptor::new(
         "ParticleSortBindGroup",
@@ -65,33 +101,96 @@ pub fn init_particle_sort_pipeline(
         ),
     );
 
+    let radix_bind_group_layout = BindGroupLayoutDescriptor::new(
+        "ParticleRadixSortBindGroup",
+        &BindGroupLayoutEntries::sequential(
+            ShaderStages::COMPUTE,
+            (
+                uniform_buffer::<SortParams>(false),
+                storage_buffer::<ParticleData>(false),
+                storage_buffer::<u32>(false),
+                storage_buffer::<u32>(false),
+                storage_buffer::<u32>(false),
+            ),
+        ),
+    );
+
     let shader = asset_server.load(SHADER_ASSET_PATH);
 
-    let queue_pipeline = |label: &'static str, entry: &'static str| {
+    let queue_pipeline = |label: &'static str,
+                          entry: &'static str,
+                          layout: &BindGroupLayoutDescriptor,
+                          shader_defs: Vec<_>| {
         pipeline_cache.queue_compute_pipeline(ComputePipelineDescriptor {
             label: Some(label.into()),
-            layout: vec![bind_group_layout.clone()],
+            layout: vec![layout.clone()],
             shader: shader.clone(),
+            shader_defs,
             entry_point: Some(Cow::from(entry)),
             ..default()
         })
     };
 
-    let init_pipeline = queue_pipeline("particle_sort_init_pipeline", "init_indices");
-    let sort_pipeline = queue_pipeline("particle_sort_pipeline", "sort");
-    let copy_pipeline = queue_pipeline("particle_sort_copy_pipeline", "copy_sorted");
+    let init_pipeline = queue_pipeline(
+        "particle_sort_init_pipeline",
+        "init_indices",
+        &bind_group_layout,
+        vec![],
+    );
+    let sort_pipeline =
+        queue_pipeline("particle_sort_pipeline", "sort", &bind_group_layout, vec![]);
+    let copy_pipeline = queue_pipeline(
+        "particle_sort_copy_pipeline",
+        "copy_sorted",
+        &bind_group_layout,
+        vec![],
+    );
+
+    let radix = match *algorithm {
+        SortAlgorithm::Bitonic => None,
+        SortAlgorithm::Radix if !supports_radix_sort(&render_device) => {
+            warn!("Particle radix sort isn't supported by this device, using bitonic sort");
+            None
+        }
+        SortAlgorithm::Radix => {
+            let queue_radix_pipeline = |label, entry| {
+                queue_pipeline(
+                    label,
+                    entry,
+                    &radix_bind_group_layout,
+                    vec!["RADIX_SORT".into()],
+                )
+            };
+            Some(RadixSortPipelines {
+                histogram_pipeline: queue_radix_pipeline(
+                    "particle_radix_histogram_pipeline",
+                    "radix_histogram",
+                ),
+                scan_pipeline: queue_radix_pipeline("particle_radix_scan_pipeline", "radix_scan"),
+                scatter_pipeline: queue_radix_pipeline(
+                    "particle_radix_scatter_pipeline",
+                    "radix_scatter",
+                ),
+                bind_group_layout: radix_bind_group_layout,
+            })
+        }
+    };
 
     commands.insert_resource(ParticleSortPipeline {
         bind_group_layout,
         init_pipeline,
         sort_pipeline,
         copy_pipeline,
+        radix,
     });
 }
 
 #[derive(Resource, Default)]
 pub struct ParticleSortData {
     pub emitters: Vec<SortEmitterData>,
+    /// Digit counts of the radix sort, shared by every emitter since they're sorted one
+    /// after the other. Sized for the emitter with the most particles.
+    pub histograms: Option<Buffer>,
 }
 
 pub struct SortEmitterData {
@@ -100,6 +199,8 @@ pub struct SortEmitterData {
     pub sorted_particles_buffer: Buffer,
     pub amount: u32,
     pub draw_order: u32,
+    pub sort_precision: f32,
+    pub extrapolate_time: f32,
     pub camera_position: Vec3,
     pub camera_forward: Vec3,
     pub emitter_transform: Mat4,
@@ -109,6 +210,9 @@ pub fn prepare_particle_sort_data(
     mut commands: Commands,
     extracted_systems: Res<ExtractedParticleSystem>,
     gpu_storage_buffers: Res<RenderAssets<GpuShaderStorageBuffer>>,
+    pipeline: Res<ParticleSortPipeline>,
+    render_device: Res<RenderDevice>,
+    mut histograms: Local<Option<Buffer>>,
 ) {
     let mut emitters = Vec::new();
 
@@ -135,13 +239,39 @@ pub fn prepare_particle_sort_data(
             sorted_particles_buffer: sorted_particles_buffer.buffer.clone(),
             amount: emitter_data.amount,
             draw_order: emitter_data.draw_order,
+            sort_precision: emitter_data.sort_precision,
+            extrapolate_time: emitter_data.extrapolate_time,
             camera_position: Vec3::from_array(emitter_data.camera_position),
             camera_forward: Vec3::from_array(emitter_data.camera_forward),
             emitter_transform: emitter_data.emitter_transform,
         });
     }
 
-    commands.insert_resource(ParticleSortData { emitters });
+    if pipeline.radix.is_some() {
+        let blocks = emitters
+            .iter()
+            .filter(|data| data.draw_order != 0)
+            .map(|data| data.amount.div_ceil(WORKGROUP_SIZE))
+            .max()
+            .unwrap_or(0);
+        let size = (RADIX_BINS * blocks.max(1) * 4) as u64;
+        if histograms
+            .as_ref()
+            .is_none_or(|buffer| buffer.size() < size)
+        {
+            *histograms = Some(render_device.create_buffer(&BufferDescriptor {
+                label: Some("particle_radix_histograms"),
+                size,
+                usage: BufferUsages::STORAGE,
+                mapped_at_creation: false,
+            }));
+        }
+    }
+
+    commands.insert_resource(ParticleSortData {
+        emitters,
+        histograms: histograms.clone(),
+    });
 }
 
 pub struct ParticleSortNode {
@@ -168,7 +298,12 @@ impl render_graph::Node for ParticleSortNode {
 
         self.ready = is_ready(pipeline.init_pipeline)
             && is_ready(pipeline.sort_pipeline)
-            && is_ready(pipeline.copy_pipeline);
+            && is_ready(pipeline.copy_pipeline)
+            && pipeline.radix.as_ref().is_none_or(|radix| {
+                is_ready(radix.histogram_pipeline)
+                    && is_ready(radix.scan_pipeline)
+                    && is_ready(radix.scatter_pipeline)
+            });
     }
 
     fn run(
@@ -202,14 +337,33 @@ impl render_graph::Node for ParticleSortNode {
             return Ok(());
         };
 
+        let radix = match (&pipeline.radix, &sort_data.histograms) {
+            (Some(radix), Some(histograms)) => {
+                let (Some(histogram), Some(scan), Some(scatter)) = (
+                    pipeline_cache.get_compute_pipeline(radix.histogram_pipeline),
+                    pipeline_cache.get_compute_pipeline(radix.scan_pipeline),
+                    pipeline_cache.get_compute_pipeline(radix.scatter_pipeline),
+                ) else {
+                    return Ok(());
+                };
+                let layout = pipeline_cache.get_bind_group_layout(&radix.bind_group_layout);
+                Some((histogram, scan, scatter, layout, histograms))
+            }
+            _ => None,
+        };
+
         let bind_group_layout = pipeline_cache.get_bind_group_layout(&pipeline.bind_group_layout);
 
+        let diagnostics = render_context.diagnostic_recorder();
+        let time_span = diagnostics.time_span(render_context.command_encoder(), SORT_SPAN);
+
         for data in &sort_data.emitters {
             let workgroups = (data.amount + WORKGROUP_SIZE - 1) / WORKGROUP_SIZE;
 
             let dispatch = |render_context: &mut RenderContext,
                             pipeline: &bevy::render::render_resource::ComputePipeline,
                             label: &str,
+                            workgroups: u32,
                             stage: u32,
                             step: u32| {
                 let sort_params = SortParams {
@@ -218,25 +372,39 @@ impl render_graph::Node for ParticleSortNode {
                     stage,
                     step,
                     camera_position: data.camera_position,
-                    _pad1: 0.0,
+                    sort_precision: data.sort_precision,
                     camera_forward: data.camera_forward,
-                    _pad2: 0.0,
+                    extrapolate_time: data.extrapolate_time,
                     emitter_transform: data.emitter_transform,
                 };
 
                 let mut uniform_buffer = UniformBuffer::from(sort_params);
                 uniform_buffer.write_buffer(render_device, render_queue);
 
-                let bind_group = render_device.create_bind_group(
-                    Some(label),
-                    &bind_group_layout,
-                    &BindGroupEntries::sequential((
-                        &uniform_buffer,
-                        data.particle_buffer.as_entire_binding(),
-                        data.indices_buffer.as_entire_binding(),
-                        data.sorted_particles_buffer.as_entire_binding(),
-                    )),
-                );
+                let bind_group = match &radix {
+                    Some((.., layout, histograms)) if stage == RADIX_STAGE => render_device
+                        .create_bind_group(
+                            Some(label),
+                            layout,
+                            &BindGroupEntries::sequential((
+                                &uniform_buffer,
+                                data.particle_buffer.as_entire_binding(),
+                                data.indices_buffer.as_entire_binding(),
+                                data.sorted_particles_buffer.as_entire_binding(),
+                                histograms.as_entire_binding(),
+                            )),
+                        ),
+                    _ => render_device.create_bind_group(
+                        Some(label),
+                        &bind_group_layout,
+                        &BindGroupEntries::sequential((
+                            &uniform_buffer,
+                            data.particle_buffer.as_entire_binding(),
+                            data.indices_buffer.as_entire_binding(),
+                            data.sorted_particles_buffer.as_entire_binding(),
+                        )),
+                    ),
+                };
 
                 let mut pass =
                     render_context
@@ -255,23 +423,46 @@ impl render_graph::Node for ParticleSortNode {
                 render_context,
                 init_pipeline,
                 "particle_sort_init_pass",
+                workgroups,
                 0,
                 0,
             );
 
             if data.draw_order != 0 {
-                let n = data.amount.next_power_of_two();
-                let num_stages = (n as f32).log2().ceil() as u32;
-
-                for stage in 0..num_stages {
-                    for step in (0..=stage).rev() {
-                        dispatch(
-                            render_context,
-                            sort_pipeline,
-                            "particle_sort_pass",
-                            stage,
-                            step,
-                        );
+                if let Some((histogram, scan, scatter, ..)) = &radix {
+                    // each pass sorts by the next digit, reusing the histograms
+                    for pass in 0..RADIX_PASSES {
+                        let steps = [
+                            (*histogram, "particle_radix_histogram_pass", workgroups),
+                            (*scan, "particle_radix_scan_pass", 1),
+                            (*scatter, "particle_radix_scatter_pass", workgroups),
+                        ];
+                        for (pipeline, label, workgroups) in steps {
+                            dispatch(
+                                render_context,
+                                pipeline,
+                                label,
+                                workgroups,
+                                RADIX_STAGE,
+                                pass,
+                            );
+                        }
+                    }
+                } else {
+                    let n = data.amount.next_power_of_two();
+                    let num_stages = (n as f32).log2().ceil() as u32;
+
+                    for stage in 0..num_stages {
+                        for step in (0..=stage).rev() {
+                            dispatch(
+                                render_context,
+                                sort_pipeline,
+                                "particle_sort_pass",
+                                workgroups,
+                                stage,
+                                step,
+                            );
+                        }
                     }
                 }
             }
@@ -280,16 +471,21 @@ impl render_graph::Node for ParticleSortNode {
                 render_context,
                 copy_pipeline,
                 "particle_sort_copy_pass",
+                workgroups,
                 0,
                 0,
             );
         }
 
+        time_span.end(render_context.command_encoder());
+
         Ok(())
     }
 }
 
-pub struct ParticleSortPlugin;
+pub struct ParticleSortPlugin {
+    pub algorithm: SortAlgorithm,
+}
 
 impl Plugin for ParticleSortPlugin {
     fn build(&self, app: &mut App) {
@@ -299,6 +495,7 @@ impl Plugin for ParticleSortPlugin {
 
         render_app
             .init_resource::<ParticleSortData>()
+            .insert_resource(self.algorithm)
             .add_systems(RenderStartup, init_particle_sort_pipeline)
             .add_systems(
                 Render,
@@ -1,17 +1,29 @@
 use bevy::{
-    light::NotShadowCaster, pbr::ExtendedMaterial, prelude::*, render::storage::ShaderStorageBuffer,
+    camera::visibility::RenderLayers,
+    light::NotShadowCaster,
+    pbr::ExtendedMaterial,
+    prelude::*,
+    render::{
+        gpu_readback::{Readback, ReadbackComplete},
+        storage::ShaderStorageBuffer,
+    },
 };
+use std::collections::HashMap;
 
 use crate::{
-    asset::{DrawPassMaterial, EmitterData, ParticleSystemAsset},
+    asset::{Burst, DrawPassMaterial, EmitterData, EmitterTime, ParticleSystemAsset},
     material::ParticleMaterialExtension,
     mesh::create_particle_mesh,
     runtime::{
-        ColliderEntity, CurrentMaterialConfig, CurrentMeshConfig, EmitterEntity, EmitterMeshEntity,
-        EmitterRuntime, ParticleBufferHandle, ParticleData, ParticleMaterial,
-        ParticleMaterialHandle, ParticleMeshHandle, ParticleSystem3D, ParticleSystemRuntime,
-        ParticlesCollider3D, SimulationStep, SubEmitterBufferHandle,
+        ColliderEntity, CrossFade, CurrentMaterialConfig, CurrentMeshConfig, DespawnOnFinish,
+        EmitterEntity, EmitterFade, EmitterMeshEntity, EmitterRuntime, EmitterStats,
+        EmitterStatsBuffer, FAR_FROM_ORIGIN_DISTANCE, FarFromOrigin, ParticleBufferGrowth,
+        ParticleBufferHandle, ParticleData, ParticleMaterial, ParticleMaterialHandle,
+        ParticleMeshHandle, ParticleSystem3D, ParticleSystemRuntime, ParticlesCollider3D,
+        ParticlesGlobalControl, PreviousParticleBuffer, SimulationStep, SubEmitterBufferHandle,
+        TriggerEmitter,
     },
+    textures::preset::CustomPresetRegistry,
 };
 
 const MAX_FRAME_DELTA: f32 = 0.1;
@@ -25,12 +37,82 @@ fn get_particle_asset<'a>(
     assets.get(&particle_system.handle)
 }
 
+struct CycleClock {
+    system_time: f32,
+    prev_system_time: f32,
+    cycle: u32,
+    accumulated_delta: f32,
+    simulation_steps: Vec<SimulationStep>,
+}
+
+impl From<&EmitterRuntime> for CycleClock {
+    fn from(runtime: &EmitterRuntime) -> Self {
+        Self {
+            system_time: runtime.system_time,
+            prev_system_time: runtime.prev_system_time,
+            cycle: runtime.cycle,
+            accumulated_delta: runtime.accumulated_delta,
+            simulation_steps: runtime.simulation_steps.clone(),
+        }
+    }
+}
+
+impl CycleClock {
+    fn apply(&self, runtime: &mut EmitterRuntime) {
+        // distance and burst spawns follow the emitter's own clock and movement, not the leader's
+        let scheduled_spawns: u32 = runtime
+            .simulation_steps
+            .iter()
+            .map(|step| step.scheduled_spawns)
+            .sum();
+
+        runtime.system_time = self.system_time;
+        runtime.prev_system_time = self.prev_system_time;
+        runtime.cycle = self.cycle;
+        runtime.accumulated_delta = self.accumulated_delta;
+        runtime.simulation_steps.clone_from(&self.simulation_steps);
+
+        let start = runtime.spawn_cursor.wrapping_sub(scheduled_spawns);
+        for (i, step) in runtime.simulation_steps.iter_mut().enumerate() {
+            step.scheduled_spawns = if i == 0 { scheduled_spawns } else { 0 };
+            step.scheduled_spawn_start = start;
+        }
+    }
+}
+
+/// Clears the simulation steps queued for the previous frame.
+///
+/// [`SprinklesPlugin`](crate::SprinklesPlugin) runs this in [`First`], so that steps
+/// queued by [`update_particle_time`] across several fixed ticks are dispatched together.
+pub fn clear_simulation_steps(mut emitter_query: Query<&mut EmitterRuntime>) {
+    for mut runtime in emitter_query.iter_mut() {
+        runtime.simulation_steps.clear();
+    }
+}
+
+/// Advances each emitter's simulation time and queues the steps to dispatch this frame.
+///
+/// Emitters sharing a [`sync_group`](crate::asset::EmitterData::sync_group) take their
+/// clock from the group's first emitter. [`SprinklesPlugin`](crate::SprinklesPlugin) runs
+/// this in the schedule picked by [`ParticleSystemSchedule`](crate::ParticleSystemSchedule),
+/// where [`Time`] advances by the fixed timestep when it is
+/// [`FixedUpdate`](crate::ParticleSystemSchedule::FixedUpdate).
+///
+/// Nothing advances while [`ParticlesGlobalControl::paused`] is set, except for
+/// pending clear requests. The same goes for one-shot emitters with
+/// [`freeze_on_complete`](crate::asset::EmitterData::freeze_on_complete) once their
+/// cycle completes, whose last step ends exactly at the end of the cycle.
 pub fn update_particle_time(
     time: Res<Time>,
     assets: Res<Assets<ParticleSystemAsset>>,
+    global: Option<Res<ParticlesGlobalControl>>,
     system_query: Query<(&ParticleSystem3D, &ParticleSystemRuntime)>,
     mut emitter_query: Query<(&EmitterEntity, &mut EmitterRuntime)>,
 ) {
+    let globally_paused = global.as_ref().is_some_and(|global| global.paused);
+    let simulation_rate = global.map_or(1.0, |global| global.effective_simulation_rate());
+    let mut group_clocks = HashMap::new();
+
     for (emitter, mut runtime) in emitter_query.iter_mut() {
         let Ok((particle_system, system_runtime)) = system_query.get(emitter.parent_system) else {
             continue;
@@ -44,12 +126,16 @@ pub fn update_particle_time(
             continue;
         };
 
-        runtime.simulation_steps.clear();
+        // steps from earlier fixed ticks this frame are kept until `clear_simulation_steps`
+        let first_step = runtime.simulation_steps.len();
 
         let clear_requested = runtime.clear_requested;
         runtime.clear_requested = false;
 
-        if system_runtime.paused {
+        let freeze_on_complete = emitter_data.freeze_on_complete && emitter_data.time.one_shot;
+        let frozen = freeze_on_complete && runtime.one_shot_completed;
+
+        if globally_paused || system_runtime.paused || runtime.awaiting_trigger || frozen {
             if clear_requested {
                 let step = SimulationStep {
                     prev_system_time: runtime.system_time,
@@ -57,6 +143,8 @@ pub fn update_particle_time(
                     cycle: runtime.cycle,
                     delta_time: 0.0,
                     clear_requested: true,
+                    scheduled_spawns: 0,
+                    scheduled_spawn_start: runtime.spawn_cursor,
                 };
                 runtime.simulation_steps.push(step);
             }
@@ -64,22 +152,28 @@ pub fn update_particle_time(
         }
 
         let fixed_fps = emitter_data.time.fixed_fps;
-        let total_duration = emitter_data.time.total_duration();
+        let total_duration = asset.cycle_duration(runtime.emitter_index);
 
         if fixed_fps > 0 {
-            let fixed_delta = 1.0 / fixed_fps as f32;
+            let fixed_delta = 1.0 / (fixed_fps as f32 * simulation_rate);
             let frame_delta = time.delta_secs().min(MAX_FRAME_DELTA);
             runtime.accumulated_delta += frame_delta;
 
             while runtime.accumulated_delta >= fixed_delta
-                || (clear_requested && runtime.simulation_steps.is_empty())
+                || (clear_requested && runtime.simulation_steps.len() == first_step)
             {
                 runtime.accumulated_delta -= fixed_delta;
 
                 let prev_time = runtime.system_time;
+                let mut delta = fixed_delta;
                 runtime.system_time += fixed_delta;
 
-                if runtime.system_time >= total_duration && total_duration > 0.0 {
+                let completed = runtime.system_time >= total_duration && total_duration > 0.0;
+                // emitters resumed after freezing wrap around like any other
+                if completed && freeze_on_complete && prev_time < total_duration {
+                    freeze_at_cycle_end(&mut runtime, total_duration);
+                    delta = runtime.system_time - prev_time;
+                } else if completed {
                     runtime.system_time = runtime.system_time % total_duration;
                     runtime.cycle += 1;
                 }
@@ -88,47 +182,333 @@ pub fn update_particle_time(
                     prev_system_time: prev_time,
                     system_time: runtime.system_time,
                     cycle: runtime.cycle,
-                    delta_time: fixed_delta,
-                    clear_requested: if runtime.simulation_steps.is_empty() {
+                    delta_time: delta,
+                    clear_requested: if runtime.simulation_steps.len() == first_step {
                         clear_requested
                     } else {
                         false
                     },
+                    scheduled_spawns: 0,
+                    scheduled_spawn_start: runtime.spawn_cursor,
                 };
                 runtime.simulation_steps.push(step);
+
+                // the remaining ticks would run past the frozen end of the cycle
+                if runtime.one_shot_completed && freeze_on_complete {
+                    runtime.accumulated_delta = 0.0;
+                    break;
+                }
             }
 
-            if !runtime.simulation_steps.is_empty() {
-                runtime.prev_system_time = runtime.simulation_steps[0].prev_system_time;
+            if let Some(step) = runtime.simulation_steps.get(first_step) {
+                runtime.prev_system_time = step.prev_system_time;
             }
         } else {
-            let delta = time.delta_secs();
-            let prev_time = runtime.system_time;
-            runtime.prev_system_time = runtime.system_time;
-            runtime.system_time += delta;
-
-            if runtime.system_time >= total_duration && total_duration > 0.0 {
-                runtime.system_time = runtime.system_time % total_duration;
-                runtime.cycle += 1;
-            }
-
-            let step = SimulationStep {
-                prev_system_time: prev_time,
-                system_time: runtime.system_time,
-                cycle: runtime.cycle,
-                delta_time: delta,
-                clear_requested,
-            };
-            runtime.simulation_steps.push(step);
+            // under a reduced simulation rate, skipped frames are simulated by the next step
+            runtime.accumulated_delta += time.delta_secs();
+            runtime.simulation_credit += simulation_rate;
+            if runtime.simulation_credit >= 1.0 - f32::EPSILON || clear_requested {
+                runtime.simulation_credit = (runtime.simulation_credit - 1.0).max(0.0);
+
+                let mut delta = runtime.accumulated_delta;
+                runtime.accumulated_delta = 0.0;
+                let prev_time = runtime.system_time;
+                runtime.prev_system_time = runtime.system_time;
+                runtime.system_time += delta;
+
+                let completed = runtime.system_time >= total_duration && total_duration > 0.0;
+                if completed && freeze_on_complete && prev_time < total_duration {
+                    freeze_at_cycle_end(&mut runtime, total_duration);
+                    delta = runtime.system_time - prev_time;
+                } else if completed {
+                    runtime.system_time = runtime.system_time % total_duration;
+                    runtime.cycle += 1;
+                }
+
+                let step = SimulationStep {
+                    prev_system_time: prev_time,
+                    system_time: runtime.system_time,
+                    cycle: runtime.cycle,
+                    delta_time: delta,
+                    clear_requested,
+                    scheduled_spawns: 0,
+                    scheduled_spawn_start: runtime.spawn_cursor,
+                };
+                runtime.simulation_steps.push(step);
+            }
+        }
+
+        // distance-based emitters spawn everything owed for this frame on its first step
+        if let Some(spacing) = emitter_data.emission.mode.spacing() {
+            if runtime.simulation_steps.len() > first_step {
+                let spawns = (runtime.distance_travelled / spacing).floor();
+                runtime.distance_travelled -= spawns * spacing;
+                let start = runtime.spawn_cursor;
+                runtime.spawn_cursor = start.wrapping_add(spawns as u32);
+
+                let step = &mut runtime.simulation_steps[first_step];
+                step.scheduled_spawns = spawns as u32;
+                step.scheduled_spawn_start = start;
+            }
+        } else {
+            runtime.distance_travelled = 0.0;
+            if let Some(burst) = emitter_data.emission.active_burst() {
+                for i in first_step..runtime.simulation_steps.len() {
+                    let spawns = burst_spawns(
+                        burst,
+                        &emitter_data.time,
+                        runtime.random_seed,
+                        &runtime.simulation_steps[i],
+                        total_duration,
+                    );
+                    let start = runtime.spawn_cursor;
+                    runtime.spawn_cursor = start.wrapping_add(spawns);
+                    let step = &mut runtime.simulation_steps[i];
+                    step.scheduled_spawns = spawns;
+                    step.scheduled_spawn_start = start;
+                }
+            }
+        }
+
+        if emitter_data.sync_group.is_some() {
+            group_clocks.insert(
+                (emitter.parent_system, runtime.emitter_index),
+                CycleClock::from(&*runtime),
+            );
         }
+    }
+
+    // emitters in a sync group follow the clock of the group's first emitter
+    for (emitter, mut runtime) in emitter_query.iter_mut() {
+        let Ok((particle_system, _)) = system_query.get(emitter.parent_system) else {
+            continue;
+        };
+        let Some(asset) = assets.get(&particle_system.handle) else {
+            continue;
+        };
+        let Some(emitter_data) = asset.emitters.get(runtime.emitter_index) else {
+            continue;
+        };
 
-        if emitter_data.time.one_shot && runtime.cycle > 0 && !runtime.one_shot_completed {
+        if runtime.awaiting_trigger {
+            continue;
+        }
+
+        if let Some(clock) = asset
+            .sync_leader(runtime.emitter_index)
+            .and_then(|leader| group_clocks.get(&(emitter.parent_system, leader)))
+        {
+            let clear_requested = runtime.simulation_steps.iter().any(|s| s.clear_requested);
+            clock.apply(&mut runtime);
+            if let Some(first) = runtime.simulation_steps.first_mut() {
+                first.clear_requested |= clear_requested;
+            }
+        }
+
+        // freezing emitters complete at the end of their cycle instead, so resuming one
+        // plays another cycle
+        if emitter_data.time.one_shot
+            && !emitter_data.freeze_on_complete
+            && runtime.cycle > 0
+            && !runtime.one_shot_completed
+        {
             runtime.emitting = false;
             runtime.one_shot_completed = true;
         }
     }
 }
 
+/// Returns how many particles the bursts going off during `step` spawn, including those
+/// at the start of the next cycle when the step wraps around into it.
+fn burst_spawns(
+    burst: &Burst,
+    time: &EmitterTime,
+    seed: u32,
+    step: &SimulationStep,
+    total_duration: f32,
+) -> u32 {
+    let delay = time.cycle_delay(seed, step.cycle);
+    if step.system_time >= step.prev_system_time {
+        return burst.spawns_between(step.prev_system_time - delay, step.system_time - delay);
+    }
+    let prev_delay = time.cycle_delay(seed, step.cycle.wrapping_sub(1));
+    burst
+        .spawns_between(
+            step.prev_system_time - prev_delay,
+            total_duration - prev_delay,
+        )
+        .saturating_add(burst.spawns_between(-delay, step.system_time - delay))
+}
+
+/// Ends a freezing one-shot emitter's cycle without wrapping its clock, so the
+/// particles it spawned keep their last simulated state.
+fn freeze_at_cycle_end(runtime: &mut EmitterRuntime, total_duration: f32) {
+    runtime.system_time = total_duration;
+    runtime.emitting = false;
+    runtime.one_shot_completed = true;
+}
+
+/// Starts the emitter targeted by a [`TriggerEmitter`] event.
+///
+/// [`SprinklesPlugin`](crate::SprinklesPlugin) registers this as an observer.
+pub fn trigger_emitter(
+    trigger: On<TriggerEmitter>,
+    mut emitter_query: Query<(&EmitterEntity, &mut EmitterRuntime)>,
+) {
+    for (emitter, mut runtime) in emitter_query.iter_mut() {
+        if emitter.parent_system == trigger.system && runtime.emitter_index == trigger.emitter_index
+        {
+            runtime.play();
+        }
+    }
+}
+
+/// Despawns particle systems marked with [`DespawnOnFinish`] once every one-shot emitter
+/// has completed and their particles, including those of sub-emitters, have all died.
+///
+/// Live particles are counted from the [`EmitterStats`] read back for each emitter. If
+/// any emitter of the system has none, the system is despawned once the longest chain of
+/// sub-emitter lifetimes has passed since completion instead.
+///
+/// [`SprinklesPlugin`](crate::SprinklesPlugin) runs this right after
+/// [`update_particle_time`], in the same schedule.
+pub fn despawn_finished_particle_systems(
+    mut commands: Commands,
+    time: Res<Time>,
+    global: Option<Res<ParticlesGlobalControl>>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    mut system_query: Query<(
+        Entity,
+        &ParticleSystem3D,
+        &ParticleSystemRuntime,
+        &mut DespawnOnFinish,
+    )>,
+    emitter_query: Query<(&EmitterEntity, &EmitterRuntime, Option<&EmitterStats>)>,
+) {
+    if global.is_some_and(|global| global.paused) {
+        return;
+    }
+
+    for (system_entity, particle_system, system_runtime, mut despawn) in system_query.iter_mut() {
+        let Some(asset) = assets.get(&particle_system.handle) else {
+            continue;
+        };
+
+        let sub_target_indices: Vec<usize> = asset
+            .emitters
+            .iter()
+            .filter_map(|e| e.sub_emitter.as_ref().map(|s| s.target_emitter))
+            .collect();
+
+        let source_emitters = || {
+            asset
+                .emitters
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| !sub_target_indices.contains(idx))
+        };
+
+        if let Some((_, looping)) = source_emitters().find(|(_, e)| !e.time.one_shot) {
+            warn!(
+                "Particle system \"{}\" will not be despawned automatically because emitter \"{}\" is not one-shot",
+                asset.name, looping.name
+            );
+            commands.entity(system_entity).remove::<DespawnOnFinish>();
+            continue;
+        }
+
+        let all_completed = source_emitters().all(|(idx, _)| {
+            emitter_query.iter().any(|(emitter, runtime, _)| {
+                emitter.parent_system == system_entity
+                    && runtime.emitter_index == idx
+                    && runtime.one_shot_completed
+            })
+        });
+
+        if !all_completed || system_runtime.paused {
+            continue;
+        }
+
+        let stats: Option<Vec<&EmitterStats>> = emitter_query
+            .iter()
+            .filter(|(emitter, ..)| emitter.parent_system == system_entity)
+            .map(|(_, _, stats)| stats)
+            .collect();
+
+        let finished = match stats {
+            Some(stats) => despawn.tick_idle(stats.iter().all(|stats| stats.alive_particles == 0)),
+            None => {
+                // particles spawned at the very end of the cycle live for up to one more
+                // lifetime, and so on down each chain of sub-emitters they feed
+                let drain_time = source_emitters()
+                    .map(|(idx, _)| asset.sub_emitter_chain_lifetime(idx))
+                    .fold(0.0, f32::max);
+                despawn.tick(time.delta_secs(), drain_time)
+            }
+        };
+
+        if finished {
+            commands.entity(system_entity).despawn();
+        }
+    }
+}
+
+/// Gives the emitters of particle systems with [`DespawnOnFinish`] an
+/// [`EmitterStatsBuffer`] and reads it back, and takes it away from the others.
+///
+/// Only emitters with a stats buffer count their live particles, so systems that are
+/// never despawned automatically don't pay for the count on the GPU.
+pub fn sync_emitter_stats_readbacks(
+    mut commands: Commands,
+    mut buffers: ResMut<Assets<ShaderStorageBuffer>>,
+    despawning: Query<(), With<DespawnOnFinish>>,
+    emitters: Query<(Entity, &EmitterEntity, Has<EmitterStatsBuffer>)>,
+) {
+    for (entity, emitter, is_tracked) in &emitters {
+        let wanted = despawning.contains(emitter.parent_system);
+        if wanted && !is_tracked {
+            let stats_buffer = create_stats_buffer(&mut buffers);
+            let readback = Readback::buffer(stats_buffer.buffer.clone());
+            commands.entity(entity).insert((stats_buffer, readback));
+        } else if !wanted && is_tracked {
+            commands
+                .entity(entity)
+                .remove::<(EmitterStatsBuffer, Readback, EmitterStats)>();
+        }
+    }
+}
+
+/// Stores the [`EmitterStats`] read back for an emitter.
+///
+/// [`SprinklesPlugin`](crate::SprinklesPlugin) registers this as an observer.
+pub fn read_emitter_stats(
+    readback: On<ReadbackComplete>,
+    mut commands: Commands,
+    mut emitters: Query<Option<&mut EmitterStats>, With<EmitterStatsBuffer>>,
+) {
+    let Ok(current) = emitters.get_mut(readback.entity) else {
+        return;
+    };
+    let Some(stats) = EmitterStats::from_bytes(&readback.data) else {
+        return;
+    };
+    match current {
+        Some(mut current) => *current = stats,
+        None => {
+            commands.entity(readback.entity).insert(stats);
+        }
+    }
+}
+
+fn create_stats_buffer(buffers: &mut Assets<ShaderStorageBuffer>) -> EmitterStatsBuffer {
+    let mut buffer = ShaderStorageBuffer::from(vec![0u32; 1]);
+    buffer.buffer_description.usage |= bevy::render::render_resource::BufferUsages::COPY_SRC
+        | bevy::render::render_resource::BufferUsages::COPY_DST;
+    EmitterStatsBuffer {
+        buffer: buffers.add(buffer),
+    }
+}
+
 fn combined_particle_flags(emitter: &EmitterData) -> u32 {
     use crate::asset::TransformAlign;
     let mut flags = emitter.particle_flags.bits();
@@ -138,23 +518,33 @@ fn combined_particle_flags(emitter: &EmitterData) -> u32 {
         Some(TransformAlign::YToVelocity) => 2,
         Some(TransformAlign::BillboardYToVelocity) => 3,
         Some(TransformAlign::BillboardFixedY) => 4,
+        Some(TransformAlign::BillboardScreenAligned) => 5,
     };
     flags |= transform_align_bits << 3;
     flags
 }
 
 fn create_particle_material_from_config(
-    config: &DrawPassMaterial,
+    emitter: &EmitterData,
     sorted_particles_buffer: Handle<ShaderStorageBuffer>,
     max_particles: u32,
-    particle_flags: u32,
     asset_server: &AssetServer,
+    custom_presets: &CustomPresetRegistry,
 ) -> ParticleMaterial {
-    let base = match config {
-        DrawPassMaterial::Standard(mat) => mat.to_standard_material(asset_server),
-        DrawPassMaterial::CustomShader { .. } => {
-            todo!("custom shader support not yet implemented")
-        }
+    let (base, vertex_shader, fragment_shader) = match &emitter.draw_pass.material {
+        DrawPassMaterial::Standard(mat) => (
+            mat.to_standard_material_with_presets(asset_server, custom_presets),
+            None,
+            None,
+        ),
+        DrawPassMaterial::CustomShader {
+            vertex_shader,
+            fragment_shader,
+        } => (
+            StandardMaterial::default(),
+            vertex_shader.as_ref().map(|path| asset_server.load(path)),
+            fragment_shader.as_ref().map(|path| asset_server.load(path)),
+        ),
     };
 
     ExtendedMaterial {
@@ -162,86 +552,273 @@ fn create_particle_material_from_config(
         extension: ParticleMaterialExtension {
             sorted_particles: sorted_particles_buffer,
             max_particles,
-            particle_flags,
+            particle_flags: combined_particle_flags(emitter),
+            billboard_axis: emitter.draw_pass.billboard_axis.to_uniform(),
+            vertex_shader,
+            fragment_shader,
         },
     }
 }
 
+fn create_particle_buffers(
+    amount: u32,
+    buffers: &mut Assets<ShaderStorageBuffer>,
+) -> ParticleBufferHandle {
+    let particles: Vec<ParticleData> = (0..amount).map(|_| ParticleData::default()).collect();
+    let indices: Vec<u32> = (0..amount).collect();
+
+    let mut particle_buffer = ShaderStorageBuffer::from(particles.clone());
+    #[allow(unused_mut)]
+    let mut indices_buffer = ShaderStorageBuffer::from(indices);
+    // grown buffers are filled from the ones they replace
+    particle_buffer.buffer_description.usage |=
+        bevy::render::render_resource::BufferUsages::COPY_SRC
+            | bevy::render::render_resource::BufferUsages::COPY_DST;
+    #[cfg(feature = "readback")]
+    {
+        indices_buffer.buffer_description.usage |=
+            bevy::render::render_resource::BufferUsages::COPY_SRC;
+    }
+
+    ParticleBufferHandle {
+        particle_buffer: buffers.add(particle_buffer),
+        indices_buffer: buffers.add(indices_buffer),
+        sorted_particles_buffer: buffers.add(ShaderStorageBuffer::from(particles)),
+        max_particles: amount,
+    }
+}
+
+fn create_sub_emitter_buffer(
+    target_amount: u32,
+    buffers: &mut Assets<ShaderStorageBuffer>,
+) -> Handle<ShaderStorageBuffer> {
+    let buffer_len = 4 + 12 * target_amount as usize;
+    let mut initial_data = vec![0u32; buffer_len];
+    initial_data[1] = target_amount;
+    let mut buffer = ShaderStorageBuffer::from(initial_data);
+    buffer.buffer_description.usage |= bevy::render::render_resource::BufferUsages::COPY_DST;
+    buffers.add(buffer)
+}
+
+/// Scales `amounts` down by the same proportion so their buffers fit in what's left of
+/// [`ParticleBufferGrowth::max_buffer_bytes`] after `allocated_bytes`, warning if they
+/// had to be reduced. Emitters keep room for at least one particle, so an exhausted
+/// budget is exceeded by a few particles rather than leaving empty buffers.
+fn fit_buffer_budget(
+    amounts: &mut [u32],
+    allocated_bytes: u64,
+    growth: &ParticleBufferGrowth,
+    system_name: &str,
+) {
+    let Some(max_buffer_bytes) = growth.max_buffer_bytes else {
+        return;
+    };
+
+    let requested_bytes: u64 = amounts
+        .iter()
+        .map(|&amount| amount as u64 * ParticleBufferHandle::BYTES_PER_PARTICLE)
+        .sum();
+    let available_bytes = max_buffer_bytes.saturating_sub(allocated_bytes);
+    if requested_bytes <= available_bytes {
+        return;
+    }
+
+    for amount in amounts.iter_mut() {
+        let scaled = (*amount as u64 * available_bytes / requested_bytes) as u32;
+        *amount = scaled.max(1).min(*amount);
+    }
+    warn!(
+        "particle system \"{}\" needs {} bytes of particle buffers but only {} of the {} byte budget are left, reducing its emitter capacities",
+        system_name, requested_bytes, available_bytes, max_buffer_bytes
+    );
+}
+
+/// Spawns the emitter and collider entities of newly added particle systems.
+///
+/// When the [`handle`](ParticleSystem3D::handle) of a system spawned here changes, its
+/// entities are replaced once the new asset has loaded. With a [`CrossFade`], the old
+/// emitters are moved to a temporary particle system that fades out and despawns
+/// itself, while the new emitters fade in.
+///
+/// The particle meshes are drawn on the system's [`RenderLayers`], if it has any.
+///
+/// Emitter buffers are sized to their particle amount, capped by [`ParticleBufferGrowth`].
 pub fn setup_particle_systems(
     mut commands: Commands,
-    query: Query<(Entity, &ParticleSystem3D), Without<ParticleSystemRuntime>>,
+    query: Query<(
+        Entity,
+        &ParticleSystem3D,
+        Option<&ParticleSystemRuntime>,
+        Option<&CrossFade>,
+        Option<&RenderLayers>,
+        Option<&Transform>,
+    )>,
+    mut emitter_query: Query<(Entity, &mut EmitterEntity)>,
+    collider_query: Query<(Entity, &ColliderEntity)>,
+    buffer_query: Query<&ParticleBufferHandle>,
+    mut spawned_handles: Local<HashMap<Entity, Handle<ParticleSystemAsset>>>,
     assets: Res<Assets<ParticleSystemAsset>>,
     asset_server: Res<AssetServer>,
+    custom_presets: Res<CustomPresetRegistry>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut buffers: ResMut<Assets<ShaderStorageBuffer>>,
     mut materials: ResMut<Assets<ParticleMaterial>>,
+    growth: Res<ParticleBufferGrowth>,
+    global: Option<Res<ParticlesGlobalControl>>,
 ) {
-    for (system_entity, particle_system) in query.iter() {
+    let shadows_enabled = global.is_none_or(|global| global.shadows_enabled);
+    spawned_handles.retain(|entity, _| query.contains(*entity));
+    let mut allocated_bytes: u64 = buffer_query.iter().map(|b| b.size_in_bytes()).sum();
+
+    for (system_entity, particle_system, system_runtime, cross_fade, render_layers, transform) in
+        query.iter()
+    {
         let Some(asset) = assets.get(&particle_system.handle) else {
             continue;
         };
 
-        if asset.emitters.is_empty() {
-            continue;
-        }
-
-        commands
-            .entity(system_entity)
-            .insert(ParticleSystemRuntime::default())
-            .insert_if_new((Transform::default(), Visibility::default()));
+        let mut fade_in = None;
+        if let Some(system_runtime) = system_runtime {
+            // systems that weren't spawned here, or still use the same asset, are left alone
+            let Some(previous) = spawned_handles.get(&system_entity) else {
+                continue;
+            };
+            if previous.id() == particle_system.handle.id() {
+                continue;
+            }
+            let previous = previous.clone();
+
+            let old_emitters: Vec<Entity> = emitter_query
+                .iter()
+                .filter(|(_, emitter)| emitter.parent_system == system_entity)
+                .map(|(entity, _)| entity)
+                .collect();
+            let duration = cross_fade
+                .map(|fade| fade.duration)
+                .filter(|&duration| duration > 0.0);
+
+            match duration {
+                Some(duration) if !old_emitters.is_empty() => {
+                    let fading_system = commands
+                        .spawn((
+                            ParticleSystem3D::new(previous),
+                            ParticleSystemRuntime {
+                                paused: system_runtime.paused,
+                                force_loop: system_runtime.force_loop,
+                                global_seed: system_runtime.global_seed,
+                                particle_budget: system_runtime.particle_budget,
+                            },
+                            Transform::default(),
+                            Visibility::default(),
+                        ))
+                        .id();
+                    commands.entity(system_entity).add_child(fading_system);
+                    for &entity in &old_emitters {
+                        if let Ok((_, mut emitter)) = emitter_query.get_mut(entity) {
+                            emitter.parent_system = fading_system;
+                        }
+                        commands
+                            .entity(entity)
+                            .insert(EmitterFade::fade_out(duration));
+                    }
+                    commands.entity(fading_system).add_children(&old_emitters);
+                    fade_in = Some(duration);
+                }
+                _ => {
+                    for entity in old_emitters {
+                        if let Ok(buffer_handle) = buffer_query.get(entity) {
+                            allocated_bytes =
+                                allocated_bytes.saturating_sub(buffer_handle.size_in_bytes());
+                        }
+                        commands.entity(entity).despawn();
+                    }
+                }
+            }
 
-        let mut emitter_entities: Vec<Entity> = Vec::new();
+            for (entity, collider) in collider_query.iter() {
+                if collider.parent_system == system_entity {
+                    commands.entity(entity).despawn();
+                }
+            }
+        } else {
+            if asset.emitters.is_empty() {
+                continue;
+            }
 
-        for (emitter_index, emitter) in asset.emitters.iter().enumerate() {
-            let amount = emitter.emission.particles_amount;
+            let system_runtime = ParticleSystemRuntime::default();
+            if particle_system.spawn_rotation_randomness.is_some() {
+                let mut transform = transform.copied().unwrap_or_default();
+                transform.rotate_local(particle_system.spawn_rotation(system_runtime.global_seed));
+                commands.entity(system_entity).insert(transform);
+            }
+            commands
+                .entity(system_entity)
+                .insert(system_runtime)
+                .insert_if_new((Transform::default(), Visibility::default()));
+        }
 
-            let particles: Vec<ParticleData> =
-                (0..amount).map(|_| ParticleData::default()).collect();
+        spawned_handles.insert(system_entity, particle_system.handle.clone());
 
-            let particle_buffer_handle = buffers.add(ShaderStorageBuffer::from(particles.clone()));
+        let mut emitter_entities: Vec<Entity> = Vec::new();
 
-            let indices: Vec<u32> = (0..amount).collect();
-            let indices_buffer_handle = buffers.add(ShaderStorageBuffer::from(indices));
+        let mut amounts: Vec<u32> = asset
+            .emitters
+            .iter()
+            .map(|emitter| emitter.emission.particles_amount.min(growth.max_particles))
+            .collect();
+        fit_buffer_budget(&mut amounts, allocated_bytes, &growth, &asset.name);
 
-            let sorted_particles_buffer_handle = buffers.add(ShaderStorageBuffer::from(particles));
+        for (emitter_index, emitter) in asset.emitters.iter().enumerate() {
+            let amount = amounts[emitter_index];
+            let buffer_handle = create_particle_buffers(amount, &mut buffers);
+            allocated_bytes += buffer_handle.size_in_bytes();
 
             let current_mesh = emitter.draw_pass.mesh.clone();
             let current_material = emitter.draw_pass.material.clone();
-            let shadow_caster = emitter.draw_pass.shadow_caster;
+            let shadow_caster = emitter.draw_pass.shadow_caster && shadows_enabled;
 
             let particle_mesh_handle = create_particle_mesh(&current_mesh, amount, &mut meshes);
 
             let material_handle = materials.add(create_particle_material_from_config(
-                &current_material,
-                sorted_particles_buffer_handle.clone(),
+                emitter,
+                buffer_handle.sorted_particles_buffer.clone(),
                 amount,
-                combined_particle_flags(emitter),
                 &asset_server,
+                &custom_presets,
             ));
 
+            let mut runtime = EmitterRuntime::new(emitter_index, emitter.time.fixed_seed);
+            if emitter.time.wait_for_trigger {
+                runtime.emitting = false;
+                runtime.awaiting_trigger = true;
+            }
+            if fade_in.is_some() {
+                runtime.fade = 0.0;
+            }
+
             let emitter_entity = commands
                 .spawn((
                     EmitterEntity {
                         parent_system: system_entity,
                     },
-                    EmitterRuntime::new(emitter_index, emitter.time.fixed_seed),
-                    ParticleBufferHandle {
-                        particle_buffer: particle_buffer_handle.clone(),
-                        indices_buffer: indices_buffer_handle.clone(),
-                        sorted_particles_buffer: sorted_particles_buffer_handle.clone(),
-                        max_particles: amount,
-                    },
+                    runtime,
+                    buffer_handle,
                     CurrentMeshConfig(current_mesh),
                     CurrentMaterialConfig(current_material),
                     ParticleMeshHandle(particle_mesh_handle.clone()),
                     ParticleMaterialHandle(material_handle.clone()),
-                    Transform::from_translation(emitter.position),
+                    Transform::from_translation(emitter.position * asset.mirror_scale()),
                     Visibility::default(),
                 ))
                 .id();
 
             emitter_entities.push(emitter_entity);
             commands.entity(system_entity).add_child(emitter_entity);
+            if let Some(duration) = fade_in {
+                commands
+                    .entity(emitter_entity)
+                    .insert(EmitterFade::fade_in(duration));
+            }
 
             let mut mesh_entity = commands.spawn((
                 Mesh3d(particle_mesh_handle),
@@ -254,6 +831,9 @@ pub fn setup_particle_systems(
             if !shadow_caster {
                 mesh_entity.insert(NotShadowCaster);
             }
+            if let Some(render_layers) = render_layers {
+                mesh_entity.insert(render_layers.clone());
+            }
         }
 
         for (emitter_index, emitter) in asset.emitters.iter().enumerate() {
@@ -263,15 +843,8 @@ pub fn setup_particle_systems(
                     continue;
                 }
 
-                let target_amount = asset.emitters[target_index].emission.particles_amount;
-                let buffer_len = 4 + 12 * target_amount as usize;
-                let mut initial_data = vec![0u32; buffer_len];
-                initial_data[1] = target_amount;
-                let mut buffer = ShaderStorageBuffer::from(initial_data);
-                buffer.buffer_description.usage |=
-                    bevy::render::render_resource::BufferUsages::COPY_DST;
-
-                let buffer_handle = buffers.add(buffer);
+                let target_amount = amounts[target_index];
+                let buffer_handle = create_sub_emitter_buffer(target_amount, &mut buffers);
                 let target_entity = emitter_entities[target_index];
                 let parent_entity = emitter_entities[emitter_index];
 
@@ -296,7 +869,7 @@ pub fn setup_particle_systems(
                         enabled: collider_data.enabled,
                         shape: collider_data.shape.clone(),
                     },
-                    Transform::from_translation(collider_data.position),
+                    Transform::from_translation(collider_data.position * asset.mirror_scale()),
                     Name::new(collider_data.name.clone()),
                 ))
                 .id();
@@ -306,25 +879,140 @@ pub fn setup_particle_systems(
     }
 }
 
+/// Advances every [`EmitterFade`] and applies it to [`EmitterRuntime::fade`].
+///
+/// Finished fade-ins are removed. When a fade-out finishes, the particle system holding
+/// the emitter is despawned along with all of its emitters. Fades hold while
+/// [`ParticlesGlobalControl::paused`] is set.
+pub fn update_emitter_fades(
+    mut commands: Commands,
+    time: Res<Time>,
+    global: Option<Res<ParticlesGlobalControl>>,
+    mut query: Query<(
+        Entity,
+        &EmitterEntity,
+        &mut EmitterRuntime,
+        &mut EmitterFade,
+    )>,
+) {
+    if global.is_some_and(|global| global.paused) {
+        return;
+    }
+
+    for (entity, emitter, mut runtime, mut fade) in query.iter_mut() {
+        fade.elapsed += time.delta_secs();
+        runtime.fade = fade.opacity();
+
+        if !fade.is_finished() {
+            continue;
+        }
+        if fade.fade_out {
+            commands.entity(emitter.parent_system).try_despawn();
+        } else {
+            commands.entity(entity).remove::<EmitterFade>();
+        }
+    }
+}
+
+/// Measures [`EmitterRuntime::velocity`] from how far each emitter entity moved since
+/// the previous frame.
+///
+/// The velocity is left untouched on frames where no time passed, so a paused app
+/// doesn't reset what particles inherit.
+pub fn update_emitter_velocities(
+    time: Res<Time>,
+    mut query: Query<(&GlobalTransform, &mut EmitterRuntime)>,
+) {
+    let delta = time.delta_secs();
+    for (transform, mut runtime) in query.iter_mut() {
+        let position = transform.translation();
+        if let Some(prev_position) = runtime.prev_position {
+            if delta > 0.0 {
+                runtime.velocity = (position - prev_position) / delta;
+            }
+            runtime.distance_travelled += position.distance(prev_position);
+        }
+        runtime.prev_position = Some(position);
+    }
+}
+
+/// Adds or removes [`NotShadowCaster`] on every particle mesh when
+/// [`ParticlesGlobalControl::shadows_enabled`] changes.
+///
+/// Meshes cast shadows only if shadows are enabled globally and their emitter's
+/// [`shadow_caster`](crate::asset::EmitterDrawPass::shadow_caster) is set.
+pub fn sync_particle_shadows(
+    mut commands: Commands,
+    global: Option<Res<ParticlesGlobalControl>>,
+    particle_systems: Query<&ParticleSystem3D>,
+    emitter_query: Query<(&EmitterEntity, &EmitterRuntime)>,
+    mesh_query: Query<(Entity, &EmitterMeshEntity)>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+) {
+    let Some(global) = global.filter(|global| global.is_changed()) else {
+        return;
+    };
+
+    for (mesh_entity, emitter_mesh) in mesh_query.iter() {
+        let Ok((emitter, runtime)) = emitter_query.get(emitter_mesh.emitter_entity) else {
+            continue;
+        };
+        let Some(emitter_data) =
+            get_particle_asset(emitter.parent_system, &particle_systems, &assets)
+                .and_then(|asset| asset.emitters.get(runtime.emitter_index))
+        else {
+            continue;
+        };
+
+        if global.shadows_enabled && emitter_data.draw_pass.shadow_caster {
+            commands.entity(mesh_entity).remove::<NotShadowCaster>();
+        } else {
+            commands.entity(mesh_entity).insert(NotShadowCaster);
+        }
+    }
+}
+
 const EMITTER_DEPTH_OFFSET: f32 = 0.0001;
 
+/// Moves each particle mesh to its emitter, pushed away from the camera by a tiny
+/// amount so transparent emitters are drawn in
+/// [`emitter_draw_order`](ParticleSystemAsset::emitter_draw_order). Offscreen cameras
+/// drawn before the main one, like thumbnail cameras, are ignored.
 pub fn sync_emitter_mesh_transforms(
-    camera_query: Query<&GlobalTransform, With<Camera3d>>,
-    emitter_query: Query<(&GlobalTransform, &EmitterRuntime), With<EmitterEntity>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    particle_systems: Query<&ParticleSystem3D>,
+    emitter_query: Query<(&GlobalTransform, &EmitterEntity, &EmitterRuntime)>,
     mut mesh_query: Query<(&EmitterMeshEntity, &mut Transform)>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    mut draw_orders: Local<HashMap<Entity, Vec<usize>>>,
 ) {
     let camera_forward = camera_query
         .iter()
-        .next()
-        .map(|t| t.forward().as_vec3())
+        .max_by_key(|(camera, _)| camera.order)
+        .map(|(_, t)| t.forward().as_vec3())
         .unwrap_or(Vec3::NEG_Z);
 
+    draw_orders.clear();
     for (emitter_mesh, mut mesh_transform) in mesh_query.iter_mut() {
-        if let Ok((emitter_global, runtime)) = emitter_query.get(emitter_mesh.emitter_entity) {
-            let depth_offset =
-                camera_forward * (runtime.emitter_index as f32 * EMITTER_DEPTH_OFFSET);
-            mesh_transform.translation = emitter_global.translation() + depth_offset;
-        }
+        let Ok((emitter_global, emitter, runtime)) = emitter_query.get(emitter_mesh.emitter_entity)
+        else {
+            continue;
+        };
+        let draw_order = draw_orders.entry(emitter.parent_system).or_insert_with(|| {
+            get_particle_asset(emitter.parent_system, &particle_systems, &assets)
+                .map(ParticleSystemAsset::emitter_draw_order)
+                .unwrap_or_default()
+        });
+
+        // transparent meshes are drawn back to front, so the first ones drawn sit furthest
+        let depth_rank = draw_order
+            .iter()
+            .position(|&index| index == runtime.emitter_index)
+            .map_or(runtime.emitter_index, |position| {
+                draw_order.len() - 1 - position
+            });
+        let depth_offset = camera_forward * (depth_rank as f32 * EMITTER_DEPTH_OFFSET);
+        mesh_transform.translation = emitter_global.translation() + depth_offset;
     }
 }
 
@@ -380,16 +1068,17 @@ pub fn sync_collider_data(
     }
 
     for (collider, mut collider3d, mut transform) in collider_query.iter_mut() {
-        let Some(collider_data) =
-            get_particle_asset(collider.parent_system, &particle_systems, &assets)
-                .and_then(|asset| asset.colliders.get(collider.collider_index))
+        let Some(asset) = get_particle_asset(collider.parent_system, &particle_systems, &assets)
         else {
             continue;
         };
+        let Some(collider_data) = asset.colliders.get(collider.collider_index) else {
+            continue;
+        };
 
         collider3d.enabled = collider_data.enabled;
         collider3d.shape = collider_data.shape.clone();
-        *transform = Transform::from_translation(collider_data.position);
+        *transform = Transform::from_translation(collider_data.position * asset.mirror_scale());
     }
 }
 
@@ -403,14 +1092,15 @@ pub fn sync_emitter_transform(
     }
 
     for (emitter, runtime, mut transform) in emitter_query.iter_mut() {
-        let Some(emitter_data) =
-            get_particle_asset(emitter.parent_system, &particle_systems, &assets)
-                .and_then(|asset| asset.emitters.get(runtime.emitter_index))
+        let Some(asset) = get_particle_asset(emitter.parent_system, &particle_systems, &assets)
         else {
             continue;
         };
+        let Some(emitter_data) = asset.emitters.get(runtime.emitter_index) else {
+            continue;
+        };
 
-        *transform = Transform::from_translation(emitter_data.position);
+        *transform = Transform::from_translation(emitter_data.position * asset.mirror_scale());
     }
 }
 
@@ -469,6 +1159,7 @@ pub fn sync_particle_material(
     mut mesh_entities: Query<(&EmitterMeshEntity, &mut MeshMaterial3d<ParticleMaterial>)>,
     assets: Res<Assets<ParticleSystemAsset>>,
     asset_server: Res<AssetServer>,
+    custom_presets: Res<CustomPresetRegistry>,
     mut materials: ResMut<Assets<ParticleMaterial>>,
 ) {
     for (
@@ -498,11 +1189,11 @@ pub fn sync_particle_material(
             };
 
             let new_material_handle = materials.add(create_particle_material_from_config(
-                &new_material,
+                emitter_data,
                 sorted_particles_handle,
                 buffer_handle.max_particles,
-                combined_particle_flags(emitter_data),
                 &asset_server,
+                &custom_presets,
             ));
 
             for (emitter_mesh, mut material3d) in mesh_entities.iter_mut() {
@@ -515,11 +1206,243 @@ pub fn sync_particle_material(
             material_handle.0 = new_material_handle;
         } else {
             let new_flags = combined_particle_flags(emitter_data);
+            let new_axis = emitter_data.draw_pass.billboard_axis.to_uniform();
             if let Some(material) = materials.get_mut(&material_handle.0) {
-                if material.extension.particle_flags != new_flags {
+                if material.extension.particle_flags != new_flags
+                    || material.extension.billboard_axis != new_axis
+                {
                     material.extension.particle_flags = new_flags;
+                    material.extension.billboard_axis = new_axis;
+                }
+            }
+        }
+    }
+}
+
+/// Reallocates an emitter's particle buffers when its
+/// [`particles_amount`](crate::asset::EmitterEmission::particles_amount) outgrows them.
+///
+/// The new buffers are sized to the requested amount, capped at
+/// [`ParticleBufferGrowth::max_particles`] and at what's left of
+/// [`ParticleBufferGrowth::max_buffer_bytes`]. The old particle buffer is kept as a
+/// [`PreviousParticleBuffer`] for one frame so the render world can copy the particles
+/// alive at the time of the resize into the new one. The mesh instance count, material
+/// and any sub-emitter buffers targeting the emitter are updated to match.
+pub fn grow_particle_buffers(
+    mut commands: Commands,
+    particle_systems: Query<&ParticleSystem3D>,
+    mut emitter_query: Query<(
+        Entity,
+        &EmitterEntity,
+        &EmitterRuntime,
+        &mut ParticleBufferHandle,
+        &CurrentMeshConfig,
+        &mut ParticleMeshHandle,
+        &ParticleMaterialHandle,
+    )>,
+    mut sub_emitter_buffers: Query<&mut SubEmitterBufferHandle>,
+    mut mesh_entities: Query<(&EmitterMeshEntity, &mut Mesh3d)>,
+    copied_buffers: Query<Entity, With<PreviousParticleBuffer>>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    growth: Res<ParticleBufferGrowth>,
+    mut buffers: ResMut<Assets<ShaderStorageBuffer>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ParticleMaterial>>,
+    mut warned_cap: Local<bool>,
+    mut warned_budget: Local<bool>,
+) {
+    // the copies were extracted at the end of the previous frame
+    for entity in &copied_buffers {
+        commands.entity(entity).remove::<PreviousParticleBuffer>();
+    }
+
+    let mut allocated_bytes: u64 = emitter_query
+        .iter()
+        .map(|(_, _, _, buffer_handle, ..)| buffer_handle.size_in_bytes())
+        .sum();
+
+    for (
+        emitter_entity,
+        emitter,
+        runtime,
+        mut buffer_handle,
+        current_mesh,
+        mut mesh_handle,
+        material_handle,
+    ) in emitter_query.iter_mut()
+    {
+        let Some(emitter_data) =
+            get_particle_asset(emitter.parent_system, &particle_systems, &assets)
+                .and_then(|asset| asset.emitters.get(runtime.emitter_index))
+        else {
+            continue;
+        };
+
+        let requested = emitter_data.emission.particles_amount;
+        if requested <= buffer_handle.max_particles {
+            continue;
+        }
+
+        let mut capacity = requested.min(growth.max_particles);
+        if requested > capacity && !*warned_cap {
+            warn!(
+                "emitter \"{}\" requested {} particles, clamping to the buffer cap of {}",
+                emitter_data.name, requested, capacity
+            );
+            *warned_cap = true;
+        }
+        if let Some(max_buffer_bytes) = growth.max_buffer_bytes {
+            let available_bytes =
+                max_buffer_bytes.saturating_sub(allocated_bytes) + buffer_handle.size_in_bytes();
+            let budgeted = (available_bytes / ParticleBufferHandle::BYTES_PER_PARTICLE)
+                .min(u32::MAX as u64) as u32;
+            if capacity > budgeted {
+                if !*warned_budget {
+                    warn!(
+                        "emitter \"{}\" requested {} particles, clamping to {} to stay within the {} byte buffer budget",
+                        emitter_data.name, requested, budgeted, max_buffer_bytes
+                    );
+                    *warned_budget = true;
                 }
+                capacity = budgeted;
+            }
+        }
+        if capacity <= buffer_handle.max_particles {
+            continue;
+        }
+
+        info!(
+            "growing particle buffers for emitter \"{}\" from {} to {}",
+            emitter_data.name, buffer_handle.max_particles, capacity
+        );
+
+        allocated_bytes -= buffer_handle.size_in_bytes();
+        commands
+            .entity(emitter_entity)
+            .insert(PreviousParticleBuffer {
+                buffer: buffer_handle.particle_buffer.clone(),
+                max_particles: buffer_handle.max_particles,
+            });
+        *buffer_handle = create_particle_buffers(capacity, &mut buffers);
+        allocated_bytes += buffer_handle.size_in_bytes();
+
+        let new_mesh_handle = create_particle_mesh(&current_mesh.0, capacity, &mut meshes);
+        for (emitter_mesh, mut mesh3d) in mesh_entities.iter_mut() {
+            if emitter_mesh.emitter_entity == emitter_entity {
+                mesh3d.0 = new_mesh_handle.clone();
+            }
+        }
+        mesh_handle.0 = new_mesh_handle;
+
+        if let Some(material) = materials.get_mut(&material_handle.0) {
+            material.extension.sorted_particles = buffer_handle.sorted_particles_buffer.clone();
+            material.extension.max_particles = capacity;
+        }
+
+        for mut sub_buffer in sub_emitter_buffers.iter_mut() {
+            if sub_buffer.target_emitter == emitter_entity {
+                sub_buffer.buffer = create_sub_emitter_buffer(capacity, &mut buffers);
+                sub_buffer.max_particles = capacity;
+            }
+        }
+    }
+}
+
+/// Warns once per emitter when its sub-emitter can produce more events in one step than
+/// the target emitter holds.
+///
+/// The compute shader stops accepting events once the target's
+/// [`max_particles`](SubEmitterBufferHandle::max_particles) is reached, so the excess is
+/// dropped. The warning is repeated if the capacity or the parent's amount changes.
+pub fn warn_sub_emitter_overflow(
+    particle_systems: Query<&ParticleSystem3D>,
+    emitters: Query<(
+        Entity,
+        &EmitterEntity,
+        &EmitterRuntime,
+        &ParticleBufferHandle,
+        &SubEmitterBufferHandle,
+    )>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    mut warned: Local<HashMap<Entity, (u32, u32)>>,
+) {
+    warned.retain(|entity, _| emitters.contains(*entity));
+
+    for (entity, emitter, runtime, buffer_handle, sub_buffer) in &emitters {
+        let Some(emitter_data) =
+            get_particle_asset(emitter.parent_system, &particle_systems, &assets)
+                .and_then(|asset| asset.emitters.get(runtime.emitter_index))
+        else {
+            continue;
+        };
+        let Some(config) = &emitter_data.sub_emitter else {
+            continue;
+        };
+
+        let parent_amount = emitter_data
+            .emission
+            .particles_amount
+            .min(buffer_handle.max_particles);
+        let max_events = config.max_events_per_step(parent_amount);
+        if max_events <= sub_buffer.max_particles {
+            warned.remove(&entity);
+            continue;
+        }
+
+        let key = (max_events, sub_buffer.max_particles);
+        if warned.get(&entity) == Some(&key) {
+            continue;
+        }
+        warn!(
+            "sub-emitter of emitter \"{}\" can produce up to {} events per step, but its target \
+             holds {} particles; events beyond that are dropped",
+            emitter_data.name, max_events, sub_buffer.max_particles
+        );
+        warned.insert(entity, key);
+    }
+}
+
+/// Flags emitters further than [`FAR_FROM_ORIGIN_DISTANCE`] from the world origin with
+/// [`FarFromOrigin`], warning once each time an emitter moves out that far.
+///
+/// Emitters aren't moved back; see [`FAR_FROM_ORIGIN_DISTANCE`] for why it matters.
+pub fn flag_far_emitters(
+    mut commands: Commands,
+    particle_systems: Query<&ParticleSystem3D>,
+    mut emitters: Query<(
+        Entity,
+        &EmitterEntity,
+        &EmitterRuntime,
+        &GlobalTransform,
+        Option<&mut FarFromOrigin>,
+    )>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+) {
+    for (entity, emitter, runtime, transform, far) in &mut emitters {
+        let distance = transform.translation().length();
+        if distance <= FAR_FROM_ORIGIN_DISTANCE {
+            if far.is_some() {
+                commands.entity(entity).remove::<FarFromOrigin>();
+            }
+            continue;
+        }
+
+        // already flagged emitters only have their distance kept up to date
+        if let Some(mut far) = far {
+            if far.distance != distance {
+                far.distance = distance;
             }
+            continue;
         }
+
+        let name = get_particle_asset(emitter.parent_system, &particle_systems, &assets)
+            .and_then(|asset| asset.emitters.get(runtime.emitter_index))
+            .map_or("", |emitter_data| emitter_data.name.as_str());
+        warn!(
+            "emitter \"{}\" is {:.0} units from the origin, past the {} where particles \
+             start losing precision",
+            name, distance, FAR_FROM_ORIGIN_DISTANCE
+        );
+        commands.entity(entity).insert(FarFromOrigin { distance });
     }
 }
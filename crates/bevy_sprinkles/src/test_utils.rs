@@ -0,0 +1,7 @@
+pub use crate::random::{hash, hash_to_float};
+pub use crate::spawning::{
+    clear_simulation_steps, despawn_finished_particle_systems, flag_far_emitters,
+    grow_particle_buffers, read_emitter_stats, setup_particle_systems,
+    sync_emitter_stats_readbacks, sync_particle_shadows, trigger_emitter, update_emitter_fades,
+    update_emitter_velocities, update_particle_time, warn_sub_emitter_overflow,
+};
@@ -6,18 +6,25 @@ use bevy::{
     },
 };
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 
 use crate::asset::{
-    CurveTexture, Gradient, GradientInterpolation, ParticleSystemAsset, SolidOrGradientColor,
+    CurveTexture, EmitterColors, Gradient, ParticleSystemAsset, SolidOrGradientColor,
 };
 use crate::runtime::ParticleSystem3D;
 
-const TEXTURE_WIDTH: u32 = 256;
+/// Width of baked gradient and curve textures, which is also the number of embedded
+/// samples they're uploaded from (see [`CurveTexture::samples`] and [`Gradient::samples`]).
+pub const TEXTURE_WIDTH: u32 = 256;
 
 /// Cache for baked gradient textures, avoiding redundant texture creation.
 ///
 /// Each unique gradient (identified by its [`Gradient::cache_key`]) is baked into
-/// a 1D RGBA texture once and reused across all emitters that reference it.
+/// a 1D RGBA texture once and reused across all emitters that reference it. HDR
+/// gradients (see [`Gradient::hdr`]) are baked as `Rgba16Float` instead.
+///
+/// Per-channel color curves (see [`EmitterColors::color_channels_over_lifetime`]) are
+/// cached here too, since they're bound in place of a gradient.
 #[derive(Resource, Default)]
 pub struct GradientTextureCache {
     cache: HashMap<u64, Handle<Image>>,
@@ -45,84 +52,103 @@ impl GradientTextureCache {
     pub fn get(&self, gradient: &Gradient) -> Option<Handle<Image>> {
         self.cache.get(&gradient.cache_key()).cloned()
     }
-}
 
-fn bake_gradient_texture(gradient: &Gradient) -> Image {
-    let mut data = Vec::with_capacity((TEXTURE_WIDTH * 4) as usize);
+    /// Returns a cached texture handle for red, green, blue and alpha curves, creating
+    /// and baking a new texture if one doesn't already exist.
+    pub fn get_or_create_channels(
+        &mut self,
+        channels: &[CurveTexture; 4],
+        images: &mut Assets<Image>,
+    ) -> Handle<Image> {
+        let key = channels_cache_key(channels);
+        if let Some(handle) = self.cache.get(&key) {
+            return handle.clone();
+        }
+        let image = bake_channels_texture(channels);
+        let handle = images.add(image);
+        self.cache.insert(key, handle.clone());
+        handle
+    }
 
-    for i in 0..TEXTURE_WIDTH {
-        let t = if TEXTURE_WIDTH > 1 {
-            i as f32 / (TEXTURE_WIDTH - 1) as f32
-        } else {
-            0.0
-        };
-        let color = sample_gradient(gradient, t);
-        data.push((color[0] * 255.0).clamp(0.0, 255.0) as u8);
-        data.push((color[1] * 255.0).clamp(0.0, 255.0) as u8);
-        data.push((color[2] * 255.0).clamp(0.0, 255.0) as u8);
-        data.push((color[3] * 255.0).clamp(0.0, 255.0) as u8);
+    /// Returns the cached texture handle for red, green, blue and alpha curves, if it
+    /// exists.
+    pub fn get_channels(&self, channels: &[CurveTexture; 4]) -> Option<Handle<Image>> {
+        self.cache.get(&channels_cache_key(channels)).cloned()
     }
 
-    create_1d_texture(data, TextureFormat::Rgba8UnormSrgb)
+    /// Returns the cached texture bound as the color-over-lifetime texture of an
+    /// emitter: its [`color_channels_over_lifetime`](EmitterColors::color_channels_over_lifetime)
+    /// curves when set, or else its [`color_over_lifetime`](EmitterColors::color_over_lifetime)
+    /// gradient.
+    pub fn get_color_over_lifetime(&self, colors: &EmitterColors) -> Option<Handle<Image>> {
+        match &colors.color_channels_over_lifetime {
+            Some(channels) => self.get_channels(channels),
+            None => self.get(&colors.color_over_lifetime),
+        }
+    }
 }
 
-fn sample_gradient(gradient: &Gradient, t: f32) -> [f32; 4] {
-    let stops = &gradient.stops;
-
-    if stops.is_empty() {
-        return [1.0, 1.0, 1.0, 1.0];
-    }
-    if stops.len() == 1 {
-        return stops[0].color;
+fn channels_cache_key(channels: &[CurveTexture; 4]) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    // keeps channel textures apart from gradients sharing the map
+    "channels".hash(&mut hasher);
+    for curve in channels {
+        curve.cache_key().hash(&mut hasher);
     }
+    hasher.finish()
+}
 
-    let t = t.clamp(0.0, 1.0);
-    let mut left_idx = 0;
-    let mut right_idx = stops.len() - 1;
+// stored as sRGB like a gradient, so a channel value reads the same as a gradient component
+fn bake_channels_texture(channels: &[CurveTexture; 4]) -> Image {
+    let mut data = Vec::with_capacity((TEXTURE_WIDTH * 4) as usize);
 
-    for (i, stop) in stops.iter().enumerate() {
-        if stop.position <= t {
-            left_idx = i;
-        }
-    }
-    for (i, stop) in stops.iter().enumerate() {
-        if stop.position >= t {
-            right_idx = i;
-            break;
+    let samples = channels.each_ref().map(CurveTexture::baked_samples);
+    for i in 0..TEXTURE_WIDTH as usize {
+        for channel in &samples {
+            data.push((channel[i] * 255.0).clamp(0.0, 255.0) as u8);
         }
     }
 
-    let left = &stops[left_idx];
-    let right = &stops[right_idx];
+    create_1d_texture(data, TextureFormat::Rgba8UnormSrgb)
+}
 
-    if left_idx == right_idx {
-        return left.color;
+fn bake_gradient_texture(gradient: &Gradient) -> Image {
+    if gradient.hdr {
+        return bake_hdr_gradient_texture(gradient);
     }
 
-    let range = right.position - left.position;
-    if range <= 0.0 {
-        return left.color;
+    let mut data = Vec::with_capacity((TEXTURE_WIDTH * 4) as usize);
+
+    for color in gradient.baked_samples().iter() {
+        data.push((color[0] * 255.0).clamp(0.0, 255.0) as u8);
+        data.push((color[1] * 255.0).clamp(0.0, 255.0) as u8);
+        data.push((color[2] * 255.0).clamp(0.0, 255.0) as u8);
+        data.push((color[3] * 255.0).clamp(0.0, 255.0) as u8);
     }
 
-    let local_t = (t - left.position) / range;
+    create_1d_texture(data, TextureFormat::Rgba8UnormSrgb)
+}
 
-    match gradient.interpolation {
-        GradientInterpolation::Steps => left.color,
-        GradientInterpolation::Linear => lerp_color(left.color, right.color, local_t),
-        GradientInterpolation::Smoothstep => {
-            let smooth_t = local_t * local_t * (3.0 - 2.0 * local_t);
-            lerp_color(left.color, right.color, smooth_t)
-        }
+// the sRGB transfer is applied on the CPU so an HDR gradient looks the same as its
+// `Rgba8UnormSrgb` counterpart below 1.0, while values above it are kept; alpha stays in `[0, 1]`
+fn bake_hdr_gradient_texture(gradient: &Gradient) -> Image {
+    // four f16 channels per texel
+    let mut image = create_1d_texture(
+        vec![0; (TEXTURE_WIDTH * 8) as usize],
+        TextureFormat::Rgba16Float,
+    );
+
+    for (i, &[r, g, b, a]) in gradient.baked_samples().iter().enumerate() {
+        let color = Color::linear_rgba(
+            Srgba::gamma_function(r.max(0.0)),
+            Srgba::gamma_function(g.max(0.0)),
+            Srgba::gamma_function(b.max(0.0)),
+            a.clamp(0.0, 1.0),
+        );
+        let _ = image.set_color_at(i as u32, 0, color);
     }
-}
 
-fn lerp_color(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
-    [
-        a[0] + (b[0] - a[0]) * t,
-        a[1] + (b[1] - a[1]) * t,
-        a[2] + (b[2] - a[2]) * t,
-        a[3] + (b[3] - a[3]) * t,
-    ]
+    image
 }
 
 /// A 1x1 white fallback texture used when no gradient texture is available.
@@ -147,7 +173,14 @@ pub fn prepare_gradient_textures(
             if let SolidOrGradientColor::Gradient { gradient } = &emitter.colors.initial_color {
                 cache.get_or_create(gradient, &mut images);
             }
-            cache.get_or_create(&emitter.colors.color_over_lifetime, &mut images);
+            match &emitter.colors.color_channels_over_lifetime {
+                Some(channels) => {
+                    cache.get_or_create_channels(channels, &mut images);
+                }
+                None => {
+                    cache.get_or_create(&emitter.colors.color_over_lifetime, &mut images);
+                }
+            }
         }
     }
 }
@@ -188,13 +221,7 @@ impl CurveTextureCache {
 fn bake_curve_texture(curve: &CurveTexture) -> Image {
     let mut data = Vec::with_capacity((TEXTURE_WIDTH * 4) as usize);
 
-    for i in 0..TEXTURE_WIDTH {
-        let t = if TEXTURE_WIDTH > 1 {
-            i as f32 / (TEXTURE_WIDTH - 1) as f32
-        } else {
-            0.0
-        };
-        let value = curve.sample(t);
+    for value in curve.baked_samples().iter() {
         let byte = (value.clamp(0.0, 1.0) * 255.0) as u8;
         data.push(byte); // R
         data.push(byte); // G
@@ -12,6 +12,15 @@
 /// [`GradientInterpolation`](crate::asset::GradientInterpolation) mode.
 /// See [`GradientTextureCache`].
 ///
+/// Gradients with [`hdr`](crate::asset::Gradient::hdr) set are baked as
+/// `Rgba16Float` instead, so colors brighter than `1.0` (e.g. emissive colors
+/// meant to bloom) aren't clamped.
+///
+/// Per-channel color curves
+/// ([`color_channels_over_lifetime`](crate::asset::EmitterColors::color_channels_over_lifetime))
+/// are baked the same way, with each curve filling one channel, and take the
+/// color-over-lifetime gradient's place.
+///
 /// # Curve textures
 ///
 /// A curve is baked into a 256-wide `Rgba8Unorm` grayscale image (1 pixel
@@ -27,6 +36,15 @@
 /// regardless of how many emitters use them. Constant curves (all points
 /// with a `1.0` value) skip baking entirely.
 ///
+/// # Embedded samples
+///
+/// Curves and gradients can carry precomputed samples
+/// ([`CurveTexture::samples`](crate::asset::CurveTexture::samples) and
+/// [`Gradient::samples`](crate::asset::Gradient::samples)), which are uploaded
+/// as-is instead of evaluating the curve for every texel. Use
+/// [`ParticleSystemAsset::embed_samples`](crate::asset::ParticleSystemAsset::embed_samples)
+/// to fill them in for a whole asset before saving it.
+///
 /// A [`FallbackGradientTexture`] and [`FallbackCurveTexture`] (1x1 white)
 /// are created at startup so shaders always have a valid texture binding.
 pub mod baked;
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::hash::Hash;
 
 #[cfg(feature = "preset-textures")]
@@ -150,16 +151,109 @@ pub enum TextureRef {
     Asset(String),
     /// A texture loaded from a local/relative path.
     Local(String),
+    /// A texture registered by the app under this key in the [`CustomPresetRegistry`].
+    Custom(String),
 }
 
 impl TextureRef {
     /// Loads the referenced texture via the [`AssetServer`].
+    ///
+    /// [`Custom`](Self::Custom) references load as the default handle; use
+    /// [`load_with_presets`](Self::load_with_presets) to resolve them.
     pub fn load(&self, asset_server: &AssetServer) -> Handle<Image> {
+        self.load_with_presets(asset_server, &CustomPresetRegistry::default())
+    }
+
+    /// Loads the referenced texture via the [`AssetServer`], resolving
+    /// [`Custom`](Self::Custom) references through `custom_presets`.
+    ///
+    /// Custom references fall back to the default handle when their key isn't registered.
+    pub fn load_with_presets(
+        &self,
+        asset_server: &AssetServer,
+        custom_presets: &CustomPresetRegistry,
+    ) -> Handle<Image> {
         match self {
             #[cfg(feature = "preset-textures")]
             Self::Preset(preset) => asset_server.load(preset.embedded_path()),
             Self::Local(path) | Self::Asset(path) if !path.is_empty() => asset_server.load(path),
+            Self::Custom(key) => custom_presets.load(key, asset_server).unwrap_or_default(),
             _ => Handle::default(),
         }
     }
 }
+
+/// Where a custom preset texture comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CustomPresetSource {
+    /// An image that is already loaded or embedded by the app.
+    Handle(Handle<Image>),
+    /// A path loaded via the [`AssetServer`], e.g. an `embedded://` path.
+    Path(String),
+}
+
+impl From<Handle<Image>> for CustomPresetSource {
+    fn from(handle: Handle<Image>) -> Self {
+        Self::Handle(handle)
+    }
+}
+
+impl From<&str> for CustomPresetSource {
+    fn from(path: &str) -> Self {
+        Self::Path(path.to_string())
+    }
+}
+
+impl From<String> for CustomPresetSource {
+    fn from(path: String) -> Self {
+        Self::Path(path)
+    }
+}
+
+/// Named textures registered by the app, referenced from emitters with [`TextureRef::Custom`].
+///
+/// This lets libraries ship their own named textures alongside the built-in presets, which
+/// are only available with the `preset-textures` feature:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_sprinkles::textures::preset::CustomPresetRegistry;
+/// fn register_presets(mut presets: ResMut<CustomPresetRegistry>) {
+///     presets.register("spark", "embedded://my_crate/spark.png");
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CustomPresetRegistry {
+    presets: HashMap<String, CustomPresetSource>,
+}
+
+impl CustomPresetRegistry {
+    /// Registers a texture under `key`, replacing any texture previously registered with it.
+    pub fn register(&mut self, key: impl Into<String>, source: impl Into<CustomPresetSource>) {
+        self.presets.insert(key.into(), source.into());
+    }
+
+    /// Removes the texture registered under `key`, returning its source.
+    pub fn unregister(&mut self, key: &str) -> Option<CustomPresetSource> {
+        self.presets.remove(key)
+    }
+
+    /// Returns the source registered under `key`, if any.
+    pub fn get(&self, key: &str) -> Option<&CustomPresetSource> {
+        self.presets.get(key)
+    }
+
+    /// Returns an iterator over all registered keys.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.presets.keys().map(String::as_str)
+    }
+
+    /// Returns a handle to the texture registered under `key`, loading it if it was
+    /// registered by path.
+    pub fn load(&self, key: &str, asset_server: &AssetServer) -> Option<Handle<Image>> {
+        match self.presets.get(key)? {
+            CustomPresetSource::Handle(handle) => Some(handle.clone()),
+            CustomPresetSource::Path(path) => Some(asset_server.load(path.clone())),
+        }
+    }
+}
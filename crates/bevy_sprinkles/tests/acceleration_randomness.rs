@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::EmitterAccelerations;
+use bevy_sprinkles::test_utils::{hash, hash_to_float};
+
+fn random_range(seed: u32, variation: f32) -> f32 {
+    (hash_to_float(seed) * 2.0 - 1.0) * variation
+}
+
+/// Mirrors the acceleration applied to a particle in `update_particle` in
+/// `particle_simulate.wgsl`, where `seed` is the particle's stored seed.
+fn particle_acceleration(accelerations: &EmitterAccelerations, seed: u32) -> Vec3 {
+    let randomness = accelerations.acceleration_randomness;
+    accelerations.scaled_gravity()
+        + Vec3::new(
+            random_range(seed + 100, randomness.x),
+            random_range(seed + 101, randomness.y),
+            random_range(seed + 102, randomness.z),
+        )
+}
+
+#[test]
+fn test_acceleration_randomness_round_trips_through_ron() {
+    let accelerations = EmitterAccelerations {
+        acceleration_randomness: Vec3::new(2.0, 0.0, 1.5),
+        ..Default::default()
+    };
+    let ron = ron::to_string(&accelerations).expect("failed to serialize accelerations");
+    let parsed: EmitterAccelerations = ron::from_str(&ron).expect("failed to parse accelerations");
+    assert_eq!(parsed, accelerations);
+
+    let plain = ron::to_string(&EmitterAccelerations::default()).unwrap();
+    assert!(!plain.contains("acceleration_randomness"));
+    let parsed: EmitterAccelerations = ron::from_str(&plain).unwrap();
+    assert_eq!(parsed.acceleration_randomness, Vec3::ZERO);
+}
+
+#[test]
+fn test_random_acceleration_is_tied_to_the_particle_seed() {
+    let randomness = Vec3::new(2.0, 1.0, 0.0);
+    let accelerations = EmitterAccelerations {
+        gravity: Vec3::ZERO,
+        acceleration_randomness: randomness,
+        ..Default::default()
+    };
+
+    let seeds: Vec<u32> = (0..256).map(|idx| hash(1 + idx)).collect();
+    let first: Vec<Vec3> = seeds
+        .iter()
+        .map(|seed| particle_acceleration(&accelerations, *seed))
+        .collect();
+    let second: Vec<Vec3> = seeds
+        .iter()
+        .map(|seed| particle_acceleration(&accelerations, *seed))
+        .collect();
+    assert_eq!(
+        first, second,
+        "the same seed should give the same acceleration"
+    );
+
+    for acceleration in &first {
+        assert!(acceleration.abs().cmple(randomness).all(), "{acceleration}");
+        assert_eq!(acceleration.z, 0.0);
+    }
+    let distinct = first
+        .iter()
+        .filter(|acceleration| **acceleration != first[0])
+        .count();
+    assert!(
+        distinct > 200,
+        "particles should get different accelerations"
+    );
+}
+
+#[test]
+fn test_zero_randomness_leaves_only_gravity() {
+    let accelerations = EmitterAccelerations::default();
+    for idx in 0..64 {
+        assert_eq!(
+            particle_acceleration(&accelerations, hash(1 + idx)),
+            accelerations.gravity
+        );
+    }
+}
@@ -5,7 +5,9 @@ use std::path::Path;
 use thiserror::Error;
 
 use bevy_sprinkles::asset::versioning::{self, VersionStatus};
-use bevy_sprinkles::asset::{ParticleSystemAsset, ParticleSystemAssetLoader};
+use bevy_sprinkles::asset::{
+    ParticleSystemAsset, ParticleSystemAssetLoader, ParticleSystemAssetLoaderSettings,
+};
 
 #[derive(Asset, TypePath, Debug, Serialize, Deserialize, PartialEq)]
 struct DummyData {
@@ -164,6 +166,77 @@ fn test_bevy_fails_to_load_invalid_ron_as_particle_system() {
     );
 }
 
+#[test]
+fn test_malformed_emitter_fails_strict_load() {
+    let mut app = create_test_app();
+
+    let handle: Handle<ParticleSystemAsset> = {
+        let asset_server = app.world().resource::<AssetServer>();
+        asset_server.load("malformed_emitter_particle_system.ron")
+    };
+
+    assert!(
+        run_until_failed(&mut app, &handle, 100),
+        "Should fail to load a particle system with a malformed emitter by default"
+    );
+}
+
+#[test]
+fn test_lenient_load_skips_malformed_emitter() {
+    let mut app = create_test_app();
+
+    let handle: Handle<ParticleSystemAsset> = {
+        let asset_server = app.world().resource::<AssetServer>();
+        asset_server.load_with_settings(
+            "malformed_emitter_particle_system.ron",
+            |settings: &mut ParticleSystemAssetLoaderSettings| settings.lenient = true,
+        )
+    };
+
+    assert!(
+        run_until_loaded(&mut app, &handle, 100),
+        "Should load the valid emitters of a particle system in lenient mode"
+    );
+
+    let assets = app.world().resource::<Assets<ParticleSystemAsset>>();
+    let asset = assets.get(&handle).expect("Asset should be available");
+    let names: Vec<&str> = asset.emitters.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, vec!["Flames", "Smoke", "Sparks"]);
+    assert_eq!(asset.emitters[0].emission.particles_amount, 16);
+    assert!(!asset.emitters[1].enabled);
+    assert_eq!(asset.emitters[2].emission.particles_amount, 4);
+}
+
+#[test]
+fn test_from_ron_lenient_reports_malformed_emitter() {
+    let bytes =
+        std::fs::read(Path::new(&fixtures_path()).join("malformed_emitter_particle_system.ron"))
+            .unwrap();
+    assert!(ParticleSystemAsset::from_ron(&bytes).is_err());
+
+    let (asset, status, errors) = ParticleSystemAsset::from_ron_lenient(&bytes).unwrap();
+    assert!(matches!(status, VersionStatus::Current));
+    assert_eq!(asset.emitters.len(), 3);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].index, 1);
+    assert_eq!(errors[0].name.as_deref(), Some("Smoke"));
+    assert!(errors[0].to_string().contains("emitter 1"), "{}", errors[0]);
+
+    // valid files load the same way as with `from_ron`
+    let bytes =
+        std::fs::read(Path::new(&fixtures_path()).join("valid_particle_system.ron")).unwrap();
+    let (asset, _, errors) = ParticleSystemAsset::from_ron_lenient(&bytes).unwrap();
+    assert!(errors.is_empty());
+    assert_eq!(asset.emitters[0].name, "Test Emitter");
+}
+
+#[test]
+fn test_lenient_load_still_rejects_non_particle_systems() {
+    let bytes =
+        std::fs::read(Path::new(&fixtures_path()).join("invalid_particle_system.ron")).unwrap();
+    assert!(ParticleSystemAsset::from_ron_lenient(&bytes).is_err());
+}
+
 #[test]
 fn test_bevy_loads_dummy_data_ron() {
     let mut app = create_test_app();
@@ -271,6 +344,29 @@ fn test_outdated_version_loads_successfully() {
     assert_eq!(asset.name, "Outdated Particle System");
 }
 
+#[test]
+fn test_unsorted_gradient_stops_are_sorted_on_load() {
+    let mut app = create_test_app();
+
+    let handle: Handle<ParticleSystemAsset> = {
+        let asset_server = app.world().resource::<AssetServer>();
+        asset_server.load("unsorted_gradient_particle_system.ron")
+    };
+
+    assert!(
+        run_until_loaded(&mut app, &handle, 100),
+        "Should load particle system with unsorted gradient stops"
+    );
+
+    let assets = app.world().resource::<Assets<ParticleSystemAsset>>();
+    let asset = assets.get(&handle).expect("Asset should be available");
+    let gradient = &asset.emitters[0].colors.color_over_lifetime;
+    let positions: Vec<f32> = gradient.stops.iter().map(|s| s.position).collect();
+    assert_eq!(positions, vec![0.0, 0.5, 1.0]);
+    assert_eq!(gradient.stops[0].color, [1.0, 0.0, 0.0, 1.0]);
+    assert!(asset.validate().is_empty());
+}
+
 #[test]
 fn test_unknown_version_fails_to_load() {
     let mut app = create_test_app();
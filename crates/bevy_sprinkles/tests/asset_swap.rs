@@ -0,0 +1,123 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::{EmitterData, ParticleSystemAsset};
+use bevy_sprinkles::runtime::{
+    CrossFade, EmitterEntity, EmitterFade, EmitterRuntime, ParticleSystem3D,
+};
+use bevy_sprinkles::test_utils::{setup_particle_systems, update_emitter_fades};
+
+const STEP: Duration = Duration::from_millis(100);
+
+fn create_test_app() -> App {
+    let mut app = common::create_test_app(STEP);
+    app.add_systems(Update, (setup_particle_systems, update_emitter_fades));
+    app
+}
+
+fn add_asset(app: &mut App, emitter_names: &[&str]) -> Handle<ParticleSystemAsset> {
+    let emitters = emitter_names
+        .iter()
+        .map(|name| EmitterData {
+            name: name.to_string(),
+            ..Default::default()
+        })
+        .collect();
+    common::add_asset(app, emitters)
+}
+
+/// Returns the emitter indices owned by `system`, sorted.
+fn emitter_indices(app: &mut App, system: Entity) -> Vec<usize> {
+    let mut query = app.world_mut().query::<(&EmitterEntity, &EmitterRuntime)>();
+    let mut indices: Vec<usize> = query
+        .iter(app.world())
+        .filter(|(emitter, _)| emitter.parent_system == system)
+        .map(|(_, runtime)| runtime.emitter_index)
+        .collect();
+    indices.sort();
+    indices
+}
+
+fn emitter_count(app: &mut App) -> usize {
+    app.world_mut()
+        .query::<&EmitterEntity>()
+        .iter(app.world())
+        .count()
+}
+
+fn swap_handle(app: &mut App, system: Entity, handle: Handle<ParticleSystemAsset>) {
+    app.world_mut()
+        .get_mut::<ParticleSystem3D>(system)
+        .unwrap()
+        .handle = handle;
+}
+
+#[test]
+fn test_changing_handle_rebuilds_emitters() {
+    let mut app = create_test_app();
+    let calm = add_asset(&mut app, &["Embers"]);
+    let intense = add_asset(&mut app, &["Flames", "Embers", "Smoke"]);
+
+    let system = app.world_mut().spawn(ParticleSystem3D::new(calm)).id();
+    app.update();
+    assert_eq!(emitter_indices(&mut app, system), [0]);
+
+    swap_handle(&mut app, system, intense);
+    app.update();
+    assert_eq!(emitter_indices(&mut app, system), [0, 1, 2]);
+    assert_eq!(emitter_count(&mut app), 3);
+
+    // leaving the handle alone keeps the same emitters
+    app.update();
+    assert_eq!(emitter_count(&mut app), 3);
+}
+
+#[test]
+fn test_cross_fade_keeps_old_emitters_until_faded_out() {
+    let mut app = create_test_app();
+    let calm = add_asset(&mut app, &["Embers"]);
+    let intense = add_asset(&mut app, &["Flames", "Smoke"]);
+
+    let system = app
+        .world_mut()
+        .spawn((ParticleSystem3D::new(calm), CrossFade { duration: 0.5 }))
+        .id();
+    app.update();
+
+    swap_handle(&mut app, system, intense);
+    app.update();
+    assert_eq!(emitter_indices(&mut app, system), [0, 1]);
+    assert_eq!(emitter_count(&mut app), 3);
+
+    let mut fades = app.world_mut().query::<(&EmitterEntity, &EmitterFade)>();
+    let fading_out: Vec<Entity> = fades
+        .iter(app.world())
+        .filter(|(_, fade)| fade.fade_out)
+        .map(|(emitter, _)| emitter.parent_system)
+        .collect();
+    assert_eq!(fading_out.len(), 1);
+    assert_ne!(fading_out[0], system);
+
+    for _ in 0..8 {
+        app.update();
+    }
+    assert_eq!(emitter_count(&mut app), 2);
+    assert!(app.world().get_entity(fading_out[0]).is_err());
+
+    let mut runtimes = app.world_mut().query::<&EmitterRuntime>();
+    assert!(
+        runtimes
+            .iter(app.world())
+            .all(|runtime| runtime.fade == 1.0)
+    );
+    assert_eq!(
+        app.world_mut()
+            .query::<&EmitterFade>()
+            .iter(app.world())
+            .count(),
+        0
+    );
+}
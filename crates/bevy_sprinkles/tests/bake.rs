@@ -0,0 +1,86 @@
+use bevy::mesh::VertexAttributeValues;
+use bevy::prelude::*;
+
+use bevy_sprinkles::bake::{ParticleMeshBaker, mesh_to_obj, particles_from_bytes};
+use bevy_sprinkles::runtime::ParticleData;
+
+fn particle(position: Vec3, scale: f32, active: bool) -> ParticleData {
+    let flags = if active { ParticleData::FLAG_ACTIVE } else { 0 };
+    ParticleData {
+        position: [position.x, position.y, position.z, scale],
+        color: [1.0, 0.5, 0.25, 1.0],
+        custom: [0.0, 0.0, 0.0, f32::from_bits(flags)],
+        ..Default::default()
+    }
+}
+
+fn particles(active: usize, inactive: usize) -> Vec<ParticleData> {
+    (0..active + inactive)
+        .map(|i| particle(Vec3::splat(i as f32), 1.0, i < active))
+        .collect()
+}
+
+#[test]
+fn test_vertex_count_matches_active_particles() {
+    for active in [1, 7, 32] {
+        let mut baker = ParticleMeshBaker::new(Quat::IDENTITY);
+        baker.add_particles(&particles(active, 5), Mat4::IDENTITY);
+        assert_eq!(baker.particle_count(), active);
+
+        let mesh = baker.build();
+        assert_eq!(mesh.count_vertices(), active * 4);
+        assert_eq!(mesh.indices().unwrap().len(), active * 6);
+    }
+}
+
+#[test]
+fn test_empty_buffer_bakes_empty_mesh() {
+    let mut baker = ParticleMeshBaker::new(Quat::IDENTITY);
+    baker.add_particles(&particles(0, 16), Mat4::IDENTITY);
+    let mesh = baker.build();
+
+    assert_eq!(mesh.count_vertices(), 0);
+    assert_eq!(mesh_to_obj(&mesh).lines().count(), 1);
+}
+
+#[test]
+fn test_quads_are_transformed_and_scaled() {
+    let mut baker = ParticleMeshBaker::new(Quat::IDENTITY);
+    baker.add_particles(
+        &[particle(Vec3::ZERO, 2.0, true)],
+        Mat4::from_translation(Vec3::new(10.0, 0.0, 0.0)),
+    );
+    let mesh = baker.build();
+
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        panic!("baked mesh should have positions");
+    };
+    assert_eq!(positions[0], [9.0, -1.0, 0.0]);
+    assert_eq!(positions[2], [11.0, 1.0, 0.0]);
+}
+
+#[test]
+fn test_particles_round_trip_through_bytes() {
+    let source = particles(3, 2);
+    let bytes = bytemuck::cast_slice::<ParticleData, u8>(&source);
+    let decoded = particles_from_bytes(bytes);
+
+    assert_eq!(decoded.len(), source.len());
+    assert_eq!(decoded.iter().filter(|p| p.is_active()).count(), 3);
+    assert_eq!(decoded[2].position, source[2].position);
+}
+
+#[test]
+fn test_obj_export_writes_every_quad() {
+    let mut baker = ParticleMeshBaker::new(Quat::IDENTITY);
+    baker.add_particles(&particles(3, 0), Mat4::IDENTITY);
+    let obj = mesh_to_obj(&baker.build());
+
+    let count = |prefix: &str| obj.lines().filter(|l| l.starts_with(prefix)).count();
+    assert_eq!(count("v "), 12);
+    assert_eq!(count("vt "), 12);
+    assert_eq!(count("vn "), 12);
+    assert_eq!(count("f "), 6);
+}
@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use bevy_sprinkles::asset::EmissionShape;
+
+const SAMPLES: usize = 1000;
+
+/// Samples a spawn position, mirroring the `EMISSION_SHAPE_BOX` and
+/// `EMISSION_SHAPE_BOX_SURFACE` cases of `get_emission_offset` in `particle_simulate.wgsl`.
+fn sample_position(shape: &EmissionShape, random: [f32; 4]) -> Vec3 {
+    let EmissionShape::Box {
+        extents: e,
+        surface_only,
+    } = *shape
+    else {
+        panic!("expected a box shape");
+    };
+    let [r0, r1, r2, r3] = random;
+    let u = r0 * 2.0 - 1.0;
+    let v = r1 * 2.0 - 1.0;
+    if !surface_only {
+        return Vec3::new(u, v, r2 * 2.0 - 1.0) * e;
+    }
+
+    let areas = Vec3::new(e.y * e.z, e.x * e.z, e.x * e.y);
+    let pick = r3 * (areas.x + areas.y + areas.z);
+    let side = if r2 < 0.5 { 1.0 } else { -1.0 };
+    if pick < areas.x {
+        Vec3::new(side, u, v) * e
+    } else if pick < areas.x + areas.y {
+        Vec3::new(u, side, v) * e
+    } else {
+        Vec3::new(u, v, side) * e
+    }
+}
+
+// a cheap deterministic spread of inputs in [0, 1)
+fn random(i: usize) -> [f32; 4] {
+    let fract = |x: f32| x - x.floor();
+    let i = i as f32;
+    [
+        fract(i * 0.618_034),
+        fract(i * 0.414_214 + 0.1),
+        fract(i * 0.732_051 + 0.2),
+        fract(i * 0.236_068 + 0.3),
+    ]
+}
+
+fn on_a_face(position: Vec3, extents: Vec3) -> bool {
+    let inside = position.abs().cmple(extents + Vec3::splat(1e-5)).all();
+    let touching = (position.abs() - extents).abs().min_element() < 1e-5;
+    inside && touching
+}
+
+#[test]
+fn test_surface_only_points_lie_on_a_face() {
+    let extents = Vec3::new(2.0, 1.0, 0.5);
+    let shape = EmissionShape::Box {
+        extents,
+        surface_only: true,
+    };
+
+    let mut faces = [0usize; 3];
+    for i in 0..SAMPLES {
+        let position = sample_position(&shape, random(i));
+        assert!(on_a_face(position, extents), "{position} is not on a face");
+        let axis = (position.abs() - extents)
+            .abs()
+            .to_array()
+            .iter()
+            .position(|d| *d < 1e-5)
+            .unwrap();
+        faces[axis] += 1;
+    }
+
+    // the X faces are the smallest and the Z faces the largest
+    assert!(faces[0] < faces[1] && faces[1] < faces[2], "{faces:?}");
+}
+
+#[test]
+fn test_volume_emission_fills_the_box() {
+    let extents = Vec3::splat(1.0);
+    let shape = EmissionShape::Box {
+        extents,
+        surface_only: false,
+    };
+
+    let interior = (0..SAMPLES)
+        .map(|i| sample_position(&shape, random(i)))
+        .filter(|position| !on_a_face(*position, extents))
+        .count();
+    assert!(interior > SAMPLES * 9 / 10);
+}
+
+#[test]
+fn test_surface_only_roundtrips_and_defaults_to_volume() {
+    let shape = EmissionShape::Box {
+        extents: Vec3::new(1.0, 2.0, 3.0),
+        surface_only: true,
+    };
+    let ron = ron::to_string(&shape).unwrap();
+    assert!(ron.contains("surface_only:true"), "{ron}");
+    assert_eq!(ron::from_str::<EmissionShape>(&ron).unwrap(), shape);
+
+    let volume = EmissionShape::Box {
+        extents: Vec3::ONE,
+        surface_only: false,
+    };
+    let ron = ron::to_string(&volume).unwrap();
+    assert!(!ron.contains("surface_only"), "{ron}");
+
+    let legacy: EmissionShape = ron::from_str("Box(extents:(1.0,1.0,1.0))").unwrap();
+    assert_eq!(legacy, volume);
+}
@@ -0,0 +1,83 @@
+use bevy_sprinkles::asset::{EmitterColors, FlickerConfig};
+use bevy_sprinkles::test_utils::{hash, hash_to_float};
+
+/// Mirrors `get_brightness_flicker` in `particle_simulate.wgsl`, where `seed` is the
+/// particle's stored seed.
+fn brightness_flicker(flicker: Option<FlickerConfig>, seed: u32, age: f32) -> f32 {
+    let Some(flicker) = flicker else {
+        return 1.0;
+    };
+    let n = age * flicker.frequency + hash_to_float(seed.wrapping_add(110)) * 256.0;
+    let cell = n.floor() as u32;
+    let f = n.fract();
+    let base = hash(seed.wrapping_add(111));
+    let a = hash_to_float(base.wrapping_add(cell));
+    let b = hash_to_float(base.wrapping_add(cell + 1));
+    let t = f * f * (3.0 - 2.0 * f);
+    let noise = (a + (b - a) * t) * 2.0 - 1.0;
+    (1.0 + flicker.amplitude * noise).max(0.0)
+}
+
+fn samples(flicker: Option<FlickerConfig>, seed: u32) -> Vec<f32> {
+    (0..120)
+        .map(|frame| brightness_flicker(flicker, seed, frame as f32 / 60.0))
+        .collect()
+}
+
+#[test]
+fn test_brightness_flicker_round_trips_through_ron() {
+    let colors = EmitterColors {
+        brightness_flicker: Some(FlickerConfig {
+            amplitude: 0.5,
+            frequency: 12.0,
+        }),
+        ..Default::default()
+    };
+    let ron = ron::to_string(&colors).expect("failed to serialize colors");
+    let parsed: EmitterColors = ron::from_str(&ron).expect("failed to parse colors");
+    assert_eq!(parsed, colors);
+
+    let plain = ron::to_string(&EmitterColors::default()).unwrap();
+    assert!(!plain.contains("brightness_flicker"));
+    let parsed: EmitterColors = ron::from_str(&plain).unwrap();
+    assert_eq!(parsed.brightness_flicker, None);
+
+    let parsed: EmitterColors = ron::from_str("(brightness_flicker: Some(()))").unwrap();
+    assert_eq!(parsed.brightness_flicker, Some(FlickerConfig::default()));
+}
+
+#[test]
+fn test_flicker_stays_within_amplitude() {
+    let flicker = Some(FlickerConfig {
+        amplitude: 0.4,
+        frequency: 10.0,
+    });
+    for seed in (0..32).map(hash) {
+        let values = samples(flicker, seed);
+        assert!(values.iter().all(|v| (0.6..=1.4).contains(v)));
+        let spread = values.iter().cloned().fold(f32::MIN, f32::max)
+            - values.iter().cloned().fold(f32::MAX, f32::min);
+        assert!(spread > 0.1, "brightness should vary over time");
+    }
+}
+
+#[test]
+fn test_flicker_is_seeded_per_particle() {
+    let flicker = Some(FlickerConfig::default());
+    assert_eq!(samples(flicker, hash(1)), samples(flicker, hash(1)));
+    assert_ne!(samples(flicker, hash(1)), samples(flicker, hash(2)));
+}
+
+#[test]
+fn test_flicker_never_goes_below_black() {
+    let flicker = Some(FlickerConfig {
+        amplitude: 3.0,
+        frequency: 10.0,
+    });
+    assert!(samples(flicker, hash(7)).iter().all(|v| *v >= 0.0));
+}
+
+#[test]
+fn test_no_flicker_keeps_brightness() {
+    assert!(samples(None, hash(1)).iter().all(|v| *v == 1.0));
+}
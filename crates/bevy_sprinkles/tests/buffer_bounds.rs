@@ -0,0 +1,126 @@
+#![cfg(feature = "bounds-checks")]
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::render::storage::ShaderStorageBuffer;
+
+use bevy_sprinkles::asset::{EmitterData, EmitterEmission};
+use bevy_sprinkles::bounds::{BufferBoundsError, check_buffer_bounds, validate_particle_buffers};
+use bevy_sprinkles::runtime::{ParticleBufferHandle, ParticleData};
+
+const CAPACITY: u32 = 16;
+
+fn create_test_app() -> App {
+    let mut app = common::create_test_app(Duration::from_millis(16));
+    app.add_systems(PostUpdate, validate_particle_buffers);
+    app
+}
+
+fn particle_buffer(
+    buffers: &mut Assets<ShaderStorageBuffer>,
+    len: u32,
+) -> Handle<ShaderStorageBuffer> {
+    buffers.add(ShaderStorageBuffer::from(vec![
+        ParticleData::default();
+        len as usize
+    ]))
+}
+
+/// Creates buffers claiming to hold [`CAPACITY`] particles, with a particle buffer that
+/// actually holds `particle_len`.
+fn buffer_handle(
+    buffers: &mut Assets<ShaderStorageBuffer>,
+    particle_len: u32,
+) -> ParticleBufferHandle {
+    ParticleBufferHandle {
+        particle_buffer: particle_buffer(buffers, particle_len),
+        indices_buffer: buffers.add(ShaderStorageBuffer::from(
+            (0..CAPACITY).collect::<Vec<u32>>(),
+        )),
+        sorted_particles_buffer: particle_buffer(buffers, CAPACITY),
+        max_particles: CAPACITY,
+    }
+}
+
+fn spawn_emitter(app: &mut App, particles_amount: u32, particle_len: u32) {
+    let (_, emitters) = common::spawn_system(
+        app,
+        vec![EmitterData {
+            emission: EmitterEmission {
+                particles_amount,
+                ..Default::default()
+            },
+            ..Default::default()
+        }],
+    );
+    let buffer_handle = buffer_handle(
+        &mut app
+            .world_mut()
+            .resource_mut::<Assets<ShaderStorageBuffer>>(),
+        particle_len,
+    );
+    app.world_mut()
+        .entity_mut(emitters[0])
+        .insert(buffer_handle);
+}
+
+#[test]
+fn test_correctly_sized_buffers_pass() {
+    let mut buffers = Assets::<ShaderStorageBuffer>::default();
+    let handle = buffer_handle(&mut buffers, CAPACITY);
+    assert_eq!(check_buffer_bounds(&handle, CAPACITY, &buffers), Ok(()));
+
+    let mut app = create_test_app();
+    spawn_emitter(&mut app, CAPACITY, CAPACITY);
+    app.update();
+}
+
+#[test]
+fn test_amount_over_capacity_is_reported() {
+    let mut buffers = Assets::<ShaderStorageBuffer>::default();
+    let handle = buffer_handle(&mut buffers, CAPACITY);
+    assert_eq!(
+        check_buffer_bounds(&handle, CAPACITY + 1, &buffers),
+        Err(BufferBoundsError::AmountExceedsCapacity {
+            amount: CAPACITY + 1,
+            max_particles: CAPACITY,
+        })
+    );
+}
+
+#[test]
+fn test_undersized_buffer_is_reported() {
+    let mut buffers = Assets::<ShaderStorageBuffer>::default();
+    let handle = buffer_handle(&mut buffers, CAPACITY / 2);
+    let stride = size_of::<ParticleData>() as u64;
+    assert_eq!(
+        check_buffer_bounds(&handle, CAPACITY, &buffers),
+        Err(BufferBoundsError::BufferTooSmall {
+            buffer: "particle",
+            len: (CAPACITY / 2) as u64 * stride,
+            required: CAPACITY as u64 * stride,
+            max_particles: CAPACITY,
+        })
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "The particle buffer holds")]
+fn test_undersized_buffer_panics_in_debug_builds() {
+    let mut app = create_test_app();
+    spawn_emitter(&mut app, CAPACITY, CAPACITY / 2);
+    app.update();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Emitter dispatches 17 particles but its buffers hold 16")]
+fn test_amount_over_capacity_panics_in_debug_builds() {
+    let mut app = create_test_app();
+    spawn_emitter(&mut app, CAPACITY + 1, CAPACITY);
+    app.update();
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::EmitterData;
+use bevy_sprinkles::runtime::{
+    EmitterEntity, EmitterRuntime, ParticleBufferGrowth, ParticleBufferHandle, ParticleSystem3D,
+};
+use bevy_sprinkles::test_utils::setup_particle_systems;
+
+fn create_test_app(max_buffer_particles: Option<u64>) -> App {
+    let mut app = common::create_test_app(Duration::from_millis(16));
+    app.insert_resource(ParticleBufferGrowth {
+        max_buffer_bytes: max_buffer_particles
+            .map(|particles| particles * ParticleBufferHandle::BYTES_PER_PARTICLE),
+        ..Default::default()
+    })
+    .add_systems(Update, setup_particle_systems);
+    app
+}
+
+fn spawn_system(app: &mut App, amounts: &[u32]) -> Entity {
+    let emitters = amounts
+        .iter()
+        .map(|&amount| {
+            let mut emitter = EmitterData::default();
+            emitter.emission.particles_amount = amount;
+            emitter
+        })
+        .collect();
+    let handle = common::add_asset(app, emitters);
+    app.world_mut().spawn(ParticleSystem3D::new(handle)).id()
+}
+
+/// Returns the buffer capacity of each emitter owned by `system`, by emitter index.
+fn capacities(app: &mut App, system: Entity) -> Vec<u32> {
+    let mut query = app
+        .world_mut()
+        .query::<(&EmitterEntity, &EmitterRuntime, &ParticleBufferHandle)>();
+    let mut capacities: Vec<(usize, u32)> = query
+        .iter(app.world())
+        .filter(|(emitter, ..)| emitter.parent_system == system)
+        .map(|(_, runtime, buffers)| (runtime.emitter_index, buffers.max_particles))
+        .collect();
+    capacities.sort();
+    capacities
+        .into_iter()
+        .map(|(_, capacity)| capacity)
+        .collect()
+}
+
+#[test]
+fn test_no_budget_keeps_full_capacity() {
+    let mut app = create_test_app(None);
+    let systems: Vec<Entity> = (0..4).map(|_| spawn_system(&mut app, &[1000])).collect();
+    app.update();
+
+    for system in systems {
+        assert_eq!(capacities(&mut app, system), [1000]);
+    }
+}
+
+#[test]
+fn test_tight_budget_reduces_capacity_of_many_systems() {
+    let mut app = create_test_app(Some(2500));
+    let systems: Vec<Entity> = (0..4).map(|_| spawn_system(&mut app, &[1000])).collect();
+    app.update();
+
+    let capacities: Vec<u32> = systems
+        .iter()
+        .flat_map(|&system| capacities(&mut app, system))
+        .collect();
+    assert_eq!(capacities.len(), 4);
+    assert!(capacities.iter().any(|&capacity| capacity < 1000));
+    // the last system finds the budget exhausted and keeps a single particle
+    assert_eq!(capacities.iter().sum::<u32>(), 2501);
+}
+
+#[test]
+fn test_budget_is_shared_by_later_systems() {
+    let mut app = create_test_app(Some(1500));
+    let first = spawn_system(&mut app, &[1000]);
+    app.update();
+    let second = spawn_system(&mut app, &[1000]);
+    app.update();
+
+    assert_eq!(capacities(&mut app, first), [1000]);
+    assert_eq!(capacities(&mut app, second), [500]);
+}
+
+#[test]
+fn test_emitters_are_reduced_proportionally() {
+    let mut app = create_test_app(Some(2000));
+    let system = spawn_system(&mut app, &[1000, 3000]);
+    app.update();
+
+    assert_eq!(capacities(&mut app, system), [500, 1500]);
+}
+
+#[test]
+fn test_exhausted_budget_keeps_one_particle_per_emitter() {
+    let mut app = create_test_app(Some(1000));
+    let first = spawn_system(&mut app, &[1000]);
+    app.update();
+    let second = spawn_system(&mut app, &[1000, 500]);
+    app.update();
+
+    assert_eq!(capacities(&mut app, first), [1000]);
+    assert_eq!(capacities(&mut app, second), [1, 1]);
+}
+
+#[test]
+fn test_long_lifetime_leaves_capacity_unchanged() {
+    let mut app = create_test_app(None);
+    let mut emitter = EmitterData::default();
+    emitter.emission.particles_amount = 100;
+    emitter.time.lifetime = 1000.0;
+    let handle = common::add_asset(&mut app, vec![emitter]);
+    let system = app.world_mut().spawn(ParticleSystem3D::new(handle)).id();
+    for _ in 0..3 {
+        app.update();
+    }
+
+    // capacity only follows `particles_amount`, so lifetime can't make buffers run away
+    assert_eq!(capacities(&mut app, system), [100]);
+}
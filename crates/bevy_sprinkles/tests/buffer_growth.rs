@@ -0,0 +1,205 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::pbr::ExtendedMaterial;
+use bevy::prelude::*;
+use bevy::render::storage::ShaderStorageBuffer;
+
+use bevy_sprinkles::asset::{EmitterData, EmitterEmission, ParticleMesh, ParticleSystemAsset};
+use bevy_sprinkles::material::ParticleMaterialExtension;
+use bevy_sprinkles::runtime::{
+    CurrentMeshConfig, ParticleBufferGrowth, ParticleBufferHandle, ParticleData, ParticleMaterial,
+    ParticleMaterialHandle, ParticleMeshHandle, ParticleSystem3D, PreviousParticleBuffer,
+};
+use bevy_sprinkles::test_utils::grow_particle_buffers;
+
+const INITIAL_CAPACITY: u32 = 8;
+
+fn create_test_app(growth: ParticleBufferGrowth) -> App {
+    let mut app = common::create_test_app(Duration::from_millis(16));
+    app.insert_resource(growth)
+        .add_systems(Update, grow_particle_buffers);
+    app
+}
+
+fn buffer(app: &mut App, len: u32) -> Handle<ShaderStorageBuffer> {
+    app.world_mut()
+        .resource_mut::<Assets<ShaderStorageBuffer>>()
+        .add(ShaderStorageBuffer::from(vec![0u32; len as usize]))
+}
+
+/// Spawns a single-emitter system whose buffers were sized for [`INITIAL_CAPACITY`]
+/// particles, and returns the emitter entity together with the asset handle.
+fn spawn_emitter(app: &mut App) -> (Entity, Handle<ParticleSystemAsset>) {
+    let (system, emitters) = common::spawn_system(
+        app,
+        vec![EmitterData {
+            emission: EmitterEmission {
+                particles_amount: INITIAL_CAPACITY,
+                ..Default::default()
+            },
+            ..Default::default()
+        }],
+    );
+    let handle = app
+        .world()
+        .get::<ParticleSystem3D>(system)
+        .unwrap()
+        .handle
+        .clone();
+
+    let buffer_handle = ParticleBufferHandle {
+        particle_buffer: buffer(app, INITIAL_CAPACITY),
+        indices_buffer: buffer(app, INITIAL_CAPACITY),
+        sorted_particles_buffer: buffer(app, INITIAL_CAPACITY),
+        max_particles: INITIAL_CAPACITY,
+    };
+    let material = app
+        .world_mut()
+        .resource_mut::<Assets<ParticleMaterial>>()
+        .add(ExtendedMaterial {
+            base: StandardMaterial::default(),
+            extension: ParticleMaterialExtension {
+                sorted_particles: buffer_handle.sorted_particles_buffer.clone(),
+                max_particles: INITIAL_CAPACITY,
+                particle_flags: 0,
+                billboard_axis: Vec4::Y,
+                vertex_shader: None,
+                fragment_shader: None,
+            },
+        });
+
+    app.world_mut().entity_mut(emitters[0]).insert((
+        buffer_handle,
+        CurrentMeshConfig(ParticleMesh::default()),
+        ParticleMeshHandle(Handle::default()),
+        ParticleMaterialHandle(material),
+    ));
+
+    (emitters[0], handle)
+}
+
+fn set_particles_amount(app: &mut App, handle: &Handle<ParticleSystemAsset>, amount: u32) {
+    app.world_mut()
+        .resource_mut::<Assets<ParticleSystemAsset>>()
+        .get_mut(handle)
+        .unwrap()
+        .emitters[0]
+        .emission
+        .particles_amount = amount;
+}
+
+#[test]
+fn test_raising_particles_amount_grows_buffers() {
+    let mut app = create_test_app(ParticleBufferGrowth::default());
+    let (emitter, handle) = spawn_emitter(&mut app);
+    app.update();
+
+    let original = app.world().get::<ParticleBufferHandle>(emitter).unwrap();
+    assert_eq!(original.max_particles, INITIAL_CAPACITY);
+    let original_buffer = original.particle_buffer.clone();
+
+    set_particles_amount(&mut app, &handle, 64);
+    app.update();
+
+    let grown = app.world().get::<ParticleBufferHandle>(emitter).unwrap();
+    assert_eq!(grown.max_particles, 64);
+    assert_ne!(grown.particle_buffer, original_buffer);
+
+    let buffers = app.world().resource::<Assets<ShaderStorageBuffer>>();
+    let data = buffers
+        .get(&grown.particle_buffer)
+        .unwrap()
+        .data
+        .as_ref()
+        .unwrap();
+    assert_eq!(
+        data.len(),
+        64 * size_of::<ParticleData>(),
+        "particle buffer should hold 64 particles"
+    );
+
+    let material_handle = &app
+        .world()
+        .get::<ParticleMaterialHandle>(emitter)
+        .unwrap()
+        .0;
+    let material = app
+        .world()
+        .resource::<Assets<ParticleMaterial>>()
+        .get(material_handle)
+        .unwrap();
+    assert_eq!(material.extension.max_particles, 64);
+    assert_eq!(
+        material.extension.sorted_particles,
+        grown.sorted_particles_buffer
+    );
+}
+
+#[test]
+fn test_grown_buffers_keep_the_previous_buffer_for_one_frame() {
+    let mut app = create_test_app(ParticleBufferGrowth::default());
+    let (emitter, handle) = spawn_emitter(&mut app);
+    app.update();
+    assert!(app.world().get::<PreviousParticleBuffer>(emitter).is_none());
+
+    let original_buffer = app
+        .world()
+        .get::<ParticleBufferHandle>(emitter)
+        .unwrap()
+        .particle_buffer
+        .clone();
+
+    set_particles_amount(&mut app, &handle, 64);
+    app.update();
+
+    let previous = app
+        .world()
+        .get::<PreviousParticleBuffer>(emitter)
+        .expect("grown emitter should keep its previous particle buffer");
+    assert_eq!(previous.buffer, original_buffer);
+    assert_eq!(previous.max_particles, INITIAL_CAPACITY);
+
+    app.update();
+    assert!(
+        app.world().get::<PreviousParticleBuffer>(emitter).is_none(),
+        "previous particle buffer should be dropped once copied"
+    );
+}
+
+#[test]
+fn test_lowering_particles_amount_keeps_buffers() {
+    let mut app = create_test_app(ParticleBufferGrowth::default());
+    let (emitter, handle) = spawn_emitter(&mut app);
+    app.update();
+
+    let original_buffer = app
+        .world()
+        .get::<ParticleBufferHandle>(emitter)
+        .unwrap()
+        .particle_buffer
+        .clone();
+
+    set_particles_amount(&mut app, &handle, 4);
+    app.update();
+
+    let buffer_handle = app.world().get::<ParticleBufferHandle>(emitter).unwrap();
+    assert_eq!(buffer_handle.max_particles, INITIAL_CAPACITY);
+    assert_eq!(buffer_handle.particle_buffer, original_buffer);
+}
+
+#[test]
+fn test_buffer_growth_is_capped() {
+    let mut app = create_test_app(ParticleBufferGrowth {
+        max_particles: 16,
+        ..Default::default()
+    });
+    let (emitter, handle) = spawn_emitter(&mut app);
+
+    set_particles_amount(&mut app, &handle, 1000);
+    app.update();
+
+    let buffer_handle = app.world().get::<ParticleBufferHandle>(emitter).unwrap();
+    assert_eq!(buffer_handle.max_particles, 16);
+}
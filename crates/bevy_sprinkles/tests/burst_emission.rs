@@ -0,0 +1,122 @@
+use bevy_sprinkles::asset::{
+    Burst, EmissionMode, EmitterData, EmitterEmission, EmitterTime, ParticleSystemAsset,
+    ParticleSystemDimension,
+};
+
+// a power of two, so frame boundaries land exactly on the burst times
+const DT: f32 = 1.0 / 64.0;
+
+fn burst(count: u32, cycles: u32, interval: f32) -> Burst {
+    Burst {
+        count,
+        cycles,
+        interval,
+    }
+}
+
+fn emitter(burst: Option<Burst>) -> EmitterData {
+    EmitterData {
+        time: EmitterTime {
+            lifetime: 2.0,
+            ..Default::default()
+        },
+        emission: EmitterEmission {
+            particles_amount: 64,
+            burst,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Steps through `duration` seconds at 64 fps and returns the frames where bursts spawn
+/// particles, with how many each spawned.
+fn spawn_schedule(burst: &Burst, duration: f32) -> Vec<(u32, u32)> {
+    let frames = (duration / DT).round() as u32;
+    (0..frames)
+        .filter_map(|frame| {
+            let start = frame as f32 * DT;
+            let spawns = burst.spawns_between(start, start + DT);
+            (spawns > 0).then_some((frame, spawns))
+        })
+        .collect()
+}
+
+#[test]
+fn test_burst_round_trips_through_ron() {
+    let bursting = emitter(Some(burst(12, 3, 0.25)));
+    let ron = ron::to_string(&bursting.emission).expect("failed to serialize emission");
+    assert!(ron.contains("burst"));
+    let parsed: EmitterEmission = ron::from_str(&ron).expect("failed to parse emission");
+    assert_eq!(parsed, bursting.emission);
+
+    let plain = ron::to_string(&emitter(None).emission).unwrap();
+    assert!(!plain.contains("burst"));
+    let parsed: EmitterEmission = ron::from_str(&plain).unwrap();
+    assert_eq!(parsed.burst, None);
+
+    let defaults: EmitterEmission = ron::from_str("(burst: Some(()))").unwrap();
+    assert_eq!(defaults.burst, Some(Burst::default()));
+}
+
+#[test]
+fn test_bursts_go_off_at_each_interval() {
+    let schedule = spawn_schedule(&burst(10, 3, 0.5), 2.0);
+    assert_eq!(schedule, vec![(0, 10), (32, 10), (64, 10)]);
+}
+
+#[test]
+fn test_zero_interval_spawns_every_burst_at_once() {
+    let schedule = spawn_schedule(&burst(5, 4, 0.0), 1.0);
+    assert_eq!(schedule, vec![(0, 20)]);
+}
+
+#[test]
+fn test_each_burst_is_spawned_once_across_steps() {
+    let bursts = burst(3, 8, 0.1);
+    for step in [DT, 0.05, 0.1, 0.33] {
+        let mut spawned = 0;
+        let mut time = 0.0;
+        while time < 1.0 {
+            spawned += bursts.spawns_between(time, time + step);
+            time += step;
+        }
+        assert_eq!(spawned, 24, "step {step}");
+    }
+}
+
+#[test]
+fn test_inactive_bursts_fall_back_to_continuous_emission() {
+    assert!(
+        emitter(Some(burst(0, 3, 0.5)))
+            .emission
+            .active_burst()
+            .is_none()
+    );
+    assert!(
+        emitter(Some(burst(4, 0, 0.5)))
+            .emission
+            .active_burst()
+            .is_none()
+    );
+
+    let mut trail = emitter(Some(burst(4, 1, 0.0)));
+    trail.emission.mode = EmissionMode::PerDistance { spacing: 0.5 };
+    assert!(trail.emission.active_burst().is_none());
+}
+
+#[test]
+fn test_burst_times_are_reported_as_burst_markers() {
+    let bursting = emitter(Some(burst(10, 5, 0.5)));
+    assert_eq!(bursting.burst_phases(), vec![0.0, 0.25, 0.5, 0.75]);
+
+    let system = ParticleSystemAsset::new(
+        "Bursts".to_string(),
+        ParticleSystemDimension::D3,
+        vec![bursting],
+        vec![],
+        None,
+    );
+    assert_eq!(system.burst_times(), vec![0.0, 0.5, 1.0, 1.5]);
+    assert_eq!(system.peak_particle_count(0), 64);
+}
@@ -0,0 +1,84 @@
+use bevy_sprinkles::asset::{
+    EmitterData, EmitterEmission, EmitterTime, ParticleSystemAsset, ParticleSystemDimension,
+};
+
+fn emitter(amount: u32, time: EmitterTime) -> EmitterData {
+    EmitterData {
+        time,
+        emission: EmitterEmission {
+            particles_amount: amount,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn asset(emitters: Vec<EmitterData>) -> ParticleSystemAsset {
+    ParticleSystemAsset::new(
+        "Bursts".to_string(),
+        ParticleSystemDimension::D3,
+        emitters,
+        vec![],
+        None,
+    )
+}
+
+#[test]
+fn test_explosive_emitter_bursts_at_the_start_of_the_cycle() {
+    let explosive = emitter(
+        64,
+        EmitterTime {
+            explosiveness: 0.95,
+            ..Default::default()
+        },
+    );
+    let phases = explosive.burst_phases();
+    assert_eq!(phases.len(), 1, "{phases:?}");
+    assert!(phases[0] < 0.05);
+}
+
+#[test]
+fn test_steady_emission_has_no_bursts() {
+    for amount in [1, 8, 64, 1000] {
+        assert!(
+            emitter(amount, EmitterTime::default())
+                .burst_phases()
+                .is_empty()
+        );
+    }
+    let randomized = emitter(
+        200,
+        EmitterTime {
+            spawn_time_randomness: 0.5,
+            ..Default::default()
+        },
+    );
+    assert!(randomized.burst_phases().is_empty());
+}
+
+#[test]
+fn test_burst_times_follow_delay_and_lifetime() {
+    let delayed = emitter(
+        32,
+        EmitterTime {
+            lifetime: 2.0,
+            delay: 0.5,
+            explosiveness: 1.0,
+            ..Default::default()
+        },
+    );
+    let steady = emitter(32, EmitterTime::default());
+    let mut disabled = emitter(
+        32,
+        EmitterTime {
+            explosiveness: 1.0,
+            ..Default::default()
+        },
+    );
+    disabled.enabled = false;
+
+    assert_eq!(
+        asset(vec![steady, delayed, disabled]).burst_times(),
+        vec![0.5]
+    );
+}
@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use bevy_sprinkles::asset::{CurvePoint, CurveTexture, EmitterColors, Gradient, LifetimePreset};
+use bevy_sprinkles::textures::GradientTextureCache;
+
+fn constant(value: f64) -> CurveTexture {
+    CurveTexture::new(vec![
+        CurvePoint::new(0.0, value),
+        CurvePoint::new(1.0, value),
+    ])
+}
+
+fn channels() -> [CurveTexture; 4] {
+    [
+        CurveTexture::from_preset(LifetimePreset::FadeOut),
+        constant(0.0),
+        CurveTexture::from_preset(LifetimePreset::FadeIn),
+        constant(1.0),
+    ]
+}
+
+fn colors_with_channels() -> EmitterColors {
+    EmitterColors {
+        color_over_lifetime: Gradient::from_preset(LifetimePreset::FadeOut, [0.0, 1.0, 0.0]),
+        color_channels_over_lifetime: Some(channels()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_color_channels_round_trip_through_ron() {
+    let colors = colors_with_channels();
+    let ron = ron::to_string(&colors).expect("failed to serialize colors");
+    let parsed: EmitterColors = ron::from_str(&ron).expect("failed to parse colors");
+    assert_eq!(parsed, colors);
+
+    let plain = ron::to_string(&EmitterColors::default()).unwrap();
+    assert!(!plain.contains("color_channels_over_lifetime"));
+    let parsed: EmitterColors = ron::from_str(&plain).unwrap();
+    assert_eq!(parsed.color_channels_over_lifetime, None);
+}
+
+#[test]
+fn test_color_channels_are_bound_instead_of_the_gradient() {
+    let mut cache = GradientTextureCache::default();
+    let mut images = Assets::<Image>::default();
+    let colors = colors_with_channels();
+
+    let channels_handle = cache.get_or_create_channels(
+        colors.color_channels_over_lifetime.as_ref().unwrap(),
+        &mut images,
+    );
+    let gradient_handle = cache.get_or_create(&colors.color_over_lifetime, &mut images);
+    assert_ne!(channels_handle, gradient_handle);
+
+    assert_eq!(
+        cache.get_color_over_lifetime(&colors),
+        Some(channels_handle.clone())
+    );
+    let gradient_only = EmitterColors {
+        color_channels_over_lifetime: None,
+        ..colors
+    };
+    assert_eq!(
+        cache.get_color_over_lifetime(&gradient_only),
+        Some(gradient_handle)
+    );
+
+    let image = images.get(&channels_handle).unwrap();
+    assert_eq!(
+        image.texture_descriptor.format,
+        TextureFormat::Rgba8UnormSrgb
+    );
+    let width = image.texture_descriptor.size.width;
+    let first = image.get_color_at(0, 0).unwrap().to_srgba();
+    let last = image.get_color_at(width - 1, 0).unwrap().to_srgba();
+    assert_eq!(first, Srgba::new(1.0, 0.0, 0.0, 1.0));
+    assert_eq!(last, Srgba::new(0.0, 0.0, 1.0, 1.0));
+}
+
+#[test]
+fn test_equal_channels_share_a_texture() {
+    let mut cache = GradientTextureCache::default();
+    let mut images = Assets::<Image>::default();
+
+    let a = cache.get_or_create_channels(&channels(), &mut images);
+    let b = cache.get_or_create_channels(&channels(), &mut images);
+    assert_eq!(a, b);
+
+    let mut swapped = channels();
+    swapped.swap(0, 2);
+    assert_ne!(cache.get_or_create_channels(&swapped, &mut images), a);
+}
@@ -0,0 +1,54 @@
+use bevy_sprinkles::asset::{Gradient, LifetimePreset};
+use bevy_sprinkles::runtime::EmitterRuntime;
+
+// mirrors the final color in `particle_simulate.wgsl`
+fn sampled_color(gradient: &Gradient, t: f32, runtime: &EmitterRuntime) -> [f32; 4] {
+    let color = gradient.sample(t);
+    let tint = runtime.tint();
+    let alpha = color[3] * runtime.alpha_multiplier();
+    [
+        color[0] * tint[0],
+        color[1] * tint[1],
+        color[2] * tint[2],
+        alpha * tint[3],
+    ]
+}
+
+#[test]
+fn test_no_tint_leaves_colors_unchanged() {
+    let runtime = EmitterRuntime::new(0, Some(1));
+    assert_eq!(runtime.color_tint, None);
+    assert_eq!(runtime.tint(), [1.0; 4]);
+
+    let gradient = Gradient::from_preset(LifetimePreset::FadeOut, [0.2, 0.6, 0.9]);
+    for t in [0.0, 0.5, 1.0] {
+        assert_eq!(sampled_color(&gradient, t, &runtime), gradient.sample(t));
+    }
+}
+
+#[test]
+fn test_red_tint_multiplies_particle_colors() {
+    let gradient = Gradient::from_preset(LifetimePreset::FadeOut, [0.5, 0.8, 0.2]);
+    let mut runtime = EmitterRuntime::new(0, Some(1));
+    runtime.color_tint = Some([1.0, 0.0, 0.0, 1.0]);
+
+    for t in [0.0, 0.25, 0.5] {
+        let plain = gradient.sample(t);
+        let tinted = sampled_color(&gradient, t, &runtime);
+        assert_eq!(tinted, [plain[0], 0.0, 0.0, plain[3]], "color at {t}");
+    }
+
+    let white = Gradient::white();
+    assert_eq!(sampled_color(&white, 0.5, &runtime), [1.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn test_tint_alpha_stacks_with_opacity() {
+    let white = Gradient::white();
+    let mut runtime = EmitterRuntime::new(0, Some(1));
+    runtime.opacity = 0.5;
+    runtime.color_tint = Some([0.5, 0.5, 1.0, 0.5]);
+
+    let color = sampled_color(&white, 0.5, &runtime);
+    assert_eq!(color, [0.5, 0.5, 1.0, 0.25]);
+}
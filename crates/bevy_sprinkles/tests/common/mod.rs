@@ -0,0 +1,90 @@
+//! App and particle system fixtures shared by the integration tests.
+
+// every test binary compiles this module but only uses part of it
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::render::storage::ShaderStorageBuffer;
+use bevy::time::TimeUpdateStrategy;
+
+use bevy_sprinkles::CustomPresetRegistry;
+use bevy_sprinkles::asset::{EmitterData, ParticleSystemAsset, ParticleSystemDimension};
+use bevy_sprinkles::runtime::{
+    EmitterEntity, EmitterRuntime, ParticleBufferGrowth, ParticleMaterial, ParticleSystem3D,
+    ParticleSystemRuntime, ParticlesGlobalControl,
+};
+use bevy_sprinkles::test_utils::clear_simulation_steps;
+
+/// Builds a headless app with the assets and resources the particle systems read, whose
+/// clock advances by `step` on every update.
+///
+/// Tests add the systems they exercise on top, and insert their own resources over the
+/// defaults where they need different settings.
+pub fn create_test_app(step: Duration) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(step))
+        .init_asset::<ParticleSystemAsset>()
+        .init_asset::<ShaderStorageBuffer>()
+        .init_asset::<Mesh>()
+        .init_asset::<Image>()
+        .init_asset::<ParticleMaterial>()
+        .init_resource::<ParticleBufferGrowth>()
+        .init_resource::<CustomPresetRegistry>()
+        .init_resource::<ParticlesGlobalControl>()
+        .add_systems(First, clear_simulation_steps);
+    app
+}
+
+/// Adds a 3D particle system asset holding `emitters`.
+pub fn add_asset(app: &mut App, emitters: Vec<EmitterData>) -> Handle<ParticleSystemAsset> {
+    app.world_mut()
+        .resource_mut::<Assets<ParticleSystemAsset>>()
+        .add(ParticleSystemAsset::new(
+            "Test".to_string(),
+            ParticleSystemDimension::D3,
+            emitters,
+            vec![],
+            None,
+        ))
+}
+
+/// Spawns a particle system for a new asset holding `emitters`, with one emitter entity
+/// per emitter as `setup_particle_systems` would spawn them, minus their GPU resources.
+///
+/// Each emitter is seeded with its index. Returns the system and its emitters.
+pub fn spawn_system(app: &mut App, emitters: Vec<EmitterData>) -> (Entity, Vec<Entity>) {
+    let gated: Vec<bool> = emitters.iter().map(|e| e.time.wait_for_trigger).collect();
+    let handle = add_asset(app, emitters);
+    let system = app
+        .world_mut()
+        .spawn((
+            ParticleSystem3D::new(handle),
+            ParticleSystemRuntime::default(),
+        ))
+        .id();
+
+    let emitters = gated
+        .into_iter()
+        .enumerate()
+        .map(|(idx, gated)| {
+            let mut runtime = EmitterRuntime::new(idx, Some(idx as u32));
+            if gated {
+                runtime.emitting = false;
+                runtime.awaiting_trigger = true;
+            }
+            app.world_mut()
+                .spawn((
+                    EmitterEntity {
+                        parent_system: system,
+                    },
+                    runtime,
+                ))
+                .id()
+        })
+        .collect();
+
+    (system, emitters)
+}
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use bevy_sprinkles::asset::{Containment, EmitterData};
+
+const DT: f32 = 1.0 / 60.0;
+
+/// Moves a particle one frame, mirroring the containment force and position update in
+/// `update_particle` in `particle_simulate.wgsl`.
+fn step(position: &mut Vec3, velocity: &mut Vec3, containment: Option<Containment>) {
+    if let Some(containment) = containment {
+        let distance = position.length();
+        if distance > containment.radius {
+            let dir = *position / distance;
+            let overshoot = distance - containment.radius;
+            let outward_speed = velocity.dot(dir).max(0.0);
+            let damping =
+                (outward_speed * 2.0 * containment.strength.sqrt() * DT).min(outward_speed);
+            *velocity -= dir * (overshoot * containment.strength * DT + damping);
+        }
+    }
+    *position += *velocity * DT;
+}
+
+/// Simulates a particle launched from the emitter origin for `seconds`, returning its
+/// distance from the origin on every frame.
+fn distances(velocity: Vec3, containment: Option<Containment>, seconds: f32) -> Vec<f32> {
+    let mut position = Vec3::ZERO;
+    let mut velocity = velocity;
+    (0..(seconds / DT) as usize)
+        .map(|_| {
+            step(&mut position, &mut velocity, containment);
+            position.length()
+        })
+        .collect()
+}
+
+#[test]
+fn test_containment_round_trips_through_ron() {
+    let emitter = EmitterData {
+        containment: Some(Containment {
+            radius: 2.5,
+            strength: 40.0,
+        }),
+        ..Default::default()
+    };
+    let ron = ron::to_string(&emitter).expect("failed to serialize emitter");
+    let parsed: EmitterData = ron::from_str(&ron).expect("failed to parse emitter");
+    assert_eq!(parsed.containment, emitter.containment);
+
+    let plain = ron::to_string(&EmitterData::default()).unwrap();
+    assert!(!plain.contains("containment"));
+}
+
+#[test]
+fn test_fast_particles_are_pulled_back_within_the_radius() {
+    let containment = Some(Containment {
+        radius: 2.0,
+        strength: 10.0,
+    });
+    let velocity = Vec3::new(20.0, 5.0, 0.0);
+    let contained = distances(velocity, containment, 10.0);
+
+    assert!(contained.iter().any(|d| *d > 2.0), "particle should stray");
+    let last_second = &contained[contained.len() - 60..];
+    assert!(last_second.iter().any(|d| *d < 2.0));
+    assert!(last_second.iter().all(|d| *d < 2.2), "{last_second:?}");
+
+    let free = distances(velocity, None, 10.0);
+    assert!(*free.last().unwrap() > 100.0);
+}
+
+#[test]
+fn test_particles_inside_the_radius_move_freely() {
+    let containment = Some(Containment {
+        radius: 5.0,
+        strength: 10.0,
+    });
+    let velocity = Vec3::new(0.0, 1.0, 0.0);
+    assert_eq!(
+        distances(velocity, containment, 1.0),
+        distances(velocity, None, 1.0)
+    );
+}
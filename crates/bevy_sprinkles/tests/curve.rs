@@ -0,0 +1,222 @@
+use bevy_sprinkles::asset::{CurveMode, CurvePoint, CurveTexture, LifetimePreset, Range};
+
+fn hold_curve(from: f64, to: f64) -> CurveTexture {
+    CurveTexture::new(vec![
+        CurvePoint::new(0.0, from),
+        CurvePoint::new(1.0, to)
+            .with_mode(CurveMode::Hold)
+            .with_tension(0.8),
+    ])
+}
+
+#[test]
+fn test_hold_ascending_segment_keeps_left_value() {
+    let curve = hold_curve(0.2, 0.9);
+    for t in [0.0, 0.25, 0.5, 0.75] {
+        assert_eq!(curve.sample(t), 0.2, "sample at t={t}");
+    }
+    assert_eq!(curve.sample(1.0), 0.9);
+}
+
+#[test]
+fn test_hold_descending_segment_keeps_left_value() {
+    let curve = hold_curve(0.9, 0.2);
+    for t in [0.0, 0.25, 0.5, 0.75] {
+        assert_eq!(curve.sample(t), 0.9, "sample at t={t}");
+    }
+    assert_eq!(curve.sample(1.0), 0.2);
+}
+
+#[test]
+fn test_hold_between_multiple_points() {
+    let curve = CurveTexture::new(vec![
+        CurvePoint::new(0.0, 1.0),
+        CurvePoint::new(0.5, 0.0).with_mode(CurveMode::Hold),
+        CurvePoint::new(1.0, 0.5).with_mode(CurveMode::Hold),
+    ]);
+
+    assert_eq!(curve.sample(0.25), 1.0);
+    assert_eq!(curve.sample(0.5), 0.0);
+    assert_eq!(curve.sample(0.75), 0.0);
+    assert_eq!(curve.sample(1.0), 0.5);
+}
+
+fn catmull_rom_curve(values: [f64; 3]) -> CurveTexture {
+    CurveTexture::new(vec![
+        CurvePoint::new(0.0, values[0]),
+        CurvePoint::new(0.5, values[1]).with_mode(CurveMode::CatmullRom),
+        CurvePoint::new(1.0, values[2]).with_mode(CurveMode::CatmullRom),
+    ])
+}
+
+fn linear_curve(values: [f64; 3]) -> CurveTexture {
+    CurveTexture::new(vec![
+        CurvePoint::new(0.0, values[0]),
+        CurvePoint::new(0.5, values[1]).with_mode(CurveMode::SingleCurve),
+        CurvePoint::new(1.0, values[2]).with_mode(CurveMode::SingleCurve),
+    ])
+}
+
+#[test]
+fn test_catmull_rom_passes_through_points() {
+    let curve = catmull_rom_curve([0.0, 1.0, 0.0]);
+    assert_eq!(curve.sample(0.0), 0.0);
+    assert_eq!(curve.sample(0.5), 1.0);
+    assert_eq!(curve.sample(1.0), 0.0);
+}
+
+#[test]
+fn test_catmull_rom_smooths_peak_versus_linear() {
+    let values = [0.0, 1.0, 0.0];
+    let smooth = catmull_rom_curve(values);
+    let linear = linear_curve(values);
+
+    // rounded shoulders sit above the linear tent
+    assert!(smooth.sample(0.25) > linear.sample(0.25));
+    assert!(smooth.sample(0.75) > linear.sample(0.75));
+    assert!((smooth.sample(0.25) - 0.625).abs() < 1e-6);
+
+    // the tangent at the peak is flat, so both sides approach it symmetrically
+    let before = smooth.sample(0.49);
+    let after = smooth.sample(0.51);
+    assert!((before - after).abs() < 1e-4);
+    assert!(1.0 - before < 1e-3);
+}
+
+#[test]
+fn test_catmull_rom_overshoots_into_plateau() {
+    let values = [0.0, 1.0, 1.0];
+    let smooth = catmull_rom_curve(values);
+    let linear = linear_curve(values);
+
+    assert_eq!(linear.sample(0.75), 1.0);
+    assert!(smooth.sample(0.75) > 1.0);
+    assert!((smooth.sample(0.75) - 1.0625).abs() < 1e-6);
+}
+
+#[test]
+fn test_invert_flips_sampled_values() {
+    let curve = linear_curve([0.0, 0.25, 1.0]);
+    let inverted = curve.clone().inverted();
+
+    for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+        let expected = 1.0 - curve.sample(t);
+        assert!(
+            (inverted.sample(t) - expected).abs() < 1e-6,
+            "sample at t={t}"
+        );
+    }
+}
+
+#[test]
+fn test_clamp_applies_after_invert() {
+    let curve = linear_curve([0.0, 0.5, 1.0])
+        .inverted()
+        .with_clamp(Range::new(0.2, 0.6));
+
+    assert_eq!(curve.sample(0.0), 0.6);
+    assert_eq!(curve.sample(0.5), 0.5);
+    assert_eq!(curve.sample(1.0), 0.2);
+}
+
+#[test]
+fn test_post_ops_change_cache_key() {
+    let curve = linear_curve([0.0, 0.5, 1.0]);
+    let inverted = curve.clone().inverted();
+    let clamped = curve.clone().with_clamp(Range::new(0.0, 0.5));
+
+    assert_ne!(curve.cache_key(), inverted.cache_key());
+    assert_ne!(curve.cache_key(), clamped.cache_key());
+    assert_ne!(inverted.cache_key(), clamped.cache_key());
+}
+
+#[test]
+fn test_inverted_flat_curve_is_not_constant() {
+    let flat = CurveTexture::default();
+    assert!(flat.is_constant());
+    assert!(!flat.inverted().is_constant());
+}
+
+#[test]
+fn test_fade_out_preset_is_named_two_point_falloff() {
+    let curve = CurveTexture::from_preset(LifetimePreset::FadeOut);
+
+    assert_eq!(curve.name.as_deref(), Some("Fade Out"));
+    assert_eq!(curve.points.len(), 2);
+    assert_eq!(curve.points[0].value, 1.0);
+    assert_eq!(curve.points[1].value, 0.0);
+    assert_eq!(curve.sample(0.0), 1.0);
+    assert!((curve.sample(0.5) - 0.5).abs() < 1e-6);
+    assert_eq!(curve.sample(1.0), 0.0);
+}
+
+#[test]
+fn test_every_preset_is_named_after_itself() {
+    for preset in LifetimePreset::ALL {
+        let curve = CurveTexture::from_preset(preset);
+        assert_eq!(curve.name.as_deref(), Some(preset.name()));
+        assert!(!curve.is_constant(), "{} should not be flat", preset.name());
+    }
+}
+
+#[test]
+fn test_remove_point_keeps_two_points() {
+    let mut curve = CurveTexture::from_preset(LifetimePreset::Spike);
+    assert!(curve.remove_point(1));
+    assert_eq!(curve.points.len(), 2);
+    assert_eq!(curve.points[1].position, 1.0);
+
+    assert!(!curve.remove_point(0));
+    assert!(!curve.remove_point(5));
+    assert_eq!(curve.points.len(), 2);
+}
+
+#[test]
+fn test_nudge_point_stays_between_neighbors_and_in_range() {
+    let mut curve =
+        CurveTexture::from_preset(LifetimePreset::Spike).with_range(Range::new(0.0, 2.0));
+
+    curve.nudge_point(1, 0.01, 0.0);
+    assert!((curve.points[1].position - 0.11).abs() < 1e-6);
+    curve.nudge_point(1, -0.01, -0.25);
+    assert!((curve.points[1].position - 0.1).abs() < 1e-6);
+    assert_eq!(curve.points[1].value, 0.75);
+
+    curve.nudge_point(1, -1.0, 5.0);
+    assert_eq!(curve.points[1].position, 0.001);
+    assert_eq!(curve.points[1].value, 2.0);
+    curve.nudge_point(1, 2.0, -5.0);
+    assert_eq!(curve.points[1].position, 0.999);
+    assert_eq!(curve.points[1].value, 0.0);
+
+    curve.nudge_point(0, -1.0, 0.0);
+    assert_eq!(curve.points[0].position, 0.0);
+    assert!(
+        curve
+            .points
+            .windows(2)
+            .all(|pair| pair[0].position < pair[1].position)
+    );
+}
+
+#[test]
+fn test_to_samples_match_sample_at_even_positions() {
+    let curve = catmull_rom_curve([0.0, 1.0, 0.25]).inverted();
+    let samples = curve.to_samples(11);
+
+    assert_eq!(samples.len(), 11);
+    for (i, value) in samples.iter().enumerate() {
+        let t = i as f32 / 10.0;
+        assert_eq!(*value, curve.sample(t), "sample at t={t}");
+    }
+    assert_eq!(samples[0], curve.sample(0.0));
+    assert_eq!(samples[10], curve.sample(1.0));
+}
+
+#[test]
+fn test_to_samples_edge_counts() {
+    let curve = hold_curve(0.2, 0.9);
+    assert!(curve.to_samples(0).is_empty());
+    assert_eq!(curve.to_samples(1), vec![curve.sample(0.0)]);
+    assert_eq!(curve.to_samples(2), vec![0.2, 0.9]);
+}
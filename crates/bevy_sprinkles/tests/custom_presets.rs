@@ -0,0 +1,60 @@
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy_sprinkles::prelude::{CustomPresetRegistry, TextureRef};
+
+fn asset_server() -> AssetServer {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Image>();
+    app.world().resource::<AssetServer>().clone()
+}
+
+#[test]
+fn test_custom_preset_resolves_to_its_registered_handle() {
+    let asset_server = asset_server();
+    let mut images = Assets::<Image>::default();
+    let handle = images.add(Image::default());
+
+    let mut presets = CustomPresetRegistry::default();
+    presets.register("spark", handle.clone());
+
+    let texture = TextureRef::Custom("spark".to_string());
+    assert_eq!(texture.load_with_presets(&asset_server, &presets), handle);
+}
+
+#[test]
+fn test_custom_preset_registered_by_path_loads_that_path() {
+    let asset_server = asset_server();
+    let mut presets = CustomPresetRegistry::default();
+    presets.register("smoke", "textures/smoke.png");
+
+    let loaded = TextureRef::Custom("smoke".to_string()).load_with_presets(&asset_server, &presets);
+    assert_eq!(
+        loaded.id(),
+        asset_server.load::<Image>("textures/smoke.png").id()
+    );
+    assert_eq!(presets.keys().collect::<Vec<_>>(), vec!["smoke"]);
+}
+
+#[test]
+fn test_unregistered_custom_preset_falls_back_to_the_default_handle() {
+    let asset_server = asset_server();
+    let mut presets = CustomPresetRegistry::default();
+    presets.register("spark", "textures/spark.png");
+    presets.unregister("spark");
+
+    let texture = TextureRef::Custom("spark".to_string());
+    assert_eq!(
+        texture.load_with_presets(&asset_server, &presets),
+        Handle::default()
+    );
+}
+
+#[test]
+fn test_custom_texture_ref_round_trips_through_ron() {
+    let texture = TextureRef::Custom("spark".to_string());
+    let ron = ron::to_string(&texture).expect("failed to serialize texture ref");
+    assert_eq!(ron, "Custom(\"spark\")");
+    let parsed: TextureRef = ron::from_str(&ron).expect("failed to parse texture ref");
+    assert_eq!(parsed, texture);
+}
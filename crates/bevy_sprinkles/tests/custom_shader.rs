@@ -0,0 +1,108 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::{DrawPassMaterial, EmitterData};
+use bevy_sprinkles::runtime::{
+    EmitterEntity, ParticleBufferHandle, ParticleMaterial, ParticleMaterialHandle, ParticleSystem3D,
+};
+use bevy_sprinkles::test_utils::setup_particle_systems;
+
+fn create_test_app() -> App {
+    let mut app = common::create_test_app(Duration::from_millis(16));
+    app.init_asset::<Shader>()
+        .add_systems(Update, setup_particle_systems);
+    app
+}
+
+fn spawn_system(app: &mut App, material: DrawPassMaterial) -> Entity {
+    let mut emitter = EmitterData::default();
+    emitter.draw_pass.material = material;
+    let handle = common::add_asset(app, vec![emitter]);
+    app.world_mut().spawn(ParticleSystem3D::new(handle));
+    app.update();
+
+    let mut query = app
+        .world_mut()
+        .query_filtered::<Entity, With<EmitterEntity>>();
+    query
+        .single(app.world())
+        .expect("particle system should spawn one emitter")
+}
+
+fn material(app: &App, emitter: Entity) -> &ParticleMaterial {
+    let handle = &app
+        .world()
+        .get::<ParticleMaterialHandle>(emitter)
+        .unwrap()
+        .0;
+    app.world()
+        .resource::<Assets<ParticleMaterial>>()
+        .get(handle)
+        .expect("emitter material should exist")
+}
+
+#[test]
+fn test_custom_shader_emitter_spawns() {
+    let mut app = create_test_app();
+    let emitter = spawn_system(
+        &mut app,
+        DrawPassMaterial::CustomShader {
+            vertex_shader: Some("shaders/particle_vertex.wgsl".to_string()),
+            fragment_shader: Some("shaders/particle_fragment.wgsl".to_string()),
+        },
+    );
+
+    let sorted_particles = app
+        .world()
+        .get::<ParticleBufferHandle>(emitter)
+        .unwrap()
+        .sorted_particles_buffer
+        .clone();
+    let material = material(&app, emitter);
+    assert_eq!(material.extension.sorted_particles, sorted_particles);
+
+    let asset_server = app.world().resource::<AssetServer>();
+    let path = |handle: &Option<Handle<Shader>>| {
+        handle
+            .as_ref()
+            .and_then(|h| asset_server.get_path(h))
+            .map(|p| p.to_string())
+    };
+    assert_eq!(
+        path(&material.extension.vertex_shader).as_deref(),
+        Some("shaders/particle_vertex.wgsl")
+    );
+    assert_eq!(
+        path(&material.extension.fragment_shader).as_deref(),
+        Some("shaders/particle_fragment.wgsl")
+    );
+}
+
+#[test]
+fn test_custom_shader_stages_are_optional() {
+    let mut app = create_test_app();
+    let emitter = spawn_system(
+        &mut app,
+        DrawPassMaterial::CustomShader {
+            vertex_shader: None,
+            fragment_shader: Some("shaders/particle_fragment.wgsl".to_string()),
+        },
+    );
+
+    let material = material(&app, emitter);
+    assert!(material.extension.vertex_shader.is_none());
+    assert!(material.extension.fragment_shader.is_some());
+}
+
+#[test]
+fn test_standard_material_keeps_built_in_shaders() {
+    let mut app = create_test_app();
+    let emitter = spawn_system(&mut app, DrawPassMaterial::default());
+
+    let material = material(&app, emitter);
+    assert!(material.extension.vertex_shader.is_none());
+    assert!(material.extension.fragment_shader.is_none());
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::{EmissionMode, EmitterData, EmitterEmission, EmitterTime};
+use bevy_sprinkles::runtime::EmitterRuntime;
+use bevy_sprinkles::test_utils::{update_emitter_velocities, update_particle_time};
+
+const STEP: Duration = Duration::from_millis(16);
+
+fn create_test_app() -> App {
+    let mut app = common::create_test_app(STEP);
+    app.add_systems(
+        Update,
+        (update_emitter_velocities, update_particle_time).chain(),
+    );
+    app
+}
+
+fn spawn_emitter(app: &mut App, mode: EmissionMode) -> Entity {
+    let emitter = EmitterData {
+        time: EmitterTime {
+            lifetime: 100.0,
+            ..Default::default()
+        },
+        emission: EmitterEmission {
+            mode,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let (_, emitters) = common::spawn_system(app, vec![emitter]);
+    app.world_mut()
+        .entity_mut(emitters[0])
+        .insert(GlobalTransform::IDENTITY);
+    emitters[0]
+}
+
+/// Moves the emitter to `position` and runs a frame, returning how many particles the
+/// frame's simulation steps spawn by distance.
+fn move_emitter(app: &mut App, emitter: Entity, position: Vec3) -> u32 {
+    *app.world_mut().get_mut::<GlobalTransform>(emitter).unwrap() =
+        GlobalTransform::from_translation(position);
+    app.update();
+    app.world()
+        .get::<EmitterRuntime>(emitter)
+        .unwrap()
+        .simulation_steps
+        .iter()
+        .map(|step| step.scheduled_spawns)
+        .sum()
+}
+
+#[test]
+fn test_emission_mode_round_trips_through_ron() {
+    let emission = EmitterEmission {
+        mode: EmissionMode::PerDistance { spacing: 0.5 },
+        ..Default::default()
+    };
+    let ron = ron::to_string(&emission).expect("failed to serialize emission");
+    let parsed: EmitterEmission = ron::from_str(&ron).expect("failed to parse emission");
+    assert_eq!(parsed, emission);
+
+    let plain = ron::to_string(&EmitterEmission::default()).unwrap();
+    assert!(!plain.contains("mode"));
+    let parsed: EmitterEmission = ron::from_str(&plain).unwrap();
+    assert_eq!(parsed.mode, EmissionMode::Time);
+}
+
+#[test]
+fn test_spawns_one_particle_per_spacing_travelled() {
+    let mut app = create_test_app();
+    let emitter = spawn_emitter(&mut app, EmissionMode::PerDistance { spacing: 0.5 });
+    app.update();
+
+    // steps smaller and larger than the spacing, ending between two spawns
+    let mut spawned = 0;
+    let mut x = 0.0;
+    for _ in 0..100 {
+        x += 0.1;
+        spawned += move_emitter(&mut app, emitter, Vec3::new(x, 0.0, 0.0));
+    }
+    for _ in 0..31 {
+        x += 0.3;
+        spawned += move_emitter(&mut app, emitter, Vec3::new(x, 0.0, 0.0));
+    }
+
+    assert!((x - 19.3_f32).abs() < 1e-3, "{x}");
+    assert_eq!(spawned, 38);
+
+    let runtime = app.world().get::<EmitterRuntime>(emitter).unwrap();
+    assert_eq!(runtime.spawn_cursor, spawned);
+    assert!(runtime.distance_travelled < 0.5);
+}
+
+#[test]
+fn test_stationary_emitter_spawns_nothing() {
+    let mut app = create_test_app();
+    let emitter = spawn_emitter(&mut app, EmissionMode::PerDistance { spacing: 0.5 });
+    app.update();
+
+    let spawned: u32 = (0..60)
+        .map(|_| move_emitter(&mut app, emitter, Vec3::ZERO))
+        .sum();
+    assert_eq!(spawned, 0);
+}
+
+#[test]
+fn test_large_jump_spawns_every_particle_owed() {
+    let mut app = create_test_app();
+    let emitter = spawn_emitter(&mut app, EmissionMode::PerDistance { spacing: 0.25 });
+    app.update();
+
+    assert_eq!(
+        move_emitter(&mut app, emitter, Vec3::new(0.0, 3.0, 4.0)),
+        20
+    );
+}
+
+#[test]
+fn test_time_mode_ignores_movement() {
+    let mut app = create_test_app();
+    let emitter = spawn_emitter(&mut app, EmissionMode::Time);
+    app.update();
+
+    assert_eq!(
+        move_emitter(&mut app, emitter, Vec3::new(10.0, 0.0, 0.0)),
+        0
+    );
+    let runtime = app.world().get::<EmitterRuntime>(emitter).unwrap();
+    assert_eq!(runtime.distance_travelled, 0.0);
+}
@@ -0,0 +1,153 @@
+use bevy::prelude::*;
+use bevy_sprinkles::asset::{
+    CurveTexture, EmitterColors, EmitterData, EmitterScale, Gradient, LifetimePreset,
+    ParticleSystemAsset, ParticleSystemDimension, SolidOrGradientColor,
+};
+use bevy_sprinkles::textures::{CurveTextureCache, GradientTextureCache, TEXTURE_WIDTH};
+
+fn test_asset() -> ParticleSystemAsset {
+    let emitter = EmitterData {
+        scale: EmitterScale {
+            scale_over_lifetime: Some(CurveTexture::from_preset(LifetimePreset::FadeOut)),
+            ..Default::default()
+        },
+        colors: EmitterColors {
+            initial_color: SolidOrGradientColor::Gradient {
+                gradient: Gradient::default(),
+            },
+            color_over_lifetime: Gradient::from_preset(LifetimePreset::FadeIn, [1.0, 0.5, 0.0]),
+            color_channels_over_lifetime: Some([
+                CurveTexture::from_preset(LifetimePreset::FadeIn),
+                CurveTexture::from_preset(LifetimePreset::FadeOut),
+                CurveTexture::default(),
+                CurveTexture::default(),
+            ]),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    ParticleSystemAsset::new(
+        "Test".to_string(),
+        ParticleSystemDimension::D3,
+        vec![emitter],
+        vec![],
+        None,
+    )
+}
+
+fn baked_curve_values(curve: &CurveTexture) -> Vec<f32> {
+    let mut cache = CurveTextureCache::default();
+    let mut images = Assets::<Image>::default();
+    let handle = cache.get_or_create(curve, &mut images);
+    let image = images.get(&handle).unwrap();
+    (0..image.width())
+        .map(|x| image.get_color_at(x, 0).unwrap().to_linear().red)
+        .collect()
+}
+
+#[test]
+fn test_embed_samples_fills_every_curve_and_gradient() {
+    let mut asset = test_asset();
+    asset.embed_samples();
+
+    let emitter = &asset.emitters[0];
+    let width = TEXTURE_WIDTH as usize;
+    let scale = emitter.scale.scale_over_lifetime.as_ref().unwrap();
+    assert_eq!(scale.samples.as_deref(), Some(&scale.to_samples(width)[..]));
+    let SolidOrGradientColor::Gradient { gradient } = &emitter.colors.initial_color else {
+        panic!("expected a gradient");
+    };
+    assert_eq!(
+        gradient.samples.as_deref(),
+        Some(&gradient.to_samples(width)[..])
+    );
+    let over_lifetime = &emitter.colors.color_over_lifetime;
+    assert_eq!(over_lifetime.samples.as_ref().map(Vec::len), Some(width));
+    for curve in emitter
+        .colors
+        .color_channels_over_lifetime
+        .as_ref()
+        .unwrap()
+    {
+        assert_eq!(curve.samples.as_deref(), Some(&curve.to_samples(width)[..]));
+    }
+
+    asset.clear_embedded_samples();
+    assert_eq!(
+        ron::to_string(&asset).unwrap(),
+        ron::to_string(&test_asset()).unwrap()
+    );
+}
+
+#[test]
+fn test_embedded_samples_round_trip_through_ron() {
+    let mut asset = test_asset();
+    asset.embed_samples();
+    let ron = ron::to_string(&asset).expect("failed to serialize asset");
+    assert!(ron.contains("samples"));
+
+    let (parsed, _) = ParticleSystemAsset::from_ron(ron.as_bytes()).unwrap();
+    assert_eq!(
+        parsed.emitters[0].scale.scale_over_lifetime,
+        asset.emitters[0].scale.scale_over_lifetime
+    );
+    assert_eq!(
+        parsed.emitters[0].colors.color_over_lifetime,
+        asset.emitters[0].colors.color_over_lifetime
+    );
+
+    let plain = ron::to_string(&test_asset()).unwrap();
+    assert!(!plain.contains("samples"));
+}
+
+#[test]
+fn test_curve_texture_is_uploaded_from_embedded_samples() {
+    // samples that don't match the points show which ones the texture came from
+    let mut curve = CurveTexture::from_preset(LifetimePreset::FadeOut);
+    let ramp: Vec<f32> = (0..TEXTURE_WIDTH)
+        .map(|i| i as f32 / (TEXTURE_WIDTH - 1) as f32)
+        .collect();
+    curve.samples = Some(ramp.clone());
+
+    let baked = baked_curve_values(&curve);
+    assert_eq!(baked.len(), ramp.len());
+    for (value, expected) in baked.iter().zip(&ramp) {
+        assert!(
+            (value - expected).abs() <= 1.0 / 255.0,
+            "{value} vs {expected}"
+        );
+    }
+}
+
+#[test]
+fn test_embedded_samples_bake_like_the_curve() {
+    let mut curve = CurveTexture::from_preset(LifetimePreset::EaseInOut);
+    let baked = baked_curve_values(&curve);
+    curve.embed_samples();
+    assert_eq!(baked_curve_values(&curve), baked);
+}
+
+#[test]
+fn test_wrong_sample_count_falls_back_to_baking() {
+    let plain = CurveTexture::from_preset(LifetimePreset::FadeOut);
+    let mut curve = plain.clone();
+    curve.samples = Some(vec![0.5; 4]);
+    assert_eq!(baked_curve_values(&curve), baked_curve_values(&plain));
+}
+
+#[test]
+fn test_gradient_texture_is_uploaded_from_embedded_samples() {
+    let mut gradient = Gradient::white();
+    gradient.samples = Some(vec![[0.0, 0.0, 0.0, 1.0]; TEXTURE_WIDTH as usize]);
+
+    let mut cache = GradientTextureCache::default();
+    let mut images = Assets::<Image>::default();
+    let handle = cache.get_or_create(&gradient, &mut images);
+    let image = images.get(&handle).unwrap();
+    let color = image
+        .get_color_at(image.width() / 2, 0)
+        .unwrap()
+        .to_linear();
+    assert_eq!((color.red, color.green, color.blue), (0.0, 0.0, 0.0));
+    assert_ne!(gradient.cache_key(), Gradient::white().cache_key());
+}
@@ -0,0 +1,88 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+use bevy_sprinkles::asset::EmitterVelocities;
+use bevy_sprinkles::test_utils::{hash, hash_to_float};
+
+const SAMPLES: u32 = 512;
+
+/// Mirrors the `EMISSION_SHAPE_SPHERE_SURFACE` case of `get_emission_offset` and
+/// `get_emission_normal` in `particle_simulate.wgsl`, returning `(position, normal)`.
+fn sphere_surface_spawn(radius: f32, seed: u32) -> (Vec3, Vec3) {
+    let theta = 2.0 * PI * hash_to_float(seed);
+    let phi = (2.0 * hash_to_float(seed + 1) - 1.0).acos();
+    let normal = Vec3::new(phi.sin() * theta.cos(), phi.sin() * theta.sin(), phi.cos());
+    (normal * radius, normal)
+}
+
+/// Mirrors `get_emission_velocity` in `particle_simulate.wgsl` with no spread.
+fn emission_velocity(velocities: &EmitterVelocities, normal: Vec3, speed: f32) -> Vec3 {
+    let mut dir = velocities.initial_direction.normalize_or(Vec3::X);
+    let blend = velocities.emit_along_normal.clamp(0.0, 1.0);
+    if blend > 0.0 && normal.length() > 0.0 {
+        dir = dir.lerp(normal, blend).normalize_or(normal);
+    }
+    dir * speed
+}
+
+fn velocities(emit_along_normal: f32) -> EmitterVelocities {
+    EmitterVelocities {
+        initial_direction: Vec3::X,
+        spread: 0.0,
+        emit_along_normal,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_emit_along_normal_round_trips_through_ron() {
+    let velocities = velocities(0.75);
+    let ron = ron::to_string(&velocities).expect("failed to serialize velocities");
+    let parsed: EmitterVelocities = ron::from_str(&ron).expect("failed to parse velocities");
+    assert_eq!(parsed, velocities);
+
+    let plain = ron::to_string(&EmitterVelocities::default()).unwrap();
+    assert!(!plain.contains("emit_along_normal"));
+    let parsed: EmitterVelocities = ron::from_str(&plain).unwrap();
+    assert_eq!(parsed.emit_along_normal, 0.0);
+}
+
+#[test]
+fn test_full_blend_points_outward_from_a_sphere_surface() {
+    let radius = 2.0;
+    let velocities = velocities(1.0);
+
+    for idx in 0..SAMPLES {
+        let seed = hash(1 + idx);
+        let (position, normal) = sphere_surface_spawn(radius, seed);
+        let velocity = emission_velocity(&velocities, normal, 3.0);
+
+        assert!((position.length() - radius).abs() < 1e-4, "{position}");
+        assert!(
+            velocity.normalize().dot(position.normalize()) > 0.9999,
+            "{velocity} does not point away from {position}"
+        );
+        assert!((velocity.length() - 3.0).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn test_zero_blend_keeps_the_initial_direction() {
+    let velocities = velocities(0.0);
+    for idx in 0..SAMPLES {
+        let (_, normal) = sphere_surface_spawn(1.0, hash(1 + idx));
+        assert_eq!(emission_velocity(&velocities, normal, 1.0), Vec3::X);
+    }
+}
+
+#[test]
+fn test_partial_blend_lies_between_direction_and_normal() {
+    let velocities = velocities(0.5);
+    let normal = Vec3::Y;
+    let velocity = emission_velocity(&velocities, normal, 1.0);
+    assert!(velocity.abs_diff_eq(Vec3::new(1.0, 1.0, 0.0).normalize(), 1e-5));
+
+    // a normal opposite to the direction falls back to the normal instead of NaN
+    let velocity = emission_velocity(&velocities, -Vec3::X, 1.0);
+    assert!(velocity.is_finite(), "{velocity}");
+}
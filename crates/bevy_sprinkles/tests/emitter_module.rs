@@ -0,0 +1,99 @@
+use bevy_sprinkles::asset::{
+    EmitterData, EmitterModule, ParticleSystemAsset, ParticleSystemDimension, SubEmitterConfig,
+};
+
+fn emitter(name: &str) -> EmitterData {
+    EmitterData {
+        name: name.to_string(),
+        ..Default::default()
+    }
+}
+
+fn with_sub_emitter(mut emitter: EmitterData, target_emitter: usize) -> EmitterData {
+    emitter.sub_emitter = Some(SubEmitterConfig {
+        target_emitter,
+        ..Default::default()
+    });
+    emitter
+}
+
+fn asset(emitters: Vec<EmitterData>) -> ParticleSystemAsset {
+    ParticleSystemAsset::new(
+        "Test".to_string(),
+        ParticleSystemDimension::D3,
+        emitters,
+        vec![],
+        None,
+    )
+}
+
+fn names(emitters: &[EmitterData]) -> Vec<&str> {
+    emitters.iter().map(|e| e.name.as_str()).collect()
+}
+
+fn target(emitter: &EmitterData) -> Option<usize> {
+    emitter.sub_emitter.as_ref().map(|c| c.target_emitter)
+}
+
+#[test]
+fn test_module_round_trips_through_ron() {
+    let mut rocket = with_sub_emitter(emitter("Rocket"), 2);
+    rocket.emission.particles_amount = 3;
+    let source = asset(vec![
+        emitter("Smoke"),
+        emitter("Sparks"),
+        with_sub_emitter(emitter("Burst"), 1),
+        rocket,
+    ]);
+
+    let module = source.export_emitter_module(3).unwrap();
+    assert_eq!(names(&module.emitters), ["Rocket", "Burst", "Sparks"]);
+
+    let ron = module.to_ron().unwrap();
+    let (module, _) = EmitterModule::from_ron(ron.as_bytes()).unwrap();
+
+    let mut destination = asset(vec![emitter("Fire")]);
+    let first = destination.import_emitter_module(module);
+
+    assert_eq!(first, 1);
+    assert_eq!(
+        names(&destination.emitters),
+        ["Fire", "Rocket", "Burst", "Sparks"]
+    );
+    assert_eq!(destination.emitters[1].emission.particles_amount, 3);
+    assert_eq!(target(&destination.emitters[1]), Some(2));
+    assert_eq!(target(&destination.emitters[2]), Some(3));
+    assert_eq!(target(&destination.emitters[3]), None);
+}
+
+#[test]
+fn test_sub_emitter_cycles_are_exported_once() {
+    let source = asset(vec![
+        with_sub_emitter(emitter("A"), 1),
+        with_sub_emitter(emitter("B"), 0),
+    ]);
+
+    let module = source.export_emitter_module(1).unwrap();
+    assert_eq!(names(&module.emitters), ["B", "A"]);
+    assert_eq!(target(&module.emitters[0]), Some(1));
+    assert_eq!(target(&module.emitters[1]), Some(0));
+}
+
+#[test]
+fn test_missing_emitter_is_not_exported() {
+    assert!(asset(vec![emitter("A")]).export_emitter_module(1).is_none());
+}
+
+#[test]
+fn test_imported_sync_groups_do_not_join_existing_groups() {
+    let mut synced = emitter("Synced");
+    synced.sync_group = Some(0);
+    let source = asset(vec![synced]);
+
+    let mut existing = emitter("Existing");
+    existing.sync_group = Some(0);
+    let mut destination = asset(vec![existing]);
+    destination.import_emitter_module(source.export_emitter_module(0).unwrap());
+
+    assert_eq!(destination.emitters[1].sync_group, Some(1));
+}
@@ -0,0 +1,43 @@
+use bevy_sprinkles::asset::{Gradient, LifetimePreset};
+use bevy_sprinkles::runtime::{EmitterFade, EmitterRuntime};
+
+// mirrors the final alpha in `particle_simulate.wgsl`
+fn sampled_alpha(gradient: &Gradient, t: f32, runtime: &EmitterRuntime) -> f32 {
+    gradient.sample(t)[3] * runtime.alpha_multiplier()
+}
+
+#[test]
+fn test_half_opacity_halves_particle_alpha() {
+    let gradient = Gradient::from_preset(LifetimePreset::FadeOut, [1.0, 1.0, 1.0]);
+    let mut runtime = EmitterRuntime::new(0, Some(1));
+    assert_eq!(runtime.opacity, 1.0);
+
+    let full: Vec<f32> = [0.0, 0.25, 0.5]
+        .iter()
+        .map(|&t| sampled_alpha(&gradient, t, &runtime))
+        .collect();
+    runtime.opacity = 0.5;
+    for (i, &t) in [0.0, 0.25, 0.5].iter().enumerate() {
+        let halved = sampled_alpha(&gradient, t, &runtime);
+        assert!(
+            (halved - full[i] * 0.5).abs() < 1e-6,
+            "alpha at {t}: {halved}"
+        );
+    }
+}
+
+#[test]
+fn test_opacity_stacks_with_fade() {
+    let mut runtime = EmitterRuntime::new(0, Some(1));
+    runtime.opacity = 0.5;
+    let mut fade = EmitterFade::fade_in(1.0);
+    fade.elapsed = 0.5;
+    runtime.fade = fade.opacity();
+    assert!((runtime.alpha_multiplier() - 0.25).abs() < 1e-6);
+
+    runtime.fade = 1.0;
+    runtime.opacity = 2.0;
+    assert_eq!(runtime.alpha_multiplier(), 1.0);
+    runtime.opacity = -1.0;
+    assert_eq!(runtime.alpha_multiplier(), 0.0);
+}
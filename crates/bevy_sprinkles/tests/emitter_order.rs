@@ -0,0 +1,118 @@
+use bevy_sprinkles::asset::{
+    EmitterData, ParticleSystemAsset, ParticleSystemDimension, SubEmitterConfig,
+};
+
+fn emitter(name: &str, target: Option<usize>) -> EmitterData {
+    EmitterData {
+        name: name.to_string(),
+        sub_emitter: target.map(|target_emitter| SubEmitterConfig {
+            target_emitter,
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn asset(emitters: Vec<EmitterData>) -> ParticleSystemAsset {
+    ParticleSystemAsset::new(
+        "Order".to_string(),
+        ParticleSystemDimension::D3,
+        emitters,
+        vec![],
+        None,
+    )
+}
+
+fn names(asset: &ParticleSystemAsset) -> Vec<&str> {
+    asset.emitters.iter().map(|e| e.name.as_str()).collect()
+}
+
+fn targets(asset: &ParticleSystemAsset) -> Vec<Option<usize>> {
+    asset
+        .emitters
+        .iter()
+        .map(|e| e.sub_emitter.as_ref().map(|s| s.target_emitter))
+        .collect()
+}
+
+#[test]
+fn test_delete_remaps_dependent_sub_emitter_target() {
+    // "Rocket" spawns "Sparks" on death, and "Smoke" trails "Rocket"
+    let mut asset = asset(vec![
+        emitter("Flash", None),
+        emitter("Rocket", Some(3)),
+        emitter("Smoke", Some(1)),
+        emitter("Sparks", None),
+    ]);
+
+    let removed = asset.remove_emitter(0).unwrap();
+    assert_eq!(removed.name, "Flash");
+    assert_eq!(names(&asset), vec!["Rocket", "Smoke", "Sparks"]);
+    assert_eq!(targets(&asset), vec![Some(2), Some(0), None]);
+
+    asset.remove_emitter(0);
+    assert_eq!(names(&asset), vec!["Smoke", "Sparks"]);
+    assert_eq!(targets(&asset), vec![None, None]);
+
+    assert!(asset.remove_emitter(5).is_none());
+}
+
+#[test]
+fn test_insert_keeps_targets_on_the_same_emitters() {
+    let mut asset = asset(vec![emitter("Rocket", Some(1)), emitter("Sparks", None)]);
+
+    let duplicate = asset.emitters[0].clone();
+    asset.insert_emitter(
+        1,
+        EmitterData {
+            name: "Rocket 2".to_string(),
+            ..duplicate
+        },
+    );
+
+    assert_eq!(names(&asset), vec!["Rocket", "Rocket 2", "Sparks"]);
+    assert_eq!(targets(&asset), vec![Some(2), Some(2), None]);
+}
+
+#[test]
+fn test_move_keeps_targets_on_the_same_emitters() {
+    let mut asset = asset(vec![
+        emitter("Sparks", None),
+        emitter("Rocket", Some(0)),
+        emitter("Smoke", Some(1)),
+    ]);
+
+    asset.move_emitter(0, 2);
+    assert_eq!(names(&asset), vec!["Rocket", "Smoke", "Sparks"]);
+    assert_eq!(targets(&asset), vec![Some(2), Some(0), None]);
+
+    asset.move_emitter(1, 0);
+    assert_eq!(names(&asset), vec!["Smoke", "Rocket", "Sparks"]);
+    assert_eq!(targets(&asset), vec![Some(1), Some(2), None]);
+
+    asset.move_emitter(2, 3);
+    assert_eq!(names(&asset), vec!["Smoke", "Rocket", "Sparks"]);
+}
+
+#[test]
+fn test_draw_priority_orders_emitters() {
+    let mut sparks = emitter("Sparks", None);
+    sparks.draw_pass.draw_priority = 1;
+    let mut asset = asset(vec![sparks, emitter("Smoke", None)]);
+
+    assert_eq!(asset.emitter_draw_order(), vec![1, 0]);
+
+    asset.emitters[0].draw_pass.draw_priority = -1;
+    assert_eq!(asset.emitter_draw_order(), vec![0, 1]);
+}
+
+#[test]
+fn test_equal_draw_priorities_keep_reverse_emitter_order() {
+    let asset = asset(vec![
+        emitter("Flash", None),
+        emitter("Smoke", None),
+        emitter("Sparks", None),
+    ]);
+
+    assert_eq!(asset.emitter_draw_order(), vec![2, 1, 0]);
+}
@@ -0,0 +1,36 @@
+use bevy_sprinkles::asset::{EmitterData, EmitterScale, ParticleFlags, SubEmitterConfig};
+
+fn emitter() -> EmitterData {
+    EmitterData {
+        name: "Sparks".to_string(),
+        scale: EmitterScale {
+            scale_in_time: 0.1,
+            ..Default::default()
+        },
+        sub_emitter: Some(SubEmitterConfig {
+            target_emitter: 1,
+            ..Default::default()
+        }),
+        particle_flags: ParticleFlags::DISABLE_Z,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_emitter_ron_matches_pretty_serialization() {
+    let emitter = emitter();
+    assert_eq!(
+        emitter.to_ron().unwrap(),
+        ron::ser::to_string_pretty(&emitter, ron::ser::PrettyConfig::default()).unwrap()
+    );
+}
+
+#[test]
+fn test_emitter_ron_parses_back_to_the_same_emitter() {
+    let emitter = emitter();
+    let ron = emitter.to_ron().unwrap();
+    assert!(ron.contains('\n'), "preview should be pretty-printed");
+
+    let parsed: EmitterData = ron::from_str(&ron).expect("failed to parse emitter");
+    assert_eq!(parsed.to_ron().unwrap(), ron);
+}
@@ -0,0 +1,377 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::{
+    EmitterData, EmitterTime, ParticleSystemAsset, ParticleSystemDimension, SubEmitterConfig,
+};
+use bevy_sprinkles::runtime::{EmitterEntity, EmitterRuntime, TriggerEmitter};
+use bevy_sprinkles::test_utils::{trigger_emitter, update_particle_time};
+
+const STEP: Duration = Duration::from_millis(100);
+const FIXED_STEP: Duration = Duration::from_millis(25);
+
+fn create_test_app() -> App {
+    let mut app = common::create_test_app(STEP);
+    app.add_systems(Update, update_particle_time)
+        .add_observer(trigger_emitter);
+    app
+}
+
+/// Mirrors `SprinklesPlugin` configured with `ParticleSystemSchedule::FixedUpdate`.
+fn create_fixed_test_app() -> App {
+    let mut app = common::create_test_app(STEP);
+    app.insert_resource(Time::<Fixed>::from_duration(FIXED_STEP))
+        .add_systems(FixedUpdate, update_particle_time);
+    app
+}
+
+fn emitter(time: EmitterTime, sync_group: Option<u32>) -> EmitterData {
+    EmitterData {
+        time,
+        sync_group,
+        ..Default::default()
+    }
+}
+
+fn spawn_system(app: &mut App, emitters: Vec<EmitterData>) -> Vec<Entity> {
+    common::spawn_system(app, emitters).1
+}
+
+/// Runs the app for `frames` updates and returns the frames on which each emitter's
+/// cycle counter advanced.
+fn cycle_reset_frames(app: &mut App, emitters: &[Entity], frames: usize) -> Vec<Vec<usize>> {
+    let mut resets = vec![Vec::new(); emitters.len()];
+    let mut cycles = vec![0; emitters.len()];
+    for frame in 0..frames {
+        app.update();
+        for (i, &entity) in emitters.iter().enumerate() {
+            let cycle = app.world().get::<EmitterRuntime>(entity).unwrap().cycle;
+            if cycle != cycles[i] {
+                resets[i].push(frame);
+                cycles[i] = cycle;
+            }
+        }
+    }
+    resets
+}
+
+#[test]
+fn test_sync_group_resets_cycles_on_same_frame() {
+    let mut app = create_test_app();
+    let emitters = spawn_system(
+        &mut app,
+        vec![
+            emitter(
+                EmitterTime {
+                    lifetime: 1.0,
+                    ..Default::default()
+                },
+                Some(1),
+            ),
+            emitter(
+                EmitterTime {
+                    lifetime: 0.5,
+                    delay: 0.2,
+                    ..Default::default()
+                },
+                Some(1),
+            ),
+        ],
+    );
+
+    let resets = cycle_reset_frames(&mut app, &emitters, 35);
+
+    assert_eq!(resets[0].len(), 3);
+    assert_eq!(resets[0], resets[1]);
+}
+
+#[test]
+fn test_unsynced_emitters_keep_own_cycles() {
+    let mut app = create_test_app();
+    let emitters = spawn_system(
+        &mut app,
+        vec![
+            emitter(
+                EmitterTime {
+                    lifetime: 1.0,
+                    ..Default::default()
+                },
+                None,
+            ),
+            emitter(
+                EmitterTime {
+                    lifetime: 0.5,
+                    delay: 0.2,
+                    ..Default::default()
+                },
+                None,
+            ),
+        ],
+    );
+
+    let resets = cycle_reset_frames(&mut app, &emitters, 35);
+
+    assert_ne!(resets[0], resets[1]);
+}
+
+#[test]
+fn test_sync_group_cycle_duration_is_group_max() {
+    let asset = ParticleSystemAsset::new(
+        "Test".to_string(),
+        ParticleSystemDimension::D3,
+        vec![
+            emitter(
+                EmitterTime {
+                    lifetime: 0.5,
+                    ..Default::default()
+                },
+                Some(3),
+            ),
+            emitter(
+                EmitterTime {
+                    lifetime: 1.5,
+                    delay: 0.5,
+                    ..Default::default()
+                },
+                Some(3),
+            ),
+            emitter(EmitterTime::default(), None),
+        ],
+        vec![],
+        None,
+    );
+
+    assert_eq!(asset.cycle_duration(0), 2.0);
+    assert_eq!(asset.cycle_duration(1), 2.0);
+    assert_eq!(asset.cycle_duration(2), 1.0);
+    assert_eq!(asset.sync_leader(0), None);
+    assert_eq!(asset.sync_leader(1), Some(0));
+    assert_eq!(asset.sync_leader(2), None);
+}
+
+#[test]
+fn test_sync_group_follower_phase_follows_group_cycle() {
+    let mut app = create_test_app();
+    let leader = EmitterTime {
+        lifetime: 2.0,
+        ..Default::default()
+    };
+    let follower = EmitterTime {
+        lifetime: 0.5,
+        ..Default::default()
+    };
+    let emitters = spawn_system(
+        &mut app,
+        vec![emitter(leader, Some(1)), emitter(follower.clone(), Some(1))],
+    );
+
+    // well past the follower's own lifetime, but within the group's two second cycle
+    for _ in 0..15 {
+        app.update();
+    }
+
+    let runtime = app.world().get::<EmitterRuntime>(emitters[1]).unwrap();
+    assert_eq!(runtime.cycle, 0);
+    assert!(runtime.is_past_delay_in_cycle(&follower, 2.0));
+    assert_eq!(runtime.system_phase_in_cycle(&follower, 2.0), 1.0);
+    assert_eq!(runtime.prev_system_phase_in_cycle(&follower, 2.0), 1.0);
+}
+
+#[test]
+fn test_playback_duration_is_longest_source_cycle() {
+    let three_seconds = EmitterTime {
+        lifetime: 3.0,
+        ..Default::default()
+    };
+    let asset = ParticleSystemAsset::new(
+        "Test".to_string(),
+        ParticleSystemDimension::D3,
+        vec![emitter(three_seconds.clone(), None)],
+        vec![],
+        None,
+    );
+    assert_eq!((asset.playback_duration() * 1000.0).round() as u32, 3000);
+    assert!(asset.is_looping());
+
+    // sub-emitter targets don't count towards the playback duration
+    let mut source = emitter(
+        EmitterTime {
+            one_shot: true,
+            ..Default::default()
+        },
+        None,
+    );
+    source.sub_emitter = Some(SubEmitterConfig {
+        target_emitter: 1,
+        ..Default::default()
+    });
+    let asset = ParticleSystemAsset::new(
+        "Test".to_string(),
+        ParticleSystemDimension::D3,
+        vec![source, emitter(three_seconds, None)],
+        vec![],
+        None,
+    );
+    assert_eq!(asset.playback_duration(), 1.0);
+    assert!(!asset.is_looping());
+}
+
+#[test]
+fn test_zero_lifetime_has_zero_playback_duration() {
+    let asset = ParticleSystemAsset::new(
+        "Test".to_string(),
+        ParticleSystemDimension::D3,
+        vec![emitter(
+            EmitterTime {
+                lifetime: 0.0,
+                ..Default::default()
+            },
+            None,
+        )],
+        vec![],
+        None,
+    );
+    assert_eq!(asset.playback_duration(), 0.0);
+}
+
+#[test]
+fn test_delay_randomness_changes_delay_between_cycles() {
+    let time = EmitterTime {
+        lifetime: 1.0,
+        delay: 0.5,
+        delay_randomness: 0.4,
+        ..Default::default()
+    };
+    assert!((time.total_duration() - 1.9).abs() < 1e-6);
+
+    let mut app = create_test_app();
+    let emitters = spawn_system(&mut app, vec![emitter(time.clone(), None)]);
+
+    // frame offset within each cycle at which the emitter passed its delay
+    let mut start_offsets: Vec<usize> = Vec::new();
+    let mut cycle_start_frame = 0;
+    let mut cycle = 0;
+    let mut started = false;
+    for frame in 0..60 {
+        app.update();
+        let runtime = app.world().get::<EmitterRuntime>(emitters[0]).unwrap();
+        if runtime.cycle != cycle {
+            cycle = runtime.cycle;
+            cycle_start_frame = frame;
+            started = false;
+        }
+        if !started && runtime.is_past_delay(&time) {
+            started = true;
+            start_offsets.push(frame - cycle_start_frame);
+        }
+    }
+
+    assert!(start_offsets.len() >= 3);
+    assert_ne!(start_offsets[1], start_offsets[2]);
+}
+
+#[test]
+fn test_delay_randomness_never_goes_negative() {
+    let time = EmitterTime {
+        delay: 0.1,
+        delay_randomness: 5.0,
+        ..Default::default()
+    };
+
+    let delays: Vec<f32> = (0..64).map(|cycle| time.cycle_delay(7, cycle)).collect();
+
+    assert!(delays.iter().all(|&d| (0.0..=5.1).contains(&d)));
+    assert!(delays.contains(&0.0));
+    assert!(delays.iter().any(|&d| d > 0.1));
+    assert_eq!(time.cycle_delay(7, 3), time.cycle_delay(7, 3));
+}
+
+#[test]
+fn test_fixed_update_advances_by_fixed_delta_per_tick() {
+    let mut app = create_fixed_test_app();
+    let time = EmitterTime {
+        lifetime: 10.0,
+        ..Default::default()
+    };
+    let emitters = spawn_system(&mut app, vec![emitter(time, None)]);
+
+    // the first frame has no elapsed time to step through
+    app.update();
+    let start = app
+        .world()
+        .get::<EmitterRuntime>(emitters[0])
+        .unwrap()
+        .system_time;
+
+    app.update();
+    let runtime = app.world().get::<EmitterRuntime>(emitters[0]).unwrap();
+    let fixed_delta = FIXED_STEP.as_secs_f32();
+    let ticks = (STEP.as_secs_f32() / fixed_delta).round() as usize;
+
+    assert_eq!(runtime.simulation_steps.len(), ticks);
+    for step in &runtime.simulation_steps {
+        assert_eq!(step.delta_time, fixed_delta);
+        assert!((step.system_time - step.prev_system_time - fixed_delta).abs() < 1e-6);
+    }
+    assert!((runtime.system_time - start - STEP.as_secs_f32()).abs() < 1e-5);
+}
+
+#[test]
+fn test_update_schedule_steps_once_per_frame() {
+    let mut app = create_test_app();
+    let emitters = spawn_system(&mut app, vec![emitter(EmitterTime::default(), None)]);
+
+    app.update();
+    app.update();
+
+    let runtime = app.world().get::<EmitterRuntime>(emitters[0]).unwrap();
+    assert_eq!(runtime.simulation_steps.len(), 1);
+    assert_eq!(runtime.simulation_steps[0].delta_time, STEP.as_secs_f32());
+}
+
+#[test]
+fn test_gated_emitter_waits_for_trigger() {
+    let mut app = create_test_app();
+    let gated = EmitterTime {
+        wait_for_trigger: true,
+        ..Default::default()
+    };
+    let emitters = spawn_system(
+        &mut app,
+        vec![emitter(gated, None), emitter(EmitterTime::default(), None)],
+    );
+    let system = app
+        .world()
+        .get::<EmitterEntity>(emitters[0])
+        .unwrap()
+        .parent_system;
+
+    for _ in 0..5 {
+        app.update();
+    }
+    let runtime = app.world().get::<EmitterRuntime>(emitters[0]).unwrap();
+    assert!(!runtime.emitting);
+    assert_eq!(runtime.system_time, 0.0);
+    assert!(runtime.simulation_steps.is_empty());
+    assert!(
+        app.world()
+            .get::<EmitterRuntime>(emitters[1])
+            .unwrap()
+            .emitting
+    );
+
+    app.world_mut().trigger(TriggerEmitter {
+        system,
+        emitter_index: 0,
+    });
+    app.update();
+
+    let runtime = app.world().get::<EmitterRuntime>(emitters[0]).unwrap();
+    assert!(runtime.emitting);
+    assert!(!runtime.awaiting_trigger);
+    assert_eq!(runtime.system_time, STEP.as_secs_f32());
+    assert_eq!(runtime.simulation_steps.len(), 1);
+}
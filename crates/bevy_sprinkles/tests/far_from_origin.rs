@@ -0,0 +1,80 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::EmitterData;
+use bevy_sprinkles::runtime::{FAR_FROM_ORIGIN_DISTANCE, FarFromOrigin};
+use bevy_sprinkles::test_utils::flag_far_emitters;
+
+fn create_test_app() -> App {
+    let mut app = common::create_test_app(Duration::from_millis(16));
+    app.add_systems(Update, flag_far_emitters);
+    app
+}
+
+fn spawn_emitter(app: &mut App, translation: Vec3) -> Entity {
+    let (_, emitters) = common::spawn_system(app, vec![EmitterData::default()]);
+    app.world_mut()
+        .entity_mut(emitters[0])
+        .insert(GlobalTransform::from_translation(translation));
+    emitters[0]
+}
+
+#[test]
+fn test_far_away_emitter_is_flagged() {
+    let mut app = create_test_app();
+    let far = spawn_emitter(&mut app, Vec3::new(12_000.0, 0.0, 5_000.0));
+    let near = spawn_emitter(&mut app, Vec3::new(100.0, 20.0, -300.0));
+    app.update();
+
+    let flag = app.world().get::<FarFromOrigin>(far).unwrap();
+    assert_eq!(flag.distance, 13_000.0);
+    assert!(app.world().get::<FarFromOrigin>(near).is_none());
+}
+
+#[test]
+fn test_flag_is_cleared_when_emitter_moves_back() {
+    let mut app = create_test_app();
+    let emitter = spawn_emitter(
+        &mut app,
+        Vec3::new(0.0, FAR_FROM_ORIGIN_DISTANCE * 2.0, 0.0),
+    );
+    app.update();
+    assert!(app.world().get::<FarFromOrigin>(emitter).is_some());
+
+    *app.world_mut().get_mut::<GlobalTransform>(emitter).unwrap() = GlobalTransform::IDENTITY;
+    app.update();
+    assert!(app.world().get::<FarFromOrigin>(emitter).is_none());
+}
+
+#[test]
+fn test_flag_is_updated_in_place() {
+    let mut app = create_test_app();
+    let emitter = spawn_emitter(&mut app, Vec3::new(20_000.0, 0.0, 0.0));
+    app.update();
+    let changed = |app: &App| {
+        let ticks = app
+            .world()
+            .entity(emitter)
+            .get_change_ticks::<FarFromOrigin>();
+        ticks.unwrap().changed
+    };
+    let flagged = changed(&app);
+
+    app.update();
+    assert_eq!(
+        changed(&app),
+        flagged,
+        "a still emitter shouldn't touch its flag"
+    );
+
+    *app.world_mut().get_mut::<GlobalTransform>(emitter).unwrap() =
+        GlobalTransform::from_translation(Vec3::new(30_000.0, 0.0, 0.0));
+    app.update();
+    assert_eq!(
+        app.world().get::<FarFromOrigin>(emitter).unwrap().distance,
+        30_000.0
+    );
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::{EmitterData, EmitterTime};
+use bevy_sprinkles::runtime::EmitterRuntime;
+use bevy_sprinkles::test_utils::update_particle_time;
+
+const STEP: Duration = Duration::from_millis(100);
+const LIFETIME: f32 = 1.0;
+
+fn create_test_app() -> App {
+    let mut app = common::create_test_app(STEP);
+    app.add_systems(Update, update_particle_time);
+    app
+}
+
+fn spawn_emitter(app: &mut App, freeze_on_complete: bool) -> Entity {
+    let emitter = EmitterData {
+        time: EmitterTime {
+            lifetime: LIFETIME,
+            one_shot: true,
+            ..Default::default()
+        },
+        freeze_on_complete,
+        ..Default::default()
+    };
+    common::spawn_system(app, vec![emitter]).1[0]
+}
+
+fn runtime(app: &App, emitter: Entity) -> &EmitterRuntime {
+    app.world().get::<EmitterRuntime>(emitter).unwrap()
+}
+
+/// Ages particles through the steps queued this frame, mirroring `update_particle` in
+/// `particle_simulate.wgsl`, and spawns one particle per step during the first cycle.
+fn simulate(app: &App, emitter: Entity, ages: &mut Vec<f32>) {
+    for step in &runtime(app, emitter).simulation_steps {
+        ages.retain(|&age| age < LIFETIME);
+        for age in ages.iter_mut() {
+            *age = (*age + step.delta_time).min(LIFETIME);
+        }
+        if step.cycle == 0 && step.prev_system_time < LIFETIME {
+            ages.push(0.0);
+        }
+    }
+}
+
+#[test]
+fn test_frozen_emitter_keeps_its_particles() {
+    let mut app = create_test_app();
+    let emitter = spawn_emitter(&mut app, true);
+    let mut ages = Vec::new();
+
+    for _ in 0..20 {
+        app.update();
+        simulate(&app, emitter, &mut ages);
+    }
+    let completed = runtime(&app, emitter);
+    assert!(completed.one_shot_completed);
+    assert!(!completed.emitting);
+    assert_eq!(completed.system_time, LIFETIME);
+    assert_eq!(completed.cycle, 0);
+
+    let active = ages.len();
+    assert!(active > 0);
+    for _ in 0..20 {
+        app.update();
+        assert!(runtime(&app, emitter).simulation_steps.is_empty());
+        simulate(&app, emitter, &mut ages);
+        assert_eq!(ages.len(), active);
+    }
+}
+
+#[test]
+fn test_unfrozen_emitter_lets_particles_die() {
+    let mut app = create_test_app();
+    let emitter = spawn_emitter(&mut app, false);
+    let mut ages = Vec::new();
+
+    for _ in 0..40 {
+        app.update();
+        simulate(&app, emitter, &mut ages);
+    }
+    assert!(runtime(&app, emitter).one_shot_completed);
+    assert!(ages.is_empty());
+}
+
+#[test]
+fn test_play_resumes_frozen_emitter() {
+    let mut app = create_test_app();
+    let emitter = spawn_emitter(&mut app, true);
+    for _ in 0..20 {
+        app.update();
+    }
+
+    app.world_mut()
+        .get_mut::<EmitterRuntime>(emitter)
+        .unwrap()
+        .play();
+    app.update();
+
+    let runtime = runtime(&app, emitter);
+    assert!(runtime.emitting);
+    assert_eq!(runtime.cycle, 1);
+    assert!(runtime.system_time < LIFETIME);
+    assert!(!runtime.simulation_steps.is_empty());
+}
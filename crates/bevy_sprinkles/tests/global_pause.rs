@@ -0,0 +1,83 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::EmitterData;
+use bevy_sprinkles::runtime::{EmitterRuntime, ParticlesGlobalControl};
+use bevy_sprinkles::test_utils::update_particle_time;
+
+const STEP: Duration = Duration::from_millis(100);
+
+fn create_test_app() -> App {
+    let mut app = common::create_test_app(STEP);
+    app.add_systems(Update, update_particle_time);
+    app
+}
+
+fn spawn_system(app: &mut App) -> Entity {
+    common::spawn_system(app, vec![EmitterData::default()]).1[0]
+}
+
+fn runtime(app: &App, emitter: Entity) -> &EmitterRuntime {
+    app.world().get::<EmitterRuntime>(emitter).unwrap()
+}
+
+fn set_paused(app: &mut App, paused: bool) {
+    app.world_mut()
+        .resource_mut::<ParticlesGlobalControl>()
+        .paused = paused;
+}
+
+#[test]
+fn test_global_pause_halts_every_system() {
+    let mut app = create_test_app();
+    let emitters = [spawn_system(&mut app), spawn_system(&mut app)];
+
+    app.update();
+    app.update();
+    let before: Vec<f32> = emitters
+        .iter()
+        .map(|&e| runtime(&app, e).system_time)
+        .collect();
+    assert!(before.iter().all(|&t| t > 0.0));
+
+    set_paused(&mut app, true);
+    for _ in 0..3 {
+        app.update();
+    }
+    for (&emitter, &time) in emitters.iter().zip(&before) {
+        let runtime = runtime(&app, emitter);
+        assert_eq!(runtime.system_time, time);
+        assert!(runtime.simulation_steps.is_empty());
+    }
+
+    set_paused(&mut app, false);
+    app.update();
+    for (&emitter, &time) in emitters.iter().zip(&before) {
+        assert!(runtime(&app, emitter).system_time > time);
+    }
+}
+
+#[test]
+fn test_global_pause_keeps_clear_requests() {
+    let mut app = create_test_app();
+    let emitter = spawn_system(&mut app);
+    app.update();
+    app.update();
+    let time = runtime(&app, emitter).system_time;
+
+    set_paused(&mut app, true);
+    app.world_mut()
+        .get_mut::<EmitterRuntime>(emitter)
+        .unwrap()
+        .clear_requested = true;
+    app.update();
+
+    let runtime = runtime(&app, emitter);
+    assert_eq!(runtime.system_time, time);
+    assert_eq!(runtime.simulation_steps.len(), 1);
+    assert!(runtime.simulation_steps[0].clear_requested);
+    assert_eq!(runtime.simulation_steps[0].delta_time, 0.0);
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::light::NotShadowCaster;
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::EmitterData;
+use bevy_sprinkles::runtime::{EmitterMeshEntity, ParticleSystem3D, ParticlesGlobalControl};
+use bevy_sprinkles::test_utils::{setup_particle_systems, sync_particle_shadows};
+
+fn create_test_app(shadows_enabled: bool) -> App {
+    let mut app = common::create_test_app(Duration::from_millis(16));
+    app.insert_resource(ParticlesGlobalControl {
+        shadows_enabled,
+        ..Default::default()
+    })
+    .add_systems(
+        Update,
+        (
+            setup_particle_systems,
+            sync_particle_shadows.after(setup_particle_systems),
+        ),
+    );
+    app
+}
+
+fn spawn_system(app: &mut App, shadow_casters: &[bool]) {
+    let emitters = shadow_casters
+        .iter()
+        .map(|&shadow_caster| {
+            let mut emitter = EmitterData::default();
+            emitter.draw_pass.shadow_caster = shadow_caster;
+            emitter
+        })
+        .collect();
+    let handle = common::add_asset(app, emitters);
+    app.world_mut().spawn(ParticleSystem3D::new(handle));
+}
+
+/// Returns how many particle meshes cast shadows, out of all particle meshes.
+fn shadow_casting_meshes(app: &mut App) -> (usize, usize) {
+    let mut query = app
+        .world_mut()
+        .query_filtered::<Has<NotShadowCaster>, With<EmitterMeshEntity>>();
+    let meshes: Vec<bool> = query.iter(app.world()).collect();
+    let casting = meshes.iter().filter(|&&not_caster| !not_caster).count();
+    (casting, meshes.len())
+}
+
+#[test]
+fn test_shadows_follow_emitters_by_default() {
+    let mut app = create_test_app(true);
+    spawn_system(&mut app, &[true, false]);
+    app.update();
+
+    assert_eq!(shadow_casting_meshes(&mut app), (1, 2));
+}
+
+#[test]
+fn test_disabled_shadows_are_not_cast_by_spawned_meshes() {
+    let mut app = create_test_app(false);
+    spawn_system(&mut app, &[true, true]);
+    app.update();
+
+    assert_eq!(shadow_casting_meshes(&mut app), (0, 2));
+}
+
+#[test]
+fn test_toggling_shadows_updates_existing_meshes() {
+    let mut app = create_test_app(true);
+    spawn_system(&mut app, &[true, false]);
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ParticlesGlobalControl>()
+        .shadows_enabled = false;
+    app.update();
+    assert_eq!(shadow_casting_meshes(&mut app), (0, 2));
+
+    app.world_mut()
+        .resource_mut::<ParticlesGlobalControl>()
+        .shadows_enabled = true;
+    app.update();
+    assert_eq!(shadow_casting_meshes(&mut app), (1, 2));
+}
@@ -0,0 +1,77 @@
+use std::path::Path;
+
+use bevy::prelude::*;
+use bevy_sprinkles::asset::godot::{GodotImportError, import_process_material};
+use bevy_sprinkles::asset::{EmissionShape, Range, SolidOrGradientColor};
+
+fn load_fixture(name: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name);
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn test_import_maps_emitter_fields() {
+    let import = import_process_material(&load_fixture("godot_sparks.tres"), "Sparks").unwrap();
+    assert_eq!(import.asset.name, "Sparks");
+    assert_eq!(import.asset.emitters.len(), 1);
+    let emitter = &import.asset.emitters[0];
+
+    assert_eq!(emitter.accelerations.gravity, Vec3::new(0.0, -3.0, 0.0));
+    assert_eq!(emitter.velocities.initial_direction, Vec3::Y);
+    assert_eq!(emitter.velocities.spread, 20.0);
+    assert_eq!(emitter.velocities.flatness, 0.25);
+    assert_eq!(emitter.velocities.initial_velocity, Range::new(4.0, 6.0));
+    assert_eq!(emitter.scale.range, Range::new(0.5, 1.5));
+    assert_eq!(
+        emitter.colors.initial_color,
+        SolidOrGradientColor::solid([1.0, 0.9, 0.7, 1.0])
+    );
+    assert_eq!(
+        emitter.emission.shape,
+        EmissionShape::Ring {
+            axis: Vec3::Y,
+            height: 0.5,
+            radius: 2.0,
+            inner_radius: 1.5,
+            radial_bias: 0.0,
+        }
+    );
+}
+
+#[test]
+fn test_import_maps_scale_curve_and_color_ramp() {
+    let import = import_process_material(&load_fixture("godot_sparks.tres"), "Sparks").unwrap();
+    let emitter = &import.asset.emitters[0];
+
+    let curve = emitter.scale.scale_over_lifetime.as_ref().unwrap();
+    assert_eq!(curve.range, Range::new(0.0, 2.0));
+    assert_eq!(curve.points.len(), 3);
+    assert_eq!(curve.points[0].value, 1.0);
+    assert_eq!(curve.points[1].position, 0.5);
+    assert_eq!(curve.points[1].value, 0.5);
+    assert_eq!(curve.points[2].value, 0.0);
+
+    let ramp = &emitter.colors.color_over_lifetime;
+    assert_eq!(ramp.stops.len(), 3);
+    assert_eq!(ramp.stops[1].position, 0.6);
+    assert_eq!(ramp.stops[1].color, [1.0, 0.3, 0.0, 1.0]);
+    assert_eq!(ramp.stops[2].color, [0.2, 0.2, 0.2, 0.0]);
+}
+
+#[test]
+fn test_unmapped_properties_are_reported() {
+    let import = import_process_material(&load_fixture("godot_sparks.tres"), "Sparks").unwrap();
+    assert_eq!(import.unmapped, ["turbulence_enabled", "sub_emitter_mode"]);
+}
+
+#[test]
+fn test_missing_process_material_is_an_error() {
+    let source = "[gd_resource type=\"Gradient\" format=3]\n\n[resource]\noffsets = PackedFloat32Array(0, 1)\n";
+    assert!(matches!(
+        import_process_material(source, "Empty"),
+        Err(GodotImportError::MissingProcessMaterial)
+    ));
+}
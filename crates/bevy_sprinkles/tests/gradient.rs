@@ -0,0 +1,213 @@
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use bevy_sprinkles::asset::{
+    COLOR_CHANNEL_STEP, Gradient, GradientInterpolation, GradientStop, LifetimePreset,
+    step_color_channel,
+};
+use bevy_sprinkles::textures::GradientTextureCache;
+
+fn stop(position: f32, color: [f32; 4]) -> GradientStop {
+    GradientStop {
+        color,
+        position,
+        midpoint: 0.5,
+    }
+}
+
+fn three_stop_gradient() -> Gradient {
+    Gradient {
+        stops: vec![
+            stop(0.0, [1.0, 0.0, 0.0, 1.0]),
+            stop(0.25, [0.0, 1.0, 0.0, 1.0]),
+            stop(1.0, [0.0, 0.0, 1.0, 0.5]),
+        ],
+        interpolation: GradientInterpolation::Smoothstep,
+        hdr: false,
+        samples: None,
+    }
+}
+
+#[test]
+fn test_reversed_mirrors_positions_and_keeps_colors() {
+    let gradient = three_stop_gradient();
+    let reversed = gradient.reversed();
+
+    let positions: Vec<f32> = reversed.stops.iter().map(|s| s.position).collect();
+    assert_eq!(positions, vec![0.0, 0.75, 1.0]);
+    assert_eq!(reversed.stops[0].color, [0.0, 0.0, 1.0, 0.5]);
+    assert_eq!(reversed.stops[2].color, [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(reversed.interpolation, GradientInterpolation::Smoothstep);
+    let (a, b) = (reversed.sample(0.1), gradient.sample(0.9));
+    assert!(a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-5));
+}
+
+#[test]
+fn test_reversing_twice_yields_original() {
+    let gradient = three_stop_gradient();
+    assert_eq!(gradient.reversed().reversed(), gradient);
+}
+
+#[test]
+fn test_distribute_evenly_spaces_three_stops() {
+    let mut gradient = three_stop_gradient();
+    gradient.distribute_evenly();
+
+    let positions: Vec<f32> = gradient.stops.iter().map(|s| s.position).collect();
+    assert_eq!(positions, vec![0.0, 0.5, 1.0]);
+    assert_eq!(gradient.stops[1].color, [0.0, 1.0, 0.0, 1.0]);
+}
+
+fn emissive_gradient(hdr: bool) -> Gradient {
+    Gradient {
+        stops: vec![
+            stop(0.0, [4.0, 2.0, 0.5, 1.0]),
+            stop(1.0, [0.0, 0.0, 0.0, 1.0]),
+        ],
+        interpolation: GradientInterpolation::Linear,
+        hdr,
+        samples: None,
+    }
+}
+
+#[test]
+fn test_hdr_flag_changes_cache_key() {
+    assert_ne!(
+        emissive_gradient(false).cache_key(),
+        emissive_gradient(true).cache_key()
+    );
+}
+
+#[test]
+fn test_hdr_gradient_bakes_values_above_one() {
+    let mut cache = GradientTextureCache::default();
+    let mut images = Assets::<Image>::default();
+
+    let handle = cache.get_or_create(&emissive_gradient(true), &mut images);
+    let image = images.get(&handle).unwrap();
+    assert_eq!(image.texture_descriptor.format, TextureFormat::Rgba16Float);
+
+    let first = image.get_color_at(0, 0).unwrap().to_linear();
+    assert!(first.red > 1.0, "expected HDR red, got {}", first.red);
+    assert!(first.green > 1.0, "expected HDR green, got {}", first.green);
+    assert!((first.alpha - 1.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_sdr_gradient_bake_clamps_to_one() {
+    let mut cache = GradientTextureCache::default();
+    let mut images = Assets::<Image>::default();
+
+    let handle = cache.get_or_create(&emissive_gradient(false), &mut images);
+    let image = images.get(&handle).unwrap();
+    assert_eq!(
+        image.texture_descriptor.format,
+        TextureFormat::Rgba8UnormSrgb
+    );
+
+    let first = image.get_color_at(0, 0).unwrap().to_linear();
+    assert!(first.red <= 1.0);
+}
+
+#[test]
+fn test_gradient_preset_shapes_alpha() {
+    let gradient = Gradient::from_preset(LifetimePreset::Spike, [1.0, 0.5, 0.0]);
+
+    assert_eq!(gradient.stops.len(), 3);
+    assert_eq!(gradient.sample(0.0), [1.0, 0.5, 0.0, 0.0]);
+    assert_eq!(gradient.sample(0.1), [1.0, 0.5, 0.0, 1.0]);
+    assert_eq!(gradient.sample(1.0), [1.0, 0.5, 0.0, 0.0]);
+    assert_eq!(
+        Gradient::from_preset(LifetimePreset::EaseInOut, [1.0; 3]).interpolation,
+        GradientInterpolation::Smoothstep
+    );
+}
+
+#[test]
+fn test_remove_stop_keeps_two_stops() {
+    let mut gradient = three_stop_gradient();
+    assert!(gradient.remove_stop(1));
+    assert_eq!(gradient.stops.len(), 2);
+    assert!(!gradient.remove_stop(0));
+    assert_eq!(gradient.stops.len(), 2);
+}
+
+#[test]
+fn test_nudge_stop_keeps_stops_sorted() {
+    let mut gradient = three_stop_gradient();
+
+    gradient.nudge_stop(1, 0.01);
+    assert!((gradient.stops[1].position - 0.26).abs() < 1e-6);
+    gradient.nudge_stop(1, 1.0);
+    assert_eq!(gradient.stops[1].position, 0.999);
+    gradient.nudge_stop(0, 1.0);
+    assert_eq!(gradient.stops[0].position, 0.998);
+    gradient.nudge_stop(2, 1.0);
+    assert_eq!(gradient.stops[2].position, 1.0);
+}
+
+fn baked_halfway_color(midpoint: f32) -> LinearRgba {
+    let mut gradient = Gradient::default();
+    gradient.stops[0].midpoint = midpoint;
+
+    let mut cache = GradientTextureCache::default();
+    let mut images = Assets::<Image>::default();
+    let handle = cache.get_or_create(&gradient, &mut images);
+    let image = images.get(&handle).unwrap();
+    image
+        .get_color_at(image.width() / 2, 0)
+        .unwrap()
+        .to_linear()
+}
+
+#[test]
+fn test_midpoint_shifts_halfway_color_toward_next_stop() {
+    let even = baked_halfway_color(0.5);
+    let early = baked_halfway_color(0.25);
+
+    // the default gradient blends from black to white
+    assert!(early.red > even.red + 0.1);
+    assert!(early.green > even.green + 0.1);
+    assert!(early.blue > even.blue + 0.1);
+}
+
+#[test]
+fn test_midpoint_changes_cache_key() {
+    let mut gradient = Gradient::default();
+    let key = gradient.cache_key();
+    gradient.stops[0].midpoint = 0.25;
+    assert_ne!(gradient.cache_key(), key);
+}
+
+#[test]
+fn test_step_color_channel_moves_one_level_per_step() {
+    assert_eq!(step_color_channel(0.5, 1.0), 0.5 + 1.0 / 255.0);
+    assert_eq!(step_color_channel(0.5, -1.0), 0.5 - 1.0 / 255.0);
+    assert_eq!(step_color_channel(0.0, 3.0), 3.0 * COLOR_CHANNEL_STEP);
+
+    let mut value = 0.0;
+    for _ in 0..255 {
+        value = step_color_channel(value, 1.0);
+    }
+    assert!((value - 1.0).abs() < 1e-5, "{value}");
+}
+
+#[test]
+fn test_step_color_channel_clamps_to_unit_range() {
+    assert_eq!(step_color_channel(1.0, 1.0), 1.0);
+    assert_eq!(step_color_channel(0.0, -1.0), 0.0);
+    assert_eq!(step_color_channel(0.999, 10.0), 1.0);
+}
+
+#[test]
+fn test_to_samples_match_sample_at_even_positions() {
+    let gradient = three_stop_gradient();
+    let samples = gradient.to_samples(9);
+
+    assert_eq!(samples.len(), 9);
+    for (i, color) in samples.iter().enumerate() {
+        let t = i as f32 / 8.0;
+        assert_eq!(*color, gradient.sample(t), "sample at t={t}");
+    }
+    assert_eq!(samples[0], [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(samples[8], [0.0, 0.0, 1.0, 0.5]);
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::EmitterAccelerations;
+
+const DT: f32 = 1.0 / 60.0;
+
+/// Simulates a particle launched with `velocity` for one second, mirroring the gravity
+/// step in `update_particle` in `particle_simulate.wgsl`, and returns where it ends up.
+fn simulate(accelerations: &EmitterAccelerations, velocity: Vec3) -> Vec3 {
+    let gravity = accelerations.scaled_gravity();
+    let mut position = Vec3::ZERO;
+    let mut velocity = velocity;
+    for _ in 0..60 {
+        velocity += gravity * DT;
+        position += velocity * DT;
+    }
+    position
+}
+
+#[test]
+fn test_gravity_scale_round_trips_through_ron() {
+    let accelerations = EmitterAccelerations {
+        gravity_scale: 0.25,
+        ..Default::default()
+    };
+    let ron = ron::to_string(&accelerations).expect("failed to serialize accelerations");
+    let parsed: EmitterAccelerations = ron::from_str(&ron).expect("failed to parse accelerations");
+    assert_eq!(parsed, accelerations);
+
+    let plain = ron::to_string(&EmitterAccelerations::default()).unwrap();
+    assert!(!plain.contains("gravity_scale"));
+    let parsed: EmitterAccelerations = ron::from_str(&plain).unwrap();
+    assert_eq!(parsed.gravity_scale, 1.0);
+}
+
+#[test]
+fn test_zero_gravity_scale_ignores_gravity() {
+    let accelerations = EmitterAccelerations {
+        gravity: Vec3::new(0.0, -9.8, 2.0),
+        gravity_scale: 0.0,
+        ..Default::default()
+    };
+    assert_eq!(accelerations.scaled_gravity(), Vec3::ZERO);
+
+    let velocity = Vec3::new(1.0, 2.0, 0.0);
+    let end = simulate(&accelerations, velocity);
+    assert!(end.abs_diff_eq(velocity, 1e-4), "{end}");
+}
+
+#[test]
+fn test_gravity_scale_multiplies_gravity() {
+    let full = EmitterAccelerations::default();
+    let light = EmitterAccelerations {
+        gravity_scale: 0.5,
+        ..Default::default()
+    };
+    assert_eq!(light.scaled_gravity(), full.gravity * 0.5);
+
+    let fall = simulate(&full, Vec3::ZERO).y;
+    let light_fall = simulate(&light, Vec3::ZERO).y;
+    assert!(fall < 0.0);
+    assert!((light_fall - fall * 0.5).abs() < 1e-4);
+}
@@ -0,0 +1,100 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+use bevy_sprinkles::asset::{EmissionShape, EmitterData, EmitterEmission};
+use bevy_sprinkles::test_utils::{hash, hash_to_float};
+
+const SAMPLES: u32 = 256;
+
+/// Mirrors the `EMISSION_SHAPE_SPHERE` case of `get_emission_offset` and the scaling
+/// applied to it in `spawn_particle` in `particle_simulate.wgsl`.
+fn spawn_position(radius: f32, seed: u32, inherited_scale: Vec3) -> Vec3 {
+    let theta = 2.0 * PI * hash_to_float(seed);
+    let phi = (2.0 * hash_to_float(seed + 1) - 1.0).acos();
+    let r = hash_to_float(seed + 2).powf(1.0 / 3.0) * radius;
+    let pos = Vec3::new(
+        r * phi.sin() * theta.cos(),
+        r * phi.sin() * theta.sin(),
+        r * phi.cos(),
+    );
+    pos * inherited_scale
+}
+
+/// Largest distance from the emitter of the particles it spawns.
+fn spread(radius: f32, inherited_scale: Vec3) -> f32 {
+    (0..SAMPLES)
+        .map(|idx| spawn_position(radius, hash(1 + idx), inherited_scale).length())
+        .fold(0.0, f32::max)
+}
+
+fn emitter(inherit_scale: bool) -> EmitterData {
+    EmitterData {
+        inherit_scale,
+        emission: EmitterEmission {
+            shape: EmissionShape::Sphere { radius: 1.5 },
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Spawns an emitter under a parent with the given scale and returns the emitter's
+/// world scale once transforms have propagated.
+fn emitter_world_scale(parent_scale: Vec3) -> Vec3 {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, TransformPlugin));
+    let parent = app
+        .world_mut()
+        .spawn(Transform::from_scale(parent_scale))
+        .id();
+    let emitter = app
+        .world_mut()
+        .spawn((Transform::from_xyz(1.0, 0.0, 0.0), ChildOf(parent)))
+        .id();
+    app.update();
+    app.world().get::<GlobalTransform>(emitter).unwrap().scale()
+}
+
+#[test]
+fn test_inherit_scale_round_trips_through_ron() {
+    let data = emitter(true);
+    let ron = ron::to_string(&data).expect("failed to serialize emitter");
+    assert!(ron.contains("inherit_scale"));
+    let parsed: EmitterData = ron::from_str(&ron).expect("failed to parse emitter");
+    assert!(parsed.inherit_scale);
+
+    let plain = ron::to_string(&EmitterData::default()).unwrap();
+    assert!(!plain.contains("inherit_scale"));
+}
+
+#[test]
+fn test_scaled_parent_doubles_spread_with_inherit_scale() {
+    let unscaled = emitter_world_scale(Vec3::ONE);
+    let scaled = emitter_world_scale(Vec3::splat(2.0));
+    assert!(scaled.abs_diff_eq(Vec3::splat(2.0), 1e-5), "{scaled}");
+
+    let data = emitter(true);
+    let base = spread(1.5, data.inherited_scale(unscaled));
+    let doubled = spread(1.5, data.inherited_scale(scaled));
+    assert!(base > 1.0, "{base}");
+    assert!((doubled - 2.0 * base).abs() < 1e-4, "{doubled} vs {base}");
+
+    for idx in 0..SAMPLES {
+        let seed = hash(1 + idx);
+        let position = spawn_position(1.5, seed, data.inherited_scale(scaled));
+        let expected = spawn_position(1.5, seed, data.inherited_scale(unscaled)) * 2.0;
+        assert!(position.abs_diff_eq(expected, 1e-5));
+    }
+}
+
+#[test]
+fn test_scaled_parent_keeps_authored_spread_by_default() {
+    let scaled = emitter_world_scale(Vec3::splat(2.0));
+    let data = emitter(false);
+
+    assert_eq!(data.inherited_scale(scaled), Vec3::ONE);
+    assert_eq!(
+        spread(1.5, data.inherited_scale(scaled)),
+        spread(1.5, Vec3::ONE)
+    );
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::EmitterVelocities;
+use bevy_sprinkles::runtime::EmitterRuntime;
+use bevy_sprinkles::test_utils::update_emitter_velocities;
+
+const STEP: Duration = Duration::from_millis(100);
+
+fn create_test_app() -> App {
+    let mut app = common::create_test_app(STEP);
+    app.add_systems(Update, update_emitter_velocities);
+    app
+}
+
+fn move_emitter(app: &mut App, emitter: Entity, position: Vec3) {
+    *app.world_mut().get_mut::<GlobalTransform>(emitter).unwrap() =
+        GlobalTransform::from_translation(position);
+    app.update();
+}
+
+fn velocity(app: &App, emitter: Entity) -> Vec3 {
+    app.world().get::<EmitterRuntime>(emitter).unwrap().velocity
+}
+
+/// Mirrors `get_inherited_velocity` in `particle_simulate.wgsl`.
+fn inherited_velocity(velocities: &EmitterVelocities, emitter_velocity: Vec3) -> Vec3 {
+    let inherited = emitter_velocity * velocities.inherit_ratio;
+    let speed = inherited.length();
+    if speed <= velocities.inherit_velocity_max.max(0.0) {
+        return inherited;
+    }
+    inherited * (velocities.inherit_velocity_max.max(0.0) / speed)
+}
+
+#[test]
+fn test_emitter_velocity_follows_movement() {
+    let mut app = create_test_app();
+    let emitter = app
+        .world_mut()
+        .spawn((GlobalTransform::IDENTITY, EmitterRuntime::new(0, Some(0))))
+        .id();
+    app.update();
+    assert_eq!(velocity(&app, emitter), Vec3::ZERO);
+
+    for frame in 1..=3 {
+        move_emitter(&mut app, emitter, Vec3::X * frame as f32);
+        let measured = velocity(&app, emitter);
+        assert!((measured - Vec3::X * 10.0).length() < 1e-3, "{measured}");
+    }
+
+    move_emitter(&mut app, emitter, Vec3::X * 3.0);
+    assert!(velocity(&app, emitter).length() < 1e-3);
+}
+
+#[test]
+fn test_large_parent_motion_is_clamped() {
+    let mut app = create_test_app();
+    let emitter = app
+        .world_mut()
+        .spawn((GlobalTransform::IDENTITY, EmitterRuntime::new(0, Some(0))))
+        .id();
+    app.update();
+    move_emitter(&mut app, emitter, Vec3::new(0.0, 300.0, 400.0));
+
+    let velocities = EmitterVelocities {
+        inherit_ratio: 1.0,
+        inherit_velocity_max: 20.0,
+        ..Default::default()
+    };
+    let inherited = inherited_velocity(&velocities, velocity(&app, emitter));
+    assert!((inherited.length() - 20.0).abs() < 1e-3);
+    assert!((inherited.normalize() - Vec3::new(0.0, 0.6, 0.8)).length() < 1e-4);
+}
+
+#[test]
+fn test_slow_parent_motion_is_scaled_by_ratio() {
+    let velocities = EmitterVelocities {
+        inherit_ratio: 0.5,
+        ..Default::default()
+    };
+    assert_eq!(velocities.inherit_velocity_max, 50.0);
+    assert_eq!(
+        inherited_velocity(&velocities, Vec3::new(4.0, 0.0, 0.0)),
+        Vec3::new(2.0, 0.0, 0.0)
+    );
+}
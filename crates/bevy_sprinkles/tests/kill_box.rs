@@ -0,0 +1,72 @@
+use bevy::math::bounding::Aabb3d;
+use bevy::prelude::*;
+use bevy_sprinkles::asset::EmitterData;
+use bevy_sprinkles::runtime::ParticleData;
+
+fn moving_particle(position: Vec3, velocity: Vec3) -> ParticleData {
+    ParticleData {
+        position: [position.x, position.y, position.z, 1.0],
+        velocity: [velocity.x, velocity.y, velocity.z, 10.0],
+        custom: [0.0, 0.0, 0.0, f32::from_bits(ParticleData::FLAG_ACTIVE)],
+        ..Default::default()
+    }
+}
+
+/// Moves an active particle one frame and applies the kill box, mirroring
+/// `update_particle` in `particle_simulate.wgsl`.
+fn step(particle: &mut ParticleData, kill_box: Option<Aabb3d>, dt: f32) {
+    if !particle.is_active() {
+        return;
+    }
+    let velocity = Vec3::from_slice(&particle.velocity[..3]);
+    let position = Vec3::from_slice(&particle.position[..3]) + velocity * dt;
+    particle.position[..3].copy_from_slice(&position.to_array());
+
+    if let Some(kill_box) = kill_box {
+        let min = Vec3::from(kill_box.min);
+        let max = Vec3::from(kill_box.max);
+        if position.cmplt(min).any() || position.cmpgt(max).any() {
+            particle.custom[3] = f32::from_bits(0);
+        }
+    }
+}
+
+#[test]
+fn test_particle_dies_on_the_frame_it_leaves_the_kill_box() {
+    let kill_box = Some(Aabb3d::new(Vec3::ZERO, Vec3::splat(1.0)));
+    let dt = 0.1;
+    let mut particle = moving_particle(Vec3::new(0.75, 0.0, 0.0), Vec3::new(2.0, 0.0, 0.0));
+
+    step(&mut particle, kill_box, dt);
+    assert!(particle.is_active(), "particle is still inside at x = 0.95");
+
+    step(&mut particle, kill_box, dt);
+    assert!(
+        !particle.is_active(),
+        "particle crossed the boundary at x = 1.15"
+    );
+}
+
+#[test]
+fn test_particles_travel_freely_without_kill_box() {
+    let mut particle = moving_particle(Vec3::ZERO, Vec3::new(0.0, -50.0, 0.0));
+    for _ in 0..60 {
+        step(&mut particle, None, 1.0 / 60.0);
+    }
+    assert!(particle.is_active());
+}
+
+#[test]
+fn test_kill_box_round_trips_through_ron() {
+    let mut emitter = EmitterData::default();
+    assert!(!ron::to_string(&emitter).unwrap().contains("kill_box"));
+
+    emitter.kill_box = Some(Aabb3d::new(
+        Vec3::new(0.0, 2.0, 0.0),
+        Vec3::new(4.0, 2.0, 4.0),
+    ));
+    let ron = ron::to_string(&emitter).unwrap();
+    let decoded: EmitterData = ron::from_str(&ron).unwrap();
+
+    assert_eq!(decoded.kill_box, emitter.kill_box);
+}
@@ -0,0 +1,174 @@
+use bevy_sprinkles::asset::{Gradient, GradientInterpolation, GradientStop};
+use bevy_sprinkles::runtime::ParticleData;
+
+const END_COLOR: [f32; 4] = [1.0, 0.0, 0.0, 0.0];
+
+#[cfg_attr(not(feature = "readback"), allow(dead_code))]
+fn fade_gradient() -> Gradient {
+    Gradient {
+        stops: vec![
+            GradientStop {
+                color: [1.0, 1.0, 1.0, 1.0],
+                position: 0.0,
+                midpoint: 0.5,
+            },
+            GradientStop {
+                color: END_COLOR,
+                position: 1.0,
+                midpoint: 0.5,
+            },
+        ],
+        interpolation: GradientInterpolation::Linear,
+        hdr: false,
+        samples: None,
+    }
+}
+
+fn particle(lifetime: f32, age: f32) -> ParticleData {
+    ParticleData {
+        velocity: [0.0, 0.0, 0.0, lifetime],
+        custom: [age, 0.0, 0.0, f32::from_bits(ParticleData::FLAG_ACTIVE)],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_lifetime_fraction_uses_particle_lifetime() {
+    assert_eq!(particle(1.0, 0.5).lifetime_fraction(), 0.5);
+    assert_eq!(particle(1.0, 1.0).lifetime_fraction(), 1.0);
+    assert_eq!(particle(4.0, 1.0).lifetime_fraction(), 0.25);
+}
+
+// Runs the compute shader and reads the particles back every frame, so it needs a GPU.
+#[cfg(feature = "readback")]
+mod simulation {
+    use std::time::Duration;
+
+    use bevy::log::LogPlugin;
+    use bevy::prelude::*;
+    use bevy::render::gpu_readback::{Readback, ReadbackComplete};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy::window::ExitCondition;
+    use bevy::winit::WinitPlugin;
+
+    use bevy_sprinkles::SprinklesPlugin;
+    use bevy_sprinkles::asset::{
+        EmitterColors, EmitterData, EmitterEmission, EmitterTime, ParticleSystemAsset,
+        ParticleSystemDimension,
+    };
+    use bevy_sprinkles::runtime::{ParticleBufferHandle, ParticleData, ParticleSystem3D};
+
+    use super::{END_COLOR, fade_gradient};
+
+    const STEP: Duration = Duration::from_millis(16);
+    const MAX_FRAMES: usize = 600;
+
+    /// Particle buffer contents read back after each frame, oldest first.
+    #[derive(Resource, Default)]
+    struct Snapshots(Vec<Vec<ParticleData>>);
+
+    fn read_back_particles(
+        mut commands: Commands,
+        emitters: Query<(Entity, &ParticleBufferHandle), Without<Readback>>,
+    ) {
+        for (entity, buffers) in &emitters {
+            commands
+                .entity(entity)
+                .insert(Readback::buffer(buffers.particle_buffer.clone()));
+        }
+    }
+
+    fn store_snapshot(readback: On<ReadbackComplete>, mut snapshots: ResMut<Snapshots>) {
+        snapshots
+            .0
+            .push(readback.to_shader_type::<Vec<ParticleData>>());
+    }
+
+    fn create_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: None,
+                    exit_condition: ExitCondition::DontExit,
+                    ..default()
+                })
+                .disable::<WinitPlugin>()
+                .disable::<LogPlugin>(),
+            SprinklesPlugin::default(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(STEP))
+        .init_resource::<Snapshots>()
+        .add_systems(Update, read_back_particles)
+        .add_observer(store_snapshot);
+        app.finish();
+        app.cleanup();
+        app
+    }
+
+    #[test]
+    fn test_randomized_lifetimes_reach_gradient_end() {
+        let mut app = create_test_app();
+        let asset = ParticleSystemAsset::new(
+            "lifetime".into(),
+            ParticleSystemDimension::D3,
+            vec![EmitterData {
+                time: EmitterTime {
+                    lifetime: 1.0,
+                    lifetime_randomness: 0.9,
+                    explosiveness: 1.0,
+                    ..default()
+                },
+                emission: EmitterEmission {
+                    particles_amount: 64,
+                    ..default()
+                },
+                colors: EmitterColors {
+                    color_over_lifetime: fade_gradient(),
+                    ..default()
+                },
+                ..default()
+            }],
+            vec![],
+            None,
+        );
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<ParticleSystemAsset>>()
+            .add(asset);
+        app.world_mut().spawn(ParticleSystem3D::new(handle));
+
+        // the lifetimes of the particles seen dying
+        let mut deaths = Vec::new();
+        let mut checked = 0;
+        for _ in 0..MAX_FRAMES {
+            app.update();
+
+            let snapshots = &app.world().resource::<Snapshots>().0;
+            for pair in snapshots[checked.max(1) - 1..].windows(2) {
+                for (before, after) in pair[0].iter().zip(&pair[1]) {
+                    if !before.is_active() || after.is_active() {
+                        continue;
+                    }
+                    for (channel, expected) in after.color.iter().zip(END_COLOR) {
+                        assert!(
+                            (channel - expected).abs() < 1e-3,
+                            "particle with lifetime {} died with {:?}",
+                            after.lifetime(),
+                            after.color
+                        );
+                    }
+                    deaths.push(after.lifetime());
+                }
+            }
+            checked = snapshots.len();
+
+            let shortest = deaths.iter().copied().fold(f32::MAX, f32::min);
+            let longest = deaths.iter().copied().fold(0.0, f32::max);
+            if shortest < 0.3 && longest > 0.7 {
+                return;
+            }
+        }
+        panic!("no short- and long-lived particles died within {MAX_FRAMES} frames: {deaths:?}");
+    }
+}
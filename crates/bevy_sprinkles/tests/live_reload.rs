@@ -0,0 +1,101 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bevy_sprinkles::asset::versioning::VersionStatus;
+use bevy_sprinkles::asset::{
+    EmitterData, ParticleSystemAsset, ParticleSystemAssetLoaderError, ParticleSystemDimension,
+};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+fn emitter(name: &str) -> EmitterData {
+    EmitterData {
+        name: name.to_string(),
+        ..Default::default()
+    }
+}
+
+fn write_asset(path: &Path, asset: &ParticleSystemAsset) {
+    let contents = ron::ser::to_string_pretty(asset, ron::ser::PrettyConfig::default()).unwrap();
+    fs::write(path, contents).expect("failed to write asset file");
+}
+
+fn read_asset(path: &Path) -> ParticleSystemAsset {
+    let bytes = fs::read(path).expect("failed to read asset file");
+    let (asset, status) = ParticleSystemAsset::from_ron(&bytes).expect("failed to parse asset");
+    assert!(matches!(status, VersionStatus::Current));
+    asset
+}
+
+#[test]
+fn test_reload_reflects_external_file_change() {
+    let dir = std::env::temp_dir().join(format!("sprinkles-live-reload-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("fire.ron");
+
+    let original = ParticleSystemAsset::new(
+        "Fire".to_string(),
+        ParticleSystemDimension::D3,
+        vec![emitter("Flames")],
+        vec![],
+        None,
+    );
+    write_asset(&path, &original);
+    let loaded = read_asset(&path);
+    assert_eq!(loaded.name, "Fire");
+    assert_eq!(loaded.emitters.len(), 1);
+
+    // simulates someone editing the file in another program
+    let contents = fs::read_to_string(&path).unwrap();
+    let edited = contents
+        .replace("\"Fire\"", "\"Campfire\"")
+        .replace("\"Flames\"", "\"Embers\"");
+    assert_ne!(contents, edited);
+    fs::write(&path, edited).unwrap();
+
+    let reloaded = read_asset(&path);
+    assert_eq!(reloaded.name, "Campfire");
+    assert_eq!(reloaded.emitters.len(), 1);
+    assert_eq!(reloaded.emitters[0].name, "Embers");
+
+    let mut extended = reloaded.clone();
+    extended.emitters.push(emitter("Smoke"));
+    write_asset(&path, &extended);
+    let reloaded = read_asset(&path);
+    let names: Vec<&str> = reloaded.emitters.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, vec!["Embers", "Smoke"]);
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_from_ron_upgrades_outdated_version() {
+    let bytes = fs::read(fixture("outdated_particle_system.ron")).unwrap();
+    let (mut asset, status) = ParticleSystemAsset::from_ron(&bytes).unwrap();
+    assert!(matches!(status, VersionStatus::Outdated { .. }));
+    assert_eq!(asset.name, "Outdated Particle System");
+    assert!(matches!(
+        asset.try_upgrade_version(),
+        VersionStatus::Current
+    ));
+}
+
+#[test]
+fn test_from_ron_rejects_unreadable_files() {
+    let bytes = fs::read(fixture("unknown_version_particle_system.ron")).unwrap();
+    assert!(matches!(
+        ParticleSystemAsset::from_ron(&bytes),
+        Err(ParticleSystemAssetLoaderError::UnknownVersion)
+    ));
+
+    let bytes = fs::read(fixture("invalid_particle_system.ron")).unwrap();
+    assert!(matches!(
+        ParticleSystemAsset::from_ron(&bytes),
+        Err(ParticleSystemAssetLoaderError::Ron(_))
+    ));
+}
@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+use bevy::render::storage::ShaderStorageBuffer;
+
+use bevy_sprinkles::CustomPresetRegistry;
+use bevy_sprinkles::asset::{EmitterData, ParticleSystemAsset, ParticleSystemDimension};
+use bevy_sprinkles::runtime::{
+    EmitterEntity, ParticleBufferGrowth, ParticleMaterial, ParticleSystem3D,
+};
+use bevy_sprinkles::test_utils::setup_particle_systems;
+
+fn asset(mirror: BVec3) -> ParticleSystemAsset {
+    let mut asset = ParticleSystemAsset::new(
+        "Mirrored".to_string(),
+        ParticleSystemDimension::D3,
+        vec![EmitterData {
+            position: Vec3::new(2.0, 1.0, -3.0),
+            ..Default::default()
+        }],
+        vec![],
+        None,
+    );
+    asset.mirror = mirror;
+    asset
+}
+
+/// Mirrors a spawned particle's position and velocity, like `spawn_particle` in
+/// `particle_simulate.wgsl`.
+fn spawn(emission_pos: Vec3, velocity: Vec3, mirror: Vec3) -> (Vec3, Vec3) {
+    (emission_pos * mirror, velocity * mirror)
+}
+
+#[test]
+fn test_mirror_round_trips_through_ron() {
+    let mirrored = asset(BVec3::new(true, false, true));
+    let ron = ron::to_string(&mirrored).expect("failed to serialize asset");
+    let parsed: ParticleSystemAsset = ron::from_str(&ron).expect("failed to parse asset");
+    assert_eq!(parsed.mirror, BVec3::new(true, false, true));
+
+    let plain = ron::to_string(&asset(BVec3::FALSE)).unwrap();
+    assert!(!plain.contains("mirror"));
+    let parsed: ParticleSystemAsset = ron::from_str(&plain).unwrap();
+    assert_eq!(parsed.mirror, BVec3::FALSE);
+}
+
+#[test]
+fn test_mirroring_x_flips_spawned_x_positions() {
+    let mirror = asset(BVec3::new(true, false, false)).mirror_scale();
+    assert_eq!(mirror, Vec3::new(-1.0, 1.0, 1.0));
+
+    let (position, velocity) = spawn(Vec3::new(0.5, 0.25, 1.0), Vec3::new(3.0, 1.0, 0.0), mirror);
+    assert_eq!(position, Vec3::new(-0.5, 0.25, 1.0));
+    assert_eq!(velocity, Vec3::new(-3.0, 1.0, 0.0));
+
+    let unmirrored = asset(BVec3::FALSE).mirror_scale();
+    assert_eq!(
+        spawn(Vec3::new(0.5, 0.25, 1.0), Vec3::X, unmirrored).0,
+        Vec3::new(0.5, 0.25, 1.0)
+    );
+}
+
+#[test]
+fn test_mirroring_x_flips_emitter_translation() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<ParticleSystemAsset>()
+        .init_asset::<ShaderStorageBuffer>()
+        .init_asset::<Mesh>()
+        .init_asset::<ParticleMaterial>()
+        .init_resource::<ParticleBufferGrowth>()
+        .init_resource::<CustomPresetRegistry>()
+        .add_systems(Update, setup_particle_systems);
+
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<ParticleSystemAsset>>()
+        .add(asset(BVec3::new(true, false, false)));
+    app.world_mut().spawn(ParticleSystem3D::new(handle));
+    app.update();
+
+    let mut query = app
+        .world_mut()
+        .query_filtered::<&Transform, With<EmitterEntity>>();
+    let transform = query.single(app.world()).unwrap();
+    assert_eq!(transform.translation, Vec3::new(-2.0, 1.0, -3.0));
+}
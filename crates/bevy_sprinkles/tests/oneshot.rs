@@ -0,0 +1,244 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::render::gpu_readback::ReadbackComplete;
+
+use bevy_sprinkles::asset::{EmitterData, EmitterTime, SubEmitterConfig};
+use bevy_sprinkles::runtime::{DespawnOnFinish, EmitterRuntime, EmitterStatsBuffer};
+use bevy_sprinkles::test_utils::{
+    despawn_finished_particle_systems, read_emitter_stats, sync_emitter_stats_readbacks,
+};
+
+const STEP: Duration = Duration::from_millis(100);
+
+fn create_test_app() -> App {
+    let mut app = common::create_test_app(STEP);
+    app.add_systems(Update, despawn_finished_particle_systems)
+        .add_observer(read_emitter_stats);
+    app
+}
+
+fn emitter(one_shot: bool) -> EmitterData {
+    EmitterData {
+        time: EmitterTime {
+            lifetime: 1.0,
+            one_shot,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn spawn_system(app: &mut App, emitters: Vec<EmitterData>) -> (Entity, Vec<Entity>) {
+    let (system, emitters) = common::spawn_system(app, emitters);
+    app.world_mut()
+        .entity_mut(system)
+        .insert(DespawnOnFinish::default());
+    (system, emitters)
+}
+
+fn chained_emitter(target: Option<usize>) -> EmitterData {
+    EmitterData {
+        sub_emitter: target.map(|target_emitter| SubEmitterConfig {
+            target_emitter,
+            ..Default::default()
+        }),
+        ..emitter(true)
+    }
+}
+
+fn track_stats(app: &mut App, emitters: &[Entity]) {
+    for &entity in emitters {
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(EmitterStatsBuffer {
+                buffer: Handle::default(),
+            });
+    }
+}
+
+/// Delivers stats for every emitter as if they were read back from the GPU.
+fn report_alive(app: &mut App, emitters: &[Entity], alive_particles: u32) {
+    for &entity in emitters {
+        let data = alive_particles.to_le_bytes().to_vec();
+        app.world_mut().trigger(ReadbackComplete { entity, data });
+    }
+}
+
+fn complete(app: &mut App, emitters: &[Entity]) {
+    for &entity in emitters {
+        let mut runtime = app.world_mut().get_mut::<EmitterRuntime>(entity).unwrap();
+        runtime.emitting = false;
+        runtime.one_shot_completed = true;
+    }
+}
+
+#[test]
+fn test_oneshot_system_despawns_after_completion() {
+    let mut app = create_test_app();
+    let (system, emitters) = spawn_system(&mut app, vec![emitter(true), emitter(true)]);
+
+    for _ in 0..5 {
+        app.update();
+    }
+    assert!(
+        app.world().get_entity(system).is_ok(),
+        "system should stay alive while emitters are running"
+    );
+
+    complete(&mut app, &emitters);
+    app.update();
+    assert!(
+        app.world().get_entity(system).is_ok(),
+        "system should wait for the last particles to die"
+    );
+
+    for _ in 0..15 {
+        app.update();
+    }
+    assert!(
+        app.world().get_entity(system).is_err(),
+        "system should be despawned once its particles have died"
+    );
+}
+
+#[test]
+fn test_oneshot_system_with_looping_emitter_is_kept() {
+    let mut app = create_test_app();
+    let (system, emitters) = spawn_system(&mut app, vec![emitter(true), emitter(false)]);
+
+    complete(&mut app, &emitters);
+    for _ in 0..30 {
+        app.update();
+    }
+
+    assert!(app.world().get_entity(system).is_ok());
+    assert!(app.world().get::<DespawnOnFinish>(system).is_none());
+}
+
+#[test]
+fn test_oneshot_system_waits_for_reported_particles_to_die() {
+    let mut app = create_test_app();
+    let (system, emitters) = spawn_system(&mut app, vec![emitter(true), emitter(true)]);
+    track_stats(&mut app, &emitters);
+
+    complete(&mut app, &emitters);
+    // well past the emitters' lifetime, but the GPU still reports live particles
+    for _ in 0..30 {
+        report_alive(&mut app, &emitters, 3);
+        app.update();
+    }
+    assert!(
+        app.world().get_entity(system).is_ok(),
+        "system should stay alive while particles are reported alive"
+    );
+
+    report_alive(&mut app, &emitters, 0);
+    app.update();
+    assert!(
+        app.world().get_entity(system).is_ok(),
+        "system should wait for readbacks to catch up"
+    );
+
+    for _ in 0..5 {
+        report_alive(&mut app, &emitters, 0);
+        app.update();
+    }
+    assert!(
+        app.world().get_entity(system).is_err(),
+        "system should be despawned once no particles are reported alive"
+    );
+}
+
+#[test]
+fn test_oneshot_system_without_stats_waits_for_whole_sub_emitter_chain() {
+    let mut app = create_test_app();
+    let (system, emitters) = spawn_system(
+        &mut app,
+        vec![
+            chained_emitter(Some(1)),
+            chained_emitter(Some(2)),
+            chained_emitter(None),
+        ],
+    );
+
+    complete(&mut app, &emitters);
+    // each link of the chain lives for one second
+    for _ in 0..25 {
+        app.update();
+    }
+    assert!(
+        app.world().get_entity(system).is_ok(),
+        "system should wait for the last sub-emitter in the chain"
+    );
+
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(app.world().get_entity(system).is_err());
+}
+
+#[test]
+fn test_oneshot_system_with_stats_outlives_sub_emitter_chain_lifetime() {
+    let mut app = create_test_app();
+    let (system, emitters) = spawn_system(
+        &mut app,
+        vec![
+            chained_emitter(Some(1)),
+            chained_emitter(Some(2)),
+            chained_emitter(None),
+        ],
+    );
+    track_stats(&mut app, &emitters);
+
+    complete(&mut app, &emitters);
+    // the chain's lifetimes add up to three seconds, but particles are still reported alive
+    for _ in 0..60 {
+        report_alive(&mut app, &emitters[2..], 1);
+        report_alive(&mut app, &emitters[..2], 0);
+        app.update();
+    }
+    assert!(
+        app.world().get_entity(system).is_ok(),
+        "system should not fall back to the lifetime heuristic while stats are tracked"
+    );
+
+    for _ in 0..5 {
+        report_alive(&mut app, &emitters, 0);
+        app.update();
+    }
+    assert!(app.world().get_entity(system).is_err());
+}
+
+#[test]
+fn test_only_oneshot_systems_track_stats() {
+    let mut app = create_test_app();
+    app.add_systems(Update, sync_emitter_stats_readbacks);
+    let (oneshot, oneshot_emitters) = spawn_system(&mut app, vec![emitter(true)]);
+    let (_, looping_emitters) = common::spawn_system(&mut app, vec![emitter(false)]);
+    app.update();
+
+    assert!(
+        app.world()
+            .get::<EmitterStatsBuffer>(oneshot_emitters[0])
+            .is_some()
+    );
+    assert!(
+        app.world()
+            .get::<EmitterStatsBuffer>(looping_emitters[0])
+            .is_none(),
+        "emitters of systems that aren't despawned automatically shouldn't count particles"
+    );
+
+    app.world_mut()
+        .entity_mut(oneshot)
+        .remove::<DespawnOnFinish>();
+    app.update();
+    assert!(
+        app.world()
+            .get::<EmitterStatsBuffer>(oneshot_emitters[0])
+            .is_none()
+    );
+}
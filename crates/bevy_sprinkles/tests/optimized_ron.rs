@@ -0,0 +1,65 @@
+use bevy_sprinkles::asset::{
+    CurveTexture, EmitterData, EmitterScale, LifetimePreset, ParticleSystemAsset,
+    ParticleSystemDimension,
+};
+
+fn asset(emitters: Vec<EmitterData>) -> ParticleSystemAsset {
+    ParticleSystemAsset::new(
+        "Optimized".to_string(),
+        ParticleSystemDimension::D3,
+        emitters,
+        vec![],
+        None,
+    )
+}
+
+fn scaled_emitter() -> EmitterData {
+    EmitterData {
+        scale: EmitterScale {
+            scale_over_lifetime: Some(CurveTexture::from_preset(LifetimePreset::FadeOut)),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_equal_assets_serialize_to_identical_bytes() {
+    let first = asset(vec![scaled_emitter(), EmitterData::default()]);
+    let second = asset(vec![scaled_emitter(), EmitterData::default()]);
+    let first_ron = first.to_optimized_ron().expect("failed to serialize asset");
+    assert_eq!(
+        first_ron.as_bytes(),
+        second.to_optimized_ron().unwrap().as_bytes()
+    );
+    assert!(!first_ron.contains('\r'));
+
+    let (parsed, _) = ParticleSystemAsset::from_ron(first_ron.as_bytes()).unwrap();
+    assert_eq!(parsed.to_optimized_ron().unwrap(), first_ron);
+}
+
+#[test]
+fn test_default_emitter_produces_minimal_output() {
+    let ron = asset(vec![EmitterData::default()])
+        .to_optimized_ron()
+        .unwrap();
+    assert!(!ron.contains("colliders"));
+
+    let start = ron.find("emitters:").unwrap();
+    let emitters = &ron[start + "emitters:".len()..];
+    let emitters = &emitters[..emitters.find(']').unwrap()];
+    let fields: Vec<&str> = emitters
+        .lines()
+        .filter_map(|line| line.split_once(':').map(|(field, _)| field.trim()))
+        .collect();
+    assert_eq!(fields, vec!["name"], "{emitters}");
+}
+
+#[test]
+fn test_default_curve_point_settings_are_omitted() {
+    let ron = asset(vec![scaled_emitter()]).to_optimized_ron().unwrap();
+    assert!(ron.contains("points"));
+    for field in ["mode", "tension", "easing"] {
+        assert!(!ron.contains(field), "{field} in {ron}");
+    }
+}
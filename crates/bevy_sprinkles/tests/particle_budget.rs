@@ -0,0 +1,73 @@
+use bevy_sprinkles::asset::{
+    EmitterData, ParticleSystemAsset, ParticleSystemDimension, SubEmitterConfig,
+};
+
+fn emitter(amount: u32, spawn_weight: f32) -> EmitterData {
+    let mut emitter = EmitterData {
+        spawn_weight,
+        ..Default::default()
+    };
+    emitter.emission.particles_amount = amount;
+    emitter
+}
+
+fn asset(emitters: Vec<EmitterData>) -> ParticleSystemAsset {
+    ParticleSystemAsset::new(
+        "Test".to_string(),
+        ParticleSystemDimension::D3,
+        emitters,
+        vec![],
+        None,
+    )
+}
+
+/// Returns whether a slot may respawn at the given ratio, mirroring
+/// `is_budgeted_slot` in `particle_simulate.wgsl`.
+fn is_budgeted_slot(idx: u32, spawn_ratio: f32) -> bool {
+    ((idx + 1) as f32 * spawn_ratio).floor() > (idx as f32 * spawn_ratio).floor()
+}
+
+fn spawned_slots(amount: u32, budgeted: u32) -> usize {
+    let spawn_ratio = (budgeted as f32 / amount as f32).min(1.0);
+    (0..amount)
+        .filter(|&idx| is_budgeted_slot(idx, spawn_ratio))
+        .count()
+}
+
+#[test]
+fn test_budget_is_split_by_weight() {
+    let asset = asset(vec![emitter(1000, 1.0), emitter(1000, 3.0)]);
+    let amounts = asset.budgeted_amounts(400);
+    assert_eq!(amounts, vec![100, 300]);
+
+    let spawns: Vec<usize> = amounts.iter().map(|&a| spawned_slots(1000, a)).collect();
+    assert_eq!(spawns, vec![100, 300]);
+    assert_eq!(spawns[1], spawns[0] * 3);
+}
+
+#[test]
+fn test_budget_above_total_keeps_full_amounts() {
+    let asset = asset(vec![emitter(50, 1.0), emitter(80, 3.0)]);
+    assert_eq!(asset.budgeted_amounts(1000), vec![50, 80]);
+    assert_eq!(spawned_slots(80, 80), 80);
+}
+
+#[test]
+fn test_unused_share_is_redistributed() {
+    let asset = asset(vec![emitter(10, 1.0), emitter(1000, 1.0)]);
+    assert_eq!(asset.budgeted_amounts(200), vec![10, 190]);
+}
+
+#[test]
+fn test_sub_emitter_targets_and_disabled_emitters_are_not_budgeted() {
+    let mut source = emitter(100, 1.0);
+    source.sub_emitter = Some(SubEmitterConfig {
+        target_emitter: 1,
+        ..Default::default()
+    });
+    let mut disabled = emitter(100, 1.0);
+    disabled.enabled = false;
+
+    let asset = asset(vec![source, emitter(64, 1.0), disabled]);
+    assert_eq!(asset.budgeted_amounts(40), vec![40, 64, 0]);
+}
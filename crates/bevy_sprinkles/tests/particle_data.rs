@@ -0,0 +1,91 @@
+use bevy::render::render_resource::ShaderType;
+
+use bevy_sprinkles::asset::{EmitterData, ParticleUserData};
+use bevy_sprinkles::bake::particles_from_bytes;
+use bevy_sprinkles::runtime::ParticleData;
+
+/// Six `vec4<f32>` fields, matching `Particle` in `common.wgsl`.
+const PARTICLE_SIZE: usize = 6 * 16;
+
+#[test]
+fn test_particle_data_matches_shader_layout() {
+    assert_eq!(size_of::<ParticleData>(), PARTICLE_SIZE);
+    assert_eq!(ParticleData::min_size().get() as usize, PARTICLE_SIZE);
+}
+
+/// Returns each `name: type` field of the first `struct Particle` in `wgsl`, without comments.
+fn particle_struct_fields(wgsl: &str) -> Vec<String> {
+    wgsl.lines()
+        .skip_while(|line| !line.starts_with("struct Particle {"))
+        .skip(1)
+        .take_while(|line| !line.starts_with('}'))
+        .map(|line| line.split("//").next().unwrap().trim().to_string())
+        .collect()
+}
+
+#[test]
+fn test_wgsl_struct_matches_rust_layout() {
+    let wgsl = ParticleData::wgsl_struct();
+    println!("{wgsl}");
+
+    assert!(wgsl.starts_with(&format!("// {} bytes", size_of::<ParticleData>())));
+    let mut expected_offset = 0;
+    for (line, (name, offset)) in wgsl.lines().skip(2).zip(ParticleData::FIELDS) {
+        assert_eq!(offset, expected_offset, "`{name}` is not tightly packed");
+        assert!(
+            line.trim_start()
+                .starts_with(&format!("{name}: vec4<f32>,"))
+        );
+        assert!(line.ends_with(&format!("// offset {offset}, size 16")));
+        expected_offset += 16;
+    }
+    assert_eq!(expected_offset, size_of::<ParticleData>());
+}
+
+#[test]
+fn test_wgsl_struct_matches_common_shader() {
+    let common = include_str!("../src/shaders/common.wgsl");
+    assert_eq!(
+        particle_struct_fields(&ParticleData::wgsl_struct()),
+        particle_struct_fields(common)
+    );
+}
+
+#[test]
+fn test_user_data_round_trips_through_buffer_bytes() {
+    let particles = vec![
+        ParticleData {
+            user_data: [1.0, -2.5, 3.25, 42.0],
+            ..Default::default()
+        },
+        ParticleData {
+            alignment_dir: [0.0, 1.0, 0.0, 0.5],
+            user_data: [0.125, 0.0, -1.0, 7.0],
+            ..Default::default()
+        },
+    ];
+    let bytes = bytemuck::cast_slice::<ParticleData, u8>(&particles);
+    assert_eq!(bytes.len(), 2 * PARTICLE_SIZE);
+
+    let decoded = particles_from_bytes(bytes);
+    assert_eq!(decoded[0].user_data, [1.0, -2.5, 3.25, 42.0]);
+    assert_eq!(decoded[1].user_data, [0.125, 0.0, -1.0, 7.0]);
+    assert_eq!(decoded[1].alignment_dir, [0.0, 1.0, 0.0, 0.5]);
+}
+
+#[test]
+fn test_user_data_config_round_trips_through_ron() {
+    let emitter = EmitterData {
+        user_data: Some(ParticleUserData {
+            min: [0.0, 0.0, 0.0, 1.0],
+            max: [1.0, 0.5, 0.0, 1.0],
+        }),
+        ..Default::default()
+    };
+    let ron = ron::to_string(&emitter).unwrap();
+    let parsed: EmitterData = ron::from_str(&ron).unwrap();
+    assert_eq!(parsed.user_data, emitter.user_data);
+
+    let ron = ron::to_string(&EmitterData::default()).unwrap();
+    assert!(!ron.contains("user_data"));
+}
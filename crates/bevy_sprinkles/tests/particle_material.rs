@@ -0,0 +1,130 @@
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy_sprinkles::asset::{BLOOM_HEADROOM, SerializableAlphaMode, StandardParticleMaterial};
+
+fn asset_server() -> AssetServer {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+    app.world().resource::<AssetServer>().clone()
+}
+
+#[test]
+fn test_glow_material_fields() {
+    let material = StandardParticleMaterial::glow([1.0, 0.5, 0.25, 1.0], 4.0);
+
+    assert!(material.unlit);
+    assert_eq!(material.alpha_mode, SerializableAlphaMode::Premultiplied);
+    assert_eq!(material.emissive, [4.0, 2.0, 1.0, 1.0]);
+    assert_eq!(material.base_color, [0.0, 0.0, 0.0, 0.0]);
+}
+
+#[test]
+fn test_glow_material_leaves_other_fields_default() {
+    let material = StandardParticleMaterial::glow([1.0, 0.5, 0.25, 1.0], 4.0);
+
+    assert_eq!(
+        material,
+        StandardParticleMaterial {
+            base_color: [0.0, 0.0, 0.0, 0.0],
+            emissive: [4.0, 2.0, 1.0, 1.0],
+            alpha_mode: SerializableAlphaMode::Premultiplied,
+            unlit: true,
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn test_glow_material_cache_key() {
+    let default_key = StandardParticleMaterial::default().cache_key();
+    let glow = StandardParticleMaterial::glow([1.0, 0.5, 0.25, 1.0], 4.0);
+
+    assert_ne!(glow.cache_key(), default_key);
+    assert_ne!(
+        glow.cache_key(),
+        StandardParticleMaterial::glow([1.0, 0.5, 0.25, 1.0], 8.0).cache_key()
+    );
+    assert_eq!(
+        glow.cache_key(),
+        StandardParticleMaterial::glow([1.0, 0.5, 0.25, 1.0], 4.0).cache_key()
+    );
+}
+
+#[test]
+fn test_out_of_range_material_values_are_clamped() {
+    let material: StandardParticleMaterial =
+        ron::from_str("(metallic: 5.0, reflectance: -1.0, perceptual_roughness: 0.0)")
+            .expect("failed to parse material");
+    assert_eq!(material.metallic, 5.0);
+
+    let applied = material.to_standard_material(&asset_server());
+
+    assert_eq!(applied.metallic, 1.0);
+    assert_eq!(applied.reflectance, 0.0);
+    assert_eq!(applied.perceptual_roughness, 0.089);
+}
+
+#[test]
+fn test_depth_bias_round_trips_and_applies() {
+    let material = StandardParticleMaterial {
+        depth_bias: 12.5,
+        ..Default::default()
+    };
+
+    let serialized = ron::to_string(&material).expect("failed to serialize material");
+    assert!(serialized.contains("depth_bias"));
+    let parsed: StandardParticleMaterial =
+        ron::from_str(&serialized).expect("failed to parse material");
+    assert_eq!(parsed, material);
+
+    let default_serialized = ron::to_string(&StandardParticleMaterial::default()).unwrap();
+    assert!(!default_serialized.contains("depth_bias"));
+
+    assert_eq!(
+        material.to_standard_material(&asset_server()).depth_bias,
+        12.5
+    );
+}
+
+#[test]
+fn test_depth_bias_changes_cache_key() {
+    let default_key = StandardParticleMaterial::default().cache_key();
+    let biased = StandardParticleMaterial {
+        depth_bias: 1.0,
+        ..Default::default()
+    };
+
+    assert_ne!(biased.cache_key(), default_key);
+}
+
+fn luminance(color: [f32; 4]) -> f32 {
+    0.2126 * color[0] + 0.7152 * color[1] + 0.0722 * color[2]
+}
+
+#[test]
+fn test_bloom_material_exceeds_threshold() {
+    for threshold in [0.0, 0.5, 1.0, 4.0] {
+        for color in [
+            [1.0, 0.5, 0.25, 1.0],
+            [0.0, 0.0, 0.1, 1.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ] {
+            let material = StandardParticleMaterial::with_bloom(color, threshold);
+            let emissive = luminance(material.emissive);
+            assert!(
+                emissive > threshold,
+                "luminance {emissive} under threshold {threshold} for {color:?}"
+            );
+            assert!((emissive - threshold - BLOOM_HEADROOM).abs() < 1e-3);
+            assert_eq!(material.alpha_mode, SerializableAlphaMode::Premultiplied);
+        }
+    }
+}
+
+#[test]
+fn test_bloom_material_keeps_hue() {
+    let material = StandardParticleMaterial::with_bloom([1.0, 0.5, 0.25, 1.0], 2.0);
+    let [r, g, b, _] = material.emissive;
+    assert!((g / r - 0.5).abs() < 1e-5);
+    assert!((b / r - 0.25).abs() < 1e-5);
+}
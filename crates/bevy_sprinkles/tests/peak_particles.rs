@@ -0,0 +1,106 @@
+use bevy_sprinkles::asset::{
+    EmissionMode, EmitterData, EmitterEmission, EmitterTime, ParticleSystemAsset,
+    ParticleSystemDimension, SubEmitterConfig,
+};
+
+fn emitter(amount: u32, time: EmitterTime) -> EmitterData {
+    EmitterData {
+        time,
+        emission: EmitterEmission {
+            particles_amount: amount,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn asset(emitters: Vec<EmitterData>) -> ParticleSystemAsset {
+    ParticleSystemAsset::new(
+        "Test".to_string(),
+        ParticleSystemDimension::D3,
+        emitters,
+        vec![],
+        None,
+    )
+}
+
+/// Steady emission keeps `rate * mean lifetime` particles alive, where the rate is
+/// `particles_amount / lifetime` and `lifetime_randomness` shortens lifetimes by half
+/// of itself on average.
+fn analytic_peak(emitter: &EmitterData) -> f32 {
+    let time = &emitter.time;
+    let rate = emitter.emission.particles_amount as f32 / time.lifetime;
+    rate * time.lifetime * (1.0 - time.lifetime_randomness / 2.0)
+}
+
+fn assert_near_analytic(emitter: EmitterData) {
+    let expected = analytic_peak(&emitter);
+    let peak = asset(vec![emitter]).peak_particle_count(0) as f32;
+    assert!(
+        (peak - expected).abs() <= expected * 0.1,
+        "peak {peak}, expected about {expected}"
+    );
+}
+
+#[test]
+fn test_continuous_emission_peaks_at_rate_times_lifetime() {
+    let time = EmitterTime {
+        lifetime: 2.0,
+        ..Default::default()
+    };
+    assert_near_analytic(emitter(100, time.clone()));
+    assert_eq!(asset(vec![emitter(100, time)]).peak_particle_count(0), 100);
+}
+
+#[test]
+fn test_lifetime_randomness_lowers_the_peak() {
+    for lifetime_randomness in [0.25, 0.5, 0.8] {
+        assert_near_analytic(emitter(
+            500,
+            EmitterTime {
+                lifetime: 1.0,
+                lifetime_randomness,
+                fixed_seed: Some(7),
+                ..Default::default()
+            },
+        ));
+    }
+}
+
+#[test]
+fn test_explosive_emission_peaks_at_amount() {
+    let system = asset(vec![emitter(
+        64,
+        EmitterTime {
+            lifetime: 0.5,
+            lifetime_randomness: 0.9,
+            explosiveness: 1.0,
+            ..Default::default()
+        },
+    )]);
+    assert_eq!(system.peak_particle_count(0), 64);
+}
+
+#[test]
+fn test_event_driven_emitters_report_their_amount() {
+    let mut parent = emitter(10, EmitterTime::default());
+    parent.sub_emitter = Some(SubEmitterConfig {
+        target_emitter: 1,
+        ..Default::default()
+    });
+    let mut trail = emitter(30, EmitterTime::default());
+    trail.emission.mode = EmissionMode::PerDistance { spacing: 0.1 };
+    let system = asset(vec![parent, emitter(50, EmitterTime::default()), trail]);
+
+    assert_eq!(system.peak_particle_count(1), 50);
+    assert_eq!(system.peak_particle_count(2), 30);
+}
+
+#[test]
+fn test_disabled_and_missing_emitters_have_no_particles() {
+    let mut disabled = emitter(10, EmitterTime::default());
+    disabled.enabled = false;
+    let system = asset(vec![disabled]);
+    assert_eq!(system.peak_particle_count(0), 0);
+    assert_eq!(system.peak_particle_count(1), 0);
+}
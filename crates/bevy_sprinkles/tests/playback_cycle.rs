@@ -0,0 +1,79 @@
+use bevy_sprinkles::asset::{
+    EmitterData, EmitterTime, ParticleSystemAsset, ParticleSystemDimension,
+};
+use bevy_sprinkles::runtime::EmitterRuntime;
+
+fn delayed_time() -> EmitterTime {
+    EmitterTime {
+        lifetime: 1.0,
+        delay: 0.5,
+        delay_randomness: 0.25,
+        ..Default::default()
+    }
+}
+
+fn asset(time: EmitterTime) -> ParticleSystemAsset {
+    ParticleSystemAsset::new(
+        "Cycles".to_string(),
+        ParticleSystemDimension::D3,
+        vec![EmitterData {
+            time,
+            ..Default::default()
+        }],
+        vec![],
+        None,
+    )
+}
+
+#[test]
+fn test_next_cycle_advances_by_one_total_duration() {
+    let time = delayed_time();
+    let total_duration = asset(time.clone()).cycle_duration(0);
+    assert_eq!(total_duration, time.total_duration());
+    assert_eq!(total_duration, 1.75);
+
+    let mut runtime = EmitterRuntime::new(0, Some(7));
+    runtime.seek_cycle(2);
+    let before = runtime.elapsed_time(total_duration);
+    assert_eq!(before, 2.0 * total_duration);
+
+    runtime.seek_next_cycle();
+    assert_eq!(runtime.cycle, 3);
+    assert_eq!(
+        runtime.elapsed_time(total_duration) - before,
+        total_duration
+    );
+}
+
+#[test]
+fn test_cycle_boundary_replays_delay() {
+    let time = delayed_time();
+    let mut runtime = EmitterRuntime::new(0, Some(7));
+    runtime.seek(1.2);
+    assert!(runtime.is_past_delay(&time));
+
+    runtime.seek_next_cycle();
+    assert_eq!(runtime.system_time, 0.0);
+    assert_eq!(runtime.prev_system_time, 0.0);
+    assert!(!runtime.is_past_delay(&time));
+    assert_eq!(runtime.system_phase(&time), 0.0);
+}
+
+#[test]
+fn test_prev_cycle_steps_back_past_delay() {
+    let time = delayed_time();
+    let mut runtime = EmitterRuntime::new(0, Some(7));
+    runtime.seek_cycle(3);
+    runtime.seek(1.5);
+
+    runtime.seek_prev_cycle(&time);
+    assert_eq!(runtime.cycle, 3);
+    assert_eq!(runtime.system_time, 0.0);
+
+    runtime.seek_prev_cycle(&time);
+    assert_eq!(runtime.cycle, 2);
+
+    runtime.seek_cycle(0);
+    runtime.seek_prev_cycle(&time);
+    assert_eq!(runtime.cycle, 0);
+}
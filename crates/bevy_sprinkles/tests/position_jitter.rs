@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::{EmissionShape, EmitterEmission};
+use bevy_sprinkles::test_utils::{hash, hash_to_float};
+
+fn random_range(seed: u32, variation: f32) -> f32 {
+    (hash_to_float(seed) * 2.0 - 1.0) * variation
+}
+
+/// Mirrors `get_emission_offset` in `particle_simulate.wgsl` for
+/// [`EmissionShape::Point`].
+fn point_emission_offset(emission: &EmitterEmission, seed: u32) -> Vec3 {
+    let jitter = emission.position_jitter;
+    emission.offset
+        + Vec3::new(
+            random_range(seed + 90, jitter.x),
+            random_range(seed + 91, jitter.y),
+            random_range(seed + 92, jitter.z),
+        )
+}
+
+#[test]
+fn test_position_jitter_round_trips_through_ron() {
+    let emission = EmitterEmission {
+        position_jitter: Vec3::new(0.5, 0.0, 0.25),
+        ..Default::default()
+    };
+    let ron = ron::to_string(&emission).expect("failed to serialize emission");
+    let parsed: EmitterEmission = ron::from_str(&ron).expect("failed to parse emission");
+    assert_eq!(parsed.position_jitter, Vec3::new(0.5, 0.0, 0.25));
+
+    let plain = ron::to_string(&EmitterEmission::default()).unwrap();
+    assert!(!plain.contains("position_jitter"));
+    let parsed: EmitterEmission = ron::from_str(&plain).unwrap();
+    assert_eq!(parsed.position_jitter, Vec3::ZERO);
+}
+
+#[test]
+fn test_jitter_spreads_point_emission_within_bounds() {
+    let jitter = Vec3::new(0.5, 1.0, 0.0);
+    let emission = EmitterEmission {
+        offset: Vec3::new(2.0, 0.0, 0.0),
+        shape: EmissionShape::Point,
+        position_jitter: jitter,
+        ..Default::default()
+    };
+
+    let positions: Vec<Vec3> = (0..256)
+        .map(|idx| point_emission_offset(&emission, hash(1 + idx)))
+        .collect();
+
+    for position in &positions {
+        let delta = (*position - emission.offset).abs();
+        assert!(
+            delta.cmple(jitter).all(),
+            "{position} is outside the jitter"
+        );
+    }
+    assert!(positions.iter().all(|p| p.z == 0.0));
+
+    let min = positions.iter().copied().fold(Vec3::MAX, Vec3::min);
+    let max = positions.iter().copied().fold(Vec3::MIN, Vec3::max);
+    let spread = max - min;
+    assert!(spread.x > jitter.x, "x spread {} is too narrow", spread.x);
+    assert!(spread.y > jitter.y, "y spread {} is too narrow", spread.y);
+
+    let still = EmitterEmission {
+        position_jitter: Vec3::ZERO,
+        ..emission
+    };
+    assert_eq!(point_emission_offset(&still, hash(1)), still.offset);
+}
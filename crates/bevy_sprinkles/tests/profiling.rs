@@ -0,0 +1,53 @@
+#![cfg(feature = "profiling")]
+
+use bevy::diagnostic::{Diagnostic, DiagnosticMeasurement, DiagnosticsStore};
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+
+use bevy_sprinkles::profiling::{
+    ParticleProfileStats, ParticleProfilingPlugin, SIMULATE_GPU_TIME, SORT_GPU_TIME,
+};
+
+fn create_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, ParticleProfilingPlugin));
+    app
+}
+
+#[test]
+fn test_stats_are_none_without_timestamp_support() {
+    let mut app = create_test_app();
+    app.update();
+
+    let stats = app.world().get_resource::<ParticleProfileStats>();
+    assert_eq!(stats, Some(&ParticleProfileStats::default()));
+    assert_eq!(stats.unwrap().sim_ms, None);
+    assert_eq!(stats.unwrap().sort_ms, None);
+}
+
+#[test]
+fn test_stats_follow_the_gpu_timings() {
+    let mut app = create_test_app();
+    app.update();
+
+    let mut store = app.world_mut().resource_mut::<DiagnosticsStore>();
+    store.add(Diagnostic::new(SIMULATE_GPU_TIME).with_suffix("ms"));
+    store
+        .get_mut(&SIMULATE_GPU_TIME)
+        .unwrap()
+        .add_measurement(DiagnosticMeasurement {
+            time: Instant::now(),
+            value: 0.25,
+        });
+    app.update();
+
+    let stats = app.world().resource::<ParticleProfileStats>();
+    assert_eq!(stats.sim_ms, Some(0.25));
+    assert_eq!(stats.sort_ms, None);
+    assert!(
+        app.world()
+            .resource::<DiagnosticsStore>()
+            .get(&SORT_GPU_TIME)
+            .is_none()
+    );
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::mesh::VertexAttributeValues;
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::{
+    DrawPassMaterial, EmitterData, EmitterDrawPass, EmitterEmission, ParticleMesh, QuadOrientation,
+    StandardParticleMaterial,
+};
+use bevy_sprinkles::runtime::{ParticleMeshHandle, ParticleSystem3D};
+use bevy_sprinkles::test_utils::setup_particle_systems;
+use bevy_sprinkles::textures::preset::TextureRef;
+
+const PARTICLES: u32 = 3;
+
+fn create_test_app() -> App {
+    let mut app = common::create_test_app(Duration::from_millis(16));
+    app.add_systems(Update, setup_particle_systems);
+    app
+}
+
+fn normal_mapped(mesh: ParticleMesh) -> EmitterData {
+    EmitterData {
+        emission: EmitterEmission {
+            particles_amount: PARTICLES,
+            ..Default::default()
+        },
+        draw_pass: EmitterDrawPass {
+            mesh,
+            material: DrawPassMaterial::Standard(StandardParticleMaterial {
+                normal_map_texture: Some(TextureRef::Local("normal.png".to_string())),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn spawn_particle_mesh(emitter: EmitterData) -> Mesh {
+    let mut app = create_test_app();
+    let handle = common::add_asset(&mut app, vec![emitter]);
+    app.world_mut().spawn(ParticleSystem3D::new(handle));
+    app.update();
+
+    let mesh_handle = app
+        .world_mut()
+        .query::<&ParticleMeshHandle>()
+        .single(app.world())
+        .expect("the emitter should have a particle mesh")
+        .0
+        .clone();
+    app.world()
+        .resource::<Assets<Mesh>>()
+        .get(&mesh_handle)
+        .unwrap()
+        .clone()
+}
+
+fn tangents(mesh: &Mesh) -> Vec<[f32; 4]> {
+    match mesh.attribute(Mesh::ATTRIBUTE_TANGENT) {
+        Some(VertexAttributeValues::Float32x4(tangents)) => tangents.clone(),
+        _ => panic!("mesh has no tangents"),
+    }
+}
+
+#[test]
+fn test_normal_mapped_quad_has_tangents() {
+    for subdivide in [Vec2::ZERO, Vec2::new(2.0, 3.0)] {
+        let mesh = spawn_particle_mesh(normal_mapped(ParticleMesh::Quad {
+            orientation: QuadOrientation::FaceZ,
+            size: Vec2::ONE,
+            subdivide,
+        }));
+
+        let tangents = tangents(&mesh);
+        assert_eq!(tangents.len(), mesh.count_vertices());
+        assert!(tangents.iter().all(|t| *t == [1.0, 0.0, 0.0, 1.0]));
+    }
+}
+
+#[test]
+fn test_quad_tangents_match_generated_ones() {
+    for orientation in [
+        QuadOrientation::FaceX,
+        QuadOrientation::FaceY,
+        QuadOrientation::FaceZ,
+    ] {
+        let mesh = spawn_particle_mesh(normal_mapped(ParticleMesh::Quad {
+            orientation,
+            size: Vec2::new(2.0, 1.0),
+            subdivide: Vec2::ONE,
+        }));
+
+        let mut generated = mesh.clone();
+        generated.remove_attribute(Mesh::ATTRIBUTE_TANGENT);
+        generated.generate_tangents().unwrap();
+
+        for (ours, theirs) in tangents(&mesh).iter().zip(tangents(&generated)) {
+            let ours = Vec4::from_array(*ours);
+            let theirs = Vec4::from_array(theirs);
+            assert!(
+                ours.abs_diff_eq(theirs, 1e-5),
+                "{orientation:?}: {ours} != {theirs}"
+            );
+        }
+    }
+}
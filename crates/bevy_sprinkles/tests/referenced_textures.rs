@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use bevy_sprinkles::asset::ParticleSystemAsset;
+use bevy_sprinkles::prelude::TextureRef;
+
+fn load_fixture(name: &str) -> ParticleSystemAsset {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name);
+    let contents = std::fs::read_to_string(path).unwrap();
+    ron::de::from_str(&contents).unwrap()
+}
+
+#[test]
+fn test_maximal_fixture_referenced_textures() {
+    let asset = load_fixture("maximal_particle_system.ron");
+
+    assert_eq!(
+        asset.referenced_textures(),
+        vec![
+            TextureRef::Asset("textures/spark.png".to_string()),
+            TextureRef::Asset("textures/spark_glow.png".to_string()),
+            TextureRef::Local("textures/spark_mr.png".to_string()),
+            TextureRef::Local("textures/spark_normal.png".to_string()),
+            TextureRef::Asset("textures/smoke.png".to_string()),
+            TextureRef::Asset("textures/smoke_ao.png".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_untextured_asset_has_no_referenced_textures() {
+    let asset = load_fixture("valid_particle_system.ron");
+    assert!(asset.referenced_textures().is_empty());
+}
@@ -0,0 +1,45 @@
+use bevy_sprinkles::runtime::{EmitterRuntime, ParticleSystemRuntime};
+
+#[test]
+fn test_reseed_always_changes_global_seed() {
+    let mut runtime = ParticleSystemRuntime::default();
+    for _ in 0..16 {
+        let previous = runtime.global_seed;
+        let seed = runtime.reseed();
+        assert_ne!(seed, previous);
+        assert_eq!(runtime.global_seed, seed);
+    }
+}
+
+#[test]
+fn test_emitter_seeds_differ_per_emitter_and_follow_reseed() {
+    let mut runtime = ParticleSystemRuntime {
+        global_seed: 7,
+        ..Default::default()
+    };
+    assert_ne!(runtime.emitter_seed(0), runtime.emitter_seed(1));
+    assert_eq!(runtime.emitter_seed(1), runtime.emitter_seed(1));
+
+    let before = runtime.emitter_seed(0);
+    runtime.reseed();
+    assert_ne!(runtime.emitter_seed(0), before);
+}
+
+#[test]
+fn test_restart_with_emitter_seed_overrides_fixed_seed() {
+    let fixed_seed = Some(42);
+    let mut system = ParticleSystemRuntime::default();
+    let mut emitter = EmitterRuntime::new(0, fixed_seed);
+    emitter.system_time = 1.5;
+    emitter.cycle = 3;
+
+    system.reseed();
+    emitter.restart(Some(system.emitter_seed(0)));
+
+    assert_eq!(emitter.random_seed, system.emitter_seed(0));
+    assert_ne!(emitter.random_seed, 42);
+    assert_eq!(emitter.system_time, 0.0);
+    assert_eq!(emitter.cycle, 0);
+    assert!(emitter.emitting);
+    assert!(emitter.clear_requested);
+}
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use bevy_sprinkles::asset::EmissionShape;
+
+const SAMPLES: usize = 1000;
+
+/// Samples a spawn radius, mirroring the `EMISSION_SHAPE_RING` case of
+/// `get_emission_offset` in `particle_simulate.wgsl`.
+fn sample_radius(shape: &EmissionShape, v: f32) -> f32 {
+    let EmissionShape::Ring {
+        radius,
+        inner_radius,
+        radial_bias,
+        ..
+    } = *shape
+    else {
+        panic!("expected a ring shape");
+    };
+    let biased_v = v.powf((-2.0 * radial_bias.clamp(-1.0, 1.0)).exp2());
+    inner_radius + biased_v.sqrt() * (radius - inner_radius)
+}
+
+fn mean_radius(radial_bias: f32) -> f32 {
+    let shape = EmissionShape::Ring {
+        axis: Vec3::Y,
+        height: 0.0,
+        radius: 2.0,
+        inner_radius: 1.0,
+        radial_bias,
+    };
+    let total: f32 = (0..SAMPLES)
+        .map(|i| sample_radius(&shape, (i as f32 + 0.5) / SAMPLES as f32))
+        .sum();
+    total / SAMPLES as f32
+}
+
+#[test]
+fn test_positive_bias_shifts_mean_radius_outward() {
+    let uniform = mean_radius(0.0);
+    let outer = mean_radius(1.0);
+    let inner = mean_radius(-1.0);
+
+    assert!(
+        outer > uniform + 0.1,
+        "{outer} should be well above {uniform}"
+    );
+    assert!(
+        inner < uniform - 0.1,
+        "{inner} should be well below {uniform}"
+    );
+    assert!((1.0..=2.0).contains(&outer));
+    assert!((1.0..=2.0).contains(&inner));
+}
+
+#[test]
+fn test_radial_bias_defaults_to_uniform() {
+    let shape: EmissionShape =
+        ron::from_str("Ring(axis:(0.0,1.0,0.0),height:0.0,radius:2.0,inner_radius:1.0)").unwrap();
+    assert!(matches!(
+        shape,
+        EmissionShape::Ring {
+            radial_bias: 0.0,
+            ..
+        }
+    ));
+    assert!(!ron::to_string(&shape).unwrap().contains("radial_bias"));
+}
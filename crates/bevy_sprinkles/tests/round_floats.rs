@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::{
+    ColliderData, DEFAULT_ROUNDING_DECIMALS, EmitterData, Gradient, ParticleSystemAsset,
+    ParticleSystemDimension, ParticlesColliderShape3D, SolidOrGradientColor,
+};
+
+/// The float right after `value`, like the noise editing leaves behind.
+fn noisy(value: f32) -> f32 {
+    f32::from_bits(value.to_bits() + 1)
+}
+
+fn asset(emitter: EmitterData, colliders: Vec<ColliderData>) -> ParticleSystemAsset {
+    ParticleSystemAsset::new(
+        "Test".to_string(),
+        ParticleSystemDimension::D3,
+        vec![emitter],
+        colliders,
+        None,
+    )
+}
+
+#[test]
+fn test_round_floats_cleans_up_noise() {
+    let mut emitter = EmitterData::default();
+    emitter.velocities.spread = "0.30000001".parse().unwrap();
+    emitter.velocities.flatness = noisy(0.3);
+    assert_eq!(emitter.velocities.flatness.to_string(), "0.30000004");
+    let mut asset = asset(emitter, vec![]);
+
+    assert!(asset.round_floats(DEFAULT_ROUNDING_DECIMALS));
+
+    let velocities = &asset.emitters[0].velocities;
+    assert_eq!(velocities.spread.to_string(), "0.3");
+    assert_eq!(velocities.flatness.to_string(), "0.3");
+}
+
+#[test]
+fn test_round_floats_reaches_nested_fields() {
+    let mut emitter = EmitterData {
+        position: Vec3::new(noisy(1.5), 0.0, noisy(-2.25)),
+        ..Default::default()
+    };
+    let mut gradient = Gradient::default();
+    gradient.stops[0].position = noisy(0.1);
+    emitter.colors.initial_color = SolidOrGradientColor::Gradient { gradient };
+    let collider = ColliderData {
+        shape: ParticlesColliderShape3D::Sphere { radius: noisy(2.0) },
+        ..Default::default()
+    };
+    let mut asset = asset(emitter, vec![collider]);
+
+    assert!(asset.round_floats(DEFAULT_ROUNDING_DECIMALS));
+
+    let emitter = &asset.emitters[0];
+    assert_eq!(emitter.position, Vec3::new(1.5, 0.0, -2.25));
+    let SolidOrGradientColor::Gradient { gradient } = &emitter.colors.initial_color else {
+        panic!("initial color should still be a gradient");
+    };
+    assert_eq!(gradient.stops[0].position, 0.1);
+    let ParticlesColliderShape3D::Sphere { radius } = asset.colliders[0].shape else {
+        panic!("collider should still be a sphere");
+    };
+    assert_eq!(radius, 2.0);
+}
+
+#[test]
+fn test_round_floats_respects_decimals() {
+    let mut emitter = EmitterData::default();
+    emitter.velocities.spread = 12.3456;
+    let mut asset = asset(emitter, vec![]);
+
+    assert!(!asset.round_floats(4));
+    assert_eq!(asset.emitters[0].velocities.spread, 12.3456);
+    assert!(asset.round_floats(2));
+    assert_eq!(asset.emitters[0].velocities.spread, 12.35);
+}
+
+#[test]
+fn test_round_floats_reports_clean_assets() {
+    let mut asset = asset(EmitterData::default(), vec![ColliderData::default()]);
+    assert!(!asset.round_floats(DEFAULT_ROUNDING_DECIMALS));
+}
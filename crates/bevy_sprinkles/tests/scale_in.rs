@@ -0,0 +1,96 @@
+use bevy_sprinkles::asset::{CurvePoint, CurveTexture, EmitterScale};
+use bevy_sprinkles::runtime::ParticleData;
+
+const DT: f32 = 1.0 / 60.0;
+
+fn spawn(lifetime: f32) -> ParticleData {
+    ParticleData {
+        velocity: [0.0, 0.0, 0.0, lifetime],
+        custom: [0.0, 0.0, 0.0, f32::from_bits(ParticleData::FLAG_ACTIVE)],
+        ..Default::default()
+    }
+}
+
+/// Mirrors `get_scale_at_lifetime` in `particle_simulate.wgsl`.
+fn scale_at(scale: &EmitterScale, initial_scale: f32, particle: &ParticleData) -> f32 {
+    let t = particle.lifetime_fraction();
+    let mut value = initial_scale;
+    let scale_in_time = scale.scale_in_time.clamp(0.0, 1.0);
+    if scale_in_time > 0.0 {
+        let x = (t / scale_in_time).clamp(0.0, 1.0);
+        value *= 1.0 - (1.0 - x) * (1.0 - x);
+    }
+    if let Some(curve) = &scale.scale_over_lifetime {
+        value *= curve.sample(t);
+    }
+    value
+}
+
+/// Steps a particle forward by `dt`, mirroring the age update of `update_particle`.
+fn step(particle: &mut ParticleData, dt: f32) {
+    particle.custom[0] = (particle.age() + dt).min(particle.lifetime());
+}
+
+#[test]
+fn test_scale_in_time_round_trips_through_ron() {
+    let scale = EmitterScale {
+        scale_in_time: 0.2,
+        ..Default::default()
+    };
+    let ron = ron::to_string(&scale).expect("failed to serialize scale");
+    let parsed: EmitterScale = ron::from_str(&ron).expect("failed to parse scale");
+    assert_eq!(parsed, scale);
+
+    let plain = ron::to_string(&EmitterScale::default()).unwrap();
+    assert!(!plain.contains("scale_in_time"));
+}
+
+#[test]
+fn test_particle_starts_near_zero_scale_with_scale_in_time() {
+    let scale = EmitterScale {
+        scale_in_time: 0.25,
+        ..Default::default()
+    };
+    let mut particle = spawn(2.0);
+    assert_eq!(scale_at(&scale, 1.0, &particle), 0.0);
+
+    step(&mut particle, 0.001);
+    let just_spawned = scale_at(&scale, 1.0, &particle);
+    assert!(just_spawned < 0.01, "{just_spawned}");
+
+    // grows every frame until it reaches full size at a quarter of its life
+    let mut previous = just_spawned;
+    while particle.lifetime_fraction() < 0.25 {
+        step(&mut particle, DT);
+        let current = scale_at(&scale, 1.0, &particle);
+        assert!(current >= previous, "{current} < {previous}");
+        previous = current;
+    }
+    assert_eq!(scale_at(&scale, 1.0, &particle), 1.0);
+}
+
+#[test]
+fn test_zero_scale_in_time_spawns_at_full_size() {
+    let scale = EmitterScale::default();
+    let mut particle = spawn(1.0);
+    step(&mut particle, 0.001);
+    assert_eq!(scale_at(&scale, 1.5, &particle), 1.5);
+}
+
+#[test]
+fn test_scale_in_multiplies_with_scale_over_lifetime() {
+    let scale = EmitterScale {
+        scale_over_lifetime: Some(CurveTexture::new(vec![
+            CurvePoint::new(0.0, 0.5),
+            CurvePoint::new(1.0, 0.5),
+        ])),
+        scale_in_time: 0.5,
+        ..Default::default()
+    };
+    let mut particle = spawn(1.0);
+    step(&mut particle, 0.25);
+
+    // halfway through the scale in, the ease-out gives 0.75 of the curve value
+    let value = scale_at(&scale, 1.0, &particle);
+    assert!((value - 0.75 * 0.5).abs() < 1e-5, "{value}");
+}
@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use bevy_sprinkles::asset::{
+    EmitterData, ParticleMesh, SolidOrGradientColor, StandardParticleMaterial,
+};
+
+#[test]
+fn test_default_emitter_omits_default_fields() {
+    let ron = ron::to_string(&EmitterData::default()).expect("failed to serialize emitter");
+
+    assert_eq!(ron, r#"(name:"Emitter")"#);
+}
+
+#[test]
+fn test_minimal_emitter_deserializes_to_defaults() {
+    let emitter: EmitterData =
+        ron::from_str(r#"(name:"Emitter")"#).expect("failed to deserialize emitter");
+    let default = EmitterData::default();
+
+    assert_eq!(emitter.time, default.time);
+    assert_eq!(emitter.draw_pass, default.draw_pass);
+    assert_eq!(emitter.emission, default.emission);
+    assert_eq!(emitter.scale, default.scale);
+    assert_eq!(emitter.colors, default.colors);
+    assert_eq!(emitter.velocities, default.velocities);
+    assert_eq!(emitter.accelerations, default.accelerations);
+    assert_eq!(emitter.collision, default.collision);
+    assert_eq!(emitter.particle_flags, default.particle_flags);
+}
+
+#[test]
+fn test_non_default_fields_are_serialized() {
+    let mut emitter = EmitterData::default();
+    emitter.accelerations.gravity = Vec3::ZERO;
+    emitter.draw_pass.mesh = ParticleMesh::Sphere { radius: 2.0 };
+    emitter.colors.initial_color = SolidOrGradientColor::solid([1.0, 0.0, 0.0, 1.0]);
+
+    let ron = ron::to_string(&emitter).expect("failed to serialize emitter");
+
+    assert!(ron.contains("gravity:(0.0,0.0,0.0)"));
+    assert!(ron.contains("radius:2.0"));
+    assert!(ron.contains("initial_color:"));
+    assert!(!ron.contains("material:"));
+    assert!(!ron.contains("velocities:"));
+}
+
+#[test]
+fn test_default_material_fields_are_omitted() {
+    let material = StandardParticleMaterial {
+        metallic: 1.0,
+        ..Default::default()
+    };
+
+    let ron = ron::to_string(&material).expect("failed to serialize material");
+
+    assert_eq!(ron, "(metallic:1.0)");
+}
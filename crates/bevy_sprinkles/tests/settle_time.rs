@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::{
+    ColliderData, EmitterAccelerations, EmitterCollision, EmitterCollisionMode, EmitterData,
+    EmitterVelocities, ParticleSystemAsset, ParticleSystemDimension, ParticlesColliderShape3D,
+    Range,
+};
+
+const SPEED_THRESHOLD: f32 = 0.05;
+
+fn debris(mode: EmitterCollisionMode) -> EmitterData {
+    EmitterData {
+        position: Vec3::new(0.0, 0.5, 0.0),
+        velocities: EmitterVelocities {
+            initial_direction: Vec3::Y,
+            spread: 15.0,
+            initial_velocity: Range::new(0.5, 1.0),
+            ..Default::default()
+        },
+        accelerations: EmitterAccelerations {
+            gravity: Vec3::new(0.0, -30.0, 0.0),
+            ..Default::default()
+        },
+        collision: EmitterCollision {
+            mode: Some(mode),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn rigid(bounce: f32) -> EmitterCollisionMode {
+    EmitterCollisionMode::Rigid {
+        friction: 0.5,
+        bounce,
+    }
+}
+
+fn floor() -> ColliderData {
+    ColliderData {
+        shape: ParticlesColliderShape3D::Box {
+            size: Vec3::new(20.0, 1.0, 20.0),
+        },
+        position: Vec3::new(0.0, -0.5, 0.0),
+        ..Default::default()
+    }
+}
+
+fn asset(emitter: EmitterData, colliders: Vec<ColliderData>) -> ParticleSystemAsset {
+    ParticleSystemAsset::new(
+        "Debris".to_string(),
+        ParticleSystemDimension::D3,
+        vec![emitter],
+        colliders,
+        None,
+    )
+}
+
+#[test]
+fn test_high_gravity_debris_settles_quickly() {
+    let settle = asset(debris(rigid(0.0)), vec![floor()])
+        .settle_time(0, SPEED_THRESHOLD)
+        .expect("debris should settle on the floor");
+
+    // the fastest probe rises ~1.7cm then falls ~0.52m under 30 m/s²: about 0.22s
+    assert!((0.15..0.6).contains(&settle), "settled after {settle}s");
+}
+
+#[test]
+fn test_bouncing_debris_settles_later() {
+    let dead = asset(debris(rigid(0.0)), vec![floor()]).settle_time(0, SPEED_THRESHOLD);
+    let bouncy = asset(debris(rigid(0.6)), vec![floor()]).settle_time(0, SPEED_THRESHOLD);
+    assert!(bouncy.unwrap() > dead.unwrap());
+}
+
+#[test]
+fn test_hide_on_contact_settles_at_impact() {
+    let hidden = asset(debris(EmitterCollisionMode::HideOnContact), vec![floor()])
+        .settle_time(0, SPEED_THRESHOLD)
+        .unwrap();
+    let resting = asset(debris(rigid(0.0)), vec![floor()])
+        .settle_time(0, SPEED_THRESHOLD)
+        .unwrap();
+    assert!(hidden <= resting);
+    assert!(hidden > 0.0);
+}
+
+#[test]
+fn test_falling_forever_never_settles() {
+    let no_floor = asset(debris(rigid(0.0)), vec![]);
+    assert_eq!(no_floor.settle_time(0, SPEED_THRESHOLD), None);
+    let missing = asset(debris(rigid(0.0)), vec![floor()]);
+    assert_eq!(missing.settle_time(1, SPEED_THRESHOLD), None);
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_sprinkles::MIN_SIMULATION_RATE;
+use bevy_sprinkles::asset::{EmitterData, EmitterTime};
+use bevy_sprinkles::runtime::{EmitterRuntime, ParticlesGlobalControl};
+use bevy_sprinkles::test_utils::update_particle_time;
+
+const STEP: Duration = Duration::from_millis(16);
+const FRAMES: usize = 300;
+
+fn create_test_app(simulation_rate: f32) -> App {
+    let mut app = common::create_test_app(STEP);
+    app.insert_resource(ParticlesGlobalControl {
+        simulation_rate,
+        ..Default::default()
+    })
+    .add_systems(Update, update_particle_time);
+    app
+}
+
+fn spawn_emitter(app: &mut App, emitter: EmitterData) -> Entity {
+    common::spawn_system(app, vec![emitter]).1[0]
+}
+
+/// Runs the app for [`FRAMES`] frames, returning how many steps were dispatched and how
+/// much time they simulated in total.
+fn run(app: &mut App, emitter: Entity) -> (usize, f32) {
+    let mut steps = 0;
+    let mut simulated = 0.0;
+    for _ in 0..FRAMES {
+        app.update();
+        let runtime = app.world().get::<EmitterRuntime>(emitter).unwrap();
+        steps += runtime.simulation_steps.len();
+        simulated += runtime
+            .simulation_steps
+            .iter()
+            .map(|step| step.delta_time)
+            .sum::<f32>();
+    }
+    (steps, simulated)
+}
+
+fn long_lived() -> EmitterData {
+    EmitterData {
+        time: EmitterTime {
+            lifetime: 100.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_half_rate_dispatches_half_as_often() {
+    let mut full = create_test_app(1.0);
+    let emitter = spawn_emitter(&mut full, long_lived());
+    let (full_steps, full_time) = run(&mut full, emitter);
+
+    let mut half = create_test_app(0.5);
+    let emitter = spawn_emitter(&mut half, long_lived());
+    let (half_steps, half_time) = run(&mut half, emitter);
+
+    assert!(full_steps >= FRAMES - 1, "{full_steps}");
+    let ratio = half_steps as f32 / full_steps as f32;
+    assert!(
+        (ratio - 0.5).abs() < 0.02,
+        "{half_steps} of {full_steps} steps"
+    );
+
+    // skipped frames are simulated by the next step, so no time is lost
+    let runtime = half.world().get::<EmitterRuntime>(emitter).unwrap();
+    let pending = runtime.accumulated_delta;
+    assert!((half_time + pending - full_time).abs() < 1e-3);
+}
+
+#[test]
+fn test_fixed_fps_emitters_scale_their_rate() {
+    let fixed = EmitterData {
+        time: EmitterTime {
+            lifetime: 100.0,
+            fixed_fps: 30,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let mut full = create_test_app(1.0);
+    let emitter = spawn_emitter(&mut full, fixed.clone());
+    let (full_steps, _) = run(&mut full, emitter);
+
+    let mut half = create_test_app(0.5);
+    let emitter = spawn_emitter(&mut half, fixed);
+    let (half_steps, _) = run(&mut half, emitter);
+
+    let ratio = half_steps as f32 / full_steps as f32;
+    assert!(
+        (ratio - 0.5).abs() < 0.05,
+        "{half_steps} of {full_steps} steps"
+    );
+}
+
+#[test]
+fn test_simulation_rate_is_clamped() {
+    let control = |simulation_rate| ParticlesGlobalControl {
+        simulation_rate,
+        ..Default::default()
+    };
+    assert_eq!(control(1.0).effective_simulation_rate(), 1.0);
+    assert_eq!(control(4.0).effective_simulation_rate(), 1.0);
+    assert_eq!(
+        control(0.0).effective_simulation_rate(),
+        MIN_SIMULATION_RATE
+    );
+    assert_eq!(control(f32::NAN).effective_simulation_rate(), 1.0);
+}
@@ -0,0 +1,344 @@
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::EmitterDrawPass;
+
+/// Mirrors `quantize_key` in `particle_sort.wgsl`.
+fn quantize_key(key: f32, precision: f32) -> f32 {
+    if precision > 0.0 {
+        (key / precision).round()
+    } else {
+        key
+    }
+}
+
+/// Mirrors `sorts_after` in `particle_sort.wgsl`.
+fn sorts_after(key_a: f32, index_a: u32, key_b: f32, index_b: u32) -> bool {
+    if key_a != key_b {
+        return key_a > key_b;
+    }
+    index_a > index_b
+}
+
+/// Runs the bitonic passes dispatched by `ParticleSortNode`, with the compare and swap of
+/// `sort` in `particle_sort.wgsl`, over already quantized sort keys.
+fn bitonic_sort(keys: &[f32]) -> Vec<u32> {
+    let amount = keys.len() as u32;
+    let key = |index: u32| keys[index as usize];
+
+    let mut indices: Vec<u32> = (0..amount).collect();
+    let stages = amount.next_power_of_two().ilog2();
+    for stage in 0..stages {
+        for step in (0..=stage).rev() {
+            let d = 1 << step;
+            for idx in 0..amount {
+                let within_block = idx % (2 * d);
+                let partner = if step == stage {
+                    idx - within_block + 2 * d - 1 - within_block
+                } else {
+                    idx + d
+                };
+                if within_block >= d || partner >= amount {
+                    continue;
+                }
+                let (a, b) = (indices[idx as usize], indices[partner as usize]);
+                if sorts_after(key(a), a, key(b), b) {
+                    indices.swap(idx as usize, partner as usize);
+                }
+            }
+        }
+    }
+    indices
+}
+
+// These tests run Rust copies of the shader's sorts rather than the GPU pipelines, so they
+// check the algorithms, not `particle_sort.wgsl` itself.
+
+const RADIX_WORKGROUP_SIZE: u32 = 256;
+const RADIX_BITS: u32 = 4;
+const RADIX_BINS: u32 = 1 << RADIX_BITS;
+
+/// Mirrors `sortable_bits` in `particle_sort.wgsl`.
+fn sortable_bits(key: f32) -> u32 {
+    let bits = if key == 0.0 { 0 } else { key.to_bits() };
+    if bits & 0x8000_0000 != 0 {
+        !bits
+    } else {
+        bits | 0x8000_0000
+    }
+}
+
+/// Mirrors the `radix_histogram`, `radix_scan` and `radix_scatter` passes of
+/// `particle_sort.wgsl`, one workgroup-sized block at a time.
+fn radix_sort(keys: &[f32]) -> Vec<u32> {
+    let amount = keys.len();
+    let blocks = amount.div_ceil(RADIX_WORKGROUP_SIZE as usize);
+    let mut indices: Vec<u32> = (0..amount as u32).collect();
+    let mut scratch = vec![0; amount];
+
+    for pass in 0..32 / RADIX_BITS {
+        let digit = |index: u32| {
+            (sortable_bits(keys[index as usize]) >> (pass * RADIX_BITS)) as usize
+                & (RADIX_BINS as usize - 1)
+        };
+
+        // per block digit counts, stored digit-major
+        let mut histograms = vec![0; RADIX_BINS as usize * blocks];
+        for (block, chunk) in indices.chunks(RADIX_WORKGROUP_SIZE as usize).enumerate() {
+            for &index in chunk {
+                histograms[digit(index) * blocks + block] += 1;
+            }
+        }
+
+        // exclusive prefix sum over all of them
+        let mut total = 0;
+        for count in &mut histograms {
+            (*count, total) = (total, total + *count);
+        }
+
+        // stable scatter, ranking each particle among the earlier ones of its block
+        for (block, chunk) in indices.chunks(RADIX_WORKGROUP_SIZE as usize).enumerate() {
+            for (local, &index) in chunk.iter().enumerate() {
+                let d = digit(index);
+                let rank = chunk[..local].iter().filter(|&&i| digit(i) == d).count();
+                scratch[histograms[d * blocks + block] + rank] = index;
+            }
+        }
+        std::mem::swap(&mut indices, &mut scratch);
+    }
+    indices
+}
+
+/// Sorts particles at `positions` by view depth, as `get_sort_key` in
+/// `particle_sort.wgsl` does for `DRAW_ORDER_VIEW_DEPTH`.
+fn sort_by_view_depth(positions: &[Vec3], camera: Vec3, forward: Vec3, precision: f32) -> Vec<u32> {
+    let keys: Vec<f32> = positions
+        .iter()
+        .map(|position| quantize_key(-(*position - camera).dot(forward), precision))
+        .collect();
+    bitonic_sort(&keys)
+}
+
+// a row of particles across the view, all at the same depth
+fn equal_depth_row(count: usize, noise: f32) -> Vec<Vec3> {
+    (0..count)
+        .map(|i| {
+            // alternating tiny offsets, like the noise from a moving emitter transform
+            let jitter = if i % 2 == 0 { noise } else { -noise };
+            Vec3::new(i as f32 - count as f32 / 2.0, 0.0, -5.0 + jitter)
+        })
+        .collect()
+}
+
+#[test]
+fn test_equal_depths_sort_in_a_stable_order() {
+    let positions = equal_depth_row(16, 0.0);
+    let first = sort_by_view_depth(&positions, Vec3::ZERO, Vec3::NEG_Z, 0.0);
+    let second = sort_by_view_depth(&positions, Vec3::ZERO, Vec3::NEG_Z, 0.0);
+
+    assert_eq!(first, second);
+    assert_eq!(first, (0..16).collect::<Vec<u32>>());
+}
+
+#[test]
+fn test_precision_hides_depth_noise_between_frames() {
+    let precision = EmitterDrawPass {
+        sort_precision: 0.01,
+        ..Default::default()
+    }
+    .sort_precision;
+
+    let frame_a = sort_by_view_depth(
+        &equal_depth_row(16, 0.0),
+        Vec3::ZERO,
+        Vec3::NEG_Z,
+        precision,
+    );
+    let frame_b = sort_by_view_depth(
+        &equal_depth_row(16, 1e-4),
+        Vec3::ZERO,
+        Vec3::NEG_Z,
+        precision,
+    );
+    assert_eq!(frame_a, frame_b);
+
+    // without it, the noise alone reorders the particles
+    let exact = sort_by_view_depth(&equal_depth_row(16, 1e-4), Vec3::ZERO, Vec3::NEG_Z, 0.0);
+    assert_ne!(exact, frame_a);
+}
+
+#[test]
+fn test_distinct_depths_still_sort_back_to_front() {
+    let positions: Vec<Vec3> = [-2.0, -8.0, -4.0, -6.0]
+        .into_iter()
+        .map(|z| Vec3::new(0.0, 0.0, z))
+        .collect();
+    let order = sort_by_view_depth(&positions, Vec3::ZERO, Vec3::NEG_Z, 0.01);
+    assert_eq!(order, vec![1, 3, 2, 0]);
+}
+
+#[test]
+fn test_sort_precision_is_omitted_by_default() {
+    let ron = ron::to_string(&EmitterDrawPass::default()).unwrap();
+    assert!(!ron.contains("sort_precision"));
+
+    let draw_pass = EmitterDrawPass {
+        sort_precision: 0.05,
+        ..Default::default()
+    };
+    let parsed: EmitterDrawPass = ron::from_str(&ron::to_string(&draw_pass).unwrap()).unwrap();
+    assert_eq!(parsed.sort_precision, 0.05);
+}
+
+#[test]
+fn test_radix_and_bitonic_sorts_agree() {
+    let mut seed = 1u32;
+    let mut random = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed as f32 / u32::MAX as f32
+    };
+
+    for amount in [1, 2, 7, 255, 256, 257, 1000] {
+        let keys: Vec<f32> = (0..amount)
+            .map(|i| match i % 7 {
+                // inactive particles
+                0 => -1e10,
+                // ties, including zeros of both signs
+                1 => 0.0,
+                2 => -0.0,
+                3 => 1.5,
+                _ => quantize_key((random() - 0.5) * 200.0, 0.01),
+            })
+            .collect();
+
+        let radix = radix_sort(&keys);
+        assert_eq!(radix, bitonic_sort(&keys), "{amount} particles");
+
+        // back to front, with ties in particle index order
+        for pair in radix.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert!(!sorts_after(keys[a as usize], a, keys[b as usize], b));
+        }
+    }
+}
+
+// Runs the sort pipelines on the GPU and reads the sorted indices back every frame, so it
+// needs a GPU.
+#[cfg(feature = "readback")]
+mod gpu {
+    use std::time::Duration;
+
+    use bevy::log::LogPlugin;
+    use bevy::prelude::*;
+    use bevy::render::gpu_readback::{Readback, ReadbackComplete};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy::window::ExitCondition;
+    use bevy::winit::WinitPlugin;
+
+    use bevy_sprinkles::asset::{
+        DrawOrder, EmitterData, EmitterDrawPass, EmitterEmission, EmitterTime, ParticleSystemAsset,
+        ParticleSystemDimension,
+    };
+    use bevy_sprinkles::runtime::{ParticleBufferHandle, ParticleSystem3D};
+    use bevy_sprinkles::{SortAlgorithm, SprinklesPlugin};
+
+    const STEP: Duration = Duration::from_millis(16);
+    const FRAMES: usize = 30;
+    // more than one 256-particle radix workgroup
+    const AMOUNT: u32 = 600;
+
+    /// Sorted indices read back after each frame, oldest first.
+    #[derive(Resource, Default)]
+    struct Snapshots(Vec<Vec<u32>>);
+
+    fn read_back_indices(
+        mut commands: Commands,
+        emitters: Query<(Entity, &ParticleBufferHandle), Without<Readback>>,
+    ) {
+        for (entity, buffers) in &emitters {
+            commands
+                .entity(entity)
+                .insert(Readback::buffer(buffers.indices_buffer.clone()));
+        }
+    }
+
+    fn store_snapshot(readback: On<ReadbackComplete>, mut snapshots: ResMut<Snapshots>) {
+        snapshots.0.push(readback.to_shader_type::<Vec<u32>>());
+    }
+
+    fn sorted_indices(algorithm: SortAlgorithm) -> Vec<Vec<u32>> {
+        let mut app = App::new();
+        app.add_plugins((
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: None,
+                    exit_condition: ExitCondition::DontExit,
+                    ..default()
+                })
+                .disable::<WinitPlugin>()
+                .disable::<LogPlugin>(),
+            SprinklesPlugin::default().with_sort_algorithm(algorithm),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(STEP))
+        .init_resource::<Snapshots>()
+        .add_systems(Update, read_back_indices)
+        .add_observer(store_snapshot);
+        app.finish();
+        app.cleanup();
+
+        // randomized lifetimes give distinct sort keys, and particles spawned together
+        // tie until they die
+        let asset = ParticleSystemAsset::new(
+            "sort".into(),
+            ParticleSystemDimension::D3,
+            vec![EmitterData {
+                time: EmitterTime {
+                    lifetime: 0.5,
+                    lifetime_randomness: 0.8,
+                    fixed_seed: Some(7),
+                    ..default()
+                },
+                emission: EmitterEmission {
+                    particles_amount: AMOUNT,
+                    ..default()
+                },
+                draw_pass: EmitterDrawPass {
+                    draw_order: DrawOrder::Lifetime,
+                    ..default()
+                },
+                ..default()
+            }],
+            vec![],
+            None,
+        );
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<ParticleSystemAsset>>()
+            .add(asset);
+        app.world_mut().spawn(ParticleSystem3D::new(handle));
+
+        for _ in 0..FRAMES {
+            app.update();
+        }
+        app.world_mut().remove_resource::<Snapshots>().unwrap().0
+    }
+
+    #[test]
+    fn test_radix_and_bitonic_pipelines_agree() {
+        let bitonic = sorted_indices(SortAlgorithm::Bitonic);
+        let radix = sorted_indices(SortAlgorithm::Radix);
+
+        let frames = bitonic.len().min(radix.len());
+        assert!(frames > 0, "no indices were read back");
+        for (frame, (bitonic, radix)) in bitonic.iter().zip(&radix).enumerate() {
+            assert_eq!(bitonic, radix, "frame {frame}");
+        }
+
+        let identity: Vec<u32> = (0..AMOUNT).collect();
+        assert!(
+            bitonic[..frames].iter().any(|indices| *indices != identity),
+            "the particles were never reordered"
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use bevy_sprinkles::asset::EmitterEmission;
+
+/// Mirrors how `spawn_particle` in `particle_simulate.wgsl` moves a particle spawned at
+/// `position` with initial velocity `velocity`.
+fn spawn_position(emission: &EmitterEmission, position: Vec3, velocity: Vec3) -> Vec3 {
+    let offset = emission.spawn_offset_along_velocity;
+    if offset != 0.0 && velocity.length() > 0.0 {
+        return position + velocity.normalize() * offset;
+    }
+    position
+}
+
+fn emission(spawn_offset_along_velocity: f32) -> EmitterEmission {
+    EmitterEmission {
+        spawn_offset_along_velocity,
+        ..Default::default()
+    }
+}
+
+// velocities of a wide spread emitter, all spawned at the emitter origin
+fn spread_velocities() -> Vec<Vec3> {
+    (0..16)
+        .map(|i| {
+            let angle = i as f32 / 16.0 * std::f32::consts::TAU;
+            Vec3::new(angle.cos(), angle.sin(), 0.0) * (1.0 + i as f32 * 0.25)
+        })
+        .collect()
+}
+
+#[test]
+fn test_spawn_offset_round_trips_through_ron() {
+    let offset = emission(0.25);
+    let ron = ron::to_string(&offset).expect("failed to serialize emission");
+    let parsed: EmitterEmission = ron::from_str(&ron).expect("failed to parse emission");
+    assert_eq!(parsed, offset);
+
+    let plain = ron::to_string(&EmitterEmission::default()).unwrap();
+    assert!(!plain.contains("spawn_offset_along_velocity"));
+    let parsed: EmitterEmission = ron::from_str(&plain).unwrap();
+    assert_eq!(parsed.spawn_offset_along_velocity, 0.0);
+}
+
+#[test]
+fn test_particles_spawn_displaced_along_their_velocity() {
+    let emission = emission(0.5);
+    let origin = Vec3::new(1.0, 2.0, 3.0);
+    for velocity in spread_velocities() {
+        let displacement = spawn_position(&emission, origin, velocity) - origin;
+        assert!((displacement.length() - 0.5).abs() < 1e-5);
+        assert!(
+            displacement
+                .normalize()
+                .abs_diff_eq(velocity.normalize(), 1e-5)
+        );
+    }
+}
+
+#[test]
+fn test_spawn_offset_spreads_particles_out_of_the_origin() {
+    let positions: Vec<Vec3> = spread_velocities()
+        .into_iter()
+        .map(|velocity| spawn_position(&emission(0.5), Vec3::ZERO, velocity))
+        .collect();
+    for (i, a) in positions.iter().enumerate() {
+        for b in &positions[i + 1..] {
+            assert!(a.distance(*b) > 0.1);
+        }
+    }
+}
+
+#[test]
+fn test_no_offset_or_velocity_keeps_the_spawn_position() {
+    let origin = Vec3::new(1.0, 0.0, 0.0);
+    assert_eq!(spawn_position(&emission(0.0), origin, Vec3::X), origin);
+    assert_eq!(spawn_position(&emission(0.5), origin, Vec3::ZERO), origin);
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use std::f32::consts::PI;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_sprinkles::asset::{EmitterData, ParticleSystemAsset};
+use bevy_sprinkles::runtime::{ParticleSystem3D, ParticleSystemRuntime};
+use bevy_sprinkles::test_utils::setup_particle_systems;
+
+fn create_test_app() -> App {
+    let mut app = common::create_test_app(Duration::from_millis(16));
+    app.add_systems(Update, setup_particle_systems);
+    app
+}
+
+fn add_asset(app: &mut App) -> Handle<ParticleSystemAsset> {
+    common::add_asset(app, vec![EmitterData::default()])
+}
+
+fn muzzle_flash() -> ParticleSystem3D {
+    ParticleSystem3D::new(Handle::default()).with_spawn_rotation_randomness(Vec3::new(0.0, 0.0, PI))
+}
+
+#[test]
+fn test_different_seeds_get_different_rotations() {
+    let system = muzzle_flash();
+    let first = system.spawn_rotation(1);
+    let second = system.spawn_rotation(2);
+    assert!(!first.abs_diff_eq(second, 1e-4), "{first} == {second}");
+
+    let rotations: Vec<Quat> = (0..16).map(|seed| system.spawn_rotation(seed)).collect();
+    for (i, a) in rotations.iter().enumerate() {
+        for b in &rotations[i + 1..] {
+            assert!(!a.abs_diff_eq(*b, 1e-4));
+        }
+    }
+}
+
+#[test]
+fn test_same_seed_gets_the_same_rotation() {
+    let a = muzzle_flash();
+    let b = muzzle_flash();
+    for seed in [0, 7, 12345, u32::MAX] {
+        assert_eq!(a.spawn_rotation(seed), b.spawn_rotation(seed));
+    }
+}
+
+#[test]
+fn test_rotation_stays_within_its_ranges() {
+    let system = ParticleSystem3D::new(Handle::default())
+        .with_spawn_rotation_randomness(Vec3::new(0.0, 0.5, 0.0));
+    for seed in 0..256 {
+        let (axis, angle) = system.spawn_rotation(seed).to_axis_angle();
+        assert!(angle <= 0.5 + 1e-5, "{angle}");
+        if angle > 1e-4 {
+            assert!(axis.abs().abs_diff_eq(Vec3::Y, 1e-4), "{axis}");
+        }
+    }
+}
+
+#[test]
+fn test_no_randomness_keeps_identity() {
+    let system = ParticleSystem3D::new(Handle::default());
+    assert_eq!(system.spawn_rotation(42), Quat::IDENTITY);
+}
+
+#[test]
+fn test_rotation_is_applied_once_on_spawn() {
+    let mut app = create_test_app();
+    let handle = add_asset(&mut app);
+
+    let base = Quat::from_rotation_x(0.3);
+    let system = app
+        .world_mut()
+        .spawn((
+            ParticleSystem3D::new(handle).with_spawn_rotation_randomness(Vec3::splat(PI)),
+            Transform::from_xyz(1.0, 2.0, 3.0).with_rotation(base),
+        ))
+        .id();
+    app.update();
+
+    let seed = app
+        .world()
+        .get::<ParticleSystemRuntime>(system)
+        .unwrap()
+        .global_seed;
+    let expected = base
+        * app
+            .world()
+            .get::<ParticleSystem3D>(system)
+            .unwrap()
+            .spawn_rotation(seed);
+    let transform = *app.world().get::<Transform>(system).unwrap();
+    assert!(transform.rotation.abs_diff_eq(expected, 1e-5));
+    assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+
+    app.update();
+    app.update();
+    assert_eq!(*app.world().get::<Transform>(system).unwrap(), transform);
+}
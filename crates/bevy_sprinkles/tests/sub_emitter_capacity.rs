@@ -0,0 +1,89 @@
+use bevy_sprinkles::asset::{SubEmitterConfig, SubEmitterMode};
+
+/// CPU mirror of the sub-emission buffer shared by a parent and its target emitter.
+struct EmissionBuffer {
+    particle_count: i32,
+    particle_max: u32,
+}
+
+impl EmissionBuffer {
+    fn new(particle_max: u32) -> Self {
+        Self {
+            particle_count: 0,
+            particle_max,
+        }
+    }
+
+    /// Mirrors `emit_subparticle` in `particle_simulate.wgsl`.
+    fn emit(&mut self) -> bool {
+        let dst_index = self.particle_count;
+        self.particle_count += 1;
+        if dst_index >= self.particle_max as i32 {
+            self.particle_count -= 1;
+            return false;
+        }
+        true
+    }
+
+    /// Mirrors the sub-emitter target branch of `main`: each inactive particle
+    /// consumes one event.
+    fn consume(&mut self, target: &mut [bool]) -> usize {
+        let mut spawned = 0;
+        for active in target.iter_mut().filter(|active| !**active) {
+            self.particle_count -= 1;
+            if self.particle_count >= 0 {
+                *active = true;
+                spawned += 1;
+            }
+        }
+        spawned
+    }
+}
+
+fn config(amount: u32) -> SubEmitterConfig {
+    SubEmitterConfig {
+        mode: SubEmitterMode::AtEnd,
+        target_emitter: 1,
+        amount,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_over_producing_parent_is_capped_at_target_capacity() {
+    let target_capacity = 32;
+    let config = config(4);
+    let parent_amount = 64;
+
+    let mut buffer = EmissionBuffer::new(target_capacity);
+    let mut accepted = 0;
+    for _ in 0..config.max_events_per_step(parent_amount) {
+        if buffer.emit() {
+            accepted += 1;
+        }
+    }
+    assert_eq!(accepted, target_capacity);
+
+    let mut target = vec![false; target_capacity as usize];
+    let spawned = buffer.consume(&mut target);
+    assert_eq!(spawned, target_capacity as usize);
+    assert!(target.iter().all(|active| *active));
+}
+
+#[test]
+fn test_events_within_capacity_are_all_spawned() {
+    let config = config(2);
+    let mut buffer = EmissionBuffer::new(32);
+    for _ in 0..config.max_events_per_step(8) {
+        assert!(buffer.emit());
+    }
+
+    let mut target = vec![false; 32];
+    assert_eq!(buffer.consume(&mut target), 16);
+}
+
+#[test]
+fn test_max_events_per_step_saturates() {
+    assert_eq!(config(3).max_events_per_step(10), 30);
+    assert_eq!(config(u32::MAX).max_events_per_step(2), u32::MAX);
+}
@@ -0,0 +1,167 @@
+use bevy::prelude::*;
+use bevy_sprinkles::asset::{SubEmitterConfig, SubEmitterMode};
+use bevy_sprinkles::runtime::ParticleData;
+
+const DT: f32 = 1.0 / 60.0;
+
+const EMISSION_FLAG_HAS_POSITION: u32 = 1;
+const EMISSION_FLAG_HAS_VELOCITY: u32 = 2;
+const EMISSION_FLAG_KEEP_LOCAL: u32 = 4;
+
+fn particle(position: Vec3, velocity: Vec3, age: f32) -> ParticleData {
+    ParticleData {
+        position: position.extend(1.0).to_array(),
+        velocity: velocity.extend(10.0).to_array(),
+        custom: [age, 0.0, 0.0, f32::from_bits(ParticleData::FLAG_ACTIVE)],
+        ..Default::default()
+    }
+}
+
+fn position(p: &ParticleData) -> Vec3 {
+    Vec3::from_slice(&p.position[..3])
+}
+
+/// Mirrors `sub_emission_flags` in `particle_simulate.wgsl`.
+fn sub_emission_flags(config: &SubEmitterConfig) -> u32 {
+    if config.keep_local {
+        return EMISSION_FLAG_HAS_POSITION | EMISSION_FLAG_KEEP_LOCAL;
+    }
+    if config.keep_velocity {
+        return EMISSION_FLAG_HAS_POSITION | EMISSION_FLAG_HAS_VELOCITY;
+    }
+    EMISSION_FLAG_HAS_POSITION
+}
+
+/// Mirrors how a sub-emitter target consumes an emission entry in `main` in
+/// `particle_simulate.wgsl`, for a child with no velocity of its own.
+fn spawn_child(parent: &ParticleData, parent_index: u32, flags: u32) -> ParticleData {
+    let mut child = particle(position(parent), Vec3::ZERO, 0.0);
+    if flags & EMISSION_FLAG_HAS_VELOCITY != 0 {
+        child.velocity[..3].copy_from_slice(&parent.velocity[..3]);
+    }
+    if flags & EMISSION_FLAG_KEEP_LOCAL != 0 {
+        let follow =
+            ParticleData::FLAG_FOLLOWS_PARENT | (parent_index << ParticleData::PARENT_INDEX_SHIFT);
+        child.custom[3] = f32::from_bits(child.custom[3].to_bits() | follow);
+    }
+    child
+}
+
+/// Mirrors `follow_parent` in `particle_simulate.wgsl`.
+fn follow_parent(mut p: ParticleData, parents: &[ParticleData]) -> ParticleData {
+    let flags = p.custom[3].to_bits();
+    if !p.is_active() || flags & ParticleData::FLAG_FOLLOWS_PARENT == 0 {
+        return p;
+    }
+
+    let following = parents
+        .get((flags >> ParticleData::PARENT_INDEX_SHIFT) as usize)
+        .filter(|parent| parent.is_active() && parent.age() + DT >= p.age());
+    match following {
+        Some(parent) => {
+            for axis in 0..3 {
+                p.position[axis] += parent.velocity[axis] * DT;
+            }
+        }
+        None => p.custom[3] = f32::from_bits(flags & ParticleData::FLAG_ACTIVE),
+    }
+    p
+}
+
+/// Moves a particle along its velocity for one step, like `update_particle` with no
+/// forces acting on it.
+fn step(mut p: ParticleData) -> ParticleData {
+    for axis in 0..3 {
+        p.position[axis] += p.velocity[axis] * DT;
+    }
+    p.custom[0] += DT;
+    p
+}
+
+/// Spawns a child from a moving parent in slot 3, then runs both for a second and
+/// returns them.
+fn simulate(config: &SubEmitterConfig) -> (ParticleData, ParticleData) {
+    let mut parents = vec![ParticleData::default(); 4];
+    parents[3] = particle(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 2.0, 0.0), 0.5);
+    let mut child = spawn_child(&parents[3], 3, sub_emission_flags(config));
+
+    for _ in 0..60 {
+        parents[3] = step(parents[3]);
+        child = follow_parent(step(child), &parents);
+    }
+    (parents[3], child)
+}
+
+fn config(keep_local: bool) -> SubEmitterConfig {
+    SubEmitterConfig {
+        mode: SubEmitterMode::AtStart,
+        target_emitter: 1,
+        keep_local,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_keep_local_round_trips_through_ron() {
+    let local = config(true);
+    let ron = ron::to_string(&local).expect("failed to serialize config");
+    assert!(ron.contains("keep_local"));
+    let parsed: SubEmitterConfig = ron::from_str(&ron).expect("failed to parse config");
+    assert!(parsed.keep_local);
+
+    let plain = ron::to_string(&config(false)).unwrap();
+    assert!(!plain.contains("keep_local"));
+    let parsed: SubEmitterConfig = ron::from_str(&plain).unwrap();
+    assert!(!parsed.keep_local);
+}
+
+#[test]
+fn test_world_space_children_stay_behind_a_moving_parent() {
+    let (parent, child) = simulate(&config(false));
+
+    assert!(position(&parent).abs_diff_eq(Vec3::new(1.0, 2.0, 0.0), 1e-4));
+    assert_eq!(position(&child), Vec3::new(1.0, 0.0, 0.0));
+    assert_eq!(child.parent_index(), None);
+}
+
+#[test]
+fn test_local_children_follow_a_moving_parent() {
+    let (parent, child) = simulate(&config(true));
+
+    assert!(position(&child).abs_diff_eq(position(&parent), 1e-4));
+    assert_eq!(child.parent_index(), Some(3));
+    assert!(child.is_active());
+}
+
+#[test]
+fn test_local_children_ignore_keep_velocity() {
+    let mut both = config(true);
+    both.keep_velocity = true;
+    let (parent, child) = simulate(&both);
+
+    // inheriting the velocity too would move the child twice as far as its parent
+    assert!(position(&child).abs_diff_eq(position(&parent), 1e-4));
+    assert_eq!(Vec3::from_slice(&child.velocity[..3]), Vec3::ZERO);
+}
+
+#[test]
+fn test_children_stop_following_a_respawned_parent() {
+    let mut parents = vec![particle(Vec3::ZERO, Vec3::X, 0.0)];
+    let mut child = spawn_child(&parents[0], 0, EMISSION_FLAG_KEEP_LOCAL);
+    for _ in 0..10 {
+        parents[0] = step(parents[0]);
+        child = follow_parent(step(child), &parents);
+    }
+    let left_at = position(&child);
+    assert!(left_at.x > 0.1);
+
+    // the slot is reused by a new, younger parent
+    parents[0] = particle(Vec3::new(5.0, 0.0, 0.0), Vec3::X, 0.0);
+    for _ in 0..10 {
+        parents[0] = step(parents[0]);
+        child = follow_parent(step(child), &parents);
+    }
+    assert_eq!(position(&child), left_at);
+    assert_eq!(child.parent_index(), None);
+    assert!(child.is_active());
+}
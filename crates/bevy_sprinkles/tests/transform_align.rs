@@ -0,0 +1,219 @@
+use bevy::prelude::*;
+use bevy::render::storage::ShaderStorageBuffer;
+
+use bevy_sprinkles::asset::{
+    BillboardAxis, EmitterData, EmitterDrawPass, ParticleMesh, ParticleSystemAsset,
+    ParticleSystemDimension, TransformAlign,
+};
+use bevy_sprinkles::runtime::{
+    EmitterRuntime, ParticleBufferGrowth, ParticleMaterial, ParticleMaterialHandle,
+    ParticleSystem3D,
+};
+use bevy_sprinkles::test_utils::setup_particle_systems;
+use bevy_sprinkles::{CustomPresetRegistry, ParticleMaterialExtension};
+
+// mirrors `TRANSFORM_ALIGN_SHIFT`, `TRANSFORM_ALIGN_MASK` and the align modes in `common.wgsl`
+const TRANSFORM_ALIGN_SHIFT: u32 = 3;
+const TRANSFORM_ALIGN_MASK: u32 = 7;
+const TRANSFORM_ALIGN_DISABLED: u32 = 0;
+const TRANSFORM_ALIGN_BILLBOARD: u32 = 1;
+const TRANSFORM_ALIGN_BILLBOARD_SCREEN_ALIGNED: u32 = 5;
+
+/// Returns whether `particle_material.wgsl` takes the camera-facing branch for the given
+/// material flags, rather than drawing each particle as a regular mesh.
+fn is_billboard_path(particle_flags: u32) -> bool {
+    let align = (particle_flags >> TRANSFORM_ALIGN_SHIFT) & TRANSFORM_ALIGN_MASK;
+    matches!(align, 1 | 3 | 4 | 5)
+}
+
+/// Mirrors the screen aligned basis in `particle_material.wgsl`, returning the quad's
+/// right and up axes for a camera with the given transform.
+fn screen_aligned_basis(camera: Transform) -> (Vec3, Vec3) {
+    let cam_forward = camera.back().as_vec3();
+    let mut cam_right = camera.right().as_vec3();
+    let mut cam_up = camera.up().as_vec3();
+    let screen_up = Vec3::Y - cam_forward * cam_forward.y;
+    if screen_up.length() > 0.001 {
+        cam_up = screen_up.normalize();
+        cam_right = cam_up.cross(cam_forward);
+    }
+    (cam_right, cam_up)
+}
+
+fn debris_emitter(transform_align: Option<TransformAlign>) -> EmitterData {
+    EmitterData {
+        draw_pass: EmitterDrawPass {
+            mesh: ParticleMesh::Cuboid {
+                half_size: Vec3::splat(0.5),
+            },
+            transform_align,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn fixed_axis_emitter(billboard_axis: BillboardAxis) -> EmitterData {
+    EmitterData {
+        draw_pass: EmitterDrawPass {
+            transform_align: Some(TransformAlign::BillboardFixedY),
+            billboard_axis,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn material_extensions(emitters: Vec<EmitterData>) -> Vec<ParticleMaterialExtension> {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<ParticleSystemAsset>()
+        .init_asset::<ShaderStorageBuffer>()
+        .init_asset::<Mesh>()
+        .init_asset::<ParticleMaterial>()
+        .init_resource::<ParticleBufferGrowth>()
+        .init_resource::<CustomPresetRegistry>()
+        .add_systems(Update, setup_particle_systems);
+
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<ParticleSystemAsset>>()
+        .add(ParticleSystemAsset::new(
+            "Debris".to_string(),
+            ParticleSystemDimension::D3,
+            emitters,
+            vec![],
+            None,
+        ));
+    app.world_mut().spawn(ParticleSystem3D::new(handle));
+    app.update();
+
+    let mut query = app
+        .world_mut()
+        .query::<(&EmitterRuntime, &ParticleMaterialHandle)>();
+    let mut handles: Vec<_> = query
+        .iter(app.world())
+        .map(|(runtime, material)| (runtime.emitter_index, material.0.clone()))
+        .collect();
+    handles.sort_by_key(|(index, _)| *index);
+
+    let materials = app.world().resource::<Assets<ParticleMaterial>>();
+    handles
+        .iter()
+        .map(|(_, handle)| materials.get(handle).unwrap().extension.clone())
+        .collect()
+}
+
+fn material_flags(emitters: Vec<EmitterData>) -> Vec<u32> {
+    material_extensions(emitters)
+        .iter()
+        .map(|extension| extension.particle_flags)
+        .collect()
+}
+
+#[test]
+fn test_transform_align_round_trips_through_ron() {
+    for align in [
+        None,
+        Some(TransformAlign::Billboard),
+        Some(TransformAlign::BillboardScreenAligned),
+    ] {
+        let draw_pass = debris_emitter(align).draw_pass;
+        let ron = ron::to_string(&draw_pass).expect("failed to serialize draw pass");
+        let parsed: EmitterDrawPass = ron::from_str(&ron).expect("failed to parse draw pass");
+        assert_eq!(parsed.transform_align, align);
+        assert_eq!(parsed.mesh, draw_pass.mesh);
+        assert_eq!(ron.contains("transform_align"), align.is_some());
+    }
+}
+
+#[test]
+fn test_unaligned_meshes_skip_the_billboard_path() {
+    let flags = material_flags(vec![
+        debris_emitter(None),
+        debris_emitter(Some(TransformAlign::Billboard)),
+        debris_emitter(Some(TransformAlign::YToVelocity)),
+    ]);
+
+    let align = |flags: u32| (flags >> TRANSFORM_ALIGN_SHIFT) & TRANSFORM_ALIGN_MASK;
+    assert_eq!(align(flags[0]), TRANSFORM_ALIGN_DISABLED);
+    assert_eq!(align(flags[1]), TRANSFORM_ALIGN_BILLBOARD);
+
+    assert!(!is_billboard_path(flags[0]));
+    assert!(is_billboard_path(flags[1]));
+    assert!(!is_billboard_path(flags[2]));
+}
+
+#[test]
+fn test_billboard_axis_round_trips_through_ron() {
+    for axis in [
+        BillboardAxis::WorldUp,
+        BillboardAxis::EmitterUp,
+        BillboardAxis::Custom {
+            axis: Vec3::new(1.0, 0.0, 1.0),
+        },
+    ] {
+        let draw_pass = fixed_axis_emitter(axis).draw_pass;
+        let ron = ron::to_string(&draw_pass).expect("failed to serialize draw pass");
+        let parsed: EmitterDrawPass = ron::from_str(&ron).expect("failed to parse draw pass");
+        assert_eq!(parsed.billboard_axis, axis);
+        assert_eq!(
+            ron.contains("billboard_axis"),
+            axis != BillboardAxis::WorldUp
+        );
+    }
+}
+
+#[test]
+fn test_billboard_axis_is_passed_to_the_material() {
+    let extensions = material_extensions(vec![
+        fixed_axis_emitter(BillboardAxis::WorldUp),
+        fixed_axis_emitter(BillboardAxis::EmitterUp),
+        fixed_axis_emitter(BillboardAxis::Custom {
+            axis: Vec3::new(0.0, 0.0, 2.0),
+        }),
+        fixed_axis_emitter(BillboardAxis::Custom { axis: Vec3::ZERO }),
+    ]);
+
+    let axes: Vec<Vec4> = extensions.iter().map(|e| e.billboard_axis).collect();
+    assert_eq!(
+        axes,
+        vec![
+            Vec4::new(0.0, 1.0, 0.0, 0.0),
+            Vec4::new(0.0, 1.0, 0.0, 1.0),
+            Vec4::new(0.0, 0.0, 1.0, 0.0),
+            Vec4::new(0.0, 1.0, 0.0, 0.0),
+        ]
+    );
+}
+
+#[test]
+fn test_screen_aligned_billboards_take_the_billboard_path() {
+    let flags = material_flags(vec![debris_emitter(Some(
+        TransformAlign::BillboardScreenAligned,
+    ))]);
+
+    let align = (flags[0] >> TRANSFORM_ALIGN_SHIFT) & TRANSFORM_ALIGN_MASK;
+    assert_eq!(align, TRANSFORM_ALIGN_BILLBOARD_SCREEN_ALIGNED);
+    assert!(is_billboard_path(flags[0]));
+}
+
+#[test]
+fn test_screen_aligned_basis_ignores_camera_roll() {
+    let camera = Transform::from_xyz(4.0, 3.0, 6.0).looking_at(Vec3::ZERO, Vec3::Y);
+    let (right, up) = screen_aligned_basis(camera);
+    assert!(right.abs_diff_eq(camera.right().as_vec3(), 1e-5));
+    assert!(up.abs_diff_eq(camera.up().as_vec3(), 1e-5));
+
+    for roll in [0.3, 1.2, -2.0] {
+        let rolled = camera.with_rotation(camera.rotation * Quat::from_rotation_z(roll));
+        let (rolled_right, rolled_up) = screen_aligned_basis(rolled);
+        assert!(rolled_right.abs_diff_eq(right, 1e-5));
+        assert!(rolled_up.abs_diff_eq(up, 1e-5));
+    }
+
+    let looking_down = Transform::from_xyz(0.0, 5.0, 0.0).looking_at(Vec3::ZERO, Vec3::Z);
+    let (right, up) = screen_aligned_basis(looking_down);
+    assert!(right.abs_diff_eq(looking_down.right().as_vec3(), 1e-5));
+    assert!(up.abs_diff_eq(looking_down.up().as_vec3(), 1e-5));
+}
@@ -0,0 +1,132 @@
+use bevy::prelude::*;
+use bevy_sprinkles::asset::{
+    AssetWarning, EmitterData, EmitterEmission, EmitterTime, ParticleSystemAsset,
+    ParticleSystemDimension, SubEmitterConfig,
+};
+
+fn emitter(amount: u32, sub_emitter: Option<SubEmitterConfig>) -> EmitterData {
+    EmitterData {
+        emission: EmitterEmission {
+            particles_amount: amount,
+            ..Default::default()
+        },
+        sub_emitter,
+        ..Default::default()
+    }
+}
+
+fn targeting(target_emitter: usize, amount: u32) -> Option<SubEmitterConfig> {
+    Some(SubEmitterConfig {
+        target_emitter,
+        amount,
+        ..Default::default()
+    })
+}
+
+fn asset(emitters: Vec<EmitterData>) -> ParticleSystemAsset {
+    ParticleSystemAsset::new(
+        "Validate".to_string(),
+        ParticleSystemDimension::D3,
+        emitters,
+        vec![],
+        None,
+    )
+}
+
+#[test]
+fn test_valid_asset_has_no_warnings() {
+    let asset = asset(vec![emitter(8, targeting(1, 4)), emitter(32, None)]);
+    assert!(asset.validate().is_empty());
+}
+
+#[test]
+fn test_bad_sub_emitter_target_is_reported_on_its_emitter() {
+    let mut asset = asset(vec![emitter(32, None), emitter(8, targeting(0, 1))]);
+    assert!(asset.validate().is_empty());
+
+    asset.emitters[1].sub_emitter = targeting(5, 1);
+    let warnings = asset.validate();
+    assert_eq!(
+        warnings,
+        vec![AssetWarning::MissingSubEmitterTarget {
+            emitter: 1,
+            target: 5
+        }]
+    );
+    assert_eq!(warnings[0].emitter(), 1);
+    assert_eq!(warnings[0].field(), "sub_emitter.target_emitter");
+    assert!(warnings[0].to_string().contains("#5"));
+
+    asset.emitters[1].sub_emitter = targeting(1, 1);
+    assert_eq!(
+        asset.validate(),
+        vec![AssetWarning::SelfSubEmitterTarget { emitter: 1 }]
+    );
+}
+
+#[test]
+fn test_sub_emitter_overflow_is_reported() {
+    let asset = asset(vec![emitter(8, targeting(1, 16)), emitter(32, None)]);
+    assert_eq!(
+        asset.validate(),
+        vec![AssetWarning::SubEmitterOverflow {
+            emitter: 0,
+            max_events: 128,
+            capacity: 32,
+        }]
+    );
+}
+
+#[test]
+fn test_empty_emitters_are_reported() {
+    let mut dead = emitter(0, None);
+    dead.time = EmitterTime {
+        lifetime: 0.0,
+        ..Default::default()
+    };
+    let warnings = asset(vec![emitter(8, None), dead]).validate();
+    assert_eq!(
+        warnings,
+        vec![
+            AssetWarning::NoParticles { emitter: 1 },
+            AssetWarning::NonPositiveLifetime {
+                emitter: 1,
+                lifetime: 0.0
+            },
+        ]
+    );
+    assert_eq!(warnings[1].field(), "time.lifetime");
+}
+
+#[test]
+fn test_far_away_emitter_is_reported() {
+    let mut far = emitter(8, None);
+    far.position = Vec3::new(0.0, 0.0, -20_000.0);
+    let warnings = asset(vec![emitter(8, None), far]).validate();
+    assert_eq!(
+        warnings,
+        vec![AssetWarning::FarFromOrigin {
+            emitter: 1,
+            distance: 20_000.0,
+        }]
+    );
+    assert_eq!(warnings[0].field(), "position");
+}
+
+#[test]
+fn test_unsorted_gradient_is_reported_and_sorted() {
+    let mut unsorted = emitter(8, None);
+    unsorted.colors.color_over_lifetime.stops.reverse();
+    let mut asset = asset(vec![emitter(8, None), unsorted]);
+
+    let expected = AssetWarning::UnsortedGradientStops {
+        emitter: 1,
+        field: "colors.color_over_lifetime",
+    };
+    assert_eq!(asset.validate(), vec![expected.clone()]);
+    assert_eq!(expected.field(), "colors.color_over_lifetime");
+
+    assert_eq!(asset.sort_gradient_stops(), vec![expected]);
+    assert!(asset.emitters[1].colors.color_over_lifetime.is_sorted());
+    assert!(asset.validate().is_empty());
+}
@@ -0,0 +1,116 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use bevy_sprinkles::asset::{EmitterVelocities, WobbleConfig};
+use bevy_sprinkles::test_utils::{hash, hash_to_float};
+
+const DT: f32 = 1.0 / 60.0;
+
+/// Mirrors `get_wobble_offset` in `particle_simulate.wgsl`, where `seed` is the
+/// particle's stored seed.
+fn wobble_offset(wobble: Option<WobbleConfig>, seed: u32, age: f32) -> Vec3 {
+    let Some(wobble) = wobble else {
+        return Vec3::ZERO;
+    };
+    let phase = Vec3::new(
+        hash_to_float(seed.wrapping_add(120)),
+        hash_to_float(seed.wrapping_add(121)),
+        hash_to_float(seed.wrapping_add(122)),
+    );
+    let angle = (age * wobble.frequency + phase) * TAU;
+    wobble.amplitude * Vec3::new(angle.x.sin(), angle.y.sin(), angle.z.sin())
+}
+
+/// Moves a particle along a constant velocity for `frames` steps, like `update_particle`
+/// with no forces acting on it, and returns its position after each step.
+fn simulate(wobble: Option<WobbleConfig>, seed: u32, velocity: Vec3, frames: u32) -> Vec<Vec3> {
+    let mut position = Vec3::ZERO;
+    let mut age = 0.0;
+    (0..frames)
+        .map(|_| {
+            let prev_age = age;
+            age += DT;
+            position += velocity * DT;
+            position += wobble_offset(wobble, seed, age) - wobble_offset(wobble, seed, prev_age);
+            position
+        })
+        .collect()
+}
+
+#[test]
+fn test_wobble_round_trips_through_ron() {
+    let velocities = EmitterVelocities {
+        wobble: Some(WobbleConfig {
+            amplitude: Vec3::new(0.5, 0.0, 0.25),
+            frequency: 2.0,
+        }),
+        ..Default::default()
+    };
+    let ron = ron::to_string(&velocities).expect("failed to serialize velocities");
+    let parsed: EmitterVelocities = ron::from_str(&ron).expect("failed to parse velocities");
+    assert_eq!(parsed, velocities);
+
+    let plain = ron::to_string(&EmitterVelocities::default()).unwrap();
+    assert!(!plain.contains("wobble"));
+    let parsed: EmitterVelocities = ron::from_str(&plain).unwrap();
+    assert_eq!(parsed.wobble, None);
+
+    let parsed: EmitterVelocities = ron::from_str("(wobble: Some(()))").unwrap();
+    assert_eq!(parsed.wobble, Some(WobbleConfig::default()));
+}
+
+#[test]
+fn test_wobbling_particle_oscillates_around_its_drift_path() {
+    let wobble = Some(WobbleConfig {
+        amplitude: Vec3::new(0.5, 0.2, 0.0),
+        frequency: 1.0,
+    });
+    let velocity = Vec3::new(0.0, 1.0, 0.0);
+    let seed = hash(3);
+    // particles spawn partway through their sway, so it's centred this far from the spawn
+    // point
+    let start = wobble_offset(wobble, seed, 0.0);
+
+    // two full periods
+    let deviations: Vec<Vec3> = simulate(wobble, seed, velocity, 120)
+        .iter()
+        .enumerate()
+        .map(|(frame, position)| *position - velocity * (frame + 1) as f32 * DT + start)
+        .collect();
+
+    let max = deviations
+        .iter()
+        .fold(Vec3::ZERO, |acc, d| acc.max(d.abs()));
+    assert!(
+        max.x > 0.45 && max.x <= 0.5 + 1e-4,
+        "max x deviation {}",
+        max.x
+    );
+    assert!(
+        max.y > 0.15 && max.y <= 0.2 + 1e-4,
+        "max y deviation {}",
+        max.y
+    );
+    assert!(max.z < 1e-6);
+
+    let mean = deviations.iter().sum::<Vec3>() / deviations.len() as f32;
+    assert!(mean.abs().max_element() < 0.01, "mean deviation {mean}");
+
+    // back where the drift alone would put it after whole periods
+    assert!(deviations[119].abs_diff_eq(start, 1e-4));
+}
+
+#[test]
+fn test_wobble_is_seeded_per_particle() {
+    let wobble = Some(WobbleConfig::default());
+    let a = simulate(wobble, hash(1), Vec3::ZERO, 30);
+    assert_eq!(a, simulate(wobble, hash(1), Vec3::ZERO, 30));
+    assert_ne!(a, simulate(wobble, hash(2), Vec3::ZERO, 30));
+}
+
+#[test]
+fn test_no_wobble_follows_the_drift_path() {
+    let velocity = Vec3::new(1.0, 2.0, 3.0);
+    let positions = simulate(None, hash(1), velocity, 60);
+    assert!(positions[59].abs_diff_eq(velocity, 1e-4));
+}
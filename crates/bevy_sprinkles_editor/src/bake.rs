@@ -0,0 +1,194 @@
+use std::fs::File;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use bevy::render::gpu_readback::ReadbackComplete;
+use bevy::tasks::IoTaskPool;
+use bevy_sprinkles::bake::{
+    ParticleMeshBaker, mesh_to_obj, particle_readback, particles_from_bytes,
+};
+use bevy_sprinkles::prelude::*;
+use bevy_sprinkles::runtime::ParticleBufferHandle;
+use inflector::Inflector;
+
+use crate::io::projects_dir;
+use crate::state::EditorState;
+use crate::ui::components::toasts::ToastEvent;
+use crate::viewport::{EditorCamera, EditorParticlePreview};
+
+pub fn plugin(app: &mut App) {
+    app.add_observer(on_bake_mesh_event)
+        .add_systems(Update, poll_bake_result);
+}
+
+#[derive(Event)]
+pub struct BakeMeshEvent;
+
+#[derive(Component)]
+struct BakeReadback {
+    emitter_transform: Mat4,
+}
+
+#[derive(Resource)]
+struct PendingBake {
+    baker: ParticleMeshBaker,
+    remaining: usize,
+}
+
+#[derive(Clone)]
+enum BakeResultStatus {
+    Success(String),
+    WriteError(String),
+    CreateError,
+}
+
+#[derive(Resource, Clone)]
+struct BakeResult(Arc<Mutex<Option<BakeResultStatus>>>);
+
+fn on_bake_mesh_event(
+    _event: On<BakeMeshEvent>,
+    pending: Option<Res<PendingBake>>,
+    preview_systems: Query<Entity, With<EditorParticlePreview>>,
+    emitters: Query<(&EmitterEntity, &ParticleBufferHandle, &GlobalTransform)>,
+    camera: Single<&GlobalTransform, With<EditorCamera>>,
+    mut commands: Commands,
+) {
+    if pending.is_some() {
+        return;
+    }
+
+    let mut remaining = 0;
+    for (emitter, buffers, transform) in &emitters {
+        if !preview_systems.contains(emitter.parent_system) {
+            continue;
+        }
+        commands
+            .spawn((
+                particle_readback(buffers),
+                BakeReadback {
+                    emitter_transform: transform.to_matrix(),
+                },
+            ))
+            .observe(on_bake_readback_complete);
+        remaining += 1;
+    }
+
+    if remaining == 0 {
+        commands.trigger(ToastEvent::error("Nothing to bake"));
+        return;
+    }
+
+    commands.insert_resource(PendingBake {
+        baker: ParticleMeshBaker::new(camera.rotation()),
+        remaining,
+    });
+}
+
+fn on_bake_readback_complete(
+    event: On<ReadbackComplete>,
+    readbacks: Query<&BakeReadback>,
+    pending: Option<ResMut<PendingBake>>,
+    editor_state: Res<EditorState>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    mut commands: Commands,
+) {
+    // readbacks repeat every frame until the entity is gone
+    commands.entity(event.entity).despawn();
+
+    let (Ok(readback), Some(mut pending)) = (readbacks.get(event.entity), pending) else {
+        return;
+    };
+
+    let particles = particles_from_bytes(&event.data);
+    pending
+        .baker
+        .add_particles(&particles, readback.emitter_transform);
+    pending.remaining -= 1;
+    if pending.remaining > 0 {
+        return;
+    }
+
+    commands.remove_resource::<PendingBake>();
+    let baker = std::mem::replace(&mut pending.baker, ParticleMeshBaker::new(Quat::IDENTITY));
+    if baker.particle_count() == 0 {
+        commands.trigger(ToastEvent::error("Nothing to bake: no particles are alive"));
+        return;
+    }
+
+    let contents = mesh_to_obj(&baker.build());
+    let name = editor_state
+        .current_project
+        .as_ref()
+        .and_then(|handle| assets.get(handle))
+        .map(|asset| asset.name.to_kebab_case())
+        .unwrap_or_else(|| "particles".to_string());
+
+    let result = Arc::new(Mutex::new(None));
+    let result_clone = result.clone();
+
+    let task = rfd::AsyncFileDialog::new()
+        .set_title("Bake to Mesh")
+        .set_directory(projects_dir())
+        .set_file_name(format!("{name}.obj"))
+        .add_filter("OBJ files", &["obj"])
+        .save_file();
+
+    IoTaskPool::get()
+        .spawn(async move {
+            let Some(file_handle) = task.await else {
+                return;
+            };
+            let path = file_handle.path().to_path_buf();
+            let status = match File::create(&path) {
+                Ok(mut file) => {
+                    let filename = path
+                        .file_name()
+                        .map(|n| n.to_string_lossy().to_string())
+                        .unwrap_or_default();
+                    if file.write_all(contents.as_bytes()).is_ok() {
+                        BakeResultStatus::Success(filename)
+                    } else {
+                        BakeResultStatus::WriteError(filename)
+                    }
+                }
+                Err(_) => BakeResultStatus::CreateError,
+            };
+            if let Ok(mut guard) = result_clone.lock() {
+                *guard = Some(status);
+            }
+        })
+        .detach();
+
+    commands.insert_resource(BakeResult(result));
+}
+
+fn poll_bake_result(result: Option<Res<BakeResult>>, mut commands: Commands) {
+    let Some(result) = result else {
+        return;
+    };
+
+    let status = {
+        let Ok(mut guard) = result.0.lock() else {
+            return;
+        };
+        guard.take()
+    };
+
+    if let Some(status) = status {
+        match status {
+            BakeResultStatus::Success(filename) => {
+                commands.trigger(ToastEvent::success(format!("Baked to \"{filename}\"")));
+            }
+            BakeResultStatus::WriteError(filename) => {
+                commands.trigger(ToastEvent::error(format!(
+                    "Failed to write to \"{filename}\""
+                )));
+            }
+            BakeResultStatus::CreateError => {
+                commands.trigger(ToastEvent::error("Failed to create mesh file"));
+            }
+        }
+        commands.remove_resource::<BakeResult>();
+    }
+}
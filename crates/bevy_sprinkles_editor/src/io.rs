@@ -17,6 +17,61 @@ pub fn plugin(app: &mut App) {
 #[derive(Resource, Serialize, Deserialize, Default)]
 pub struct EditorData {
     pub cache: EditorCache,
+    #[serde(default)]
+    pub settings: EditorSettings,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct EditorSettings {
+    /// Unit that angle fields are shown and entered in. Assets always store degrees.
+    #[serde(default)]
+    pub angle_unit: AngleUnit,
+    /// Color theme the editor UI is drawn with.
+    #[serde(default)]
+    pub theme: ThemeKind,
+    /// Project file that new projects are copied from. New projects start with a single
+    /// default emitter when unset or when the file can't be loaded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_project_template: Option<PathBuf>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ThemeKind {
+    #[default]
+    Dark,
+    HighContrast,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AngleUnit {
+    #[default]
+    Degrees,
+    Radians,
+}
+
+impl AngleUnit {
+    /// Converts an angle stored in degrees to this unit.
+    pub fn to_unit(self, degrees: f32) -> f32 {
+        match self {
+            Self::Degrees => degrees,
+            Self::Radians => degrees.to_radians(),
+        }
+    }
+
+    /// Converts an angle in this unit to degrees, for storing in the asset.
+    pub fn to_degrees(self, value: f32) -> f32 {
+        match self {
+            Self::Degrees => value,
+            Self::Radians => value.to_degrees(),
+        }
+    }
+
+    pub fn suffix(self) -> &'static str {
+        match self {
+            Self::Degrees => "°",
+            Self::Radians => "rad",
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Default)]
@@ -1,8 +1,13 @@
 mod assets;
+mod bake;
 mod io;
+mod module;
 mod plugin;
 mod project;
 mod state;
+#[cfg(test)]
+mod test_utils;
+mod thumbnail;
 mod ui;
 mod viewport;
 
@@ -23,6 +28,8 @@ fn main() {
                         resolution: WindowResolution::new(1366, 768),
                         ..default()
                     }),
+                    // quitting asks to save unsaved changes first, see `project.rs`
+                    close_when_requested: false,
                     ..default()
                 })
                 .set(AssetPlugin {
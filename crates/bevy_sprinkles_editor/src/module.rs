@@ -0,0 +1,233 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use bevy::tasks::IoTaskPool;
+use bevy_sprinkles::prelude::*;
+use inflector::Inflector;
+
+use crate::io::projects_dir;
+use crate::state::{DirtyState, EditorState, Inspectable, Inspecting};
+use crate::ui::components::data_panel::{LastLoadedProject, next_unique_name};
+use crate::ui::components::toasts::ToastEvent;
+use crate::viewport::RespawnEmittersEvent;
+
+pub fn plugin(app: &mut App) {
+    app.add_observer(on_export_emitter_module_event)
+        .add_observer(on_import_emitter_module_event)
+        .add_systems(Update, (poll_export_result, poll_import_result));
+}
+
+/// Saves the emitter at the given index, with its sub-emitter targets, to a module file.
+#[derive(Event)]
+pub struct ExportEmitterModuleEvent(pub usize);
+
+/// Appends the emitters of a module file picked by the user to the current project.
+#[derive(Event)]
+pub struct ImportEmitterModuleEvent;
+
+#[derive(Clone)]
+enum ExportResultStatus {
+    Success(String),
+    WriteError(String),
+    CreateError,
+}
+
+#[derive(Resource, Clone)]
+struct ExportResult(Arc<Mutex<Option<ExportResultStatus>>>);
+
+#[derive(Resource, Clone)]
+struct ImportResult(Arc<Mutex<Option<PathBuf>>>);
+
+fn on_export_emitter_module_event(
+    event: On<ExportEmitterModuleEvent>,
+    editor_state: Res<EditorState>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    mut commands: Commands,
+) {
+    let Some(asset) = editor_state
+        .current_project
+        .as_ref()
+        .and_then(|handle| assets.get(handle))
+    else {
+        return;
+    };
+    let Some(module) = asset.export_emitter_module(event.0) else {
+        return;
+    };
+    let Ok(contents) = module.to_ron() else {
+        commands.trigger(ToastEvent::error("Cannot export emitter with invalid data"));
+        return;
+    };
+
+    let name = asset.emitters[event.0].name.to_kebab_case();
+    let result = Arc::new(Mutex::new(None));
+    let result_clone = result.clone();
+
+    let task = rfd::AsyncFileDialog::new()
+        .set_title("Export Emitter Module")
+        .set_directory(projects_dir())
+        .set_file_name(format!("{name}.{}", EmitterModule::EXTENSION))
+        .add_filter("Emitter modules", &["ron"])
+        .save_file();
+
+    IoTaskPool::get()
+        .spawn(async move {
+            let Some(file_handle) = task.await else {
+                return;
+            };
+            let path = file_handle.path().to_path_buf();
+            let status = match File::create(&path) {
+                Ok(mut file) => {
+                    let filename = path
+                        .file_name()
+                        .map(|n| n.to_string_lossy().to_string())
+                        .unwrap_or_default();
+                    if file.write_all(contents.as_bytes()).is_ok() {
+                        ExportResultStatus::Success(filename)
+                    } else {
+                        ExportResultStatus::WriteError(filename)
+                    }
+                }
+                Err(_) => ExportResultStatus::CreateError,
+            };
+            if let Ok(mut guard) = result_clone.lock() {
+                *guard = Some(status);
+            }
+        })
+        .detach();
+
+    commands.insert_resource(ExportResult(result));
+}
+
+fn poll_export_result(result: Option<Res<ExportResult>>, mut commands: Commands) {
+    let Some(result) = result else {
+        return;
+    };
+
+    let status = {
+        let Ok(mut guard) = result.0.lock() else {
+            return;
+        };
+        guard.take()
+    };
+
+    if let Some(status) = status {
+        match status {
+            ExportResultStatus::Success(filename) => {
+                commands.trigger(ToastEvent::success(format!("Exported \"{filename}\"")));
+            }
+            ExportResultStatus::WriteError(filename) => {
+                commands.trigger(ToastEvent::error(format!(
+                    "Failed to write to \"{filename}\""
+                )));
+            }
+            ExportResultStatus::CreateError => {
+                commands.trigger(ToastEvent::error("Failed to create module file"));
+            }
+        }
+        commands.remove_resource::<ExportResult>();
+    }
+}
+
+fn on_import_emitter_module_event(
+    _event: On<ImportEmitterModuleEvent>,
+    editor_state: Res<EditorState>,
+    mut commands: Commands,
+) {
+    if editor_state.current_project.is_none() {
+        return;
+    }
+
+    let path_result = Arc::new(Mutex::new(None));
+    let path_result_clone = path_result.clone();
+
+    let task = rfd::AsyncFileDialog::new()
+        .set_title("Import Emitter Module")
+        .set_directory(projects_dir())
+        .add_filter("Emitter modules", &["ron"])
+        .pick_file();
+
+    IoTaskPool::get()
+        .spawn(async move {
+            if let Some(file_handle) = task.await {
+                let path = file_handle.path().to_path_buf();
+                if let Ok(mut guard) = path_result_clone.lock() {
+                    *guard = Some(path);
+                }
+            }
+        })
+        .detach();
+
+    commands.insert_resource(ImportResult(path_result));
+}
+
+fn poll_import_result(
+    result: Option<Res<ImportResult>>,
+    mut editor_state: ResMut<EditorState>,
+    mut assets: ResMut<Assets<ParticleSystemAsset>>,
+    mut dirty_state: ResMut<DirtyState>,
+    mut last_project: ResMut<LastLoadedProject>,
+    mut commands: Commands,
+) {
+    let Some(result) = result else {
+        return;
+    };
+
+    let path = {
+        let Ok(mut guard) = result.0.lock() else {
+            return;
+        };
+        guard.take()
+    };
+    let Some(path) = path else {
+        return;
+    };
+    commands.remove_resource::<ImportResult>();
+
+    let filename = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let module = std::fs::read(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|bytes| EmitterModule::from_ron(&bytes).map_err(|err| err.to_string()));
+    let mut module = match module {
+        Ok((module, _)) => module,
+        Err(err) => {
+            commands.trigger(ToastEvent::error(format!(
+                "Failed to import \"{filename}\": {err}"
+            )));
+            return;
+        }
+    };
+
+    let Some(asset) = editor_state
+        .current_project
+        .as_ref()
+        .and_then(|handle| assets.get_mut(handle))
+    else {
+        return;
+    };
+
+    let mut existing: Vec<String> = asset.emitters.iter().map(|e| e.name.clone()).collect();
+    for emitter in &mut module.emitters {
+        let names: Vec<&str> = existing.iter().map(String::as_str).collect();
+        emitter.name = next_unique_name(&emitter.name, &names);
+        existing.push(emitter.name.clone());
+    }
+
+    let first = asset.import_emitter_module(module);
+
+    dirty_state.has_unsaved_changes = true;
+    editor_state.inspecting = Some(Inspecting {
+        kind: Inspectable::Emitter,
+        index: first as u8,
+    });
+    last_project.handle = None;
+    commands.trigger(RespawnEmittersEvent);
+    commands.trigger(ToastEvent::success(format!("Imported \"{filename}\"")));
+}
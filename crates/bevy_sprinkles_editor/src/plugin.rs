@@ -6,9 +6,10 @@ use crate::io::{EditorData, project_path, save_editor_data, working_dir};
 use crate::project::load_project_from_path;
 use crate::state::{DirtyState, EditorState, Inspectable, Inspecting};
 use crate::viewport::{
-    CameraSettings, ViewportInputState, configure_floor_texture, despawn_preview_on_project_change,
-    draw_collider_gizmos, handle_playback_play_event, handle_playback_reset_event,
-    handle_playback_seek_event, handle_respawn_colliders, handle_respawn_emitters, orbit_camera,
+    CameraSettings, ViewportInputState, apply_muted_colliders, configure_floor_texture,
+    despawn_preview_on_project_change, draw_collider_gizmos, draw_emission_shape_gizmos,
+    handle_playback_play_event, handle_playback_reset_event, handle_playback_seek_event,
+    handle_respawn_colliders, handle_respawn_emitters, orbit_camera,
     respawn_preview_on_emitter_change, setup_camera, setup_floor, spawn_preview_particle_system,
     sync_playback_state, zoom_camera,
 };
@@ -32,10 +33,13 @@ impl Plugin for SprinklesEditorPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(CliArgs::from_env())
             .add_plugins(crate::assets::plugin)
-            .add_plugins(SprinklesPlugin)
+            .add_plugins(SprinklesPlugin::default())
             .add_plugins(crate::io::plugin)
             .add_plugins(crate::state::plugin)
             .add_plugins(crate::project::plugin)
+            .add_plugins(crate::module::plugin)
+            .add_plugins(crate::bake::plugin)
+            .add_plugins(crate::thumbnail::plugin)
             .init_resource::<CameraSettings>()
             .init_resource::<ViewportInputState>()
             .insert_resource(ClearColor(ZINC_950.into()))
@@ -56,8 +60,10 @@ impl Plugin for SprinklesEditorPlugin {
                     despawn_preview_on_project_change,
                     sync_playback_state,
                     draw_collider_gizmos,
+                    draw_emission_shape_gizmos,
                 ),
-            );
+            )
+            .add_systems(PostUpdate, apply_muted_colliders);
     }
 }
 
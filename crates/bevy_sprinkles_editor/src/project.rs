@@ -1,23 +1,42 @@
 use std::fs::File;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
 use bevy::prelude::*;
 use bevy::tasks::IoTaskPool;
+use bevy::window::WindowCloseRequested;
+use bevy_sprinkles::asset::DEFAULT_ROUNDING_DECIMALS;
 use bevy_sprinkles::asset::versioning::VersionStatus;
 use bevy_sprinkles::prelude::*;
 use inflector::Inflector;
 
 use crate::io::{EditorData, project_path, projects_dir, save_editor_data, simplify_path};
 use crate::state::{DirtyState, EditorState, Inspectable, Inspecting};
+use crate::ui::components::data_panel::LastLoadedProject;
+use crate::ui::components::inspector::{InspectedColliderTracker, InspectedEmitterTracker};
+use crate::ui::components::project_selector::NewProjectEvent;
 use crate::ui::components::toasts::ToastEvent;
+use crate::ui::widgets::dialog::{
+    DialogActionEvent, DialogSecondaryActionEvent, EditorDialog, OpenConfirmationDialogEvent,
+    OpenDialogEvent,
+};
+use crate::viewport::{EditorParticlePreview, RespawnCollidersEvent, RespawnEmittersEvent};
+
+/// How often the open project file is checked for external changes, in seconds.
+const WATCH_INTERVAL: f32 = 0.5;
 
 pub fn plugin(app: &mut App) {
-    app.add_observer(on_open_project_event)
+    app.init_resource::<ProjectFileWatcher>()
+        .add_observer(on_open_project_event)
         .add_observer(on_browse_open_project_event)
         .add_observer(on_save_project_event)
         .add_observer(on_save_project_as_event)
+        .add_observer(on_reload_confirmed)
+        .add_observer(on_clean_up_project_event)
+        .add_observer(on_unsaved_changes_save)
+        .add_observer(on_unsaved_changes_discard)
         .add_systems(
             Update,
             (
@@ -25,6 +44,11 @@ pub fn plugin(app: &mut App) {
                 poll_browse_open_result,
                 poll_save_as_result,
                 poll_save_result,
+                watch_project_file,
+                cleanup_pending_reload,
+                restore_playback_after_reload,
+                handle_window_close_requested,
+                prompt_unsaved_changes,
             ),
         );
 }
@@ -41,6 +65,16 @@ pub struct SaveProjectEvent;
 #[derive(Event)]
 pub struct SaveProjectAsEvent;
 
+/// Rounds every float in the current project to the given number of decimals.
+#[derive(Event)]
+pub struct CleanUpProjectEvent(pub u32);
+
+impl Default for CleanUpProjectEvent {
+    fn default() -> Self {
+        Self(DEFAULT_ROUNDING_DECIMALS)
+    }
+}
+
 #[derive(Resource, Clone)]
 pub struct BrowseOpenResult(pub Arc<Mutex<Option<PathBuf>>>);
 
@@ -53,11 +87,89 @@ pub enum SaveResultStatus {
     SerializationError,
     WriteError(String),
     CreateError,
+    Cancelled,
 }
 
 #[derive(Resource, Clone)]
 pub struct SaveResult(pub Arc<Mutex<Option<SaveResultStatus>>>);
 
+#[derive(Resource)]
+struct ProjectFileWatcher {
+    path: Option<PathBuf>,
+    modified: Option<SystemTime>,
+    timer: Timer,
+}
+
+impl Default for ProjectFileWatcher {
+    fn default() -> Self {
+        Self {
+            path: None,
+            modified: None,
+            timer: Timer::from_seconds(WATCH_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+#[derive(Resource)]
+struct PendingReload(ParticleSystemAsset);
+
+/// Something that replaces or closes the current project.
+#[derive(Clone)]
+pub enum ProjectChange {
+    New,
+    Open(String),
+    Quit,
+}
+
+impl ProjectChange {
+    fn apply(self, commands: &mut Commands) {
+        match self {
+            Self::New => commands.trigger(NewProjectEvent),
+            Self::Open(location) => commands.trigger(OpenProjectEvent(location)),
+            Self::Quit => {
+                commands.write_message(AppExit::Success);
+            }
+        }
+    }
+}
+
+enum UnsavedChangesStage {
+    Waiting,
+    Prompted,
+    Saving,
+    Confirmed,
+}
+
+/// A [`ProjectChange`] held back until the user decides what to do with unsaved changes.
+#[derive(Resource)]
+struct PendingProjectChange {
+    change: ProjectChange,
+    stage: UnsavedChangesStage,
+}
+
+/// Holds `change` back behind a Save / Discard / Cancel prompt when the current project has
+/// unsaved changes. Returns whether it was held back, in which case the caller should stop
+/// and let the prompt apply it.
+pub fn defer_if_unsaved(
+    dirty_state: &DirtyState,
+    change: ProjectChange,
+    commands: &mut Commands,
+) -> bool {
+    if !dirty_state.has_unsaved_changes {
+        return false;
+    }
+    commands.insert_resource(PendingProjectChange {
+        change,
+        stage: UnsavedChangesStage::Waiting,
+    });
+    true
+}
+
+#[derive(Resource)]
+struct RestorePlayback {
+    paused: bool,
+}
+
 pub fn load_project_from_path(
     path: &std::path::Path,
 ) -> Option<bevy_sprinkles::asset::ParticleSystemAsset> {
@@ -74,6 +186,26 @@ pub fn load_project_from_path(
         .ok()
 }
 
+/// Builds the asset for a new project named `name`, copied from `template` when it
+/// loads, or with a single default emitter otherwise.
+pub fn new_project_asset(name: String, template: Option<&Path>) -> ParticleSystemAsset {
+    if let Some(mut asset) = template.and_then(load_project_from_path) {
+        asset.name = name;
+        return asset;
+    }
+
+    ParticleSystemAsset::new(
+        name,
+        ParticleSystemDimension::D3,
+        vec![EmitterData {
+            name: "Emitter 1".to_string(),
+            ..Default::default()
+        }],
+        vec![],
+        None,
+    )
+}
+
 fn on_open_project_event(
     event: On<OpenProjectEvent>,
     mut editor_state: ResMut<EditorState>,
@@ -83,6 +215,13 @@ fn on_open_project_event(
     mut commands: Commands,
 ) {
     let location = &event.0;
+    if defer_if_unsaved(
+        &dirty_state,
+        ProjectChange::Open(location.clone()),
+        &mut commands,
+    ) {
+        return;
+    }
     let path = project_path(location);
 
     let Some(mut asset) = load_project_from_path(&path) else {
@@ -184,7 +323,7 @@ pub fn save_project_to_path(
     asset: &bevy_sprinkles::asset::ParticleSystemAsset,
     result: Arc<Mutex<Option<SaveResultStatus>>>,
 ) {
-    let Ok(contents) = ron::ser::to_string_pretty(asset, ron::ser::PrettyConfig::default()) else {
+    let Ok(contents) = asset.to_optimized_ron() else {
         if let Ok(mut guard) = result.lock() {
             *guard = Some(SaveResultStatus::SerializationError);
         }
@@ -218,7 +357,6 @@ fn on_save_project_event(
     _event: On<SaveProjectEvent>,
     editor_state: Res<EditorState>,
     assets: Res<Assets<ParticleSystemAsset>>,
-    mut dirty_state: ResMut<DirtyState>,
     mut commands: Commands,
 ) {
     let Some(handle) = &editor_state.current_project else {
@@ -232,12 +370,44 @@ fn on_save_project_event(
         let result = Arc::new(Mutex::new(None));
         save_project_to_path(path.clone(), asset, result.clone());
         commands.insert_resource(SaveResult(result));
-        dirty_state.has_unsaved_changes = false;
     } else {
         commands.trigger(SaveProjectAsEvent);
     }
 }
 
+fn on_clean_up_project_event(
+    event: On<CleanUpProjectEvent>,
+    editor_state: Res<EditorState>,
+    mut assets: ResMut<Assets<ParticleSystemAsset>>,
+    mut dirty_state: ResMut<DirtyState>,
+    mut emitter_tracker: ResMut<InspectedEmitterTracker>,
+    mut collider_tracker: ResMut<InspectedColliderTracker>,
+    mut commands: Commands,
+) {
+    let Some(asset) = editor_state
+        .current_project
+        .as_ref()
+        .and_then(|handle| assets.get_mut(handle))
+    else {
+        return;
+    };
+
+    if !asset.round_floats(event.0) {
+        commands.trigger(ToastEvent::success("Nothing to clean up"));
+        return;
+    }
+
+    dirty_state.has_unsaved_changes = true;
+    emitter_tracker.set_changed();
+    collider_tracker.set_changed();
+    commands.trigger(RespawnEmittersEvent);
+    commands.trigger(RespawnCollidersEvent);
+    commands.trigger(ToastEvent::success(format!(
+        "Rounded values to {} decimals",
+        event.0
+    )));
+}
+
 fn on_save_project_as_event(
     _event: On<SaveProjectAsEvent>,
     editor_state: Res<EditorState>,
@@ -276,6 +446,8 @@ fn on_save_project_as_event(
                 if let Ok(mut guard) = path_result_clone.lock() {
                     *guard = Some(path);
                 }
+            } else if let Ok(mut guard) = save_result_clone.lock() {
+                *guard = Some(SaveResultStatus::Cancelled);
             }
         })
         .detach();
@@ -288,7 +460,6 @@ fn poll_save_as_result(
     result: Option<Res<SaveAsResult>>,
     mut editor_state: ResMut<EditorState>,
     mut editor_data: ResMut<EditorData>,
-    mut dirty_state: ResMut<DirtyState>,
     mut commands: Commands,
 ) {
     let Some(result) = result else {
@@ -307,12 +478,18 @@ fn poll_save_as_result(
 
         editor_data.cache.add_recent_project(simplify_path(&path));
         save_editor_data(&editor_data);
-        dirty_state.has_unsaved_changes = false;
         commands.remove_resource::<SaveAsResult>();
     }
 }
 
-fn poll_save_result(result: Option<Res<SaveResult>>, mut commands: Commands) {
+// changes are only marked as saved once they are on disk, and a change waiting on the
+// save is applied only if it succeeded
+fn poll_save_result(
+    result: Option<Res<SaveResult>>,
+    pending: Option<ResMut<PendingProjectChange>>,
+    mut dirty_state: ResMut<DirtyState>,
+    mut commands: Commands,
+) {
     let Some(result) = result else {
         return;
     };
@@ -325,8 +502,10 @@ fn poll_save_result(result: Option<Res<SaveResult>>, mut commands: Commands) {
     };
 
     if let Some(status) = status {
+        let saved = matches!(status, SaveResultStatus::Success(_));
         match status {
             SaveResultStatus::Success(filename) => {
+                dirty_state.has_unsaved_changes = false;
                 commands.trigger(ToastEvent::success(format!("Saved \"{filename}\"")));
             }
             SaveResultStatus::SerializationError => {
@@ -340,8 +519,22 @@ fn poll_save_result(result: Option<Res<SaveResult>>, mut commands: Commands) {
             SaveResultStatus::CreateError => {
                 commands.trigger(ToastEvent::error("Failed to create project file"));
             }
+            SaveResultStatus::Cancelled => {
+                commands.remove_resource::<SaveAsResult>();
+            }
         }
         commands.remove_resource::<SaveResult>();
+
+        // a failed or cancelled save asks again rather than dropping the change
+        if let Some(mut pending) = pending
+            && matches!(pending.stage, UnsavedChangesStage::Saving)
+        {
+            pending.stage = if saved {
+                UnsavedChangesStage::Confirmed
+            } else {
+                UnsavedChangesStage::Waiting
+            };
+        }
     }
 }
 
@@ -355,3 +548,388 @@ fn handle_save_keyboard_shortcut(keyboard: Res<ButtonInput<KeyCode>>, mut comman
         commands.trigger(SaveProjectEvent);
     }
 }
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn watch_project_file(
+    time: Res<Time>,
+    mut watcher: ResMut<ProjectFileWatcher>,
+    editor_state: Res<EditorState>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    dirty_state: Res<DirtyState>,
+    pending: Option<Res<PendingReload>>,
+    mut commands: Commands,
+) {
+    if !watcher.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    if watcher.path != editor_state.current_project_path {
+        watcher.path = editor_state.current_project_path.clone();
+        watcher.modified = watcher.path.as_deref().and_then(modified_time);
+        return;
+    }
+
+    let Some(path) = editor_state.current_project_path.as_deref() else {
+        return;
+    };
+    let modified = modified_time(path);
+    if modified.is_none() || modified == watcher.modified {
+        return;
+    }
+    watcher.modified = modified;
+
+    let Some(current) = editor_state
+        .current_project
+        .as_ref()
+        .and_then(|handle| assets.get(handle))
+    else {
+        return;
+    };
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return;
+    };
+
+    // our own saves also touch the file, so only reload when the contents differ
+    let saved = current.to_optimized_ron();
+    if saved.is_ok_and(|saved| saved == contents) {
+        return;
+    }
+
+    let filename = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let asset = match ParticleSystemAsset::from_ron(contents.as_bytes()) {
+        Ok((asset, _)) => asset,
+        Err(err) => {
+            commands.trigger(ToastEvent::error(format!(
+                "Failed to reload \"{filename}\": {err}"
+            )));
+            return;
+        }
+    };
+
+    if !dirty_state.has_unsaved_changes {
+        commands.queue(move |world: &mut World| reload_project(world, asset));
+        commands.trigger(ToastEvent::success(format!("Reloaded \"{filename}\"")));
+        return;
+    }
+
+    let has_dialog = pending.is_some();
+    commands.insert_resource(PendingReload(asset));
+    if !has_dialog {
+        commands.trigger(
+            OpenConfirmationDialogEvent::new("Project changed on disk", "Reload").with_description(
+                format!(
+                    "\"{filename}\" was changed outside the editor. Reloading it will discard your unsaved changes."
+                ),
+            ),
+        );
+    }
+}
+
+fn on_reload_confirmed(
+    _event: On<DialogActionEvent>,
+    pending: Option<Res<PendingReload>>,
+    mut commands: Commands,
+) {
+    if pending.is_none() {
+        return;
+    }
+
+    commands.queue(|world: &mut World| {
+        if let Some(PendingReload(asset)) = world.remove_resource::<PendingReload>() {
+            reload_project(world, asset);
+        }
+    });
+}
+
+fn cleanup_pending_reload(
+    pending: Option<Res<PendingReload>>,
+    dialogs: Query<(), With<EditorDialog>>,
+    mut commands: Commands,
+) {
+    if pending.is_some() && dialogs.is_empty() {
+        commands.remove_resource::<PendingReload>();
+    }
+}
+
+// swaps the asset in place so the preview keeps its handle, then respawns it
+fn reload_project(world: &mut World, asset: ParticleSystemAsset) {
+    let Some(handle) = world.resource::<EditorState>().current_project.clone() else {
+        return;
+    };
+
+    let paused = world
+        .query_filtered::<&ParticleSystemRuntime, With<EditorParticlePreview>>()
+        .iter(world)
+        .next()
+        .map(|runtime| runtime.paused);
+
+    let emitter_count = asset.emitters.len();
+    let collider_count = asset.colliders.len();
+    {
+        let mut assets = world.resource_mut::<Assets<ParticleSystemAsset>>();
+        let Some(current) = assets.get_mut(&handle) else {
+            return;
+        };
+        *current = asset;
+    }
+
+    let mut editor_state = world.resource_mut::<EditorState>();
+    let still_valid = editor_state.inspecting.is_some_and(|inspecting| {
+        let len = match inspecting.kind {
+            Inspectable::Emitter => emitter_count,
+            Inspectable::Collider => collider_count,
+        };
+        (inspecting.index as usize) < len
+    });
+    if !still_valid {
+        editor_state.inspecting = (emitter_count > 0).then_some(Inspecting {
+            kind: Inspectable::Emitter,
+            index: 0,
+        });
+    }
+
+    world.resource_mut::<DirtyState>().has_unsaved_changes = false;
+    world.resource_mut::<LastLoadedProject>().handle = None;
+    world
+        .resource_mut::<InspectedEmitterTracker>()
+        .set_changed();
+    if let Some(paused) = paused {
+        world.insert_resource(RestorePlayback { paused });
+    }
+    world.trigger(RespawnEmittersEvent);
+    world.trigger(RespawnCollidersEvent);
+}
+
+fn restore_playback_after_reload(
+    restore: Option<Res<RestorePlayback>>,
+    mut previews: Query<&mut ParticleSystemRuntime, With<EditorParticlePreview>>,
+    mut commands: Commands,
+) {
+    let Some(restore) = restore else {
+        return;
+    };
+    let Ok(mut runtime) = previews.single_mut() else {
+        return;
+    };
+    // wait for the respawned preview to get a fresh runtime
+    if !runtime.is_added() {
+        return;
+    }
+
+    runtime.paused = restore.paused;
+    commands.remove_resource::<RestorePlayback>();
+}
+
+fn handle_window_close_requested(
+    mut requests: MessageReader<WindowCloseRequested>,
+    dirty_state: Res<DirtyState>,
+    pending: Option<Res<PendingProjectChange>>,
+    mut commands: Commands,
+) {
+    if requests.read().count() == 0 || pending.is_some() {
+        return;
+    }
+    if !defer_if_unsaved(&dirty_state, ProjectChange::Quit, &mut commands) {
+        ProjectChange::Quit.apply(&mut commands);
+    }
+}
+
+// waits for other dialogs to close before prompting, and applies the change once the
+// prompt itself is gone so it can open dialogs of its own
+fn prompt_unsaved_changes(
+    pending: Option<ResMut<PendingProjectChange>>,
+    editor_state: Res<EditorState>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    dialogs: Query<(), With<EditorDialog>>,
+    mut commands: Commands,
+) {
+    let Some(mut pending) = pending else {
+        return;
+    };
+    if !dialogs.is_empty() {
+        return;
+    }
+
+    match pending.stage {
+        UnsavedChangesStage::Waiting => {
+            let name = editor_state
+                .current_project
+                .as_ref()
+                .and_then(|handle| assets.get(handle))
+                .map(|asset| asset.name.as_str())
+                .unwrap_or("Untitled");
+            let mut dialog = OpenDialogEvent::new("Unsaved changes", "Save")
+                .with_secondary_action("Discard")
+                .with_close_button(false)
+                .with_close_on_click_outside(false);
+            dialog.description = Some(format!(
+                "Do you want to save your changes to \"{name}\" first? Unsaved changes will be lost."
+            ));
+            commands.trigger(dialog);
+            pending.stage = UnsavedChangesStage::Prompted;
+        }
+        // the prompt was cancelled
+        UnsavedChangesStage::Prompted => {
+            commands.remove_resource::<PendingProjectChange>();
+        }
+        // waits for the save to finish, which may first go through a native dialog
+        UnsavedChangesStage::Saving => {}
+        UnsavedChangesStage::Confirmed => {
+            commands.remove_resource::<PendingProjectChange>();
+            pending.change.clone().apply(&mut commands);
+        }
+    }
+}
+
+fn on_unsaved_changes_save(
+    _event: On<DialogActionEvent>,
+    pending: Option<ResMut<PendingProjectChange>>,
+    mut commands: Commands,
+) {
+    let Some(mut pending) = pending else {
+        return;
+    };
+    if !matches!(pending.stage, UnsavedChangesStage::Prompted) {
+        return;
+    }
+    commands.trigger(SaveProjectEvent);
+    pending.stage = UnsavedChangesStage::Saving;
+}
+
+fn on_unsaved_changes_discard(
+    _event: On<DialogSecondaryActionEvent>,
+    pending: Option<ResMut<PendingProjectChange>>,
+    mut dirty_state: ResMut<DirtyState>,
+) {
+    let Some(mut pending) = pending else {
+        return;
+    };
+    if !matches!(pending.stage, UnsavedChangesStage::Prompted) {
+        return;
+    }
+    dirty_state.has_unsaved_changes = false;
+    pending.stage = UnsavedChangesStage::Confirmed;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{open_project, test_app};
+
+    #[derive(Resource, Default)]
+    struct Prompts(Vec<String>);
+
+    #[test]
+    fn test_cancelling_the_unsaved_changes_prompt_aborts_the_open() {
+        let mut app = test_app();
+        app.init_resource::<Prompts>()
+            .add_observer(on_open_project_event)
+            .add_observer(|event: On<OpenDialogEvent>, mut prompts: ResMut<Prompts>| {
+                prompts.0.extend(event.title.clone());
+            })
+            .add_systems(Update, prompt_unsaved_changes);
+        let handle = open_project(&mut app, vec![EmitterData::default()], vec![]);
+        app.world_mut()
+            .resource_mut::<DirtyState>()
+            .has_unsaved_changes = true;
+
+        app.world_mut()
+            .trigger(OpenProjectEvent("other-project.ron".into()));
+        app.update();
+        assert_eq!(app.world().resource::<Prompts>().0, ["Unsaved changes"]);
+
+        // the prompt stays open until it's cancelled
+        let dialog = app.world_mut().spawn(EditorDialog).id();
+        app.update();
+        assert!(app.world().contains_resource::<PendingProjectChange>());
+
+        app.world_mut().despawn(dialog);
+        app.update();
+        assert!(!app.world().contains_resource::<PendingProjectChange>());
+        let editor_state = app.world().resource::<EditorState>();
+        assert_eq!(editor_state.current_project, Some(handle));
+        assert!(app.world().resource::<DirtyState>().has_unsaved_changes);
+        assert_eq!(app.world().resource::<Prompts>().0.len(), 1);
+    }
+
+    #[test]
+    fn test_saving_from_the_unsaved_changes_prompt_waits_for_the_save() {
+        #[derive(Resource, Default)]
+        struct Opened(usize);
+
+        let mut app = test_app();
+        app.init_resource::<Prompts>()
+            .init_resource::<Opened>()
+            .add_observer(on_unsaved_changes_save)
+            .add_observer(|event: On<OpenDialogEvent>, mut prompts: ResMut<Prompts>| {
+                prompts.0.extend(event.title.clone());
+            })
+            .add_observer(|_: On<OpenProjectEvent>, mut opened: ResMut<Opened>| {
+                opened.0 += 1;
+            })
+            .add_systems(Update, (poll_save_result, prompt_unsaved_changes).chain());
+        open_project(&mut app, vec![EmitterData::default()], vec![]);
+        app.world_mut()
+            .resource_mut::<DirtyState>()
+            .has_unsaved_changes = true;
+
+        let change = ProjectChange::Open("other-project.ron".into());
+        app.world_mut().insert_resource(PendingProjectChange {
+            change,
+            stage: UnsavedChangesStage::Waiting,
+        });
+        app.update();
+
+        let save = |app: &mut App, status: SaveResultStatus| {
+            app.world_mut().trigger(DialogActionEvent {
+                entity: Entity::PLACEHOLDER,
+            });
+            app.update();
+            assert_eq!(app.world().resource::<Opened>().0, 0);
+            let result = Arc::new(Mutex::new(Some(status)));
+            app.insert_resource(SaveResult(result));
+            app.update();
+        };
+
+        // a failed save keeps the changes and asks again
+        save(&mut app, SaveResultStatus::WriteError("test.ron".into()));
+        assert_eq!(app.world().resource::<Opened>().0, 0);
+        assert!(app.world().resource::<DirtyState>().has_unsaved_changes);
+        assert_eq!(app.world().resource::<Prompts>().0.len(), 2);
+
+        save(&mut app, SaveResultStatus::Success("test.ron".into()));
+        assert_eq!(app.world().resource::<Opened>().0, 1);
+        assert!(!app.world().resource::<DirtyState>().has_unsaved_changes);
+        assert!(!app.world().contains_resource::<PendingProjectChange>());
+    }
+
+    #[test]
+    fn test_new_projects_copy_the_template_emitters() {
+        let template = ParticleSystemAsset::new(
+            "Template".into(),
+            ParticleSystemDimension::D3,
+            vec![EmitterData::default(); 3],
+            vec![],
+            None,
+        );
+        let path =
+            std::env::temp_dir().join(format!("sprinkles-template-{}.ron", std::process::id()));
+        std::fs::write(&path, template.to_optimized_ron().unwrap()).unwrap();
+
+        let asset = new_project_asset("Fire".into(), Some(&path));
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(asset.name, "Fire");
+        assert_eq!(asset.emitters.len(), 3);
+
+        // a missing template falls back to a single default emitter
+        let asset = new_project_asset("Fire".into(), Some(&path));
+        assert_eq!(asset.emitters.len(), 1);
+    }
+}
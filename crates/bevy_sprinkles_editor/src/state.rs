@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::path::PathBuf;
 
 use bevy::prelude::*;
@@ -18,6 +19,8 @@ pub struct EditorState {
     pub current_project: Option<Handle<ParticleSystemAsset>>,
     pub current_project_path: Option<PathBuf>,
     pub inspecting: Option<Inspecting>,
+    /// Indices of colliders the preview ignores, without disabling them in the asset.
+    pub muted_colliders: HashSet<usize>,
 }
 
 impl EditorState {
@@ -30,6 +33,7 @@ impl EditorState {
         let is_example = path.starts_with(examples_dir());
         self.current_project = Some(handle);
         self.current_project_path = if is_example { None } else { Some(path) };
+        self.muted_colliders.clear();
         dirty_state.has_unsaved_changes = is_example;
     }
 }
@@ -0,0 +1,54 @@
+//! Shared setup for the editor's unit tests.
+
+use bevy::prelude::*;
+use bevy_sprinkles::prelude::*;
+
+use crate::io::EditorData;
+use crate::state::{DirtyState, EditorState, Inspectable, Inspecting};
+
+/// A headless app with the editor resources the inspector and viewport systems read.
+pub fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<ParticleSystemAsset>()
+        .init_resource::<EditorState>()
+        .init_resource::<DirtyState>()
+        .init_resource::<EditorData>();
+    app
+}
+
+/// Adds a project with the given emitters and colliders and makes it the open one.
+pub fn open_project(
+    app: &mut App,
+    emitters: Vec<EmitterData>,
+    colliders: Vec<ColliderData>,
+) -> Handle<ParticleSystemAsset> {
+    let asset = ParticleSystemAsset::new(
+        "Test".into(),
+        ParticleSystemDimension::D3,
+        emitters,
+        colliders,
+        None,
+    );
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<ParticleSystemAsset>>()
+        .add(asset);
+    app.world_mut()
+        .resource_mut::<EditorState>()
+        .current_project = Some(handle.clone());
+    handle
+}
+
+/// Selects the emitter or collider at `index` in the inspector.
+pub fn inspect(app: &mut App, kind: Inspectable, index: u8) {
+    app.world_mut().resource_mut::<EditorState>().inspecting = Some(Inspecting { kind, index });
+}
+
+/// Returns the open project's asset.
+pub fn project<'a>(app: &'a App, handle: &Handle<ParticleSystemAsset>) -> &'a ParticleSystemAsset {
+    app.world()
+        .resource::<Assets<ParticleSystemAsset>>()
+        .get(handle)
+        .unwrap()
+}
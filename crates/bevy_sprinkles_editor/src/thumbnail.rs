@@ -0,0 +1,397 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::camera::RenderTarget;
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+
+use bevy_sprinkles::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            setup_particle_thumbnails.before(SprinklesSystems::Setup),
+            // simulated after the particle time so pausing takes effect from the next frame
+            update_particle_thumbnails
+                .after(SprinklesSystems::Setup)
+                .after(SprinklesSystems::Time),
+        ),
+    );
+}
+
+/// Time step used to play each thumbnail up to its phase, matching a 30 FPS simulation.
+const THUMBNAIL_STEP: f32 = 1.0 / 30.0;
+
+/// Component that renders a particle system into a row of offscreen images, each one
+/// frozen at a different point of its emission cycle.
+///
+/// Every thumbnail gets its own camera and copy of the particle system, drawn on a
+/// separate [`RenderLayers`] layer starting at [`first_layer`](Self::first_layer). Once
+/// spawned, [`images`](Self::images) holds one image per thumbnail, ordered by
+/// [`phase`](Self::phase). The thumbnails are rendered again whenever the asset changes.
+#[derive(Component, Debug, Clone)]
+#[require(Transform, Visibility)]
+pub struct ParticleThumbnails {
+    /// Handle to the particle system asset to render.
+    pub handle: Handle<ParticleSystemAsset>,
+    /// Number of thumbnails, evenly spread over the emission cycle.
+    pub count: usize,
+    /// Size of each image in pixels. Defaults to `96x96`.
+    pub size: UVec2,
+    /// Render layer of the first thumbnail; the others use the layers right after it.
+    /// Defaults to `16`.
+    pub first_layer: usize,
+    /// Transform of the thumbnail cameras, relative to this entity.
+    pub camera_transform: Transform,
+    /// The rendered images, filled in when the thumbnails are spawned.
+    pub images: Vec<Handle<Image>>,
+}
+
+impl ParticleThumbnails {
+    /// Creates `count` thumbnails of the given particle system with the default settings.
+    pub fn new(handle: Handle<ParticleSystemAsset>, count: usize) -> Self {
+        Self {
+            handle,
+            count,
+            size: UVec2::splat(96),
+            first_layer: 16,
+            camera_transform: Transform::from_xyz(4.6, 3.5, 4.6).looking_at(Vec3::ZERO, Vec3::Y),
+            images: Vec::new(),
+        }
+    }
+
+    /// Returns the point of the emission cycle shown by the thumbnail at `index`, from
+    /// `0.0` to `1.0`. Thumbnails sit at the middle of equal slices of the cycle.
+    pub fn phase(&self, index: usize) -> f32 {
+        (index as f32 + 0.5) / self.count.max(1) as f32
+    }
+}
+
+/// Marks the particle system of a single thumbnail spawned by [`ParticleThumbnails`].
+#[derive(Component, Debug, Clone)]
+pub struct ParticleThumbnailFrame {
+    /// Point of the emission cycle this thumbnail shows, from `0.0` to `1.0`.
+    pub phase: f32,
+    simulated: bool,
+}
+
+/// Marks the camera of a single thumbnail spawned by [`ParticleThumbnails`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ParticleThumbnailCamera;
+
+fn create_thumbnail_image(size: UVec2) -> Image {
+    let mut image = Image::new_uninit(
+        Extent3d {
+            width: size.x.max(1),
+            height: size.y.max(1),
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::all(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
+
+/// Spawns the images, cameras and particle systems of new or changed [`ParticleThumbnails`].
+fn setup_particle_thumbnails(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut ParticleThumbnails), Changed<ParticleThumbnails>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    for (entity, mut thumbnails) in query.iter_mut() {
+        commands.entity(entity).despawn_related::<Children>();
+
+        let mut handles = Vec::with_capacity(thumbnails.count);
+        for index in 0..thumbnails.count {
+            let image = images.add(create_thumbnail_image(thumbnails.size));
+            let layers = RenderLayers::layer(thumbnails.first_layer + index);
+
+            commands.entity(entity).with_children(|parent| {
+                parent.spawn((
+                    ParticleThumbnailCamera,
+                    Camera3d::default(),
+                    Camera {
+                        order: -2,
+                        clear_color: ClearColorConfig::Custom(Color::NONE),
+                        ..default()
+                    },
+                    RenderTarget::Image(image.clone().into()),
+                    thumbnails.camera_transform,
+                    layers.clone(),
+                ));
+                parent.spawn((
+                    ParticleSystem3D::new(thumbnails.handle.clone()),
+                    ParticleThumbnailFrame {
+                        phase: thumbnails.phase(index),
+                        simulated: false,
+                    },
+                    layers,
+                ));
+            });
+            handles.push(image);
+        }
+
+        // keeps filling in the images from counting as a change to the settings
+        thumbnails.bypass_change_detection().images = handles;
+    }
+}
+
+/// Plays each thumbnail up to its phase and pauses it there, and renders the thumbnails
+/// again when their asset changes.
+fn update_particle_thumbnails(
+    mut commands: Commands,
+    mut asset_events: MessageReader<AssetEvent<ParticleSystemAsset>>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    mut frames: Query<(
+        Entity,
+        &ParticleSystem3D,
+        &mut ParticleThumbnailFrame,
+        Option<&mut ParticleSystemRuntime>,
+    )>,
+    mut emitters: Query<(Entity, &EmitterEntity, &mut EmitterRuntime)>,
+) {
+    let modified: Vec<AssetId<ParticleSystemAsset>> = asset_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    for (frame_entity, particle_system, mut frame, runtime) in frames.iter_mut() {
+        let Some(mut runtime) = runtime else {
+            continue;
+        };
+
+        if modified.contains(&particle_system.handle.id()) {
+            // respawned by `setup_particle_systems` with the new asset
+            for (emitter_entity, emitter, _) in emitters.iter() {
+                if emitter.parent_system == frame_entity {
+                    commands.entity(emitter_entity).despawn();
+                }
+            }
+            commands
+                .entity(frame_entity)
+                .remove::<ParticleSystemRuntime>();
+            frame.simulated = false;
+            continue;
+        }
+
+        if frame.simulated {
+            continue;
+        }
+        let Some(asset) = assets.get(&particle_system.handle) else {
+            continue;
+        };
+
+        let mut simulated = false;
+        for (_, emitter, mut emitter_runtime) in emitters.iter_mut() {
+            if emitter.parent_system != frame_entity {
+                continue;
+            }
+            let duration = asset.cycle_duration(emitter_runtime.emitter_index);
+            emitter_runtime.simulate_to(frame.phase * duration, THUMBNAIL_STEP);
+            simulated = true;
+        }
+
+        if simulated {
+            runtime.paused = true;
+            frame.simulated = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::render::storage::ShaderStorageBuffer;
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_sprinkles::runtime::{EmitterMeshEntity, ParticleBufferGrowth};
+    use bevy_sprinkles::test_utils::{setup_particle_systems, update_particle_time};
+
+    use super::*;
+
+    const LIFETIME: f32 = 2.0;
+
+    fn create_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                16,
+            )))
+            .init_asset::<ParticleSystemAsset>()
+            .init_asset::<ShaderStorageBuffer>()
+            .init_asset::<Mesh>()
+            .init_asset::<Image>()
+            .init_asset::<ParticleMaterial>()
+            .init_resource::<ParticleBufferGrowth>()
+            .init_resource::<CustomPresetRegistry>()
+            .add_systems(
+                Update,
+                (
+                    setup_particle_systems.in_set(SprinklesSystems::Setup),
+                    update_particle_time.in_set(SprinklesSystems::Time),
+                ),
+            )
+            .add_plugins(plugin);
+        app
+    }
+
+    fn add_asset(app: &mut App) -> Handle<ParticleSystemAsset> {
+        let asset = ParticleSystemAsset::new(
+            "Fire".to_string(),
+            ParticleSystemDimension::D3,
+            vec![EmitterData {
+                time: EmitterTime {
+                    lifetime: LIFETIME,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            vec![],
+            None,
+        );
+        app.world_mut()
+            .resource_mut::<Assets<ParticleSystemAsset>>()
+            .add(asset)
+    }
+
+    fn spawn_thumbnails(app: &mut App, count: usize) -> Entity {
+        let handle = add_asset(app);
+        let entity = app
+            .world_mut()
+            .spawn(ParticleThumbnails::new(handle, count))
+            .id();
+        app.update();
+        entity
+    }
+
+    fn frames(app: &mut App) -> Vec<(Entity, f32)> {
+        let mut frames: Vec<(Entity, f32)> = app
+            .world_mut()
+            .query::<(Entity, &ParticleThumbnailFrame)>()
+            .iter(app.world())
+            .map(|(entity, frame)| (entity, frame.phase))
+            .collect();
+        frames.sort_by(|a, b| a.1.total_cmp(&b.1));
+        frames
+    }
+
+    fn emitter_of(app: &mut App, frame: Entity) -> EmitterRuntime {
+        let mut query = app.world_mut().query::<(&EmitterEntity, &EmitterRuntime)>();
+        let (_, runtime) = query
+            .iter(app.world())
+            .find(|(emitter, _)| emitter.parent_system == frame)
+            .expect("the thumbnail should have an emitter");
+        EmitterRuntime {
+            simulation_steps: runtime.simulation_steps.clone(),
+            ..EmitterRuntime::new(runtime.emitter_index, Some(runtime.random_seed))
+        }
+    }
+
+    #[test]
+    fn test_thumbnails_produce_the_configured_number_of_images() {
+        for count in [1, 5, 8] {
+            let mut app = create_test_app();
+            let entity = spawn_thumbnails(&mut app, count);
+
+            let thumbnails = app.world().get::<ParticleThumbnails>(entity).unwrap();
+            assert_eq!(thumbnails.images.len(), count);
+            let images = app.world().resource::<Assets<Image>>();
+            for handle in &thumbnails.images {
+                let image = images.get(handle).expect("thumbnail image should exist");
+                assert_eq!(image.size(), UVec2::splat(96));
+            }
+
+            let cameras = app
+                .world_mut()
+                .query::<&ParticleThumbnailCamera>()
+                .iter(app.world())
+                .count();
+            assert_eq!(cameras, count);
+            assert_eq!(frames(&mut app).len(), count);
+        }
+    }
+
+    #[test]
+    fn test_thumbnails_are_frozen_at_evenly_spaced_phases() {
+        let mut app = create_test_app();
+        spawn_thumbnails(&mut app, 5);
+
+        let frames = frames(&mut app);
+        let phases: Vec<f32> = frames.iter().map(|(_, phase)| *phase).collect();
+        assert_eq!(phases, vec![0.1, 0.3, 0.5, 0.7, 0.9]);
+
+        for (frame, phase) in frames {
+            let runtime = app.world().get::<ParticleSystemRuntime>(frame).unwrap();
+            assert!(runtime.paused);
+
+            let emitter = emitter_of(&mut app, frame);
+            let steps = &emitter.simulation_steps;
+            assert!(steps[0].clear_requested);
+            let last = steps.last().unwrap();
+            assert!((last.system_time - phase * LIFETIME).abs() < 1e-4);
+            let simulated: f32 = steps.iter().map(|step| step.delta_time).sum();
+            assert!((simulated - phase * LIFETIME).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_each_thumbnail_draws_on_its_own_layer() {
+        let mut app = create_test_app();
+        spawn_thumbnails(&mut app, 3);
+        app.update();
+
+        let mut layers: Vec<RenderLayers> = app
+            .world_mut()
+            .query_filtered::<&RenderLayers, With<EmitterMeshEntity>>()
+            .iter(app.world())
+            .cloned()
+            .collect();
+        layers.sort_by_key(|layers| layers.iter().next());
+        assert_eq!(
+            layers,
+            vec![
+                RenderLayers::layer(16),
+                RenderLayers::layer(17),
+                RenderLayers::layer(18)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_changing_the_asset_renders_the_thumbnails_again() {
+        let mut app = create_test_app();
+        let entity = spawn_thumbnails(&mut app, 2);
+        let handle = app
+            .world()
+            .get::<ParticleThumbnails>(entity)
+            .unwrap()
+            .handle
+            .clone();
+
+        app.update();
+        app.world_mut()
+            .resource_mut::<Assets<ParticleSystemAsset>>()
+            .get_mut(&handle)
+            .unwrap()
+            .emitters[0]
+            .time
+            .lifetime = 4.0;
+        // the change is reported at the end of the frame, then the emitters are despawned,
+        // and they're respawned and simulated again on the frame after
+        for _ in 0..3 {
+            app.update();
+        }
+
+        for (frame, phase) in frames(&mut app) {
+            let emitter = emitter_of(&mut app, frame);
+            let last = emitter.simulation_steps.last().unwrap();
+            assert!((last.system_time - phase * 4.0).abs() < 1e-4);
+        }
+    }
+}
@@ -3,8 +3,10 @@ use bevy::prelude::*;
 use bevy::reflect::{PartialReflect, ReflectRef};
 use bevy_sprinkles::prelude::*;
 
+use crate::io::{AngleUnit, EditorData};
 use crate::state::{DirtyState, EditorState};
 use crate::ui::components::inspector::FieldKind;
+use crate::ui::components::toasts::ToastEvent;
 use crate::ui::widgets::checkbox::CheckboxCommitEvent;
 use crate::ui::widgets::color_picker::ColorPickerCommitEvent;
 use crate::ui::widgets::combobox::ComboBoxChangeEvent;
@@ -16,8 +18,8 @@ use crate::ui::widgets::variant_edit::{VariantComboBox, VariantEditConfig};
 use crate::viewport::RespawnEmittersEvent;
 
 use super::{
-    BoundTo, FieldBinding, FieldValue, get_inspected_data_mut, mark_dirty_and_restart,
-    parse_field_value, read_fixed_seed,
+    AngleField, BoundTo, FieldBinding, FieldValue, LockedAspect, apply_to_all_emitters,
+    get_inspected_data_mut, mark_dirty_and_restart, parse_field_value, read_fixed_seed,
 };
 
 #[derive(SystemParam)]
@@ -28,6 +30,9 @@ pub(super) struct CommitContext<'w, 's> {
     bindings: Query<'w, 's, &'static FieldBinding>,
     bound_query: Query<'w, 's, &'static BoundTo>,
     emitter_runtimes: Query<'w, 's, &'static mut EmitterRuntime>,
+    editor_data: Res<'w, EditorData>,
+    angle_fields: Query<'w, 's, (), With<AngleField>>,
+    locked_aspects: Query<'w, 's, (), With<LockedAspect>>,
 }
 
 impl CommitContext<'_, '_> {
@@ -39,6 +44,19 @@ impl CommitContext<'_, '_> {
         self.bindings.get(bound.binding).ok().cloned()
     }
 
+    fn binding_entity(&self, entity: Entity) -> Entity {
+        self.bound_query
+            .get(entity)
+            .map_or(entity, |bound| bound.binding)
+    }
+
+    /// Returns the unit values are entered in for `entity`'s field, if it's an angle.
+    fn angle_unit(&self, entity: Entity) -> Option<AngleUnit> {
+        self.angle_fields
+            .contains(self.binding_entity(entity))
+            .then_some(self.editor_data.settings.angle_unit)
+    }
+
     fn commit_reflected(&mut self, entity: Entity, apply_fn: impl FnOnce(&mut dyn PartialReflect)) {
         let Some(binding) = self.resolve_binding(entity) else {
             return;
@@ -100,10 +118,40 @@ fn requires_respawn_binding(binding: &FieldBinding) -> bool {
     false
 }
 
+/// Holding Ctrl/Cmd while committing a field applies the value to every emitter.
+fn is_apply_to_all_pressed(keyboard: &ButtonInput<KeyCode>) -> bool {
+    keyboard.pressed(KeyCode::SuperLeft)
+        || keyboard.pressed(KeyCode::SuperRight)
+        || keyboard.pressed(KeyCode::ControlLeft)
+        || keyboard.pressed(KeyCode::ControlRight)
+}
+
+fn apply_commit_to_all_emitters(
+    commands: &mut Commands,
+    ctx: &mut CommitContext,
+    binding: &FieldBinding,
+    fixed_seed: Option<u32>,
+) -> bool {
+    let applied = apply_to_all_emitters(&ctx.editor_state, &mut ctx.assets, binding);
+    if applied == 0 {
+        return false;
+    }
+    mark_dirty_and_restart(&mut ctx.dirty_state, &mut ctx.emitter_runtimes, fixed_seed);
+    let noun = if applied == 1 { "emitter" } else { "emitters" };
+    commands.trigger(ToastEvent::success(format!(
+        "Applied \"{}\" to {} other {}",
+        binding.path(),
+        applied,
+        noun
+    )));
+    true
+}
+
 pub(super) fn handle_text_commit(
     trigger: On<TextEditCommitEvent>,
     mut commands: Commands,
     mut ctx: CommitContext,
+    keyboard: Res<ButtonInput<KeyCode>>,
 ) {
     let Some(binding) = ctx.resolve_binding(trigger.entity) else {
         return;
@@ -114,6 +162,11 @@ pub(super) fn handle_text_commit(
         .get(trigger.entity)
         .ok()
         .and_then(|b| b.component_index);
+    let angle_unit = ctx.angle_unit(trigger.entity);
+    let locked_aspect = ctx
+        .locked_aspects
+        .contains(ctx.binding_entity(trigger.entity));
+    let to_stored = |v: f32| angle_unit.map_or(v, |unit| unit.to_degrees(v));
 
     let Some(data) = get_inspected_data_mut(&ctx.editor_state, &mut ctx.assets) else {
         return;
@@ -124,32 +177,43 @@ pub(super) fn handle_text_commit(
             let Ok(v) = trigger.text.trim().parse::<f32>() else {
                 return;
             };
+            let v = to_stored(v);
 
             let current_value = binding.read_value(&*data);
-            let new_value = set_field_value_component(&current_value, idx, v);
+            let new_value = if locked_aspect {
+                set_locked_aspect_component(&current_value, idx, v)
+            } else {
+                set_field_value_component(&current_value, idx, v)
+            };
             let fixed_seed = read_fixed_seed(&*data);
 
-            if binding.write_value(data, &new_value) {
+            let changed = binding.write_value(data, &new_value);
+            if changed {
                 mark_dirty_and_restart(&mut ctx.dirty_state, &mut ctx.emitter_runtimes, fixed_seed);
-                if requires_respawn_binding(&binding) {
-                    commands.trigger(RespawnEmittersEvent);
-                }
+            }
+            let applied_to_all = is_apply_to_all_pressed(&keyboard)
+                && apply_commit_to_all_emitters(&mut commands, &mut ctx, &binding, fixed_seed);
+            if (changed || applied_to_all) && requires_respawn_binding(&binding) {
+                commands.trigger(RespawnEmittersEvent);
             }
             return;
         }
     }
 
-    let value = parse_field_value(&trigger.text, &binding.kind);
+    let value = parse_field_value(&trigger.text, &binding.kind).map_f32(to_stored);
     if matches!(value, FieldValue::None) {
         return;
     }
 
     let fixed_seed = read_fixed_seed(&*data);
-    if binding.write_value(data, &value) {
+    let changed = binding.write_value(data, &value);
+    if changed {
         mark_dirty_and_restart(&mut ctx.dirty_state, &mut ctx.emitter_runtimes, fixed_seed);
-        if requires_respawn_binding(&binding) {
-            commands.trigger(RespawnEmittersEvent);
-        }
+    }
+    let applied_to_all = is_apply_to_all_pressed(&keyboard)
+        && apply_commit_to_all_emitters(&mut commands, &mut ctx, &binding, fixed_seed);
+    if (changed || applied_to_all) && requires_respawn_binding(&binding) {
+        commands.trigger(RespawnEmittersEvent);
     }
 }
 
@@ -284,6 +348,24 @@ pub(super) fn handle_variant_change(
     }
 }
 
+/// Sets one component of a [`FieldValue::Vec2`] and scales the other by the same
+/// ratio, or sets both to `v` when the edited component was zero.
+fn set_locked_aspect_component(value: &FieldValue, index: usize, v: f32) -> FieldValue {
+    let FieldValue::Vec2(vec) = value else {
+        return set_field_value_component(value, index, v);
+    };
+    let (edited, other) = match index {
+        0 => (vec.x, vec.y),
+        1 => (vec.y, vec.x),
+        _ => return value.clone(),
+    };
+    let other = if edited != 0.0 { other * v / edited } else { v };
+    match index {
+        0 => FieldValue::Vec2(Vec2::new(v, other)),
+        _ => FieldValue::Vec2(Vec2::new(other, v)),
+    }
+}
+
 fn set_field_value_component(value: &FieldValue, index: usize, v: f32) -> FieldValue {
     match value {
         FieldValue::Vec2(vec) => {
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+use bevy_sprinkles::prelude::*;
+
+use super::{FieldBinding, InspectedEmitterTracker, get_inspecting_emitter};
+use crate::state::EditorState;
+
+const HIGHLIGHT_WEIGHT: FontWeight = FontWeight::BOLD;
+
+/// The label of a bound field, shown in bold while the field differs from
+/// [`EmitterData::default`].
+#[derive(Component)]
+pub(super) struct DefaultHighlight {
+    label: Entity,
+    weight: FontWeight,
+}
+
+/// Finds the label of each new binding once its widget has spawned it.
+///
+/// Widgets put their label first, either as a direct child or, for vector fields, as a
+/// child of the first component.
+pub(super) fn attach_default_highlights(
+    mut commands: Commands,
+    bindings: Query<Entity, (With<FieldBinding>, Without<DefaultHighlight>)>,
+    children: Query<&Children>,
+    labels: Query<&TextFont, With<Text>>,
+) {
+    for entity in &bindings {
+        let Ok(direct) = children.get(entity) else {
+            continue;
+        };
+        let label = direct
+            .iter()
+            .find(|child| labels.contains(*child))
+            .or_else(|| {
+                let first = direct.first()?;
+                children
+                    .get(*first)
+                    .ok()?
+                    .iter()
+                    .find(|child| labels.contains(*child))
+            });
+        let Some(label) = label else {
+            continue;
+        };
+        let Ok(font) = labels.get(label) else {
+            continue;
+        };
+        commands.entity(entity).try_insert(DefaultHighlight {
+            label,
+            weight: font.weight,
+        });
+    }
+}
+
+pub(super) fn highlight_non_default_fields(
+    editor_state: Res<EditorState>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    tracker: Res<InspectedEmitterTracker>,
+    new_highlights: Query<Entity, Added<DefaultHighlight>>,
+    highlights: Query<(&FieldBinding, &DefaultHighlight)>,
+    mut fonts: Query<&mut TextFont>,
+) {
+    if !assets.is_changed() && !tracker.is_changed() && new_highlights.is_empty() {
+        return;
+    }
+
+    let emitter = get_inspecting_emitter(&editor_state, &assets).map(|(_, emitter)| emitter);
+    let default = EmitterData::default();
+
+    for (binding, highlight) in &highlights {
+        let differs = emitter.is_some_and(|emitter| is_non_default(binding, emitter, &default));
+        let weight = if differs {
+            HIGHLIGHT_WEIGHT
+        } else {
+            highlight.weight
+        };
+        let Ok(mut font) = fonts.get_mut(highlight.label) else {
+            continue;
+        };
+        if font.weight != weight {
+            font.weight = weight;
+        }
+    }
+}
+
+/// Whether the value `binding` points at in `emitter` differs from the one at the same
+/// reflection path in `default`.
+///
+/// Fields that only exist in one of them, such as the fields of a different enum variant,
+/// aren't flagged; the variant picker they belong to is.
+fn is_non_default(binding: &FieldBinding, emitter: &EmitterData, default: &EmitterData) -> bool {
+    let (Some(value), Some(default_value)) = (
+        binding.try_resolve_ref(emitter),
+        binding.try_resolve_ref(default),
+    ) else {
+        return false;
+    };
+    value.reflect_partial_eq(default_value) == Some(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Inspectable;
+    use crate::test_utils::{inspect, open_project, test_app};
+    use crate::ui::components::inspector::FieldKind;
+
+    fn spawn_field(app: &mut App, path: &str) -> Entity {
+        let label = app
+            .world_mut()
+            .spawn((Text::new(path), TextFont::default()))
+            .id();
+        app.world_mut()
+            .spawn(FieldBinding::emitter(path, FieldKind::F32))
+            .add_child(label);
+        label
+    }
+
+    #[test]
+    fn test_only_fields_that_differ_from_the_defaults_are_highlighted() {
+        let mut app = test_app();
+        app.init_resource::<InspectedEmitterTracker>().add_systems(
+            Update,
+            (attach_default_highlights, highlight_non_default_fields).chain(),
+        );
+        let mut emitter = EmitterData::default();
+        emitter.velocities.spread = 10.0;
+        open_project(&mut app, vec![emitter], vec![]);
+        inspect(&mut app, Inspectable::Emitter, 0);
+
+        let spread = spawn_field(&mut app, "velocities.spread");
+        let lifetime = spawn_field(&mut app, "time.lifetime");
+        app.update();
+
+        let weight = |app: &App, label: Entity| app.world().get::<TextFont>(label).unwrap().weight;
+        assert_eq!(weight(&app, spread), HIGHLIGHT_WEIGHT);
+        assert_eq!(weight(&app, lifetime), TextFont::default().weight);
+    }
+}
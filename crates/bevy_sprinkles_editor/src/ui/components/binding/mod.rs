@@ -1,4 +1,5 @@
 mod commit;
+mod highlight;
 mod swatch;
 mod sync;
 
@@ -116,6 +117,55 @@ pub(super) fn get_inspected_data_mut<'a>(
     }
 }
 
+/// Copies the bound field of the inspected emitter to every other emitter in the project.
+///
+/// Emitters where the field doesn't resolve, such as a different material variant, are
+/// skipped. Returns the number of emitters whose value changed.
+pub(super) fn apply_to_all_emitters(
+    editor_state: &EditorState,
+    assets: &mut Assets<ParticleSystemAsset>,
+    binding: &FieldBinding,
+) -> usize {
+    let Some(inspecting) = editor_state
+        .inspecting
+        .as_ref()
+        .filter(|i| i.kind == Inspectable::Emitter)
+    else {
+        return 0;
+    };
+    let Some(asset) = editor_state
+        .current_project
+        .as_ref()
+        .and_then(|handle| assets.get_mut(handle))
+    else {
+        return 0;
+    };
+    let source_index = inspecting.index as usize;
+    let Some(value) = asset
+        .emitters
+        .get(source_index)
+        .and_then(|emitter| binding.read_reflected(emitter))
+        .map(|value| value.to_dynamic())
+    else {
+        return 0;
+    };
+
+    let path = ReflectPath::new(binding.path());
+    let mut applied = 0;
+    for (index, emitter) in asset.emitters.iter_mut().enumerate() {
+        if index == source_index || emitter.reflect_path(path.as_str()).is_err() {
+            continue;
+        }
+        let changed = binding
+            .with_resolved_mut(emitter, |target| apply_with_change_check(target, &*value))
+            .unwrap_or(false);
+        if changed {
+            applied += 1;
+        }
+    }
+    applied
+}
+
 pub(super) fn find_ancestor<F>(
     mut entity: Entity,
     parents: &Query<&ChildOf>,
@@ -150,6 +200,7 @@ pub fn plugin(app: &mut App) {
                 propagate_bindings,
                 (
                     sync::bind_text_inputs,
+                    sync::sync_angle_suffixes,
                     sync::bind_widget_values,
                     sync::bind_color_pickers,
                     swatch::setup_variant_swatch,
@@ -158,6 +209,15 @@ pub fn plugin(app: &mut App) {
             )
                 .chain()
                 .after(super::inspector::update_inspected_emitter_tracker),
+        )
+        .add_systems(
+            Update,
+            (
+                highlight::attach_default_highlights,
+                highlight::highlight_non_default_fields,
+            )
+                .chain()
+                .after(super::inspector::update_inspected_emitter_tracker),
         );
 }
 
@@ -197,6 +257,16 @@ fn propagate_bindings(
     }
 }
 
+/// Marks a binding whose value is an angle in degrees, shown and entered in the unit
+/// picked in [`EditorSettings`](crate::io::EditorSettings).
+#[derive(Component)]
+pub struct AngleField;
+
+/// Marks a two-component vector binding whose components keep their ratio when either
+/// one is edited.
+#[derive(Component)]
+pub struct LockedAspect;
+
 #[derive(Clone)]
 pub enum FieldAccessor {
     Direct(String),
@@ -259,6 +329,24 @@ impl FieldBinding {
                 return None;
             }
         };
+        self.resolve_accessor(value)
+    }
+
+    /// Like [`resolve_ref`](Self::resolve_ref), but without warning when the path
+    /// doesn't exist in `data`.
+    pub(super) fn try_resolve_ref<'a>(
+        &self,
+        data: &'a dyn Reflect,
+    ) -> Option<&'a dyn PartialReflect> {
+        let path = ReflectPath::new(self.path());
+        let value = data.reflect_path(path.as_str()).ok()?;
+        self.resolve_accessor(value)
+    }
+
+    fn resolve_accessor<'a>(
+        &self,
+        value: &'a dyn PartialReflect,
+    ) -> Option<&'a dyn PartialReflect> {
         match &self.accessor {
             FieldAccessor::Direct(_) => Some(value),
             FieldAccessor::VariantField { field_name, .. } => {
@@ -371,6 +459,7 @@ pub(super) enum FieldValue {
     F32(f32),
     U32(u32),
     OptionalU32(Option<u32>),
+    I32(i32),
     Bool(bool),
     Vec2(Vec2),
     Vec3(Vec3),
@@ -379,6 +468,17 @@ pub(super) enum FieldValue {
 }
 
 impl FieldValue {
+    /// Applies `f` to every float component of the value, leaving other values as they are.
+    pub(super) fn map_f32(self, f: impl Fn(f32) -> f32) -> Self {
+        match self {
+            FieldValue::F32(v) => FieldValue::F32(f(v)),
+            FieldValue::Vec2(v) => FieldValue::Vec2(v.map(&f)),
+            FieldValue::Vec3(v) => FieldValue::Vec3(v.map(&f)),
+            FieldValue::Range(min, max) => FieldValue::Range(f(min), f(max)),
+            other => other,
+        }
+    }
+
     pub(super) fn to_display_string(&self, kind: &FieldKind) -> Option<String> {
         match self {
             FieldValue::F32(v) => match kind {
@@ -398,6 +498,7 @@ impl FieldValue {
                 (Some(0), FieldKind::OptionalU32) => None,
                 (Some(v), _) => Some(v.to_string()),
             },
+            FieldValue::I32(v) => Some(v.to_string()),
             _ => None,
         }
     }
@@ -457,6 +558,11 @@ pub(super) fn parse_field_value(text: &str, kind: &FieldKind) -> FieldValue {
             };
             parsed.map(FieldValue::U32).unwrap_or(FieldValue::None)
         }
+        FieldKind::I32 => text
+            .parse()
+            .ok()
+            .map(FieldValue::I32)
+            .unwrap_or(FieldValue::None),
         FieldKind::OptionalU32 => {
             let parsed: Option<Option<u32>> = if text.is_empty() {
                 Some(None)
@@ -500,6 +606,9 @@ fn reflect_to_field_value(value: &dyn PartialReflect, kind: &FieldKind) -> Field
     if let Some(v) = value.try_downcast_ref::<u32>() {
         return FieldValue::U32(*v);
     }
+    if let Some(v) = value.try_downcast_ref::<i32>() {
+        return FieldValue::I32(*v);
+    }
     if let Some(v) = value.try_downcast_ref::<bool>() {
         return FieldValue::Bool(*v);
     }
@@ -551,6 +660,7 @@ fn apply_field_value_to_reflect(target: &mut dyn PartialReflect, value: &FieldVa
         FieldValue::F32(v) => apply_with_change_check(target, v),
         FieldValue::U32(v) => apply_with_change_check(target, v),
         FieldValue::OptionalU32(v) => apply_with_change_check(target, v),
+        FieldValue::I32(v) => apply_with_change_check(target, v),
         FieldValue::Bool(v) => apply_with_change_check(target, v),
         FieldValue::Vec2(v) => apply_with_change_check(target, v),
         FieldValue::Vec3(v) => apply_with_change_check(target, v),
@@ -843,3 +953,158 @@ fn set_optional_enum_by_name(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{AngleUnit, EditorData};
+    use crate::state::Inspectable;
+    use crate::test_utils::{inspect, open_project, project, test_app};
+    use crate::ui::widgets::text_edit::TextEditCommitEvent;
+    use crate::ui::widgets::vector_edit::VectorSuffixes;
+
+    /// Commits a new lifetime on the second of three emitters and returns every
+    /// emitter's lifetime afterwards, along with whether the project was marked dirty.
+    fn commit_lifetime(keys: &[KeyCode]) -> (Vec<f32>, bool) {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_observer(commit::handle_text_commit);
+        let handle = open_project(&mut app, vec![EmitterData::default(); 3], vec![]);
+        inspect(&mut app, Inspectable::Emitter, 1);
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        for key in keys {
+            keyboard.press(*key);
+        }
+
+        let field = app
+            .world_mut()
+            .spawn(FieldBinding::emitter("time.lifetime", FieldKind::F32))
+            .id();
+        app.world_mut().trigger(TextEditCommitEvent {
+            entity: field,
+            text: "3.5".into(),
+        });
+
+        let lifetimes = project(&app, &handle)
+            .emitters
+            .iter()
+            .map(|emitter| emitter.time.lifetime)
+            .collect();
+        let dirty = app.world().resource::<DirtyState>().has_unsaved_changes;
+        (lifetimes, dirty)
+    }
+
+    #[test]
+    fn test_plain_commit_writes_to_inspected_emitter_only() {
+        let (lifetimes, dirty) = commit_lifetime(&[]);
+        assert_eq!(lifetimes, vec![1.0, 3.5, 1.0]);
+        assert!(dirty);
+    }
+
+    #[test]
+    fn test_ctrl_commit_writes_to_every_emitter() {
+        assert_eq!(commit_lifetime(&[KeyCode::ControlLeft]).0, vec![3.5; 3]);
+    }
+
+    #[test]
+    fn test_cmd_commit_writes_to_every_emitter() {
+        assert_eq!(commit_lifetime(&[KeyCode::SuperRight]).0, vec![3.5; 3]);
+    }
+
+    #[test]
+    fn test_apply_to_all_skips_emitters_already_matching() {
+        let mut app = test_app();
+        let mut emitters = vec![EmitterData::default(); 3];
+        emitters[0].time.lifetime = 2.0;
+        emitters[2].time.lifetime = 2.0;
+        let handle = open_project(&mut app, emitters, vec![]);
+        inspect(&mut app, Inspectable::Emitter, 0);
+
+        let binding = FieldBinding::emitter("time.lifetime", FieldKind::F32);
+        let world = app.world_mut();
+        let applied =
+            world.resource_scope(|world, mut assets: Mut<Assets<ParticleSystemAsset>>| {
+                apply_to_all_emitters(world.resource::<EditorState>(), &mut assets, &binding)
+            });
+
+        assert_eq!(applied, 1);
+        let lifetimes: Vec<f32> = project(&app, &handle)
+            .emitters
+            .iter()
+            .map(|emitter| emitter.time.lifetime)
+            .collect();
+        assert_eq!(lifetimes, vec![2.0; 3]);
+    }
+
+    #[test]
+    fn test_angle_entered_in_radians_is_stored_in_degrees() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_observer(commit::handle_text_commit);
+        app.world_mut()
+            .resource_mut::<EditorData>()
+            .settings
+            .angle_unit = AngleUnit::Radians;
+        let handle = open_project(&mut app, vec![EmitterData::default()], vec![]);
+        inspect(&mut app, Inspectable::Emitter, 0);
+
+        let field = app
+            .world_mut()
+            .spawn((
+                FieldBinding::emitter("velocities.spread", FieldKind::F32),
+                AngleField,
+            ))
+            .id();
+        app.world_mut().trigger(TextEditCommitEvent {
+            entity: field,
+            text: std::f32::consts::PI.to_string(),
+        });
+
+        let spread = project(&app, &handle).emitters[0].velocities.spread;
+        assert!((spread - 180.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_locked_aspect_mirrors_width_edits_to_height() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_observer(commit::handle_text_commit);
+        let mut emitter = EmitterData::default();
+        emitter.draw_pass.mesh = ParticleMesh::Quad {
+            orientation: QuadOrientation::default(),
+            size: Vec2::new(1.0, 0.5),
+            subdivide: Vec2::ZERO,
+        };
+        let handle = open_project(&mut app, vec![emitter], vec![]);
+        inspect(&mut app, Inspectable::Emitter, 0);
+
+        let binding = app
+            .world_mut()
+            .spawn((
+                FieldBinding::emitter_variant_field(
+                    "draw_pass.mesh",
+                    "size",
+                    FieldKind::Vector(VectorSuffixes::XY),
+                ),
+                LockedAspect,
+            ))
+            .id();
+        let width = app
+            .world_mut()
+            .spawn(BoundTo {
+                binding,
+                component_index: Some(0),
+            })
+            .id();
+        app.world_mut().trigger(TextEditCommitEvent {
+            entity: width,
+            text: "2".into(),
+        });
+
+        let ParticleMesh::Quad { size, .. } = project(&app, &handle).emitters[0].draw_pass.mesh
+        else {
+            panic!("the mesh should still be a quad");
+        };
+        assert_eq!(size, Vec2::new(2.0, 1.0));
+    }
+}
@@ -3,6 +3,7 @@ use bevy::reflect::{PartialReflect, ReflectRef};
 use bevy_sprinkles::prelude::*;
 use bevy_ui_text_input::TextInputQueue;
 
+use crate::io::EditorData;
 use crate::state::EditorState;
 use crate::ui::components::inspector::FieldKind;
 use crate::ui::widgets::checkbox::CheckboxState;
@@ -12,24 +13,31 @@ use crate::ui::widgets::color_picker::{
 use crate::ui::widgets::combobox::ComboBoxConfig;
 use crate::ui::widgets::curve_edit::{CurveEditState, EditorCurveEdit};
 use crate::ui::widgets::gradient_edit::{EditorGradientEdit, GradientEditState};
-use crate::ui::widgets::text_edit::{EditorTextEdit, set_text_input_value};
+use crate::ui::widgets::text_edit::{EditorTextEdit, TextEditSuffix, set_text_input_value};
 use crate::ui::widgets::variant_edit::{EditorVariantEdit, VariantDefinition, VariantEditConfig};
 
 use super::{
-    BoundTo, FieldBinding, FieldValue, InspectedEmitterTracker, format_f32, get_inspected_data,
-    get_variant_index_by_reflection,
+    AngleField, BoundTo, FieldBinding, FieldValue, InspectedEmitterTracker, format_f32,
+    get_inspected_data, get_variant_index_by_reflection,
 };
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn bind_text_inputs(
     editor_state: Res<EditorState>,
+    editor_data: Res<EditorData>,
     assets: Res<Assets<ParticleSystemAsset>>,
     tracker: Res<InspectedEmitterTracker>,
     new_bindings: Query<Entity, Added<FieldBinding>>,
     new_bound: Query<Entity, Added<BoundTo>>,
     bindings: Query<&FieldBinding>,
+    angle_fields: Query<(), With<AngleField>>,
     mut text_edits: Query<(&BoundTo, &mut TextInputQueue), With<EditorTextEdit>>,
 ) {
-    if !tracker.is_changed() && new_bindings.is_empty() && new_bound.is_empty() {
+    if !tracker.is_changed()
+        && !editor_data.is_changed()
+        && new_bindings.is_empty()
+        && new_bound.is_empty()
+    {
         return;
     }
 
@@ -42,7 +50,11 @@ pub(super) fn bind_text_inputs(
             continue;
         };
 
-        let value = binding.read_value(data);
+        let mut value = binding.read_value(data);
+        if angle_fields.contains(bound.binding) {
+            let unit = editor_data.settings.angle_unit;
+            value = value.map_f32(|degrees| unit.to_unit(degrees));
+        }
 
         if let Some(idx) = bound.component_index {
             if let FieldKind::Vector(suffixes) = &binding.kind {
@@ -63,6 +75,25 @@ pub(super) fn bind_text_inputs(
     }
 }
 
+/// Shows the current angle unit after the values of angle fields.
+pub(super) fn sync_angle_suffixes(
+    editor_data: Res<EditorData>,
+    new_bound: Query<Entity, Added<BoundTo>>,
+    angle_fields: Query<(), With<AngleField>>,
+    mut text_edits: Query<(&BoundTo, &mut TextEditSuffix), With<EditorTextEdit>>,
+) {
+    if !editor_data.is_changed() && new_bound.is_empty() {
+        return;
+    }
+
+    let suffix = editor_data.settings.angle_unit.suffix();
+    for (bound, mut text_suffix) in &mut text_edits {
+        if angle_fields.contains(bound.binding) && text_suffix.0 != suffix {
+            text_suffix.0 = suffix.to_string();
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(super) fn bind_widget_values(
     editor_state: Res<EditorState>,
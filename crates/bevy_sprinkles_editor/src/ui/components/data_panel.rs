@@ -1,9 +1,20 @@
+use std::collections::HashSet;
+
 use bevy::input_focus::InputFocus;
 use bevy::picking::hover::Hovered;
 use bevy::prelude::*;
 use bevy_sprinkles::prelude::*;
+use bevy_sprinkles::textures::GradientTextureCache;
+use bevy_ui_text_input::TextInputBuffer;
 
+use crate::module::{ExportEmitterModuleEvent, ImportEmitterModuleEvent};
 use crate::state::{DirtyState, EditorState, Inspectable, Inspecting};
+use crate::ui::components::inspector::InspectedEmitterTracker;
+use crate::ui::components::ron_preview::OpenRonPreviewEvent;
+use crate::ui::components::thumbnails::thumbnails_section;
+use crate::ui::components::toasts::ToastEvent;
+use crate::ui::components::validation::problems_section;
+use crate::ui::tokens::{BORDER_COLOR, CORNER_RADIUS};
 use crate::ui::widgets::button::{
     ButtonClickEvent, ButtonProps, ButtonVariant, EditorButton, button, set_button_variant,
 };
@@ -12,7 +23,9 @@ use crate::ui::widgets::combobox::{
 };
 use crate::ui::widgets::dialog::{DialogActionEvent, EditorDialog, OpenConfirmationDialogEvent};
 use crate::ui::widgets::panel::{PanelDirection, PanelProps, panel};
-use crate::ui::widgets::panel_section::{PanelSectionProps, panel_section};
+use crate::ui::widgets::panel_section::{
+    PanelSectionImportEvent, PanelSectionProps, panel_section,
+};
 use crate::ui::widgets::scroll::scrollbar;
 use crate::ui::widgets::text_edit::{
     EditorTextEdit, TextEditCommitEvent, TextEditProps, text_edit,
@@ -21,9 +34,18 @@ use crate::ui::widgets::utils::find_ancestor;
 use crate::viewport::{RespawnCollidersEvent, RespawnEmittersEvent};
 
 const DOUBLE_CLICK_THRESHOLD: f32 = 0.3;
+/// Speed below which "Fit Lifetime" considers a particle at rest, in units per second.
+const FIT_LIFETIME_SPEED_THRESHOLD: f32 = 0.05;
+/// Shortest lifetime "Fit Lifetime" suggests, in seconds.
+const FIT_LIFETIME_MIN: f32 = 0.1;
+const SWATCH_WIDTH: f32 = 24.0;
+const SWATCH_HEIGHT: f32 = 6.0;
+/// Room left at the right of an emitter row for its menu button.
+const SWATCH_RIGHT: f32 = 32.0;
 
 pub fn plugin(app: &mut App) {
     app.init_resource::<LastLoadedProject>()
+        .init_resource::<ItemsFilterText>()
         .add_observer(on_item_click)
         .add_observer(on_item_menu_change)
         .add_observer(on_rename_commit)
@@ -40,13 +62,15 @@ pub fn plugin(app: &mut App) {
                 handle_item_double_click,
                 focus_rename_input,
                 cleanup_pending_delete,
+                (read_items_filter, filter_items).chain(),
+                update_gradient_swatches,
             ),
         );
 }
 
 #[derive(Resource, Default)]
-struct LastLoadedProject {
-    handle: Option<AssetId<ParticleSystemAsset>>,
+pub struct LastLoadedProject {
+    pub handle: Option<AssetId<ParticleSystemAsset>>,
 }
 
 #[derive(Component)]
@@ -70,9 +94,22 @@ struct ItemButton;
 #[derive(Component)]
 struct ItemMenu;
 
+/// Preview of an emitter's color over lifetime, shown in its row.
+#[derive(Component)]
+struct GradientSwatch {
+    index: u8,
+}
+
 #[derive(Component)]
 struct ItemsList;
 
+#[derive(Component)]
+struct ItemsFilter;
+
+/// Lowercased text of the [`ItemsFilter`] input.
+#[derive(Resource, Default)]
+struct ItemsFilterText(String);
+
 #[derive(Component)]
 struct RenameInput {
     item_entity: Entity,
@@ -116,15 +153,32 @@ fn setup_data_panel(
             .entity(panel_entity)
             .with_child(scrollbar(panel_entity))
             .with_children(|parent| {
+                parent.spawn((
+                    Node {
+                        width: percent(100),
+                        padding: UiRect::all(px(12)),
+                        border: UiRect::bottom(px(1)),
+                        ..default()
+                    },
+                    BorderColor::all(BORDER_COLOR),
+                    children![(
+                        ItemsFilter,
+                        text_edit(TextEditProps::default().with_placeholder("Filter by name")),
+                    )],
+                ));
+
                 parent
                     .spawn((
                         EmittersSection,
                         panel_section(
-                            PanelSectionProps::new("Emitters").with_add_button(),
+                            PanelSectionProps::new("Emitters")
+                                .with_import_button()
+                                .with_add_button(),
                             &asset_server,
                         ),
                     ))
-                    .observe(on_add_emitter_click);
+                    .observe(on_add_emitter_click)
+                    .observe(on_import_emitter_module_click);
 
                 parent
                     .spawn((
@@ -135,6 +189,9 @@ fn setup_data_panel(
                         ),
                     ))
                     .observe(on_add_collider_click);
+
+                parent.spawn(thumbnails_section(&asset_server));
+                parent.spawn(problems_section(&asset_server));
             });
     }
 }
@@ -250,11 +307,35 @@ fn spawn_items<'a>(
             ))
             .id();
 
+        let menu_options = match kind {
+            Inspectable::Emitter => vec![
+                "Duplicate",
+                "Rename",
+                "Fit Lifetime",
+                "Export Module",
+                "View RON",
+                "Move Up",
+                "Move Down",
+                "Delete",
+            ],
+            Inspectable::Collider => {
+                let mute = if editor_state.muted_colliders.contains(&(index as usize)) {
+                    "Unmute in Preview"
+                } else {
+                    "Mute in Preview"
+                };
+                vec![
+                    "Duplicate",
+                    "Rename",
+                    mute,
+                    "Move Up",
+                    "Move Down",
+                    "Delete",
+                ]
+            }
+        };
         let menu_entity = commands
-            .spawn((
-                ItemMenu,
-                combobox_icon(vec!["Duplicate", "Rename", "Delete"]),
-            ))
+            .spawn((ItemMenu, combobox_icon(menu_options)))
             .insert(Node {
                 position_type: PositionType::Absolute,
                 right: px(0.0),
@@ -267,6 +348,30 @@ fn spawn_items<'a>(
             .entity(item_entity)
             .add_children(&[button_entity, menu_entity]);
 
+        if kind == Inspectable::Emitter {
+            commands.entity(item_entity).with_child((
+                Node {
+                    position_type: PositionType::Absolute,
+                    right: px(SWATCH_RIGHT),
+                    height: percent(100),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                Pickable::IGNORE,
+                children![(
+                    GradientSwatch { index },
+                    ImageNode::default(),
+                    Node {
+                        width: px(SWATCH_WIDTH),
+                        height: px(SWATCH_HEIGHT),
+                        border_radius: BorderRadius::all(CORNER_RADIUS),
+                        ..default()
+                    },
+                    Pickable::IGNORE,
+                )],
+            ));
+        }
+
         commands.entity(list_entity).add_child(item_entity);
     }
 }
@@ -275,6 +380,10 @@ fn on_add_emitter_click(_event: On<ButtonClickEvent>, mut commands: Commands) {
     commands.trigger(AddEmitterEvent);
 }
 
+fn on_import_emitter_module_click(_event: On<PanelSectionImportEvent>, mut commands: Commands) {
+    commands.trigger(ImportEmitterModuleEvent);
+}
+
 fn on_add_collider_click(_event: On<ButtonClickEvent>, mut commands: Commands) {
     commands.trigger(AddColliderEvent);
 }
@@ -378,6 +487,7 @@ fn on_item_menu_change(
     menus: Query<&ChildOf, With<ItemMenu>>,
     items: Query<(Entity, &InspectableItem, &Children), Without<Renaming>>,
     mut buttons: Query<&mut Node, With<ItemButton>>,
+    mut tracker: ResMut<InspectedEmitterTracker>,
 ) {
     let Ok(child_of) = menus.get(event.entity) else {
         return;
@@ -412,7 +522,7 @@ fn on_item_menu_change(
                     let existing: Vec<&str> =
                         asset.emitters.iter().map(|e| e.name.as_str()).collect();
                     new_item.name = next_unique_name(base, &existing);
-                    asset.emitters.insert(insert_index, new_item);
+                    asset.insert_emitter(insert_index, new_item);
                 }
                 Inspectable::Collider => {
                     let Some(source) = asset.colliders.get(item.index as usize) else {
@@ -428,6 +538,9 @@ fn on_item_menu_change(
 
             dirty_state.has_unsaved_changes = true;
             adjust_inspecting_after_insert(&mut editor_state.inspecting, item.kind, insert_index);
+            if item.kind == Inspectable::Collider {
+                adjust_muted_after_insert(&mut editor_state.muted_colliders, insert_index);
+            }
             trigger_respawn(&mut commands, item.kind);
             last_project.handle = None;
         }
@@ -440,6 +553,81 @@ fn on_item_menu_change(
             }
             start_rename(&mut commands, item_entity, &item_name);
         }
+        "Fit Lifetime" => {
+            let Some(handle) = &editor_state.current_project else {
+                return;
+            };
+            let Some(asset) = assets.get_mut(handle) else {
+                return;
+            };
+            let index = item.index as usize;
+
+            let Some(settle_time) = asset.settle_time(index, FIT_LIFETIME_SPEED_THRESHOLD) else {
+                commands.trigger(ToastEvent::error(format!(
+                    "Particles of {item_name} never come to rest, so its lifetime was kept"
+                )));
+                return;
+            };
+            let lifetime = settle_time.max(FIT_LIFETIME_MIN);
+            asset.emitters[index].time.lifetime = lifetime;
+
+            dirty_state.has_unsaved_changes = true;
+            // refresh the bound fields
+            tracker.set_changed();
+            commands.trigger(RespawnEmittersEvent);
+            commands.trigger(ToastEvent::success(format!(
+                "Set the lifetime of {item_name} to {lifetime:.2}s"
+            )));
+        }
+        "Mute in Preview" | "Unmute in Preview" => {
+            let index = item.index as usize;
+            if !editor_state.muted_colliders.remove(&index) {
+                editor_state.muted_colliders.insert(index);
+            }
+            // refresh the menu label
+            last_project.handle = None;
+        }
+        "Export Module" => {
+            commands.trigger(ExportEmitterModuleEvent(item.index as usize));
+        }
+        "View RON" => {
+            commands.trigger(OpenRonPreviewEvent(item.index as usize));
+        }
+        "Move Up" | "Move Down" => {
+            let Some(handle) = &editor_state.current_project else {
+                return;
+            };
+            let Some(asset) = assets.get_mut(handle) else {
+                return;
+            };
+
+            let from = item.index as usize;
+            let len = match item.kind {
+                Inspectable::Emitter => asset.emitters.len(),
+                Inspectable::Collider => asset.colliders.len(),
+            };
+            let to = if event.label == "Move Up" {
+                from.checked_sub(1)
+            } else {
+                Some(from + 1).filter(|&to| to < len)
+            };
+            let Some(to) = to else {
+                return;
+            };
+
+            match item.kind {
+                Inspectable::Emitter => asset.move_emitter(from, to),
+                Inspectable::Collider => asset.colliders.swap(from, to),
+            }
+
+            dirty_state.has_unsaved_changes = true;
+            adjust_inspecting_after_swap(&mut editor_state.inspecting, item.kind, from, to);
+            if item.kind == Inspectable::Collider {
+                adjust_muted_after_swap(&mut editor_state.muted_colliders, from, to);
+            }
+            trigger_respawn(&mut commands, item.kind);
+            last_project.handle = None;
+        }
         "Delete" => {
             let label = match item.kind {
                 Inspectable::Emitter => "Delete emitter",
@@ -508,7 +696,7 @@ fn handle_item_right_click(
     }
 }
 
-fn next_unique_name(base_name: &str, existing: &[&str]) -> String {
+pub fn next_unique_name(base_name: &str, existing: &[&str]) -> String {
     if !existing.contains(&base_name) {
         return base_name.to_string();
     }
@@ -545,6 +733,25 @@ fn adjust_inspecting_after_insert(
     });
 }
 
+fn adjust_inspecting_after_swap(
+    inspecting: &mut Option<Inspecting>,
+    kind: Inspectable,
+    a: usize,
+    b: usize,
+) {
+    let Some(current) = inspecting.as_mut() else {
+        return;
+    };
+    if current.kind != kind {
+        return;
+    }
+    if current.index as usize == a {
+        current.index = b as u8;
+    } else if current.index as usize == b {
+        current.index = a as u8;
+    }
+}
+
 fn adjust_inspecting_after_delete(
     inspecting: &mut Option<Inspecting>,
     kind: Inspectable,
@@ -566,6 +773,31 @@ fn adjust_inspecting_after_delete(
     }
 }
 
+fn adjust_muted_after_insert(muted: &mut HashSet<usize>, insert_index: usize) {
+    *muted = muted
+        .drain()
+        .map(|i| if i >= insert_index { i + 1 } else { i })
+        .collect();
+}
+
+fn adjust_muted_after_swap(muted: &mut HashSet<usize>, a: usize, b: usize) {
+    let (has_a, has_b) = (muted.remove(&a), muted.remove(&b));
+    if has_a {
+        muted.insert(b);
+    }
+    if has_b {
+        muted.insert(a);
+    }
+}
+
+fn adjust_muted_after_delete(muted: &mut HashSet<usize>, deleted_index: usize) {
+    *muted = muted
+        .drain()
+        .filter(|&i| i != deleted_index)
+        .map(|i| if i > deleted_index { i - 1 } else { i })
+        .collect();
+}
+
 fn strip_trailing_number(name: &str) -> (&str, Option<u32>) {
     if let Some(pos) = name.rfind(' ') {
         let suffix = &name[pos + 1..];
@@ -795,10 +1027,9 @@ fn on_delete_confirmed(
 
     let new_len = match kind {
         Inspectable::Emitter => {
-            if index >= asset.emitters.len() {
+            if asset.remove_emitter(index).is_none() {
                 return;
             }
-            asset.emitters.remove(index);
             asset.emitters.len()
         }
         Inspectable::Collider => {
@@ -812,6 +1043,9 @@ fn on_delete_confirmed(
 
     dirty_state.has_unsaved_changes = true;
     adjust_inspecting_after_delete(&mut editor_state.inspecting, kind, index, new_len);
+    if kind == Inspectable::Collider {
+        adjust_muted_after_delete(&mut editor_state.muted_colliders, index);
+    }
     trigger_respawn(&mut commands, kind);
     last_project.handle = None;
 }
@@ -826,6 +1060,47 @@ fn cleanup_pending_delete(
     }
 }
 
+fn read_items_filter(
+    filters: Query<Entity, With<ItemsFilter>>,
+    children_query: Query<&Children>,
+    text_edits: Query<Entity, With<EditorTextEdit>>,
+    buffers: Query<&TextInputBuffer>,
+    mut filter_text: ResMut<ItemsFilterText>,
+) {
+    let filter = filters
+        .iter()
+        .find_map(|entity| find_inner_text_edit(entity, &children_query, &text_edits))
+        .and_then(|entity| buffers.get(entity).ok())
+        .map(|buffer| buffer.get_text().trim().to_lowercase())
+        .unwrap_or_default();
+    if filter_text.0 != filter {
+        filter_text.0 = filter;
+    }
+}
+
+/// Hides emitters and colliders whose name doesn't contain the filter text, ignoring case.
+fn filter_items(
+    editor_state: Res<EditorState>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    filter_text: Res<ItemsFilterText>,
+    mut items: Query<(&InspectableItem, &mut Node)>,
+) {
+    let filter = &filter_text.0;
+    for (item, mut node) in &mut items {
+        let visible = filter.is_empty()
+            || get_item_name(&editor_state, &assets, item)
+                .is_some_and(|name| name.to_lowercase().contains(filter));
+        let display = if visible {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
+
 fn update_items(
     editor_state: Res<EditorState>,
     items: Query<(&InspectableItem, &Hovered, &Children, Has<Renaming>)>,
@@ -901,3 +1176,274 @@ fn update_items(
         }
     }
 }
+
+/// Points each emitter row's swatch at the baked texture of the emitter's color over
+/// lifetime, so it follows edits to the gradient.
+fn update_gradient_swatches(
+    editor_state: Res<EditorState>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    mut cache: ResMut<GradientTextureCache>,
+    mut images: ResMut<Assets<Image>>,
+    mut swatches: Query<(&GradientSwatch, &mut ImageNode)>,
+) {
+    let Some(asset) = editor_state
+        .current_project
+        .as_ref()
+        .and_then(|handle| assets.get(handle))
+    else {
+        return;
+    };
+
+    for (swatch, mut image) in &mut swatches {
+        let Some(emitter) = asset.emitters.get(swatch.index as usize) else {
+            continue;
+        };
+        let handle = cache.get_or_create(&emitter.colors.color_over_lifetime, &mut images);
+        if image.image != handle {
+            image.image = handle;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_sprinkles::test_utils::update_particle_time;
+
+    use super::*;
+    use crate::test_utils::{inspect, open_project, project, test_app};
+    use crate::ui::components::inspector::update_inspected_emitter_tracker;
+    use crate::viewport::{EditorParticlePreview, apply_muted_colliders, sync_playback_state};
+
+    fn named_emitter(name: &str) -> EmitterData {
+        EmitterData {
+            name: name.into(),
+            ..default()
+        }
+    }
+
+    fn display_after_filter(app: &mut App, filter: &str, item: Entity) -> Display {
+        app.world_mut().resource_mut::<ItemsFilterText>().0 = filter.into();
+        app.world_mut().run_system_once(filter_items).unwrap();
+        app.world().get::<Node>(item).unwrap().display
+    }
+
+    #[test]
+    fn test_filter_hides_emitters_whose_name_does_not_match() {
+        let mut app = test_app();
+        app.init_resource::<ItemsFilterText>();
+        open_project(
+            &mut app,
+            vec![named_emitter("Smoke"), named_emitter("Fireball")],
+            vec![],
+        );
+        let smoke = app
+            .world_mut()
+            .spawn((
+                InspectableItem {
+                    kind: Inspectable::Emitter,
+                    index: 0,
+                },
+                Node::default(),
+            ))
+            .id();
+        let fireball = app
+            .world_mut()
+            .spawn((
+                InspectableItem {
+                    kind: Inspectable::Emitter,
+                    index: 1,
+                },
+                Node::default(),
+            ))
+            .id();
+
+        assert_eq!(display_after_filter(&mut app, "fire", smoke), Display::None);
+        assert_eq!(
+            app.world().get::<Node>(fireball).unwrap().display,
+            Display::Flex
+        );
+
+        assert_eq!(display_after_filter(&mut app, "", smoke), Display::Flex);
+        assert_eq!(
+            app.world().get::<Node>(fireball).unwrap().display,
+            Display::Flex
+        );
+    }
+
+    #[test]
+    fn test_swatches_show_the_cached_gradient_of_their_emitter() {
+        let mut app = test_app();
+        app.init_asset::<Image>()
+            .init_resource::<GradientTextureCache>();
+        let mut fire = named_emitter("Fire");
+        fire.colors.color_over_lifetime.stops[0].color = [1.0, 0.3, 0.0, 1.0];
+        let smoke = named_emitter("Smoke");
+        let gradients = [
+            fire.colors.color_over_lifetime.clone(),
+            smoke.colors.color_over_lifetime.clone(),
+        ];
+        open_project(&mut app, vec![fire, smoke], vec![]);
+        let swatches: Vec<Entity> = (0..2)
+            .map(|index| {
+                app.world_mut()
+                    .spawn((GradientSwatch { index }, ImageNode::default()))
+                    .id()
+            })
+            .collect();
+
+        app.world_mut()
+            .run_system_once(update_gradient_swatches)
+            .unwrap();
+
+        let cache = app.world().resource::<GradientTextureCache>();
+        let fire_texture = cache.get(&gradients[0]).unwrap();
+        let smoke_texture = cache.get(&gradients[1]).unwrap();
+        assert_ne!(fire_texture, smoke_texture);
+        for (swatch, texture) in swatches.into_iter().zip([fire_texture, smoke_texture]) {
+            assert_eq!(app.world().get::<ImageNode>(swatch).unwrap().image, texture);
+        }
+    }
+
+    #[test]
+    fn test_changing_the_inspected_emitter_keeps_the_preview_running() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            16,
+        )))
+        .init_resource::<InspectedEmitterTracker>()
+        .add_observer(on_item_click)
+        .add_systems(
+            Update,
+            (
+                update_inspected_emitter_tracker,
+                update_particle_time,
+                sync_playback_state,
+            )
+                .chain(),
+        );
+        let handle = open_project(&mut app, vec![EmitterData::default(); 3], vec![]);
+        inspect(&mut app, Inspectable::Emitter, 0);
+
+        let preview = app
+            .world_mut()
+            .spawn((
+                ParticleSystem3D::new(handle),
+                ParticleSystemRuntime::default(),
+                EditorParticlePreview,
+            ))
+            .id();
+        let runtimes: Vec<Entity> = (0..3)
+            .map(|index| {
+                app.world_mut()
+                    .spawn((
+                        EmitterEntity {
+                            parent_system: preview,
+                        },
+                        EmitterRuntime::new(index, None),
+                    ))
+                    .id()
+            })
+            .collect();
+        let item = app
+            .world_mut()
+            .spawn(InspectableItem {
+                kind: Inspectable::Emitter,
+                index: 1,
+            })
+            .id();
+        let button = app.world_mut().spawn((ItemButton, ChildOf(item))).id();
+        for _ in 0..5 {
+            app.update();
+        }
+        let system_times = |app: &App| -> Vec<f32> {
+            runtimes
+                .iter()
+                .map(|entity| {
+                    app.world()
+                        .get::<EmitterRuntime>(*entity)
+                        .unwrap()
+                        .system_time
+                })
+                .collect()
+        };
+        let before = system_times(&app);
+
+        app.world_mut().trigger(ButtonClickEvent { entity: button });
+        app.update();
+
+        let inspecting = app.world().resource::<EditorState>().inspecting.unwrap();
+        assert_eq!(inspecting.index, 1);
+        for (before, after) in before.into_iter().zip(system_times(&app)) {
+            assert!(before > 0.0);
+            assert!(after > before, "an emitter restarted: {before} -> {after}");
+        }
+    }
+
+    #[test]
+    fn test_muting_a_collider_only_disables_it_in_the_preview() {
+        let mut app = test_app();
+        app.init_resource::<LastLoadedProject>()
+            .init_resource::<InspectedEmitterTracker>()
+            .add_observer(on_item_menu_change)
+            .add_systems(PostUpdate, apply_muted_colliders);
+        let collider = ColliderData {
+            name: "Floor".into(),
+            ..default()
+        };
+        let handle = open_project(&mut app, vec![], vec![collider]);
+
+        let preview = app
+            .world_mut()
+            .spawn((ParticleSystem3D::new(handle.clone()), EditorParticlePreview))
+            .id();
+        let preview_collider = app
+            .world_mut()
+            .spawn((
+                ColliderEntity {
+                    parent_system: preview,
+                    collider_index: 0,
+                },
+                ParticlesCollider3D::default(),
+            ))
+            .id();
+        let item = app
+            .world_mut()
+            .spawn(InspectableItem {
+                kind: Inspectable::Collider,
+                index: 0,
+            })
+            .id();
+        let menu = app.world_mut().spawn((ItemMenu, ChildOf(item))).id();
+
+        app.world_mut().trigger(ComboBoxChangeEvent {
+            entity: menu,
+            selected: 2,
+            label: "Mute in Preview".into(),
+            value: None,
+        });
+        app.update();
+
+        let enabled = |app: &App| {
+            app.world()
+                .get::<ParticlesCollider3D>(preview_collider)
+                .unwrap()
+                .enabled
+        };
+        assert!(!enabled(&app));
+        assert!(project(&app, &handle).colliders[0].enabled);
+        assert!(!app.world().resource::<DirtyState>().has_unsaved_changes);
+
+        app.world_mut().trigger(ComboBoxChangeEvent {
+            entity: menu,
+            selected: 2,
+            label: "Unmute in Preview".into(),
+            value: None,
+        });
+        app.update();
+        assert!(enabled(&app));
+    }
+}
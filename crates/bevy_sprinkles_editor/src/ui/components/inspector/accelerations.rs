@@ -11,12 +11,25 @@ pub fn accelerations_section(asset_server: &AssetServer) -> impl Bundle {
     inspector_section(
         InspectorSection::new(
             "Accelerations",
-            vec![vec![
-                InspectorFieldProps::new("accelerations.gravity")
-                    .vector(VectorSuffixes::XYZ)
-                    .into(),
-            ]],
-        ),
+            vec![
+                vec![
+                    InspectorFieldProps::new("accelerations.gravity")
+                        .vector(VectorSuffixes::XYZ)
+                        .into(),
+                ],
+                vec![
+                    InspectorFieldProps::new("accelerations.gravity_scale")
+                        .with_min(0.0)
+                        .into(),
+                ],
+                vec![
+                    InspectorFieldProps::new("accelerations.acceleration_randomness")
+                        .vector(VectorSuffixes::XYZ)
+                        .into(),
+                ],
+            ],
+        )
+        .with_paste("accelerations"),
         asset_server,
     )
 }
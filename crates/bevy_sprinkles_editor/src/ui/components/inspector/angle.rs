@@ -1,33 +1,94 @@
 use bevy::prelude::*;
 
+use crate::io::{AngleUnit, EditorData, save_editor_data};
+use crate::ui::tokens::FONT_PATH;
+use crate::ui::widgets::combobox::{ComboBoxChangeEvent, ComboBoxOptionData};
 use crate::ui::widgets::inspector_field::InspectorFieldProps;
 use crate::ui::widgets::vector_edit::VectorSuffixes;
 
-use super::{InspectorSection, inspector_section};
+use super::{InspectorSection, inspector_section, section_needs_setup, spawn_labeled_combobox};
 
-pub fn plugin(_app: &mut App) {}
+const ANGLE_UNITS: [AngleUnit; 2] = [AngleUnit::Degrees, AngleUnit::Radians];
+
+#[derive(Component)]
+struct AngleSection;
+
+#[derive(Component)]
+struct AngleUnitComboBox;
+
+pub fn plugin(app: &mut App) {
+    app.add_observer(handle_angle_unit_change)
+        .add_systems(Update, setup_angle_unit);
+}
 
 pub fn angle_section(asset_server: &AssetServer) -> impl Bundle {
-    inspector_section(
-        InspectorSection::new(
-            "Angle",
-            vec![
-                vec![
-                    InspectorFieldProps::new("angle.range")
-                        .vector(VectorSuffixes::Range)
-                        .with_label("Initial angle")
-                        .with_suffix("°")
-                        .with_min(-360.0)
-                        .with_max(360.0)
-                        .into(),
-                ],
+    (
+        AngleSection,
+        inspector_section(
+            InspectorSection::new(
+                "Angle",
                 vec![
-                    InspectorFieldProps::new("angle.angle_over_lifetime")
-                        .curve()
-                        .into(),
+                    vec![
+                        InspectorFieldProps::new("angle.range")
+                            .vector(VectorSuffixes::Range)
+                            .with_label("Initial angle")
+                            .angle()
+                            .with_min(-360.0)
+                            .with_max(360.0)
+                            .into(),
+                    ],
+                    vec![
+                        InspectorFieldProps::new("angle.angle_over_lifetime")
+                            .curve()
+                            .into(),
+                    ],
                 ],
-            ],
+            )
+            .with_paste("angle"),
+            asset_server,
         ),
-        asset_server,
     )
 }
+
+fn setup_angle_unit(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    editor_data: Res<EditorData>,
+    sections: Query<(Entity, &InspectorSection), With<AngleSection>>,
+    existing: Query<Entity, With<AngleUnitComboBox>>,
+) {
+    let Some(entity) = section_needs_setup(&sections, &existing) else {
+        return;
+    };
+
+    let font: Handle<Font> = asset_server.load(FONT_PATH);
+    let options = vec![
+        ComboBoxOptionData::new("Degrees"),
+        ComboBoxOptionData::new("Radians"),
+    ];
+    let selected = ANGLE_UNITS
+        .iter()
+        .position(|unit| *unit == editor_data.settings.angle_unit)
+        .unwrap_or(0);
+
+    commands.entity(entity).with_children(|parent| {
+        spawn_labeled_combobox(parent, &font, "Units", options, selected, AngleUnitComboBox);
+    });
+}
+
+fn handle_angle_unit_change(
+    trigger: On<ComboBoxChangeEvent>,
+    comboboxes: Query<(), With<AngleUnitComboBox>>,
+    mut editor_data: ResMut<EditorData>,
+) {
+    if !comboboxes.contains(trigger.entity) {
+        return;
+    }
+    let Some(unit) = ANGLE_UNITS.get(trigger.selected).copied() else {
+        return;
+    };
+    if editor_data.settings.angle_unit != unit {
+        editor_data.settings.angle_unit = unit;
+        save_editor_data(&editor_data);
+    }
+}
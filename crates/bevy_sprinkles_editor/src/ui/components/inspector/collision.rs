@@ -33,7 +33,10 @@ pub fn plugin(app: &mut App) {
 pub fn collision_section(asset_server: &AssetServer) -> impl Bundle {
     (
         CollisionSection,
-        inspector_section(InspectorSection::new("Collision", vec![]), asset_server),
+        inspector_section(
+            InspectorSection::new("Collision", vec![]).with_paste("collision"),
+            asset_server,
+        ),
     )
 }
 
@@ -63,6 +63,12 @@ pub fn colors_section(asset_server: &AssetServer) -> impl Bundle {
                             .gradient()
                             .into(),
                     ],
+                    vec![
+                        InspectorFieldProps::new("colors.color_over_lifetime.hdr")
+                            .bool()
+                            .with_label("HDR")
+                            .into(),
+                    ],
                     vec![
                         InspectorFieldProps::new("colors.alpha_over_lifetime")
                             .curve()
@@ -72,7 +78,8 @@ pub fn colors_section(asset_server: &AssetServer) -> impl Bundle {
                             .into(),
                     ],
                 ],
-            ),
+            )
+            .with_paste("colors"),
             asset_server,
         ),
     )
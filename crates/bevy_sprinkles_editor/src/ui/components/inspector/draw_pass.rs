@@ -1,10 +1,15 @@
 use bevy::prelude::*;
+use bevy_sprinkles::asset::PERCEPTUAL_ROUGHNESS_RANGE;
 use bevy_sprinkles::prelude::*;
 
 use crate::state::EditorState;
-use crate::ui::components::binding::{FieldBinding, get_inspecting_emitter};
+use crate::ui::components::binding::{
+    EmitterWriter, FieldBinding, LockedAspect, get_inspecting_emitter,
+};
+use crate::ui::widgets::button::{ButtonClickEvent, ButtonProps, button};
+use crate::ui::widgets::checkbox::{CheckboxCommitEvent, CheckboxProps, checkbox};
 use crate::ui::widgets::combobox::ComboBoxOptionData;
-use crate::ui::widgets::inspector_field::InspectorFieldProps;
+use crate::ui::widgets::inspector_field::{InspectorFieldProps, fields_row};
 use crate::ui::widgets::text_edit::{TextEditProps, text_edit};
 use crate::ui::widgets::utils::find_ancestor;
 use crate::ui::widgets::variant_edit::{
@@ -14,59 +19,149 @@ use crate::ui::widgets::vector_edit::VectorSuffixes;
 
 use super::types::{FieldKind, VariantField};
 use super::utils::{VariantConfig, combobox_options_from_reflect, variants_from_reflect};
-use super::{InspectorItem, InspectorSection, inspector_section};
+use super::{
+    InspectedEmitterTracker, InspectorItem, InspectorSection, inspector_section,
+    section_needs_setup,
+};
 use crate::ui::icons::{
-    ICON_CONE, ICON_CUBE, ICON_MESH_CYLINDER, ICON_MESH_PLANE, ICON_MESH_UVSPHERE,
+    ICON_CONE, ICON_CUBE, ICON_EMPTY_AXIS, ICON_MESH_CYLINDER, ICON_MESH_PLANE, ICON_MESH_UVSPHERE,
 };
 
+const GLOW_PRESET_INTENSITY: f32 = 4.0;
+
 #[derive(Component)]
 struct MaskCutoffRow;
 
+#[derive(Component)]
+struct DrawPassSection;
+
+#[derive(Component)]
+struct GlowPresetButton;
+
+#[derive(Component)]
+struct AspectLockRow;
+
+#[derive(Component)]
+struct AspectLockCheckbox;
+
+/// Whether editing one component of a quad's size scales the other, kept across emitters.
+#[derive(Resource, Default)]
+struct QuadAspectLock(bool);
+
 pub fn plugin(app: &mut App) {
-    app.add_systems(
-        Update,
-        sync_mask_cutoff.after(super::update_inspected_emitter_tracker),
-    );
+    app.init_resource::<QuadAspectLock>()
+        .add_observer(handle_glow_preset_click)
+        .add_observer(handle_aspect_lock_checkbox)
+        .add_systems(
+            Update,
+            (
+                setup_glow_preset_button,
+                sync_mask_cutoff,
+                sync_quad_aspect_lock,
+            )
+                .after(super::update_inspected_emitter_tracker),
+        );
 }
 
 pub fn draw_pass_section(asset_server: &AssetServer) -> impl Bundle {
-    inspector_section(
-        InspectorSection::new(
-            "Draw pass",
-            vec![
-                vec![
-                    InspectorItem::Variant {
-                        path: "draw_pass.mesh".into(),
-                        props: VariantEditProps::new("draw_pass.mesh")
-                            .with_variants(mesh_variants()),
-                    },
-                    InspectorItem::Variant {
-                        path: "draw_pass.material".into(),
-                        props: VariantEditProps::new("draw_pass.material")
-                            .with_variants(material_variants()),
-                    },
-                ],
+    (
+        DrawPassSection,
+        inspector_section(
+            InspectorSection::new(
+                "Draw pass",
                 vec![
-                    InspectorFieldProps::new("draw_pass.draw_order")
-                        .combobox(combobox_options_from_reflect::<DrawOrder>())
-                        .into(),
+                    vec![
+                        InspectorItem::Variant {
+                            path: "draw_pass.mesh".into(),
+                            props: VariantEditProps::new("draw_pass.mesh")
+                                .with_variants(mesh_variants()),
+                        },
+                        InspectorItem::Variant {
+                            path: "draw_pass.material".into(),
+                            props: VariantEditProps::new("draw_pass.material")
+                                .with_variants(material_variants()),
+                        },
+                    ],
+                    vec![
+                        InspectorFieldProps::new("draw_pass.draw_order")
+                            .combobox(combobox_options_from_reflect::<DrawOrder>())
+                            .into(),
+                        InspectorFieldProps::new("draw_pass.sort_precision")
+                            .with_min(0.0)
+                            .into(),
+                    ],
+                    vec![
+                        InspectorFieldProps::new("draw_pass.transform_align")
+                            .optional_combobox(transform_align_options())
+                            .into(),
+                    ],
+                    vec![InspectorItem::Variant {
+                        path: "draw_pass.billboard_axis".into(),
+                        props: VariantEditProps::new("draw_pass.billboard_axis")
+                            .with_variants(billboard_axis_variants()),
+                    }],
+                    vec![
+                        InspectorFieldProps::new("draw_pass.draw_priority")
+                            .i32()
+                            .into(),
+                    ],
+                    vec![
+                        InspectorFieldProps::new("draw_pass.shadow_caster")
+                            .bool()
+                            .into(),
+                    ],
                 ],
-                vec![
-                    InspectorFieldProps::new("draw_pass.transform_align")
-                        .optional_combobox(transform_align_options())
-                        .into(),
-                ],
-                vec![
-                    InspectorFieldProps::new("draw_pass.shadow_caster")
-                        .bool()
-                        .into(),
-                ],
-            ],
+            )
+            .with_paste("draw_pass"),
+            asset_server,
         ),
-        asset_server,
     )
 }
 
+fn setup_glow_preset_button(
+    mut commands: Commands,
+    sections: Query<(Entity, &InspectorSection), With<DrawPassSection>>,
+    existing: Query<Entity, With<GlowPresetButton>>,
+) {
+    let Some(entity) = section_needs_setup(&sections, &existing) else {
+        return;
+    };
+
+    commands.entity(entity).with_children(|parent| {
+        parent.spawn(fields_row()).with_child((
+            GlowPresetButton,
+            button(ButtonProps::new("Apply glow preset")),
+        ));
+    });
+}
+
+fn handle_glow_preset_click(
+    trigger: On<ButtonClickEvent>,
+    buttons: Query<(), With<GlowPresetButton>>,
+    mut ew: EmitterWriter,
+    mut tracker: ResMut<InspectedEmitterTracker>,
+) {
+    if buttons.get(trigger.entity).is_err() {
+        return;
+    }
+
+    ew.modify_emitter(|emitter| {
+        // keep the current tint and sprite so the preset only changes how it's shaded
+        let (color, base_color_texture) = match &emitter.draw_pass.material {
+            DrawPassMaterial::Standard(mat) => (mat.base_color, mat.base_color_texture.clone()),
+            DrawPassMaterial::CustomShader { .. } => ([1.0, 1.0, 1.0, 1.0], None),
+        };
+        emitter.draw_pass.material = DrawPassMaterial::Standard(StandardParticleMaterial {
+            base_color_texture,
+            ..StandardParticleMaterial::glow(color, GLOW_PRESET_INTENSITY)
+        });
+        true
+    });
+
+    // refresh the bound material fields
+    tracker.set_changed();
+}
+
 fn transform_align_options() -> Vec<ComboBoxOptionData> {
     vec![
         ComboBoxOptionData::new("Disabled").with_value("Disabled"),
@@ -74,9 +169,29 @@ fn transform_align_options() -> Vec<ComboBoxOptionData> {
         ComboBoxOptionData::new("Billboard").with_value("Billboard"),
         ComboBoxOptionData::new("Billboard (Fixed Y)").with_value("BillboardFixedY"),
         ComboBoxOptionData::new("Billboard (Y to velocity)").with_value("BillboardYToVelocity"),
+        ComboBoxOptionData::new("Billboard (Screen aligned)").with_value("BillboardScreenAligned"),
     ]
 }
 
+fn billboard_axis_variants() -> Vec<VariantDefinition> {
+    variants_from_reflect::<BillboardAxis>(&[
+        (
+            "WorldUp",
+            VariantConfig::default().default_value(BillboardAxis::WorldUp),
+        ),
+        (
+            "EmitterUp",
+            VariantConfig::default().default_value(BillboardAxis::EmitterUp),
+        ),
+        (
+            "Custom",
+            VariantConfig::default()
+                .icon(ICON_EMPTY_AXIS)
+                .default_value(BillboardAxis::Custom { axis: Vec3::Y }),
+        ),
+    ])
+}
+
 fn mesh_variants() -> Vec<VariantDefinition> {
     variants_from_reflect::<ParticleMesh>(&[
         (
@@ -141,6 +256,13 @@ fn mesh_variants() -> Vec<VariantDefinition> {
     ])
 }
 
+fn roughness_field(name: &str) -> VariantField {
+    let (min, max) = PERCEPTUAL_ROUGHNESS_RANGE;
+    VariantField::f32(name)
+        .with_min(min as f64)
+        .with_max(max as f64)
+}
+
 fn material_variants() -> Vec<VariantDefinition> {
     variants_from_reflect::<DrawPassMaterial>(&[
         (
@@ -151,12 +273,14 @@ fn material_variants() -> Vec<VariantDefinition> {
                 .override_optional_combobox::<SerializableFace>("cull_mode")
                 .override_field(
                     "perceptual_roughness",
-                    VariantField::f32("perceptual_roughness")
-                        .with_min(0.089)
-                        .with_max(1.0),
+                    roughness_field("perceptual_roughness"),
                 )
                 .override_field("metallic", VariantField::percent("metallic"))
                 .override_field("reflectance", VariantField::percent("reflectance"))
+                .override_field(
+                    "clearcoat_perceptual_roughness",
+                    roughness_field("clearcoat_perceptual_roughness"),
+                )
                 .override_field(
                     "attenuation_distance",
                     VariantField::new("attenuation_distance").with_kind(FieldKind::F32OrInfinity),
@@ -337,3 +461,93 @@ fn sync_mask_cutoff(
         _ => {}
     }
 }
+
+fn is_quad_size(binding: &FieldBinding) -> bool {
+    binding.path() == "draw_pass.mesh" && binding.field_name() == Some("size")
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sync_quad_aspect_lock(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    editor_state: Res<EditorState>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    lock: Res<QuadAspectLock>,
+    new_bindings: Query<(), Added<FieldBinding>>,
+    existing: Query<Entity, With<AspectLockRow>>,
+    bindings: Query<(Entity, &FieldBinding, Has<LockedAspect>)>,
+    parents: Query<&ChildOf>,
+    children_query: Query<&Children>,
+    containers: Query<&VariantFieldsContainer>,
+) {
+    if !editor_state.is_changed()
+        && !assets.is_changed()
+        && !lock.is_changed()
+        && new_bindings.is_empty()
+    {
+        return;
+    }
+
+    let is_quad = get_inspecting_emitter(&editor_state, &assets)
+        .is_some_and(|(_, e)| matches!(e.draw_pass.mesh, ParticleMesh::Quad { .. }));
+    let size_binding = bindings
+        .iter()
+        .find(|(_, binding, _)| is_quad_size(binding))
+        .filter(|_| is_quad);
+
+    let Some((size_entity, _, is_locked)) = size_binding else {
+        for entity in &existing {
+            commands.entity(entity).try_despawn();
+        }
+        return;
+    };
+
+    if is_locked != lock.0 {
+        if lock.0 {
+            commands.entity(size_entity).try_insert(LockedAspect);
+        } else {
+            commands.entity(size_entity).try_remove::<LockedAspect>();
+        }
+    }
+
+    if !existing.is_empty() {
+        return;
+    }
+    let Some((container, _)) = find_ancestor(size_entity, &containers, &parents) else {
+        return;
+    };
+    let Some(row) = find_ancestor_child_of(size_entity, container, &parents) else {
+        return;
+    };
+    let Some(row_index) = children_query
+        .get(container)
+        .ok()
+        .and_then(|children| children.iter().position(|c| c == row))
+    else {
+        return;
+    };
+
+    let lock_row = commands
+        .spawn((AspectLockRow, fields_row()))
+        .with_child((
+            AspectLockCheckbox,
+            checkbox(
+                CheckboxProps::new("Lock aspect").checked(lock.0),
+                &asset_server,
+            ),
+        ))
+        .id();
+    commands
+        .entity(container)
+        .insert_children(row_index + 1, &[lock_row]);
+}
+
+fn handle_aspect_lock_checkbox(
+    trigger: On<CheckboxCommitEvent>,
+    checkboxes: Query<(), With<AspectLockCheckbox>>,
+    mut lock: ResMut<QuadAspectLock>,
+) {
+    if checkboxes.contains(trigger.entity) {
+        lock.0 = trigger.checked;
+    }
+}
@@ -8,7 +8,8 @@ use crate::ui::widgets::vector_edit::VectorSuffixes;
 use super::utils::{VariantConfig, variants_from_reflect};
 use super::{InspectorItem, InspectorSection, inspector_section};
 use crate::ui::icons::{
-    ICON_CUBE, ICON_EMPTY_AXIS, ICON_MESH_TORUS, ICON_MESH_UVSPHERE, ICON_SPHERE,
+    ICON_ARROW_LEFT_RIGHT, ICON_CUBE, ICON_EMPTY_AXIS, ICON_MESH_TORUS, ICON_MESH_UVSPHERE,
+    ICON_SPHERE, ICON_TIME,
 };
 
 pub fn plugin(_app: &mut App) {}
@@ -33,17 +34,49 @@ pub fn emission_section(asset_server: &AssetServer) -> impl Bundle {
                     props: VariantEditProps::new("emission.shape")
                         .with_variants(emission_shape_variants()),
                 }],
+                vec![
+                    InspectorFieldProps::new("emission.position_jitter")
+                        .vector(VectorSuffixes::XYZ)
+                        .into(),
+                ],
+                vec![InspectorFieldProps::new("emission.spawn_offset_along_velocity").into()],
+                vec![InspectorItem::Variant {
+                    path: "emission.mode".into(),
+                    props: VariantEditProps::new("emission.mode")
+                        .with_variants(emission_mode_variants()),
+                }],
                 vec![
                     InspectorFieldProps::new("emission.particles_amount")
                         .u32()
                         .into(),
+                    InspectorFieldProps::new("spawn_weight")
+                        .with_min(0.0)
+                        .into(),
                 ],
             ],
-        ),
+        )
+        .with_paste("emission"),
         asset_server,
     )
 }
 
+fn emission_mode_variants() -> Vec<VariantDefinition> {
+    variants_from_reflect::<EmissionMode>(&[
+        (
+            "Time",
+            VariantConfig::default()
+                .icon(ICON_TIME)
+                .default_value(EmissionMode::Time),
+        ),
+        (
+            "PerDistance",
+            VariantConfig::default()
+                .icon(ICON_ARROW_LEFT_RIGHT)
+                .default_value(EmissionMode::PerDistance { spacing: 1.0 }),
+        ),
+    ])
+}
+
 fn emission_shape_variants() -> Vec<VariantDefinition> {
     variants_from_reflect::<EmissionShape>(&[
         (
@@ -68,7 +101,10 @@ fn emission_shape_variants() -> Vec<VariantDefinition> {
             "Box",
             VariantConfig::default()
                 .icon(ICON_CUBE)
-                .default_value(EmissionShape::Box { extents: Vec3::ONE }),
+                .default_value(EmissionShape::Box {
+                    extents: Vec3::ONE,
+                    surface_only: false,
+                }),
         ),
         (
             "Ring",
@@ -78,12 +114,14 @@ fn emission_shape_variants() -> Vec<VariantDefinition> {
                     vec!["axis"],
                     vec!["height"],
                     vec!["radius", "inner_radius"],
+                    vec!["radial_bias"],
                 ])
                 .default_value(EmissionShape::Ring {
                     axis: Vec3::Y,
                     height: 0.0,
                     radius: 1.0,
                     inner_radius: 0.0,
+                    radial_bias: 0.0,
                 }),
         ),
     ])
@@ -6,6 +6,7 @@ mod colors;
 mod draw_pass;
 mod emission;
 mod particle_flags;
+mod paste;
 mod scale;
 mod sub_emitter;
 mod time;
@@ -27,18 +28,39 @@ use crate::ui::tokens::{
     BORDER_COLOR, FONT_PATH, TEXT_BODY_COLOR, TEXT_MUTED_COLOR, TEXT_SIZE_LG, TEXT_SIZE_SM,
 };
 use crate::ui::widgets::checkbox::{CheckboxProps, checkbox};
-use crate::ui::widgets::combobox::{ComboBoxOptionData, combobox_with_selected};
+use crate::ui::widgets::combobox::{
+    ComboBoxChangeEvent, ComboBoxOptionData, combobox_icon, combobox_with_selected,
+};
 use crate::ui::widgets::inspector_field::{InspectorFieldProps, fields_row, spawn_inspector_field};
 use crate::ui::widgets::panel::{PanelDirection, PanelProps, panel};
 use crate::ui::widgets::panel_section::{PanelSectionProps, PanelSectionSize, panel_section};
-use crate::ui::widgets::scroll::scrollbar;
+use crate::ui::widgets::scroll::{ScrollToEntity, scrollbar};
+use crate::ui::widgets::utils::is_descendant_of;
 use crate::ui::widgets::variant_edit::{VariantEditProps, variant_edit};
 
 use super::binding::FieldBinding;
 
+const EMITTER_SECTIONS: [&str; 13] = [
+    "Time",
+    "Transform",
+    "Draw pass",
+    "Emission",
+    "Scale",
+    "Colors",
+    "Velocities",
+    "Angle",
+    "Accelerations",
+    "Turbulence",
+    "Collision",
+    "Sub-emitter",
+    "Particle Flags",
+];
+
 pub fn plugin(app: &mut App) {
     app.init_resource::<InspectedEmitterTracker>()
         .init_resource::<InspectedColliderTracker>()
+        .init_resource::<JumpedSection>()
+        .add_observer(on_section_jump)
         .add_plugins((
             super::binding::plugin,
             time::plugin,
@@ -53,6 +75,7 @@ pub fn plugin(app: &mut App) {
             collision::plugin,
             sub_emitter::plugin,
             particle_flags::plugin,
+            paste::plugin,
             collider_properties::plugin,
         ))
         .add_systems(
@@ -68,6 +91,8 @@ pub fn plugin(app: &mut App) {
                     update_panel_title,
                     setup_inspector_section_fields,
                     toggle_inspector_content,
+                    toggle_section_jump_menu,
+                    scroll_to_jumped_section,
                 )
                     .after(update_inspected_emitter_tracker)
                     .after(update_inspected_collider_tracker),
@@ -140,6 +165,14 @@ struct PanelTitleIcon;
 #[derive(Component)]
 struct EnabledCheckbox;
 
+#[derive(Component)]
+struct SectionJumpMenu;
+
+/// The emitter section last picked from the jump menu, kept in view when switching
+/// between emitters.
+#[derive(Resource, Default)]
+pub struct JumpedSection(pub Option<String>);
+
 #[derive(Component)]
 pub(super) struct DynamicSectionContent;
 
@@ -298,6 +331,71 @@ fn toggle_inspector_content(
     }
 }
 
+fn toggle_section_jump_menu(
+    editor_state: Res<EditorState>,
+    mut menus: Query<&mut Node, With<SectionJumpMenu>>,
+) {
+    if !editor_state.is_changed() {
+        return;
+    }
+
+    let inspecting_emitter = editor_state
+        .inspecting
+        .as_ref()
+        .is_some_and(|i| i.kind == Inspectable::Emitter);
+    let display = if inspecting_emitter {
+        Display::Flex
+    } else {
+        Display::None
+    };
+
+    for mut node in &mut menus {
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
+
+fn on_section_jump(
+    event: On<ComboBoxChangeEvent>,
+    menus: Query<(), With<SectionJumpMenu>>,
+    mut jumped: ResMut<JumpedSection>,
+) {
+    if menus.contains(event.entity) {
+        jumped.0 = Some(event.label.clone());
+    }
+}
+
+fn scroll_to_jumped_section(
+    mut commands: Commands,
+    jumped: Res<JumpedSection>,
+    emitter_tracker: Res<InspectedEmitterTracker>,
+    panels: Query<Entity, With<EditorInspectorPanel>>,
+    emitter_content: Query<Entity, With<EmitterInspectorContent>>,
+    sections: Query<(Entity, &InspectorSection)>,
+    parents: Query<&ChildOf>,
+) {
+    if !jumped.is_changed() && !emitter_tracker.is_changed() {
+        return;
+    }
+    let Some(title) = &jumped.0 else {
+        return;
+    };
+    let (Ok(panel), Ok(content)) = (panels.single(), emitter_content.single()) else {
+        return;
+    };
+
+    let target = sections.iter().find(|(entity, section)| {
+        section.title == *title && is_descendant_of(*entity, content, &parents)
+    });
+    if let Some((target, _)) = target {
+        commands.trigger(ScrollToEntity {
+            entity: panel,
+            target,
+        });
+    }
+}
+
 fn panel_title(asset_server: &AssetServer) -> impl Bundle {
     let font: Handle<Font> = asset_server.load(FONT_PATH);
 
@@ -348,6 +446,7 @@ fn panel_title(asset_server: &AssetServer) -> impl Bundle {
                 FieldBinding::emitter("enabled", FieldKind::Bool),
                 checkbox(CheckboxProps::new("Enabled").checked(true), asset_server)
             ),
+            (SectionJumpMenu, combobox_icon(EMITTER_SECTIONS.to_vec())),
         ],
     )
 }
@@ -370,6 +469,7 @@ impl From<InspectorFieldProps> for InspectorItem {
 pub struct InspectorSection {
     pub title: String,
     pub rows: Vec<Vec<InspectorItem>>,
+    pub paste_path: Option<&'static str>,
     initialized: bool,
 }
 
@@ -378,9 +478,16 @@ impl InspectorSection {
         Self {
             title: title.into(),
             rows,
+            paste_path: None,
             initialized: false,
         }
     }
+
+    /// Lets a RON snippet from the clipboard replace the emitter field at `path`.
+    pub fn with_paste(mut self, path: &'static str) -> Self {
+        self.paste_path = Some(path);
+        self
+    }
 }
 
 pub(super) fn section_needs_setup<S: Component, C: Component>(
@@ -397,16 +504,13 @@ pub(super) fn section_needs_setup<S: Component, C: Component>(
 }
 
 pub fn inspector_section(section: InspectorSection, asset_server: &AssetServer) -> impl Bundle {
-    let title = section.title.clone();
-    (
-        section,
-        panel_section(
-            PanelSectionProps::new(title)
-                .collapsible()
-                .with_size(PanelSectionSize::XL),
-            asset_server,
-        ),
-    )
+    let mut props = PanelSectionProps::new(section.title.clone())
+        .collapsible()
+        .with_size(PanelSectionSize::XL);
+    if section.paste_path.is_some() {
+        props = props.with_paste_button();
+    }
+    (section, panel_section(props, asset_server))
 }
 
 fn setup_inspector_section_fields(
@@ -538,3 +642,68 @@ pub(super) fn spawn_labeled_combobox(
         });
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::ui::UiGlobalTransform;
+
+    use super::*;
+    use crate::ui::widgets::scroll::on_scroll_to_entity;
+
+    fn node(size: Vec2, content_size: Vec2) -> ComputedNode {
+        ComputedNode {
+            size,
+            content_size,
+            inverse_scale_factor: 1.0,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn test_jumping_to_collision_scrolls_the_panel() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<JumpedSection>()
+            .init_resource::<InspectedEmitterTracker>()
+            .add_observer(on_section_jump)
+            .add_observer(on_scroll_to_entity)
+            .add_systems(Update, scroll_to_jumped_section);
+
+        // a 400px tall panel whose content is 2000px tall, with sections stacked down it
+        let panel = app
+            .world_mut()
+            .spawn((
+                EditorInspectorPanel,
+                ScrollPosition::default(),
+                node(Vec2::new(300.0, 400.0), Vec2::new(300.0, 2000.0)),
+                UiGlobalTransform::from_xy(150.0, 200.0),
+            ))
+            .id();
+        let content = app
+            .world_mut()
+            .spawn((EmitterInspectorContent, ChildOf(panel)))
+            .id();
+        for (index, title) in EMITTER_SECTIONS.iter().enumerate() {
+            app.world_mut().spawn((
+                InspectorSection::new(*title, vec![]),
+                ChildOf(content),
+                node(Vec2::new(300.0, 150.0), Vec2::ZERO),
+                UiGlobalTransform::from_xy(150.0, 75.0 + index as f32 * 150.0),
+            ));
+        }
+        let menu = app.world_mut().spawn(SectionJumpMenu).id();
+        app.update();
+        assert_eq!(app.world().get::<ScrollPosition>(panel).unwrap().y, 0.0);
+
+        app.world_mut().trigger(ComboBoxChangeEvent {
+            entity: menu,
+            selected: 10,
+            label: "Collision".into(),
+            value: None,
+        });
+        app.update();
+
+        // the "Collision" section's top edge now sits at the panel's top edge
+        assert_eq!(app.world().get::<ScrollPosition>(panel).unwrap().y, 1500.0);
+    }
+}
@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+use bevy_sprinkles::asset::EmitterAngle;
+use bevy_sprinkles::prelude::*;
+use bevy_ui_text_input::clipboard::{Clipboard, ClipboardError};
+use serde::de::DeserializeOwned;
+
+use crate::ui::components::binding::EmitterWriter;
+use crate::ui::components::toasts::ToastEvent;
+use crate::ui::widgets::panel_section::PanelSectionPasteEvent;
+use crate::viewport::RespawnEmittersEvent;
+
+use super::{InspectedEmitterTracker, InspectorSection};
+
+/// Sections holding fields that only take effect once the emitter is respawned.
+const RESPAWN_SECTION_PATHS: &[&str] = &["draw_pass", "emission"];
+
+pub fn plugin(app: &mut App) {
+    app.add_observer(handle_section_paste);
+}
+
+fn parse_fragment<T: DeserializeOwned + Reflect>(ron: &str) -> Result<Box<dyn Reflect>, String> {
+    ron::de::from_str::<T>(ron)
+        .map(|value| Box::new(value) as Box<dyn Reflect>)
+        .map_err(|e| e.to_string())
+}
+
+fn parse_section_fragment(path: &str, ron: &str) -> Result<Box<dyn Reflect>, String> {
+    match path {
+        "time" => parse_fragment::<EmitterTime>(ron),
+        "draw_pass" => parse_fragment::<EmitterDrawPass>(ron),
+        "emission" => parse_fragment::<EmitterEmission>(ron),
+        "scale" => parse_fragment::<EmitterScale>(ron),
+        "angle" => parse_fragment::<EmitterAngle>(ron),
+        "colors" => parse_fragment::<EmitterColors>(ron),
+        "velocities" => parse_fragment::<EmitterVelocities>(ron),
+        "accelerations" => parse_fragment::<EmitterAccelerations>(ron),
+        "turbulence" => parse_fragment::<EmitterTurbulence>(ron),
+        "collision" => parse_fragment::<EmitterCollision>(ron),
+        _ => Err(format!("\"{path}\" can't be pasted into")),
+    }
+}
+
+fn read_clipboard(clipboard: &mut Clipboard) -> Result<String, String> {
+    clipboard
+        .fetch_text()
+        .poll_result()
+        .unwrap_or(Err(ClipboardError::ContentNotAvailable))
+        .map_err(|e| format!("{e:?}"))
+}
+
+/// Parses `ron` as the section at `path` and writes it over that section of the
+/// inspected emitter, leaving the rest of the emitter untouched.
+fn paste_section(ew: &mut EmitterWriter, path: &str, ron: &str) -> Result<(), String> {
+    let value = parse_section_fragment(path, ron.trim())?;
+    let mut applied = false;
+    ew.modify_emitter(|emitter| {
+        let Some(target) = emitter
+            .reflect_path_mut(path)
+            .ok()
+            .and_then(|field| field.try_as_reflect_mut())
+        else {
+            return false;
+        };
+        applied = target.set(value).is_ok();
+        applied
+    });
+    if applied {
+        Ok(())
+    } else {
+        Err("the snippet doesn't match this section".into())
+    }
+}
+
+fn handle_section_paste(
+    trigger: On<PanelSectionPasteEvent>,
+    mut commands: Commands,
+    sections: Query<&InspectorSection>,
+    mut ew: EmitterWriter,
+    mut tracker: ResMut<InspectedEmitterTracker>,
+    mut clipboard: ResMut<Clipboard>,
+) {
+    let Some(path) = sections.get(trigger.entity).ok().and_then(|s| s.paste_path) else {
+        return;
+    };
+
+    if let Err(e) =
+        read_clipboard(&mut clipboard).and_then(|ron| paste_section(&mut ew, path, &ron))
+    {
+        commands.trigger(ToastEvent::error(format!(
+            "Couldn't paste into \"{path}\": {e}"
+        )));
+        return;
+    }
+
+    if RESPAWN_SECTION_PATHS.contains(&path) {
+        commands.trigger(RespawnEmittersEvent);
+    }
+    // refresh the bound fields
+    tracker.set_changed();
+    commands.trigger(ToastEvent::success(format!(
+        "Pasted \"{path}\" from clipboard"
+    )));
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::state::{DirtyState, Inspectable};
+    use crate::test_utils::{inspect, open_project, project, test_app};
+
+    fn paste(app: &mut App, path: &'static str, ron: &'static str) -> Result<(), String> {
+        app.world_mut()
+            .run_system_once(move |mut ew: EmitterWriter| paste_section(&mut ew, path, ron))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_pasted_section_marks_dirty_and_leaves_other_fields() {
+        let mut app = test_app();
+        let mut emitter = EmitterData {
+            name: "Sparks".into(),
+            ..Default::default()
+        };
+        emitter.emission.particles_amount = 42;
+        let handle = open_project(&mut app, vec![emitter, EmitterData::default()], vec![]);
+        inspect(&mut app, Inspectable::Emitter, 0);
+
+        assert_eq!(
+            paste(&mut app, "time", "(lifetime: 2.5, delay: 0.5)"),
+            Ok(())
+        );
+
+        assert!(app.world().resource::<DirtyState>().has_unsaved_changes);
+        let asset = project(&app, &handle);
+        let pasted = &asset.emitters[0];
+        assert_eq!(pasted.time.lifetime, 2.5);
+        assert_eq!(pasted.time.delay, 0.5);
+        assert_eq!(pasted.name, "Sparks");
+        assert_eq!(pasted.emission.particles_amount, 42);
+        assert_eq!(asset.emitters[1].time.lifetime, 1.0);
+    }
+
+    #[test]
+    fn test_invalid_snippet_leaves_asset_clean() {
+        let mut app = test_app();
+        let handle = open_project(&mut app, vec![EmitterData::default()], vec![]);
+        inspect(&mut app, Inspectable::Emitter, 0);
+
+        assert!(paste(&mut app, "time", "(lifetime: \"long\")").is_err());
+        assert!(paste(&mut app, "name", "\"Sparks\"").is_err());
+
+        assert!(!app.world().resource::<DirtyState>().has_unsaved_changes);
+        assert_eq!(project(&app, &handle).emitters[0].time.lifetime, 1.0);
+    }
+}
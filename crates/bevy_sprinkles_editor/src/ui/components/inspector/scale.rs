@@ -18,13 +18,21 @@ pub fn scale_section(asset_server: &AssetServer) -> impl Bundle {
                         .with_label("Initial scale ratio")
                         .into(),
                 ],
+                vec![
+                    InspectorFieldProps::new("scale.scale_in_time")
+                        .with_min(0.0)
+                        .with_max(1.0)
+                        .into(),
+                ],
                 vec![
                     InspectorFieldProps::new("scale.scale_over_lifetime")
                         .curve()
                         .into(),
                 ],
+                vec![InspectorFieldProps::new("inherit_scale").bool().into()],
             ],
-        ),
+        )
+        .with_paste("scale"),
         asset_server,
     )
 }
@@ -227,6 +227,16 @@ fn spawn_fields(
         ));
     });
 
+    parent.spawn(fields_row()).with_children(|row| {
+        row.spawn((
+            FieldBinding::emitter_variant_field("sub_emitter", "keep_local", FieldKind::Bool),
+            checkbox(
+                CheckboxProps::new("Keep local").checked(config.keep_local),
+                asset_server,
+            ),
+        ));
+    });
+
     let target_amount = asset
         .emitters
         .get(config.target_emitter)
@@ -282,6 +292,7 @@ fn handle_sub_emitter_mode_change(
                     frequency: prev.frequency,
                     amount: prev.amount,
                     keep_velocity: prev.keep_velocity,
+                    keep_local: prev.keep_local,
                 })
             }
         };
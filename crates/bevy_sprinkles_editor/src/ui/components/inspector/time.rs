@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::ui::icons::{ICON_SEEDLING, ICON_TIME};
+use crate::ui::icons::{ICON_REPEAT, ICON_SEEDLING, ICON_TIME};
 use crate::ui::widgets::inspector_field::InspectorFieldProps;
 
 use super::{InspectorSection, inspector_section};
@@ -28,6 +28,11 @@ pub fn time_section(asset_server: &AssetServer) -> impl Bundle {
                         .with_icon(ICON_TIME)
                         .with_suffix("s")
                         .into(),
+                    InspectorFieldProps::new("time.delay_randomness")
+                        .with_min(0.)
+                        .with_icon(ICON_TIME)
+                        .with_suffix("s")
+                        .into(),
                 ],
                 vec![
                     InspectorFieldProps::new("time.explosiveness")
@@ -48,9 +53,23 @@ pub fn time_section(asset_server: &AssetServer) -> impl Bundle {
                         .with_placeholder("Random")
                         .into(),
                 ],
-                vec![InspectorFieldProps::new("time.one_shot").bool().into()],
+                vec![
+                    InspectorFieldProps::new("time.one_shot").bool().into(),
+                    InspectorFieldProps::new("time.wait_for_trigger")
+                        .bool()
+                        .into(),
+                ],
+                vec![InspectorFieldProps::new("freeze_on_complete").bool().into()],
+                vec![
+                    InspectorFieldProps::new("sync_group")
+                        .optional_u32()
+                        .with_icon(ICON_REPEAT)
+                        .with_placeholder("None")
+                        .into(),
+                ],
             ],
-        ),
+        )
+        .with_paste("time"),
         asset_server,
     )
 }
@@ -30,7 +30,8 @@ pub fn turbulence_section(asset_server: &AssetServer) -> impl Bundle {
                 vec![vec![
                     InspectorFieldProps::new("turbulence.enabled").bool().into(),
                 ]],
-            ),
+            )
+            .with_paste("turbulence"),
             asset_server,
         ),
     )
@@ -23,6 +23,7 @@ pub enum FieldKind {
     F32OrInfinity,
     U32,
     U32OrEmpty,
+    I32,
     OptionalU32,
     Bool,
     Vector(VectorSuffixes),
@@ -91,14 +91,27 @@ pub fn velocities_section(asset_server: &AssetServer) -> impl Bundle {
                 ],
                 vec![
                     InspectorFieldProps::new("velocities.inherit_ratio").into(),
-                    InspectorFieldProps::new("velocities.spread").into(),
+                    InspectorFieldProps::new("velocities.spread").angle().into(),
                     InspectorFieldProps::new("velocities.flatness").into(),
                 ],
+                vec![
+                    InspectorFieldProps::new("velocities.inherit_velocity_max")
+                        .with_min(0.0)
+                        .into(),
+                ],
+                vec![
+                    InspectorFieldProps::new("velocities.emit_along_normal")
+                        .with_min(0.0)
+                        .with_max(1.0)
+                        .into(),
+                ],
             ],
-        ),
+        )
+        .with_paste("velocities"),
         panel_section(
             PanelSectionProps::new("Velocities")
                 .with_add_button()
+                .with_paste_button()
                 .collapsible()
                 .with_size(PanelSectionSize::XL),
             asset_server,
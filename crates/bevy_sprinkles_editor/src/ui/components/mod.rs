@@ -5,7 +5,11 @@ pub mod inspector;
 pub mod navigation;
 pub mod playback_controls;
 pub mod project_selector;
+pub mod ron_preview;
 pub mod seekbar;
+pub mod thumbnails;
+pub mod timeline;
 pub mod toasts;
 pub mod topbar;
+pub mod validation;
 pub mod viewport;
@@ -1,10 +1,13 @@
 use bevy::color::palettes::tailwind;
 use bevy::prelude::*;
+use bevy::text::{FontFeatureTag, FontFeatures};
 use bevy_sprinkles::prelude::*;
 
 use crate::state::{PlaybackPlayEvent, PlaybackResetEvent};
-use crate::ui::icons::{ICON_PAUSE, ICON_PLAY, ICON_REPEAT, ICON_STOP};
-use crate::ui::tokens::{PRIMARY_COLOR, TEXT_BODY_COLOR};
+use crate::ui::icons::{
+    ICON_ARROW_DOWN, ICON_PAUSE, ICON_PLAY, ICON_REPEAT, ICON_SEEDLING, ICON_STOP,
+};
+use crate::ui::tokens::{FONT_PATH, PRIMARY_COLOR, TEXT_BODY_COLOR, TEXT_MUTED_COLOR};
 use crate::ui::widgets::button::{
     ButtonSize, ButtonVariant, IconButtonProps, icon_button, set_button_variant,
 };
@@ -17,8 +20,11 @@ pub fn plugin(app: &mut App) {
             handle_play_pause_click,
             handle_stop_click,
             handle_loop_click,
+            handle_reseed_click,
+            handle_cycle_step_click,
             update_play_pause_icon,
             update_loop_button_style,
+            update_cycle_display,
         ),
     );
 }
@@ -35,6 +41,21 @@ pub struct StopButton;
 #[derive(Component)]
 pub struct LoopButton;
 
+#[derive(Component)]
+pub struct ReseedButton;
+
+#[derive(Component)]
+pub struct CycleControls;
+
+#[derive(Component)]
+pub struct CycleLabel;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum CycleStepButton {
+    Previous,
+    Next,
+}
+
 pub fn playback_controls(asset_server: &AssetServer) -> impl Bundle {
     (
         EditorPlaybackControls,
@@ -47,6 +68,8 @@ pub fn playback_controls(asset_server: &AssetServer) -> impl Bundle {
             play_pause_button(asset_server),
             stop_button(asset_server),
             loop_button(asset_server),
+            reseed_button(asset_server),
+            cycle_controls(asset_server),
         ],
     )
 }
@@ -90,6 +113,72 @@ fn loop_button(asset_server: &AssetServer) -> impl Bundle {
     )
 }
 
+fn reseed_button(asset_server: &AssetServer) -> impl Bundle {
+    (
+        ReseedButton,
+        icon_button(
+            IconButtonProps::new(ICON_SEEDLING)
+                .color(TEXT_BODY_COLOR)
+                .variant(ButtonVariant::Ghost)
+                .with_size(ButtonSize::Icon),
+            asset_server,
+        ),
+    )
+}
+
+fn cycle_controls(asset_server: &AssetServer) -> impl Bundle {
+    let font: Handle<Font> = asset_server.load(FONT_PATH);
+    let tabular_figures: FontFeatures = [FontFeatureTag::TABULAR_FIGURES].into();
+
+    (
+        CycleControls,
+        Node {
+            align_items: AlignItems::Center,
+            column_gap: px(2),
+            display: Display::None,
+            ..default()
+        },
+        children![
+            cycle_step_button(CycleStepButton::Previous, asset_server),
+            (
+                CycleLabel,
+                Text::new("Cycle 1"),
+                TextFont {
+                    font,
+                    font_size: 12.0,
+                    font_features: tabular_figures,
+                    weight: FontWeight::MEDIUM,
+                    ..default()
+                },
+                TextColor(TEXT_MUTED_COLOR.into()),
+            ),
+            cycle_step_button(CycleStepButton::Next, asset_server),
+        ],
+    )
+}
+
+// the arrow icon points down, so it's turned sideways for each direction
+fn cycle_step_button(step: CycleStepButton, asset_server: &AssetServer) -> impl Bundle {
+    let degrees = match step {
+        CycleStepButton::Previous => 90.0,
+        CycleStepButton::Next => -90.0,
+    };
+    (
+        step,
+        UiTransform {
+            rotation: Rot2::degrees(degrees),
+            ..default()
+        },
+        icon_button(
+            IconButtonProps::new(ICON_ARROW_DOWN)
+                .color(TEXT_BODY_COLOR)
+                .variant(ButtonVariant::Ghost)
+                .with_size(ButtonSize::Icon),
+            asset_server,
+        ),
+    )
+}
+
 fn handle_play_pause_click(
     mut commands: Commands,
     mut runtime_query: Query<&mut ParticleSystemRuntime, With<EditorParticlePreview>>,
@@ -131,6 +220,101 @@ fn handle_loop_click(
     }
 }
 
+// reseeds the preview only: emitters restart from the new global seed, ignoring their
+// `fixed_seed` until the next regular restart, and the asset is left untouched
+fn handle_reseed_click(
+    mut system_query: Query<(Entity, &mut ParticleSystemRuntime), With<EditorParticlePreview>>,
+    mut emitter_query: Query<(&EmitterEntity, &mut EmitterRuntime)>,
+    button_query: Query<&Interaction, (Changed<Interaction>, With<ReseedButton>)>,
+) {
+    if !button_query.iter().any(|i| *i == Interaction::Pressed) {
+        return;
+    }
+
+    for (system_entity, mut system_runtime) in &mut system_query {
+        system_runtime.reseed();
+        system_runtime.resume();
+        for (emitter, mut runtime) in &mut emitter_query {
+            if emitter.parent_system == system_entity {
+                let seed = system_runtime.emitter_seed(runtime.emitter_index);
+                runtime.restart(Some(seed));
+            }
+        }
+    }
+}
+
+fn handle_cycle_step_click(
+    assets: Res<Assets<ParticleSystemAsset>>,
+    system_query: Query<(Entity, &ParticleSystem3D), With<EditorParticlePreview>>,
+    mut emitter_query: Query<(&EmitterEntity, &mut EmitterRuntime)>,
+    button_query: Query<(&Interaction, &CycleStepButton), Changed<Interaction>>,
+) {
+    let Some(step) = button_query
+        .iter()
+        .find(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, step)| *step)
+    else {
+        return;
+    };
+
+    for (system_entity, particle_system) in &system_query {
+        let Some(asset) = assets.get(&particle_system.handle) else {
+            continue;
+        };
+        for (emitter, mut runtime) in &mut emitter_query {
+            if emitter.parent_system != system_entity {
+                continue;
+            }
+            let Some(emitter_data) = asset.emitters.get(runtime.emitter_index) else {
+                continue;
+            };
+            match step {
+                CycleStepButton::Previous => runtime.seek_prev_cycle(&emitter_data.time),
+                CycleStepButton::Next => runtime.seek_next_cycle(),
+            }
+        }
+    }
+}
+
+fn update_cycle_display(
+    assets: Res<Assets<ParticleSystemAsset>>,
+    system_query: Query<(Entity, &ParticleSystem3D), With<EditorParticlePreview>>,
+    emitter_query: Query<(&EmitterEntity, &EmitterRuntime)>,
+    mut controls_query: Query<&mut Node, With<CycleControls>>,
+    mut label_query: Query<&mut Text, With<CycleLabel>>,
+) {
+    let Some((system_entity, particle_system)) = system_query.iter().next() else {
+        return;
+    };
+    let Some(asset) = assets.get(&particle_system.handle) else {
+        return;
+    };
+
+    let display = if asset.is_looping() {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    for mut node in &mut controls_query {
+        if node.display != display {
+            node.display = display;
+        }
+    }
+
+    let cycle = emitter_query
+        .iter()
+        .filter(|(e, _)| e.parent_system == system_entity)
+        .map(|(_, r)| r.cycle)
+        .max()
+        .unwrap_or(0);
+    let label = format!("Cycle {}", cycle + 1);
+    for mut text in &mut label_query {
+        if **text != label {
+            **text = label.clone();
+        }
+    }
+}
+
 fn update_play_pause_icon(
     asset_server: Res<AssetServer>,
     runtime_query: Query<
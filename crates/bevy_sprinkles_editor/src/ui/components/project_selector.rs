@@ -13,11 +13,11 @@ use bevy_ui_text_input::{
 use bevy_sprinkles::prelude::*;
 
 use crate::io::{
-    EditorData, data_dir, project_path, projects_dir, save_editor_data, simplify_path,
+    EditorData, ThemeKind, data_dir, project_path, projects_dir, save_editor_data, simplify_path,
 };
 use crate::project::{
-    BrowseOpenProjectEvent, OpenProjectEvent, SaveResult, load_project_from_path,
-    save_project_to_path,
+    BrowseOpenProjectEvent, OpenProjectEvent, ProjectChange, SaveResult, defer_if_unsaved,
+    load_project_from_path, new_project_asset, save_project_to_path,
 };
 use crate::state::{DirtyState, EditorState, Inspectable, Inspecting};
 use crate::ui::icons::{
@@ -39,7 +39,9 @@ use crate::ui::widgets::utils::is_descendant_of;
 pub fn plugin(app: &mut App) {
     app.add_observer(handle_trigger_click)
         .add_observer(handle_new_project_click)
+        .add_observer(on_new_project_event)
         .add_observer(handle_open_project_click)
+        .add_observer(handle_theme_toggle_click)
         .add_observer(handle_recent_project_click)
         .add_observer(handle_remove_recent_project_click)
         .add_observer(handle_popover_option_click)
@@ -61,6 +63,10 @@ pub fn plugin(app: &mut App) {
         );
 }
 
+/// Opens the new project dialog, asking to save unsaved changes first.
+#[derive(Event)]
+pub struct NewProjectEvent;
+
 #[derive(Component)]
 pub struct ProjectSelector;
 
@@ -82,6 +88,9 @@ struct NewProjectButton;
 #[derive(Component)]
 struct OpenProjectButton;
 
+#[derive(Component)]
+struct ThemeToggleButton;
+
 #[derive(Component)]
 struct RecentProjectButton(String);
 
@@ -258,6 +267,17 @@ fn handle_trigger_click(
                     .with_left_icon(ICON_FOLDER_IMAGE),
             ),
         ))
+        .with_child((
+            ThemeToggleButton,
+            button(
+                ButtonProps::new(match editor_data.settings.theme {
+                    ThemeKind::Dark => "Use high contrast theme",
+                    ThemeKind::HighContrast => "Use default theme",
+                })
+                .with_variant(ButtonVariant::Ghost)
+                .align_left(),
+            ),
+        ))
         .id();
 
     commands.entity(popover_entity).add_child(actions_wrapper);
@@ -368,6 +388,17 @@ fn handle_new_project_click(
     if buttons.get(trigger.entity).is_err() {
         return;
     }
+    commands.trigger(NewProjectEvent);
+}
+
+fn on_new_project_event(
+    _event: On<NewProjectEvent>,
+    dirty_state: Res<DirtyState>,
+    mut commands: Commands,
+) {
+    if defer_if_unsaved(&dirty_state, ProjectChange::New, &mut commands) {
+        return;
+    }
 
     let (default_name, default_slug) = next_untitled_name();
     commands.insert_resource(NewProjectDialogState {
@@ -392,6 +423,21 @@ fn handle_open_project_click(
     commands.trigger(BrowseOpenProjectEvent);
 }
 
+fn handle_theme_toggle_click(
+    trigger: On<ButtonClickEvent>,
+    buttons: Query<(), With<ThemeToggleButton>>,
+    mut editor_data: ResMut<EditorData>,
+) {
+    if !buttons.contains(trigger.entity) {
+        return;
+    }
+    editor_data.settings.theme = match editor_data.settings.theme {
+        ThemeKind::Dark => ThemeKind::HighContrast,
+        ThemeKind::HighContrast => ThemeKind::Dark,
+    };
+    save_editor_data(&editor_data);
+}
+
 fn handle_recent_project_click(
     trigger: On<ButtonClickEvent>,
     buttons: Query<&RecentProjectButton>,
@@ -800,16 +846,8 @@ fn handle_create_project(
         let _ = std::fs::create_dir_all(parent);
     }
 
-    let asset = bevy_sprinkles::asset::ParticleSystemAsset::new(
-        name,
-        bevy_sprinkles::asset::ParticleSystemDimension::D3,
-        vec![bevy_sprinkles::asset::EmitterData {
-            name: "Emitter 1".to_string(),
-            ..Default::default()
-        }],
-        vec![],
-        None,
-    );
+    let template = editor_data.settings.new_project_template.as_deref();
+    let asset = new_project_asset(name, template);
 
     let result = Arc::new(Mutex::new(None));
     save_project_to_path(path.clone(), &asset, result.clone());
@@ -818,6 +856,7 @@ fn handle_create_project(
     let handle = assets.add(asset);
     editor_state.current_project = Some(handle);
     editor_state.current_project_path = Some(path.clone());
+    editor_state.muted_colliders.clear();
     editor_state.inspecting = Some(Inspecting {
         kind: Inspectable::Emitter,
         index: 0,
@@ -0,0 +1,256 @@
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use bevy::color::palettes::tailwind;
+use bevy::picking::hover::Hovered;
+use bevy::prelude::*;
+use bevy_sprinkles::prelude::*;
+use bevy_ui_text_input::clipboard::Clipboard;
+
+use crate::state::EditorState;
+use crate::ui::components::toasts::ToastEvent;
+use crate::ui::tokens::{FONT_PATH, TEXT_BODY_COLOR, TEXT_MUTED_COLOR, TEXT_SIZE};
+use crate::ui::widgets::dialog::{
+    DialogActionEvent, DialogChildrenSlot, EditorDialog, OpenDialogEvent,
+};
+use crate::ui::widgets::scroll::scrollbar;
+
+pub fn plugin(app: &mut App) {
+    app.add_observer(on_open_ron_preview)
+        .add_observer(handle_copy_ron)
+        .add_systems(
+            Update,
+            (setup_ron_preview_content, cleanup_ron_preview_state),
+        );
+}
+
+/// Shows the RON of the emitter at the given index in a read-only dialog.
+#[derive(Event)]
+pub struct OpenRonPreviewEvent(pub usize);
+
+#[derive(Resource)]
+struct RonPreviewState {
+    name: String,
+    ron: String,
+    dialog_entity: Option<Entity>,
+    populated: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum RonToken {
+    Whitespace,
+    Field,
+    Identifier,
+    String,
+    Number,
+    Punctuation,
+}
+
+impl RonToken {
+    fn color(self) -> Srgba {
+        match self {
+            Self::Whitespace => TEXT_BODY_COLOR,
+            Self::Field => tailwind::SKY_300,
+            Self::Identifier => tailwind::VIOLET_300,
+            Self::String => tailwind::EMERALD_300,
+            Self::Number => tailwind::AMBER_300,
+            Self::Punctuation => TEXT_MUTED_COLOR,
+        }
+    }
+}
+
+// advances past the chars matching `f`, moving `end` to the end of the last one taken
+fn take_while(chars: &mut Peekable<CharIndices>, end: &mut usize, f: impl Fn(char) -> bool) {
+    while let Some(&(i, c)) = chars.peek() {
+        if !f(c) {
+            break;
+        }
+        *end = i + c.len_utf8();
+        chars.next();
+    }
+}
+
+// splits RON into runs to color, just enough to tell fields, values and structure apart
+fn tokenize_ron(ron: &str) -> Vec<(RonToken, &str)> {
+    let mut tokens = Vec::new();
+    let mut chars = ron.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let mut end = start + c.len_utf8();
+
+        let token = if c == '"' {
+            let mut escaped = false;
+            for (i, next) in chars.by_ref() {
+                end = i + next.len_utf8();
+                if next == '"' && !escaped {
+                    break;
+                }
+                escaped = next == '\\' && !escaped;
+            }
+            RonToken::String
+        } else if c.is_ascii_digit() || c == '-' {
+            take_while(&mut chars, &mut end, |c| {
+                c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+' | '_')
+            });
+            RonToken::Number
+        } else if c.is_alphabetic() || c == '_' {
+            take_while(&mut chars, &mut end, |c| c.is_alphanumeric() || c == '_');
+            if ron[end..].trim_start_matches([' ', '\t']).starts_with(':') {
+                RonToken::Field
+            } else {
+                RonToken::Identifier
+            }
+        } else if c.is_whitespace() {
+            take_while(&mut chars, &mut end, char::is_whitespace);
+            RonToken::Whitespace
+        } else {
+            RonToken::Punctuation
+        };
+
+        tokens.push((token, &ron[start..end]));
+    }
+
+    tokens
+}
+
+fn on_open_ron_preview(
+    event: On<OpenRonPreviewEvent>,
+    editor_state: Res<EditorState>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    mut commands: Commands,
+) {
+    let Some(emitter) = editor_state
+        .current_project
+        .as_ref()
+        .and_then(|handle| assets.get(handle))
+        .and_then(|asset| asset.emitters.get(event.0))
+    else {
+        return;
+    };
+    let Ok(ron) = emitter.to_ron() else {
+        commands.trigger(ToastEvent::error(
+            "Cannot preview emitter with invalid data",
+        ));
+        return;
+    };
+
+    commands.insert_resource(RonPreviewState {
+        name: emitter.name.clone(),
+        ron,
+        dialog_entity: None,
+        populated: false,
+    });
+
+    commands.trigger(
+        OpenDialogEvent::new(emitter.name.clone(), "Copy to clipboard")
+            .without_content_padding()
+            .with_max_width(px(600)),
+    );
+}
+
+fn setup_ron_preview_content(
+    state: Option<ResMut<RonPreviewState>>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    slots: Query<Entity, With<DialogChildrenSlot>>,
+    dialogs: Query<Entity, With<EditorDialog>>,
+) {
+    let Some(mut state) = state else { return };
+    if state.populated {
+        return;
+    }
+    let Ok(slot_entity) = slots.single() else {
+        return;
+    };
+
+    state.populated = true;
+    state.dialog_entity = dialogs.single().ok();
+
+    let font: Handle<Font> = asset_server.load(FONT_PATH);
+
+    let scroll_container = commands
+        .spawn((
+            Hovered::default(),
+            Node {
+                width: percent(100),
+                max_height: px(384),
+                overflow: Overflow::scroll_y(),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+        ))
+        .id();
+
+    commands
+        .entity(scroll_container)
+        .with_child(scrollbar(scroll_container));
+
+    let text = commands
+        .spawn((
+            Text::default(),
+            TextFont {
+                font: font.clone(),
+                font_size: TEXT_SIZE,
+                ..default()
+            },
+            TextColor(TEXT_BODY_COLOR.into()),
+            Node {
+                padding: UiRect::all(px(24)),
+                ..default()
+            },
+        ))
+        .id();
+
+    for (token, run) in tokenize_ron(&state.ron) {
+        commands.entity(text).with_child((
+            TextSpan::new(run),
+            TextFont {
+                font: font.clone(),
+                font_size: TEXT_SIZE,
+                ..default()
+            },
+            TextColor(token.color().into()),
+        ));
+    }
+
+    commands.entity(scroll_container).add_child(text);
+    commands.entity(slot_entity).add_child(scroll_container);
+}
+
+fn handle_copy_ron(
+    event: On<DialogActionEvent>,
+    state: Option<Res<RonPreviewState>>,
+    mut clipboard: ResMut<Clipboard>,
+    mut commands: Commands,
+) {
+    let Some(state) = state else { return };
+    if state.dialog_entity != Some(event.entity) {
+        return;
+    }
+
+    match clipboard.set_text(state.ron.clone()) {
+        Ok(()) => {
+            commands.trigger(ToastEvent::success(format!(
+                "Copied {} to clipboard",
+                state.name
+            )));
+        }
+        Err(e) => {
+            commands.trigger(ToastEvent::error(format!(
+                "Couldn't copy {} to clipboard: {e:?}",
+                state.name
+            )));
+        }
+    }
+    commands.remove_resource::<RonPreviewState>();
+}
+
+fn cleanup_ron_preview_state(
+    state: Option<Res<RonPreviewState>>,
+    dialogs: Query<(), With<EditorDialog>>,
+    mut commands: Commands,
+) {
+    if state.is_some() && dialogs.is_empty() {
+        commands.remove_resource::<RonPreviewState>();
+    }
+}
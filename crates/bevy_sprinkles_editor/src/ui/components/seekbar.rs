@@ -4,16 +4,27 @@ use bevy::text::{FontFeatureTag, FontFeatures};
 use bevy_sprinkles::prelude::*;
 
 use crate::state::PlaybackSeekEvent;
+use crate::ui::icons::ICON_REPEAT;
 use crate::ui::tokens::{FONT_PATH, TEXT_MUTED_COLOR};
 use crate::viewport::EditorParticlePreview;
 
 const SEEKBAR_HEIGHT: f32 = 4.0;
 const SEEKBAR_WIDTH: f32 = 192.0;
 const LABEL_SIZE: f32 = 12.0;
+const LOOP_ICON_SIZE: f32 = 12.0;
+const BURST_MARKER_WIDTH: f32 = 2.0;
+const BURST_MARKER_HEIGHT: f32 = 10.0;
 
 pub fn plugin(app: &mut App) {
-    app.add_systems(Update, (update_seekbar, setup_seekbar_observers))
-        .add_observer(on_seekbar_drag);
+    app.add_systems(
+        Update,
+        (
+            update_seekbar,
+            update_burst_markers,
+            setup_seekbar_observers,
+        ),
+    )
+    .add_observer(on_seekbar_drag);
 }
 
 #[derive(Component)]
@@ -25,6 +36,9 @@ pub struct SeekbarElapsed;
 #[derive(Component)]
 pub struct SeekbarDuration;
 
+#[derive(Component)]
+pub struct SeekbarLoopIcon;
+
 #[derive(Component)]
 pub struct SeekbarHitbox;
 
@@ -34,6 +48,11 @@ pub struct SeekbarTrack;
 #[derive(Component)]
 pub struct SeekbarFill;
 
+/// Holds tick marks at the points of the playback where emitters spawn bursts, as
+/// fractions of the playback duration.
+#[derive(Component, Default)]
+pub struct SeekbarBurstMarkers(Vec<f32>);
+
 #[derive(Component, Default)]
 pub struct SeekbarDragState {
     pub dragging: bool,
@@ -98,6 +117,16 @@ pub fn seekbar(asset_server: &AssetServer) -> impl Bundle {
                             BackgroundColor(tailwind::ZINC_200.into()),
                         )],
                     ),
+                    (
+                        SeekbarBurstMarkers::default(),
+                        Pickable::IGNORE,
+                        Node {
+                            position_type: PositionType::Absolute,
+                            width: percent(100),
+                            height: percent(100),
+                            ..default()
+                        },
+                    ),
                     (
                         SeekbarHitbox,
                         SeekbarDragState::default(),
@@ -125,6 +154,17 @@ pub fn seekbar(asset_server: &AssetServer) -> impl Bundle {
                 },
                 TextColor(TEXT_MUTED_COLOR.into()),
             ),
+            (
+                SeekbarLoopIcon,
+                ImageNode::new(asset_server.load(ICON_REPEAT))
+                    .with_color(Color::Srgba(TEXT_MUTED_COLOR)),
+                Node {
+                    width: px(LOOP_ICON_SIZE),
+                    height: px(LOOP_ICON_SIZE),
+                    display: Display::None,
+                    ..default()
+                },
+            ),
         ],
     )
 }
@@ -153,7 +193,8 @@ fn update_seekbar(
     emitter_query: Query<(&EmitterEntity, &EmitterRuntime)>,
     mut elapsed_label: Query<&mut Text, (With<SeekbarElapsed>, Without<SeekbarDuration>)>,
     mut duration_label: Query<&mut Text, (With<SeekbarDuration>, Without<SeekbarElapsed>)>,
-    mut fill: Query<&mut Node, With<SeekbarFill>>,
+    mut fill: Query<&mut Node, (With<SeekbarFill>, Without<SeekbarLoopIcon>)>,
+    mut loop_icon: Query<&mut Node, (With<SeekbarLoopIcon>, Without<SeekbarFill>)>,
     drag_state: Query<&SeekbarDragState, With<SeekbarHitbox>>,
 ) {
     let Ok(drag) = drag_state.single() else {
@@ -174,13 +215,7 @@ fn update_seekbar(
         .filter_map(|e| e.sub_emitter.as_ref().map(|s| s.target_emitter))
         .collect();
 
-    let duration = asset
-        .emitters
-        .iter()
-        .enumerate()
-        .filter(|(idx, _)| !sub_target_indices.contains(idx))
-        .map(|(_, e)| e.time.total_duration())
-        .fold(0.0_f32, |a, b| a.max(b));
+    let duration = asset.playback_duration();
 
     let elapsed = if drag.dragging {
         drag.drag_time
@@ -202,6 +237,17 @@ fn update_seekbar(
         **text = format_duration(duration);
     }
 
+    let loop_display = if asset.is_looping() {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    for mut node in &mut loop_icon {
+        if node.display != loop_display {
+            node.display = loop_display;
+        }
+    }
+
     if drag.dragging {
         return;
     }
@@ -217,6 +263,59 @@ fn update_seekbar(
     }
 }
 
+fn update_burst_markers(
+    assets: Res<Assets<ParticleSystemAsset>>,
+    system_query: Query<&ParticleSystem3D, With<EditorParticlePreview>>,
+    mut markers: Query<(Entity, &mut SeekbarBurstMarkers)>,
+    mut commands: Commands,
+) {
+    let Ok((entity, mut markers)) = markers.single_mut() else {
+        return;
+    };
+
+    let positions = system_query
+        .iter()
+        .next()
+        .and_then(|particle_system| assets.get(&particle_system.handle))
+        .map(|asset| {
+            let duration = asset.playback_duration();
+            if duration <= 0.0 {
+                return Vec::new();
+            }
+            let mut positions: Vec<f32> = asset
+                .burst_times()
+                .into_iter()
+                .map(|time| (time / duration).clamp(0.0, 1.0))
+                .collect();
+            positions.dedup_by(|a, b| (*a - *b).abs() < 0.005);
+            positions
+        })
+        .unwrap_or_default();
+
+    if markers.0 == positions {
+        return;
+    }
+
+    commands.entity(entity).despawn_related::<Children>();
+    for position in &positions {
+        commands.entity(entity).with_child((
+            Node {
+                position_type: PositionType::Absolute,
+                left: percent(position * 100.0),
+                top: px((SEEKBAR_HEIGHT - BURST_MARKER_HEIGHT) * 0.5),
+                width: px(BURST_MARKER_WIDTH),
+                height: px(BURST_MARKER_HEIGHT),
+                margin: UiRect::left(px(-BURST_MARKER_WIDTH * 0.5)),
+                border_radius: BorderRadius::all(px(1)),
+                ..default()
+            },
+            BackgroundColor(tailwind::YELLOW_500.into()),
+            Pickable::IGNORE,
+        ));
+    }
+    markers.0 = positions;
+}
+
 fn on_drag_start(
     event: On<Pointer<DragStart>>,
     mut hitboxes: Query<&mut SeekbarDragState, With<SeekbarHitbox>>,
@@ -283,21 +382,7 @@ fn on_seekbar_drag(
         return;
     };
 
-    let sub_target_indices: Vec<usize> = asset
-        .emitters
-        .iter()
-        .filter_map(|e| e.sub_emitter.as_ref().map(|s| s.target_emitter))
-        .collect();
-
-    let duration = asset
-        .emitters
-        .iter()
-        .enumerate()
-        .filter(|(idx, _)| !sub_target_indices.contains(idx))
-        .map(|(_, e)| e.time.total_duration())
-        .fold(0.0_f32, |a, b| a.max(b));
-
-    let seek_time = event.value * duration;
+    let seek_time = event.value * asset.playback_duration();
 
     if let Ok(mut drag_state) = hitboxes.get_mut(event.entity) {
         drag_state.drag_time = seek_time;
@@ -0,0 +1,119 @@
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+
+use crate::state::EditorState;
+use crate::thumbnail::ParticleThumbnails;
+use crate::ui::tokens::{BORDER_COLOR, CORNER_RADIUS_LG};
+use crate::ui::widgets::panel_section::{PanelSectionProps, panel_section};
+
+const THUMBNAIL_COUNT: usize = 5;
+const THUMBNAIL_FIRST_LAYER: usize = 16;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(Update, (sync_thumbnails, rebuild_thumbnail_strip));
+}
+
+/// Render layers the thumbnails are drawn on, so lights can reach them.
+pub fn thumbnail_layers() -> RenderLayers {
+    (THUMBNAIL_FIRST_LAYER..THUMBNAIL_FIRST_LAYER + THUMBNAIL_COUNT).collect()
+}
+
+#[derive(Component)]
+struct ThumbnailsSection;
+
+#[derive(Component)]
+struct ThumbnailStrip;
+
+#[derive(Component)]
+struct EditorThumbnails;
+
+pub fn thumbnails_section(asset_server: &AssetServer) -> impl Bundle {
+    (
+        ThumbnailsSection,
+        panel_section(PanelSectionProps::new("Lifetime preview"), asset_server),
+    )
+}
+
+// keeps a single set of thumbnails following the open project
+fn sync_thumbnails(
+    mut commands: Commands,
+    editor_state: Res<EditorState>,
+    mut existing: Query<(Entity, &mut ParticleThumbnails), With<EditorThumbnails>>,
+) {
+    if !editor_state.is_changed() {
+        return;
+    }
+
+    match (&editor_state.current_project, existing.single_mut()) {
+        (Some(handle), Ok((_, mut thumbnails))) => {
+            if thumbnails.handle != *handle {
+                thumbnails.handle = handle.clone();
+            }
+        }
+        (Some(handle), Err(_)) => {
+            let mut thumbnails = ParticleThumbnails::new(handle.clone(), THUMBNAIL_COUNT);
+            thumbnails.first_layer = THUMBNAIL_FIRST_LAYER;
+            commands.spawn((EditorThumbnails, thumbnails, Name::new("Thumbnails")));
+        }
+        (None, Ok((entity, _))) => {
+            commands.entity(entity).despawn();
+        }
+        (None, Err(_)) => {}
+    }
+}
+
+fn rebuild_thumbnail_strip(
+    mut commands: Commands,
+    thumbnails: Query<&ParticleThumbnails, With<EditorThumbnails>>,
+    sections: Query<Entity, With<ThumbnailsSection>>,
+    new_sections: Query<(), Added<ThumbnailsSection>>,
+    strips: Query<Entity, With<ThumbnailStrip>>,
+    mut shown: Local<Vec<AssetId<Image>>>,
+) {
+    let Ok(section) = sections.single() else {
+        return;
+    };
+    let images: Vec<Handle<Image>> = thumbnails
+        .single()
+        .map(|thumbnails| thumbnails.images.clone())
+        .unwrap_or_default();
+    let ids: Vec<AssetId<Image>> = images.iter().map(Handle::id).collect();
+    // the images are replaced when the project changes, not when the asset does
+    if new_sections.is_empty() && *shown == ids {
+        return;
+    }
+
+    for strip in &strips {
+        commands.entity(strip).despawn();
+    }
+
+    let strip = commands
+        .spawn((
+            ThumbnailStrip,
+            Node {
+                width: percent(100),
+                flex_direction: FlexDirection::Row,
+                column_gap: px(4),
+                ..default()
+            },
+        ))
+        .id();
+    commands.entity(section).add_child(strip);
+
+    for image in images {
+        commands.entity(strip).with_child((
+            ImageNode::new(image).with_mode(NodeImageMode::Stretch),
+            Node {
+                flex_grow: 1.0,
+                flex_basis: px(0),
+                aspect_ratio: Some(1.0),
+                border: UiRect::all(px(1)),
+                border_radius: BorderRadius::all(CORNER_RADIUS_LG),
+                ..default()
+            },
+            BorderColor::all(BORDER_COLOR),
+        ));
+    }
+
+    *shown = ids;
+}
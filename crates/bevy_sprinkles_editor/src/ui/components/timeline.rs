@@ -0,0 +1,597 @@
+use bevy::color::palettes::tailwind;
+use bevy::prelude::*;
+use bevy_sprinkles::prelude::*;
+
+use crate::state::{DirtyState, EditorState, Inspectable};
+use crate::ui::components::inspector::InspectedEmitterTracker;
+use crate::ui::tokens::{
+    BACKGROUND_COLOR, BORDER_COLOR, CORNER_RADIUS, FONT_PATH, PRIMARY_COLOR, TEXT_MUTED_COLOR,
+    TEXT_SIZE_SM,
+};
+
+const ROW_HEIGHT: f32 = 20.0;
+const LABEL_WIDTH: f32 = 96.0;
+const PEAK_WIDTH: f32 = 40.0;
+const MAX_HEIGHT: f32 = 160.0;
+const HANDLE_WIDTH: f32 = 6.0;
+const MARKER_WIDTH: f32 = 2.0;
+/// Extra room shown after the longest emitter, as a fraction of its end time.
+const VIEW_PADDING: f32 = 0.25;
+const MIN_VIEW_SECONDS: f32 = 1.0;
+const MIN_LIFETIME: f32 = 0.01;
+/// Dragged values snap to this many seconds.
+const SNAP: f32 = 0.01;
+/// Constant sub-emitters show at most this many spawn markers per bar.
+const MAX_MARKERS: usize = 32;
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<TimelineState>()
+        .add_systems(Update, (rebuild_timeline, update_timeline_bars).chain());
+}
+
+#[derive(Component)]
+pub struct EditorTimeline;
+
+#[derive(Component)]
+struct TimelineRows;
+
+#[derive(Component)]
+struct TimelineScaleLabel;
+
+#[derive(Component)]
+struct TimelineTrack;
+
+/// Which part of an emitter's timing a bar or handle edits when dragged.
+#[derive(Clone, Copy, PartialEq)]
+enum TimelineField {
+    Delay,
+    Lifetime,
+}
+
+#[derive(Component, Clone, Copy)]
+struct TimelineDragTarget {
+    index: usize,
+    field: TimelineField,
+}
+
+#[derive(Clone, PartialEq)]
+struct TimelineRow {
+    name: String,
+    delay: f32,
+    lifetime: f32,
+    /// Times at which the emitter's first particle triggers its sub-emitter, relative
+    /// to the start of the bar.
+    markers: Vec<f32>,
+    is_sub_emitter_target: bool,
+    /// Estimated number of particles the emitter keeps alive at once.
+    peak: u32,
+}
+
+struct TimelineDrag {
+    target: TimelineDragTarget,
+    start: f32,
+}
+
+#[derive(Resource, Default)]
+struct TimelineState {
+    project: Option<AssetId<ParticleSystemAsset>>,
+    rows: Vec<TimelineRow>,
+    seconds: f32,
+    drag: Option<TimelineDrag>,
+}
+
+pub fn timeline(asset_server: &AssetServer) -> impl Bundle {
+    let font: Handle<Font> = asset_server.load(FONT_PATH);
+
+    (
+        EditorTimeline,
+        Node {
+            width: percent(100),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(px(8)),
+            row_gap: px(4),
+            border: UiRect::top(px(1)),
+            ..default()
+        },
+        BackgroundColor(BACKGROUND_COLOR.into()),
+        BorderColor::all(BORDER_COLOR),
+        children![
+            (
+                Node {
+                    justify_content: JustifyContent::SpaceBetween,
+                    padding: UiRect {
+                        left: px(LABEL_WIDTH + 8.0),
+                        right: px(PEAK_WIDTH + 8.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                children![
+                    (
+                        Text::new("0s"),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: TEXT_SIZE_SM,
+                            ..default()
+                        },
+                        TextColor(TEXT_MUTED_COLOR.into()),
+                    ),
+                    (
+                        TimelineScaleLabel,
+                        Text::default(),
+                        TextFont {
+                            font,
+                            font_size: TEXT_SIZE_SM,
+                            ..default()
+                        },
+                        TextColor(TEXT_MUTED_COLOR.into()),
+                    ),
+                ],
+            ),
+            (
+                TimelineRows,
+                Node {
+                    width: percent(100),
+                    max_height: px(MAX_HEIGHT),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: px(4),
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                },
+            ),
+        ],
+    )
+}
+
+fn sub_emitter_markers(emitter: &EmitterData) -> Vec<f32> {
+    let Some(config) = &emitter.sub_emitter else {
+        return Vec::new();
+    };
+    let lifetime = emitter.time.lifetime;
+    match config.mode {
+        SubEmitterMode::AtStart => vec![0.0],
+        SubEmitterMode::AtEnd => vec![lifetime],
+        SubEmitterMode::Constant if config.frequency > 0.0 => (1..=MAX_MARKERS)
+            .map(|n| n as f32 * config.frequency)
+            .take_while(|time| *time <= lifetime)
+            .collect(),
+        // collisions can't be placed on the timeline
+        SubEmitterMode::Constant | SubEmitterMode::AtCollision => Vec::new(),
+    }
+}
+
+fn timeline_rows(asset: &ParticleSystemAsset) -> Vec<TimelineRow> {
+    asset
+        .emitters
+        .iter()
+        .enumerate()
+        .map(|(index, emitter)| TimelineRow {
+            name: emitter.name.clone(),
+            delay: emitter.time.delay,
+            lifetime: emitter.time.lifetime,
+            markers: sub_emitter_markers(emitter),
+            is_sub_emitter_target: asset.emitters.iter().any(|e| {
+                e.sub_emitter
+                    .as_ref()
+                    .is_some_and(|s| s.target_emitter == index)
+            }),
+            peak: asset.peak_particle_count(index),
+        })
+        .collect()
+}
+
+fn view_seconds(rows: &[TimelineRow]) -> f32 {
+    let end = rows
+        .iter()
+        .map(|row| row.delay + row.lifetime)
+        .fold(0.0, f32::max);
+    (end * (1.0 + VIEW_PADDING)).max(MIN_VIEW_SECONDS)
+}
+
+fn rebuild_timeline(
+    mut commands: Commands,
+    editor_state: Res<EditorState>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    asset_server: Res<AssetServer>,
+    mut state: ResMut<TimelineState>,
+    rows_container: Query<(Entity, Option<&Children>), With<TimelineRows>>,
+    new_timelines: Query<(), Added<EditorTimeline>>,
+    mut scale_label: Query<&mut Text, With<TimelineScaleLabel>>,
+) {
+    if state.drag.is_some() {
+        return;
+    }
+    let Some(handle) = &editor_state.current_project else {
+        return;
+    };
+    let Some(asset) = assets.get(handle) else {
+        return;
+    };
+    let Ok((container, children)) = rows_container.single() else {
+        return;
+    };
+
+    let rows = timeline_rows(asset);
+    let project = Some(handle.id());
+    if new_timelines.is_empty() && state.project == project && state.rows == rows {
+        return;
+    }
+
+    state.project = project;
+    state.seconds = view_seconds(&rows);
+    for mut text in &mut scale_label {
+        **text = format!("{:.2}s", state.seconds);
+    }
+
+    if let Some(children) = children {
+        for child in children.iter() {
+            commands.entity(child).despawn();
+        }
+    }
+
+    let font: Handle<Font> = asset_server.load(FONT_PATH);
+    for (index, row) in rows.iter().enumerate() {
+        spawn_row(&mut commands, container, &font, index, row);
+    }
+    state.rows = rows;
+}
+
+fn spawn_row(
+    commands: &mut Commands,
+    container: Entity,
+    font: &Handle<Font>,
+    index: usize,
+    row: &TimelineRow,
+) {
+    let bar_color = if row.is_sub_emitter_target {
+        tailwind::ZINC_500
+    } else {
+        PRIMARY_COLOR
+    };
+
+    let track = commands
+        .spawn((
+            TimelineTrack,
+            Node {
+                flex_grow: 1.0,
+                height: percent(100),
+                border_radius: BorderRadius::all(CORNER_RADIUS),
+                ..default()
+            },
+            BackgroundColor(tailwind::ZINC_900.into()),
+        ))
+        .id();
+
+    let bar = commands
+        .spawn((
+            TimelineDragTarget {
+                index,
+                field: TimelineField::Delay,
+            },
+            Node {
+                position_type: PositionType::Absolute,
+                top: px(3),
+                bottom: px(3),
+                border_radius: BorderRadius::all(CORNER_RADIUS),
+                ..default()
+            },
+            BackgroundColor(bar_color.into()),
+        ))
+        .observe(on_drag_start)
+        .observe(on_drag)
+        .observe(on_drag_end)
+        .id();
+
+    for time in &row.markers {
+        let left = if row.lifetime > 0.0 {
+            (time / row.lifetime).clamp(0.0, 1.0) * 100.0
+        } else {
+            0.0
+        };
+        commands.entity(bar).with_child((
+            Node {
+                position_type: PositionType::Absolute,
+                left: percent(left),
+                width: px(MARKER_WIDTH),
+                height: percent(100),
+                margin: UiRect::left(px(-MARKER_WIDTH / 2.0)),
+                ..default()
+            },
+            BackgroundColor(tailwind::ZINC_50.into()),
+            Pickable::IGNORE,
+        ));
+    }
+
+    let handle = commands
+        .spawn((
+            TimelineDragTarget {
+                index,
+                field: TimelineField::Lifetime,
+            },
+            Node {
+                position_type: PositionType::Absolute,
+                width: px(HANDLE_WIDTH),
+                height: percent(100),
+                margin: UiRect::left(px(-HANDLE_WIDTH / 2.0)),
+                ..default()
+            },
+        ))
+        .observe(on_drag_start)
+        .observe(on_drag)
+        .observe(on_drag_end)
+        .id();
+
+    commands.entity(track).add_children(&[bar, handle]);
+
+    let row_entity = commands
+        .spawn(Node {
+            width: percent(100),
+            height: px(ROW_HEIGHT),
+            flex_shrink: 0.0,
+            column_gap: px(8),
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .with_child((
+            Node {
+                width: px(LABEL_WIDTH),
+                flex_shrink: 0.0,
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            children![(
+                Text::new(row.name.clone()),
+                TextFont {
+                    font: font.clone(),
+                    font_size: TEXT_SIZE_SM,
+                    ..default()
+                },
+                TextColor(TEXT_MUTED_COLOR.into()),
+                TextLayout::new_with_no_wrap(),
+            )],
+        ))
+        .add_child(track)
+        .with_child((
+            Node {
+                width: px(PEAK_WIDTH),
+                flex_shrink: 0.0,
+                justify_content: JustifyContent::End,
+                ..default()
+            },
+            children![(
+                Text::new(row.peak.to_string()),
+                TextFont {
+                    font: font.clone(),
+                    font_size: TEXT_SIZE_SM,
+                    ..default()
+                },
+                TextColor(TEXT_MUTED_COLOR.into()),
+                TextLayout::new_with_no_wrap(),
+            )],
+        ))
+        .id();
+
+    commands.entity(container).add_child(row_entity);
+}
+
+/// Places bars and lifetime handles from the current emitter timings, so they follow
+/// drags before the rows are rebuilt.
+fn update_timeline_bars(
+    editor_state: Res<EditorState>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    state: Res<TimelineState>,
+    mut targets: Query<(&TimelineDragTarget, &mut Node)>,
+) {
+    let Some(asset) = editor_state
+        .current_project
+        .as_ref()
+        .and_then(|handle| assets.get(handle))
+    else {
+        return;
+    };
+    if state.seconds <= 0.0 {
+        return;
+    }
+
+    for (target, mut node) in &mut targets {
+        let Some(emitter) = asset.emitters.get(target.index) else {
+            continue;
+        };
+        let start = emitter.time.delay / state.seconds * 100.0;
+        let width = emitter.time.lifetime / state.seconds * 100.0;
+        let (left, width) = match target.field {
+            TimelineField::Delay => (percent(start), percent(width)),
+            TimelineField::Lifetime => (percent(start + width), node.width),
+        };
+        if node.left != left {
+            node.left = left;
+        }
+        if node.width != width {
+            node.width = width;
+        }
+    }
+}
+
+fn on_drag_start(
+    event: On<Pointer<DragStart>>,
+    editor_state: Res<EditorState>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    targets: Query<&TimelineDragTarget>,
+    mut state: ResMut<TimelineState>,
+) {
+    let Ok(target) = targets.get(event.entity) else {
+        return;
+    };
+    let Some(emitter) = editor_state
+        .current_project
+        .as_ref()
+        .and_then(|handle| assets.get(handle))
+        .and_then(|asset| asset.emitters.get(target.index))
+    else {
+        return;
+    };
+    let start = match target.field {
+        TimelineField::Delay => emitter.time.delay,
+        TimelineField::Lifetime => emitter.time.lifetime,
+    };
+    state.drag = Some(TimelineDrag {
+        target: *target,
+        start,
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+fn on_drag(
+    event: On<Pointer<Drag>>,
+    editor_state: Res<EditorState>,
+    mut assets: ResMut<Assets<ParticleSystemAsset>>,
+    mut dirty_state: ResMut<DirtyState>,
+    state: Res<TimelineState>,
+    parents: Query<&ChildOf, With<TimelineDragTarget>>,
+    tracks: Query<&ComputedNode, With<TimelineTrack>>,
+    mut emitter_runtimes: Query<&mut EmitterRuntime>,
+) {
+    let Some(drag) = &state.drag else {
+        return;
+    };
+    let Some(track) = parents
+        .get(event.entity)
+        .ok()
+        .and_then(|child_of| tracks.get(child_of.parent()).ok())
+    else {
+        return;
+    };
+    let width = track.size.x * track.inverse_scale_factor;
+    if width <= 0.0 {
+        return;
+    }
+
+    let Some(emitter) = editor_state
+        .current_project
+        .as_ref()
+        .and_then(|handle| assets.get_mut(handle))
+        .and_then(|asset| asset.emitters.get_mut(drag.target.index))
+    else {
+        return;
+    };
+
+    let value = drag.start + event.distance.x / width * state.seconds;
+    let value = (value / SNAP).round() * SNAP;
+    let (field, value) = match drag.target.field {
+        TimelineField::Delay => (&mut emitter.time.delay, value.max(0.0)),
+        TimelineField::Lifetime => (&mut emitter.time.lifetime, value.max(MIN_LIFETIME)),
+    };
+    if *field == value {
+        return;
+    }
+    *field = value;
+
+    let fixed_seed = emitter.time.fixed_seed;
+    dirty_state.has_unsaved_changes = true;
+    for mut runtime in &mut emitter_runtimes {
+        runtime.restart(fixed_seed);
+    }
+}
+
+fn on_drag_end(
+    _event: On<Pointer<DragEnd>>,
+    editor_state: Res<EditorState>,
+    mut state: ResMut<TimelineState>,
+    mut tracker: ResMut<InspectedEmitterTracker>,
+) {
+    let Some(drag) = state.drag.take() else {
+        return;
+    };
+    // refresh the inspector's time fields if they show the dragged emitter
+    let inspecting_dragged = editor_state.inspecting.is_some_and(|inspecting| {
+        inspecting.kind == Inspectable::Emitter && inspecting.index as usize == drag.target.index
+    });
+    if inspecting_dragged {
+        tracker.set_changed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::camera::NormalizedRenderTarget;
+    use bevy::picking::backend::HitData;
+    use bevy::picking::pointer::{Location, PointerButton, PointerId};
+
+    use super::*;
+    use crate::test_utils::{open_project, project, test_app};
+
+    fn pointer<E: std::fmt::Debug + Clone + Reflect>(event: E, entity: Entity) -> Pointer<E> {
+        let location = Location {
+            target: NormalizedRenderTarget::None {
+                width: 800,
+                height: 600,
+            },
+            position: Vec2::ZERO,
+        };
+        Pointer::new(PointerId::Mouse, location, event, entity)
+    }
+
+    #[test]
+    fn test_dragging_a_bar_updates_the_emitter_delay() {
+        let mut app = test_app();
+        app.init_resource::<TimelineState>();
+        let handle = open_project(
+            &mut app,
+            vec![EmitterData {
+                time: EmitterTime {
+                    delay: 0.5,
+                    ..default()
+                },
+                ..default()
+            }],
+            vec![],
+        );
+        app.world_mut().resource_mut::<TimelineState>().seconds = 4.0;
+
+        // a 200px wide track showing 4 seconds, so every 50px is a second
+        let track = app
+            .world_mut()
+            .spawn((
+                TimelineTrack,
+                ComputedNode {
+                    size: Vec2::new(200.0, ROW_HEIGHT),
+                    inverse_scale_factor: 1.0,
+                    ..default()
+                },
+            ))
+            .id();
+        let bar = app
+            .world_mut()
+            .spawn((
+                TimelineDragTarget {
+                    index: 0,
+                    field: TimelineField::Delay,
+                },
+                ChildOf(track),
+            ))
+            .observe(on_drag_start)
+            .observe(on_drag)
+            .id();
+
+        let hit = HitData::new(Entity::PLACEHOLDER, 0.0, None, None);
+        app.world_mut().trigger(pointer(
+            DragStart {
+                button: PointerButton::Primary,
+                hit,
+            },
+            bar,
+        ));
+        app.world_mut().trigger(pointer(
+            Drag {
+                button: PointerButton::Primary,
+                distance: Vec2::new(50.0, 0.0),
+                delta: Vec2::new(50.0, 0.0),
+            },
+            bar,
+        ));
+
+        let delay = project(&app, &handle).emitters[0].time.delay;
+        assert!((delay - 1.5).abs() < 1e-4);
+        assert!(app.world().resource::<DirtyState>().has_unsaved_changes);
+    }
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
-use crate::project::SaveProjectEvent;
+use crate::bake::BakeMeshEvent;
+use crate::project::{CleanUpProjectEvent, SaveProjectEvent};
 use crate::ui::components::playback_controls::playback_controls;
 use crate::ui::components::project_selector::project_selector;
 use crate::ui::components::seekbar::seekbar;
@@ -9,7 +10,14 @@ use crate::ui::widgets::button::{ButtonClickEvent, ButtonProps, ButtonVariant, b
 use crate::ui::widgets::separator::EditorSeparator;
 
 pub fn plugin(app: &mut App) {
-    app.add_systems(Update, setup_save_button_observer);
+    app.add_systems(
+        Update,
+        (
+            setup_save_button_observer,
+            setup_bake_button_observer,
+            setup_clean_up_button_observer,
+        ),
+    );
 }
 
 #[derive(Component)]
@@ -25,6 +33,35 @@ fn on_save_button_click(_event: On<ButtonClickEvent>, mut commands: Commands) {
     commands.trigger(SaveProjectEvent);
 }
 
+#[derive(Component)]
+pub struct BakeButton;
+
+fn setup_bake_button_observer(buttons: Query<Entity, Added<BakeButton>>, mut commands: Commands) {
+    for entity in &buttons {
+        commands.entity(entity).observe(on_bake_button_click);
+    }
+}
+
+fn on_bake_button_click(_event: On<ButtonClickEvent>, mut commands: Commands) {
+    commands.trigger(BakeMeshEvent);
+}
+
+#[derive(Component)]
+pub struct CleanUpButton;
+
+fn setup_clean_up_button_observer(
+    buttons: Query<Entity, Added<CleanUpButton>>,
+    mut commands: Commands,
+) {
+    for entity in &buttons {
+        commands.entity(entity).observe(on_clean_up_button_click);
+    }
+}
+
+fn on_clean_up_button_click(_event: On<ButtonClickEvent>, mut commands: Commands) {
+    commands.trigger(CleanUpProjectEvent::default());
+}
+
 #[derive(Component)]
 pub struct EditorTopbar;
 
@@ -54,6 +91,8 @@ pub fn topbar(asset_server: &AssetServer) -> impl Bundle {
                     seekbar(asset_server),
                     playback_controls(asset_server),
                     EditorSeparator::vertical(),
+                    (CleanUpButton, button(ButtonProps::new("Clean up"))),
+                    (BakeButton, button(ButtonProps::new("Bake"))),
                     (
                         SaveButton,
                         button(ButtonProps::new("Save").with_variant(ButtonVariant::Primary)),
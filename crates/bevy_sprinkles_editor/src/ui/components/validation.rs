@@ -0,0 +1,172 @@
+use bevy::picking::hover::Hovered;
+use bevy::prelude::*;
+use bevy::window::SystemCursorIcon;
+use bevy_sprinkles::prelude::*;
+
+use crate::state::{EditorState, Inspectable, Inspecting};
+use crate::ui::components::inspector::JumpedSection;
+use crate::ui::tokens::{FONT_PATH, TEXT_MUTED_COLOR, TEXT_SIZE};
+use crate::ui::widgets::alert::{AlertSpan, AlertVariant, alert};
+use crate::ui::widgets::cursor::HoverCursor;
+use crate::ui::widgets::panel_section::{PanelSectionProps, panel_section};
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(Update, (rebuild_problems, handle_problem_click));
+}
+
+#[derive(Component)]
+struct ProblemsSection;
+
+#[derive(Component)]
+struct ProblemsList;
+
+#[derive(Component)]
+struct ProblemItem {
+    emitter: usize,
+    field: &'static str,
+}
+
+pub fn problems_section(asset_server: &AssetServer) -> impl Bundle {
+    (
+        ProblemsSection,
+        panel_section(PanelSectionProps::new("Problems"), asset_server),
+    )
+}
+
+fn rebuild_problems(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    editor_state: Res<EditorState>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    sections: Query<Entity, With<ProblemsSection>>,
+    new_sections: Query<(), Added<ProblemsSection>>,
+    lists: Query<Entity, With<ProblemsList>>,
+    mut shown: Local<Option<Vec<(AssetWarning, String)>>>,
+) {
+    if !editor_state.is_changed() && !assets.is_changed() && new_sections.is_empty() {
+        return;
+    }
+    let Ok(section) = sections.single() else {
+        return;
+    };
+    let Some(asset) = editor_state
+        .current_project
+        .as_ref()
+        .and_then(|handle| assets.get(handle))
+    else {
+        return;
+    };
+
+    let problems: Vec<(AssetWarning, String)> = asset
+        .validate()
+        .into_iter()
+        .map(|warning| {
+            let emitter_name = asset
+                .emitters
+                .get(warning.emitter())
+                .map(|emitter| emitter.name.clone())
+                .unwrap_or_default();
+            (warning, emitter_name)
+        })
+        .collect();
+    // the asset changes on every edit, so only rebuild when the problems do
+    if new_sections.is_empty() && shown.as_ref() == Some(&problems) {
+        return;
+    }
+
+    for list in &lists {
+        commands.entity(list).despawn();
+    }
+
+    let list = commands
+        .spawn((
+            ProblemsList,
+            Node {
+                width: percent(100),
+                flex_direction: FlexDirection::Column,
+                row_gap: px(6.0),
+                ..default()
+            },
+        ))
+        .id();
+    commands.entity(section).add_child(list);
+
+    if problems.is_empty() {
+        let font: Handle<Font> = asset_server.load(FONT_PATH);
+        commands.entity(list).with_child((
+            Text::new("No problems found"),
+            TextFont {
+                font,
+                font_size: TEXT_SIZE,
+                ..default()
+            },
+            TextColor(TEXT_MUTED_COLOR.into()),
+        ));
+    }
+
+    for (warning, emitter_name) in &problems {
+        commands.entity(list).with_child((
+            ProblemItem {
+                emitter: warning.emitter(),
+                field: warning.field(),
+            },
+            Button,
+            Hovered::default(),
+            HoverCursor(SystemCursorIcon::Pointer),
+            Node {
+                width: percent(100),
+                ..default()
+            },
+            children![alert(
+                AlertVariant::Warning,
+                vec![
+                    AlertSpan::Bold(emitter_name.clone()),
+                    AlertSpan::Text(format!(": {warning}")),
+                ],
+            )],
+        ));
+    }
+
+    *shown = Some(problems);
+}
+
+fn handle_problem_click(
+    items: Query<(&Interaction, &ProblemItem), Changed<Interaction>>,
+    mut editor_state: ResMut<EditorState>,
+    mut jumped: ResMut<JumpedSection>,
+) {
+    for (interaction, item) in &items {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        editor_state.inspecting = Some(Inspecting {
+            kind: Inspectable::Emitter,
+            index: item.emitter as u8,
+        });
+        if let Some(title) = section_title(item.field) {
+            jumped.0 = Some(title.to_string());
+        }
+    }
+}
+
+// maps a field path to the title of the inspector section holding it
+fn section_title(field: &str) -> Option<&'static str> {
+    let title = match field.split('.').next()? {
+        "time" => "Time",
+        "position" => "Transform",
+        "draw_pass" => "Draw pass",
+        "emission" => "Emission",
+        "scale" => "Scale",
+        "colors" => "Colors",
+        "velocities" => "Velocities",
+        "angle" => "Angle",
+        "accelerations" => "Accelerations",
+        "turbulence" => "Turbulence",
+        "collision" => "Collision",
+        "sub_emitter" => "Sub-emitter",
+        "particle_flags" => "Particle Flags",
+        _ => return None,
+    };
+    Some(title)
+}
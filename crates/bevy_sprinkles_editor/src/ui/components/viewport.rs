@@ -15,7 +15,7 @@ pub fn viewport_container() -> impl Bundle {
         EditorViewportContainer,
         Node {
             flex_grow: 1.0,
-            height: percent(100),
+            min_height: px(0.0),
             ..default()
         },
         Hovered::default(),
@@ -1,5 +1,6 @@
 pub mod components;
 pub mod icons;
+pub mod theme;
 pub mod tokens;
 pub mod widgets;
 
@@ -8,6 +9,7 @@ use bevy::prelude::*;
 
 use components::data_panel::data_panel;
 use components::inspector::inspector_panel;
+use components::timeline::timeline;
 use components::topbar::topbar;
 use components::viewport::{setup_viewport, viewport_container};
 
@@ -24,7 +26,8 @@ impl Plugin for EditorUiPlugin {
             Shader::from_wgsl
         );
 
-        app.add_plugins(widgets::alert::plugin)
+        app.add_plugins(theme::plugin)
+            .add_plugins(widgets::alert::plugin)
             .add_plugins(widgets::button::plugin)
             .add_plugins(widgets::link::plugin)
             .add_plugins(widgets::checkbox::plugin)
@@ -46,10 +49,14 @@ impl Plugin for EditorUiPlugin {
             .add_plugins(components::seekbar::plugin)
             .add_plugins(components::playback_controls::plugin)
             .add_plugins(components::examples_dialog::plugin)
+            .add_plugins(components::ron_preview::plugin)
             .add_plugins(components::project_selector::plugin)
             .add_plugins(widgets::dialog::plugin)
             .add_plugins(components::toasts::plugin)
             .add_plugins(components::topbar::plugin)
+            .add_plugins(components::thumbnails::plugin)
+            .add_plugins(components::timeline::plugin)
+            .add_plugins(components::validation::plugin)
             .add_systems(Startup, setup_ui)
             .add_systems(Update, setup_viewport);
     }
@@ -75,7 +82,16 @@ fn setup_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
                 children![
                     data_panel(&asset_server),
                     inspector_panel(&asset_server),
-                    viewport_container(),
+                    (
+                        Node {
+                            flex_grow: 1.0,
+                            height: percent(100),
+                            min_width: px(0.0),
+                            flex_direction: FlexDirection::Column,
+                            ..default()
+                        },
+                        children![viewport_container(), timeline(&asset_server)],
+                    ),
                 ],
             ),
         ],
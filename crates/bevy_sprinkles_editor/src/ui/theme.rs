@@ -0,0 +1,222 @@
+use bevy::color::palettes::tailwind;
+use bevy::prelude::*;
+
+use crate::io::{EditorData, ThemeKind};
+use crate::ui::tokens::{
+    BACKGROUND_COLOR, BORDER_COLOR, PRIMARY_COLOR, TEXT_BODY_COLOR, TEXT_DISPLAY_COLOR,
+    TEXT_MUTED_COLOR,
+};
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<Theme>()
+        .add_systems(PreUpdate, sync_theme)
+        .add_systems(PostUpdate, apply_theme);
+}
+
+/// The color tokens the editor UI is drawn with.
+///
+/// Widgets are spawned with the dark tokens from [`tokens`](crate::ui::tokens), which
+/// [`apply_theme`] swaps for the matching color of the active [`Theme`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ThemeColors {
+    pub primary: Srgba,
+    pub background: Srgba,
+    pub border: Srgba,
+    pub text_body: Srgba,
+    pub text_display: Srgba,
+    pub text_muted: Srgba,
+}
+
+impl ThemeColors {
+    pub const DARK: Self = Self {
+        primary: PRIMARY_COLOR,
+        background: BACKGROUND_COLOR,
+        border: BORDER_COLOR,
+        text_body: TEXT_BODY_COLOR,
+        text_display: TEXT_DISPLAY_COLOR,
+        text_muted: TEXT_MUTED_COLOR,
+    };
+
+    // none of these match a dark token, so colors are never swapped twice
+    pub const HIGH_CONTRAST: Self = Self {
+        primary: tailwind::SKY_400,
+        background: tailwind::NEUTRAL_950,
+        border: tailwind::NEUTRAL_400,
+        text_body: tailwind::NEUTRAL_100,
+        text_display: tailwind::NEUTRAL_50,
+        text_muted: tailwind::NEUTRAL_300,
+    };
+
+    pub fn of(kind: ThemeKind) -> Self {
+        match kind {
+            ThemeKind::Dark => Self::DARK,
+            ThemeKind::HighContrast => Self::HIGH_CONTRAST,
+        }
+    }
+
+    fn tokens(&self) -> [Srgba; 6] {
+        [
+            self.primary,
+            self.background,
+            self.border,
+            self.text_body,
+            self.text_display,
+            self.text_muted,
+        ]
+    }
+
+    /// Returns `color` with a token of `from` replaced by the same token of this theme,
+    /// keeping its alpha. Colors that aren't tokens of `from` are returned unchanged.
+    pub fn remap(&self, from: &ThemeColors, color: Color) -> Color {
+        let srgba = color.to_srgba();
+        let token = from
+            .tokens()
+            .iter()
+            .position(|token| token.with_alpha(srgba.alpha) == srgba);
+        match token {
+            Some(i) => self.tokens()[i].with_alpha(srgba.alpha).into(),
+            None => color,
+        }
+    }
+}
+
+/// The editor's active color theme, picked with
+/// [`EditorSettings::theme`](crate::io::EditorSettings::theme).
+#[derive(Resource, Clone, Copy, PartialEq, Debug)]
+pub struct Theme {
+    pub kind: ThemeKind,
+    pub colors: ThemeColors,
+}
+
+impl Theme {
+    pub fn new(kind: ThemeKind) -> Self {
+        Self {
+            kind,
+            colors: ThemeColors::of(kind),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new(ThemeKind::default())
+    }
+}
+
+fn sync_theme(editor_data: Res<EditorData>, mut theme: ResMut<Theme>) {
+    if !editor_data.is_changed() {
+        return;
+    }
+    let kind = editor_data.settings.theme;
+    if theme.kind != kind {
+        *theme = Theme::new(kind);
+    }
+}
+
+type ThemedColors<'a> = (
+    Option<Mut<'a, BackgroundColor>>,
+    Option<Mut<'a, BorderColor>>,
+    Option<Mut<'a, TextColor>>,
+    Option<Mut<'a, ImageNode>>,
+);
+
+fn remap_colors(item: ThemedColors, from: &ThemeColors, to: &ThemeColors) {
+    let (background, border, text, image) = item;
+    if let Some(mut background) = background {
+        let color = to.remap(from, background.0);
+        background.set_if_neq(BackgroundColor(color));
+    }
+    if let Some(mut border) = border {
+        let remapped = BorderColor {
+            top: to.remap(from, border.top),
+            right: to.remap(from, border.right),
+            bottom: to.remap(from, border.bottom),
+            left: to.remap(from, border.left),
+        };
+        border.set_if_neq(remapped);
+    }
+    if let Some(mut text) = text {
+        let color = to.remap(from, text.0);
+        text.set_if_neq(TextColor(color));
+    }
+    if let Some(mut image) = image {
+        let color = to.remap(from, image.color);
+        if image.color != color {
+            image.color = color;
+        }
+    }
+}
+
+type Themed = Or<(
+    With<BackgroundColor>,
+    With<BorderColor>,
+    With<TextColor>,
+    With<ImageNode>,
+)>;
+
+type ThemedChanged = Or<(
+    Changed<BackgroundColor>,
+    Changed<BorderColor>,
+    Changed<TextColor>,
+    Changed<ImageNode>,
+)>;
+
+/// Swaps the token colors of UI nodes for those of the active [`Theme`].
+///
+/// Every node is recolored when the theme changes. Otherwise only nodes whose colors
+/// changed this frame are, since widgets set their colors from the dark tokens when
+/// they're spawned or change state.
+pub fn apply_theme(
+    theme: Res<Theme>,
+    mut applied: Local<Option<ThemeColors>>,
+    mut nodes: ParamSet<(
+        Query<ThemedColors, Themed>,
+        Query<ThemedColors, ThemedChanged>,
+    )>,
+) {
+    let previous = applied.unwrap_or(ThemeColors::DARK);
+    let colors = theme.colors;
+    if previous != colors {
+        for item in nodes.p0().iter_mut() {
+            remap_colors(item, &previous, &colors);
+        }
+        *applied = Some(colors);
+    }
+    if colors == ThemeColors::DARK {
+        return;
+    }
+    for item in nodes.p1().iter_mut() {
+        remap_colors(item, &ThemeColors::DARK, &colors);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_app;
+
+    fn set_theme(app: &mut App, kind: ThemeKind) {
+        app.world_mut().resource_mut::<EditorData>().settings.theme = kind;
+        app.update();
+    }
+
+    #[test]
+    fn test_switching_themes_recolors_panel_backgrounds() {
+        let mut app = test_app();
+        app.add_plugins(plugin);
+        let panel = app
+            .world_mut()
+            .spawn((Node::default(), BackgroundColor(BACKGROUND_COLOR.into())))
+            .id();
+        let background = |app: &App| app.world().get::<BackgroundColor>(panel).unwrap().0;
+
+        set_theme(&mut app, ThemeKind::HighContrast);
+        assert_eq!(
+            background(&app),
+            Color::from(ThemeColors::HIGH_CONTRAST.background)
+        );
+
+        set_theme(&mut app, ThemeKind::Dark);
+        assert_eq!(background(&app), Color::from(BACKGROUND_COLOR));
+    }
+}
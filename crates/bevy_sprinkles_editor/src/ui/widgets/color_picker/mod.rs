@@ -6,6 +6,7 @@ use bevy::picking::events::{Press, Release};
 use bevy::picking::prelude::Pickable;
 use bevy::prelude::*;
 use bevy::ui::UiGlobalTransform;
+use bevy_sprinkles::asset::step_color_channel;
 use bevy_ui_text_input::TextInputQueue;
 use bevy_ui_text_input::actions::{TextInputAction, TextInputEdit};
 
@@ -22,6 +23,7 @@ use crate::ui::widgets::popover::{
     PopoverHeaderProps, PopoverPlacement, PopoverProps, PopoverTracker, activate_trigger,
     deactivate_trigger, popover, popover_content, popover_header,
 };
+use crate::ui::widgets::scroll::Scroll;
 use crate::ui::widgets::text_edit::{EditorTextEdit, TextEditPrefix, TextEditProps, text_edit};
 use crate::ui::widgets::utils::{find_ancestor, is_descendant_of};
 
@@ -35,6 +37,8 @@ const CHECKERBOARD_SIZE: f32 = 8.0;
 const PREVIEW_CHECKERBOARD_SIZE: f32 = 12.0;
 const BORDER_RADIUS: f32 = 4.0;
 const POPOVER_WIDTH: f32 = 256.0;
+/// How much slower a control follows the cursor while Shift is held.
+const SLOW_DRAG_FACTOR: f32 = 0.1;
 
 pub fn plugin(app: &mut App) {
     app.add_plugins(UiMaterialPlugin::<HsvRectMaterial>::default())
@@ -402,6 +406,10 @@ struct Dragging;
 trait PickerControl: Component {
     fn picker_entity(&self) -> Entity;
     fn update_state(&self, state: &mut ColorPickerState, normalized: Vec2);
+    /// Moves the values by `delta`, a fraction of the control's size.
+    fn nudge_state(&self, state: &mut ColorPickerState, delta: Vec2);
+    /// Steps the value the mouse wheel adjusts by `steps` 8-bit levels.
+    fn step_state(&self, state: &mut ColorPickerState, steps: f32);
 }
 
 impl PickerControl for HsvRectangle {
@@ -413,6 +421,15 @@ impl PickerControl for HsvRectangle {
         state.saturation = (normalized.x + 0.5).clamp(0.0, 1.0);
         state.brightness = (0.5 - normalized.y).clamp(0.0, 1.0);
     }
+
+    fn nudge_state(&self, state: &mut ColorPickerState, delta: Vec2) {
+        state.saturation = (state.saturation + delta.x).clamp(0.0, 1.0);
+        state.brightness = (state.brightness - delta.y).clamp(0.0, 1.0);
+    }
+
+    fn step_state(&self, state: &mut ColorPickerState, steps: f32) {
+        state.brightness = step_color_channel(state.brightness, steps);
+    }
 }
 
 impl PickerControl for HueSlider {
@@ -423,6 +440,14 @@ impl PickerControl for HueSlider {
     fn update_state(&self, state: &mut ColorPickerState, normalized: Vec2) {
         state.hue = (normalized.x + 0.5).clamp(0.0, 1.0) * 360.0;
     }
+
+    fn nudge_state(&self, state: &mut ColorPickerState, delta: Vec2) {
+        state.hue = (state.hue / 360.0 + delta.x).clamp(0.0, 1.0) * 360.0;
+    }
+
+    fn step_state(&self, state: &mut ColorPickerState, steps: f32) {
+        state.hue = step_color_channel(state.hue / 360.0, steps) * 360.0;
+    }
 }
 
 impl PickerControl for AlphaSlider {
@@ -433,6 +458,14 @@ impl PickerControl for AlphaSlider {
     fn update_state(&self, state: &mut ColorPickerState, normalized: Vec2) {
         state.alpha = (normalized.x + 0.5).clamp(0.0, 1.0);
     }
+
+    fn nudge_state(&self, state: &mut ColorPickerState, delta: Vec2) {
+        state.alpha = (state.alpha + delta.x).clamp(0.0, 1.0);
+    }
+
+    fn step_state(&self, state: &mut ColorPickerState, steps: f32) {
+        state.alpha = step_color_channel(state.alpha, steps);
+    }
 }
 
 fn on_control_press<C: PickerControl>(
@@ -515,6 +548,7 @@ fn on_control_drag_start<C: PickerControl>(
 fn on_control_drag<C: PickerControl>(
     event: On<Pointer<Drag>>,
     mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
     controls: Query<(&C, &ComputedNode, &UiGlobalTransform), With<Dragging>>,
     mut pickers: Query<&mut ColorPickerState>,
 ) {
@@ -523,16 +557,24 @@ fn on_control_drag<C: PickerControl>(
     };
     let picker_entity = control.picker_entity();
 
-    let cursor_pos = event.pointer_location.position / computed.inverse_scale_factor;
-    let Some(normalized) = computed.normalize_point(*ui_transform, cursor_pos) else {
-        return;
-    };
-
     let Ok(mut state) = pickers.get_mut(picker_entity) else {
         return;
     };
 
-    control.update_state(&mut state, normalized);
+    if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        // follow the cursor's movement at a fraction of its speed instead of its position
+        let size = computed.size() * computed.inverse_scale_factor;
+        if size.x <= 0.0 || size.y <= 0.0 {
+            return;
+        }
+        control.nudge_state(&mut state, event.delta / size * SLOW_DRAG_FACTOR);
+    } else {
+        let cursor_pos = event.pointer_location.position / computed.inverse_scale_factor;
+        let Some(normalized) = computed.normalize_point(*ui_transform, cursor_pos) else {
+            return;
+        };
+        control.update_state(&mut state, normalized);
+    }
 
     commands.trigger(ColorPickerChangeEvent {
         entity: picker_entity,
@@ -561,6 +603,40 @@ fn on_control_drag_end<C: PickerControl>(
     }
 }
 
+fn on_control_scroll<C: PickerControl>(
+    mut scroll: On<Scroll>,
+    mut commands: Commands,
+    controls: Query<&C>,
+    mut pickers: Query<&mut ColorPickerState>,
+) {
+    let Ok(control) = controls.get(scroll.event_target()) else {
+        return;
+    };
+    // the wheel adjusts the control instead of scrolling the panel behind it
+    scroll.propagate(false);
+    if scroll.delta.y == 0.0 {
+        return;
+    }
+    let picker_entity = control.picker_entity();
+
+    let Ok(mut state) = pickers.get_mut(picker_entity) else {
+        return;
+    };
+
+    // scroll deltas point down, so scrolling up raises the value
+    control.step_state(&mut state, -scroll.delta.y.signum());
+
+    let color = state.to_rgba();
+    commands.trigger(ColorPickerChangeEvent {
+        entity: picker_entity,
+        color,
+    });
+    commands.trigger(ColorPickerCommitEvent {
+        entity: picker_entity,
+        color,
+    });
+}
+
 fn setup_color_picker(
     mut commands: Commands,
     mut pickers: Query<(Entity, &ColorPickerConfig, &ColorPickerState), Added<EditorColorPicker>>,
@@ -755,7 +831,8 @@ fn setup_color_picker_content(
                 .observe(on_control_release::<HsvRectangle>)
                 .observe(on_control_drag_start::<HsvRectangle>)
                 .observe(on_control_drag::<HsvRectangle>)
-                .observe(on_control_drag_end::<HsvRectangle>);
+                .observe(on_control_drag_end::<HsvRectangle>)
+                .observe(on_control_scroll::<HsvRectangle>);
 
             parent
                 .spawn(Node {
@@ -803,7 +880,8 @@ fn setup_color_picker_content(
                                 .observe(on_control_release::<HueSlider>)
                                 .observe(on_control_drag_start::<HueSlider>)
                                 .observe(on_control_drag::<HueSlider>)
-                                .observe(on_control_drag_end::<HueSlider>);
+                                .observe(on_control_drag_end::<HueSlider>)
+                                .observe(on_control_scroll::<HueSlider>);
 
                             slider_col
                                 .spawn((AlphaSlider(picker_entity), slider_node()))
@@ -880,7 +958,8 @@ fn setup_color_picker_content(
                                 .observe(on_control_release::<AlphaSlider>)
                                 .observe(on_control_drag_start::<AlphaSlider>)
                                 .observe(on_control_drag::<AlphaSlider>)
-                                .observe(on_control_drag_end::<AlphaSlider>);
+                                .observe(on_control_drag_end::<AlphaSlider>)
+                                .observe(on_control_scroll::<AlphaSlider>);
                         });
 
                     slider_row
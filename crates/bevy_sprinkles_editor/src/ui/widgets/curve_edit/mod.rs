@@ -10,7 +10,7 @@ use bevy::prelude::*;
 use bevy::reflect::Typed;
 use bevy::ui::UiGlobalTransform;
 use bevy::window::SystemCursorIcon;
-use bevy_sprinkles::prelude::{CurveEasing, CurveMode, CurvePoint, CurveTexture};
+use bevy_sprinkles::prelude::{CurveEasing, CurveMode, CurvePoint, CurveTexture, LifetimePreset};
 use inflector::Inflector;
 
 use materials::{CurveMaterial, MAX_POINTS};
@@ -64,6 +64,7 @@ pub fn plugin(app: &mut App) {
                 handle_canvas_right_click,
                 handle_point_right_click,
                 handle_tension_right_click,
+                handle_point_keys,
             ),
         );
 }
@@ -209,6 +210,10 @@ struct PointModeMenu;
 #[derive(Component, Default)]
 struct Dragging;
 
+/// Marks the point handle that keyboard edits apply to.
+#[derive(Component)]
+struct SelectedPoint;
+
 trait CurveControl: Component {
     fn curve_edit_entity(&self) -> Entity;
     fn canvas_entity(&self) -> Entity;
@@ -320,7 +325,7 @@ impl CurveControl for TensionHandle {
                 let snapped_tension = (raw_tension / DRAG_SNAP_STEP).round() * DRAG_SNAP_STEP;
                 state.curve.points[self.index].tension = snapped_tension;
             }
-            CurveMode::Hold => {}
+            CurveMode::Hold | CurveMode::CatmullRom => {}
         }
 
         state.mark_custom();
@@ -363,6 +368,20 @@ fn on_control_press<C: CurveControl>(
     });
 }
 
+fn on_point_select(
+    event: On<Pointer<Press>>,
+    mut commands: Commands,
+    selected: Query<Entity, With<SelectedPoint>>,
+) {
+    if event.button != PointerButton::Primary {
+        return;
+    }
+    for entity in &selected {
+        commands.entity(entity).remove::<SelectedPoint>();
+    }
+    commands.entity(event.event_target()).insert(SelectedPoint);
+}
+
 fn on_control_release<C: CurveControl>(
     event: On<Pointer<Release>>,
     mut commands: Commands,
@@ -570,7 +589,9 @@ fn handle_trigger_click(
 
     let presets: Vec<_> = CURVE_PRESETS
         .iter()
-        .map(|p| ComboBoxOptionData::new(p.name))
+        .map(|p| p.name)
+        .chain(LifetimePreset::ALL.iter().map(|p| p.name()))
+        .map(ComboBoxOptionData::new)
         .collect();
 
     let popover_entity = commands
@@ -727,6 +748,7 @@ fn spawn_point_handles(
                 HoverCursor(SystemCursorIcon::Grab),
                 handle_style(x, y, POINT_HANDLE_SIZE),
             ))
+            .observe(on_point_select)
             .observe(on_control_press::<PointHandle>)
             .observe(on_control_release::<PointHandle>)
             .observe(on_control_drag_start::<PointHandle>)
@@ -902,42 +924,118 @@ fn respawn_handles_on_point_change(
 
 fn update_handle_colors(
     mut removed_dragging: RemovedComponents<Dragging>,
+    mut removed_selected: RemovedComponents<SelectedPoint>,
     mut handles: ParamSet<(
         Query<
-            (Entity, &Hovered, Has<Dragging>, &mut BackgroundColor),
+            (
+                Entity,
+                &Hovered,
+                Has<Dragging>,
+                Has<SelectedPoint>,
+                &mut BackgroundColor,
+            ),
             (
                 Or<(With<PointHandle>, With<TensionHandle>)>,
-                Or<(Changed<Hovered>, Added<Dragging>)>,
+                Or<(Changed<Hovered>, Added<Dragging>, Added<SelectedPoint>)>,
             ),
         >,
-        Query<(&Hovered, &mut BackgroundColor), Or<(With<PointHandle>, With<TensionHandle>)>>,
+        Query<
+            (
+                &Hovered,
+                Has<Dragging>,
+                Has<SelectedPoint>,
+                &mut BackgroundColor,
+            ),
+            Or<(With<PointHandle>, With<TensionHandle>)>,
+        >,
     )>,
 ) {
-    let removed: Vec<Entity> = removed_dragging.read().collect();
-    let hover_color = BACKGROUND_COLOR.mix(&PRIMARY_COLOR, 0.8);
+    let removed: Vec<Entity> = removed_dragging
+        .read()
+        .chain(removed_selected.read())
+        .collect();
 
-    for (entity, hovered, is_dragging, mut bg) in &mut handles.p0() {
+    for (entity, hovered, is_dragging, is_selected, mut bg) in &mut handles.p0() {
         if removed.contains(&entity) {
             continue;
         }
-        *bg = if is_dragging {
-            BackgroundColor(PRIMARY_COLOR.into())
-        } else if hovered.get() {
-            BackgroundColor(hover_color.into())
-        } else {
-            BackgroundColor(BACKGROUND_COLOR.into())
-        };
+        *bg = handle_color(hovered.get(), is_dragging || is_selected);
     }
 
     for entity in removed {
-        if let Ok((hovered, mut bg)) = handles.p1().get_mut(entity) {
-            *bg = if hovered.get() {
-                BackgroundColor(hover_color.into())
-            } else {
-                BackgroundColor(BACKGROUND_COLOR.into())
-            };
+        if let Ok((hovered, is_dragging, is_selected, mut bg)) = handles.p1().get_mut(entity) {
+            *bg = handle_color(hovered.get(), is_dragging || is_selected);
+        }
+    }
+}
+
+fn handle_color(hovered: bool, active: bool) -> BackgroundColor {
+    if active {
+        BackgroundColor(PRIMARY_COLOR.into())
+    } else if hovered {
+        BackgroundColor(BACKGROUND_COLOR.mix(&PRIMARY_COLOR, 0.8).into())
+    } else {
+        BackgroundColor(BACKGROUND_COLOR.into())
+    }
+}
+
+// Delete removes the selected point, arrows nudge it by the drag snap step (ten steps
+// with shift held)
+fn handle_point_keys(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    input_focus: Res<InputFocus>,
+    selected: Query<&PointHandle, With<SelectedPoint>>,
+    mut states: Query<&mut CurveEditState>,
+) {
+    if input_focus.0.is_some() {
+        return;
+    }
+    let Ok(handle) = selected.single() else {
+        return;
+    };
+    let Ok(mut state) = states.get_mut(handle.curve_edit) else {
+        return;
+    };
+
+    if keyboard.any_just_pressed([KeyCode::Delete, KeyCode::Backspace]) {
+        if state.curve.remove_point(handle.index) {
+            state.mark_custom();
+            trigger_curve_events(&mut commands, handle.curve_edit, &state.curve);
         }
+        return;
+    }
+
+    let step = if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        DRAG_SNAP_STEP * 10.0
+    } else {
+        DRAG_SNAP_STEP
+    };
+    let horizontal = key_axis(&keyboard, KeyCode::ArrowLeft, KeyCode::ArrowRight);
+    let vertical = key_axis(&keyboard, KeyCode::ArrowDown, KeyCode::ArrowUp);
+    if horizontal == 0.0 && vertical == 0.0 {
+        return;
+    }
+
+    let value_step = step * state.curve.range.span() as f64;
+    state.curve.nudge_point(
+        handle.index,
+        (horizontal * step) as f32,
+        vertical * value_step,
+    );
+    state.mark_custom();
+    trigger_curve_events(&mut commands, handle.curve_edit, &state.curve);
+}
+
+fn key_axis(keyboard: &ButtonInput<KeyCode>, negative: KeyCode, positive: KeyCode) -> f64 {
+    let mut axis = 0.0;
+    if keyboard.just_pressed(negative) {
+        axis -= 1.0;
+    }
+    if keyboard.just_pressed(positive) {
+        axis += 1.0;
     }
+    axis
 }
 
 fn handle_preset_change(
@@ -959,6 +1057,8 @@ fn handle_preset_change(
 
     if let Some(preset) = CURVE_PRESETS.get(trigger.selected) {
         state.curve = preset.to_curve(range);
+    } else if let Some(preset) = LifetimePreset::ALL.get(trigger.selected - CURVE_PRESETS.len()) {
+        state.curve = CurveTexture::from_preset(*preset).with_range(range);
     }
 
     trigger_curve_events(&mut commands, curve_edit_entity, &state.curve);
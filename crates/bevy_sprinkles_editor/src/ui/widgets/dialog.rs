@@ -24,6 +24,7 @@ pub fn plugin(app: &mut App) {
     app.add_observer(on_open_dialog)
         .add_observer(on_open_confirmation_dialog)
         .add_observer(on_action_button_click)
+        .add_observer(on_secondary_action_button_click)
         .add_observer(on_cancel_button_click)
         .add_observer(on_close_button_click)
         .add_observer(on_close_dialog)
@@ -58,6 +59,9 @@ struct DialogCancelButton;
 #[derive(Component)]
 struct DialogActionButton;
 
+#[derive(Component)]
+struct DialogSecondaryActionButton;
+
 #[derive(Component)]
 pub struct DialogChildrenSlot;
 
@@ -88,6 +92,11 @@ pub struct DialogActionEvent {
     pub entity: Entity,
 }
 
+#[derive(EntityEvent)]
+pub struct DialogSecondaryActionEvent {
+    pub entity: Entity,
+}
+
 #[derive(Event)]
 pub struct CloseDialogEvent;
 
@@ -96,6 +105,7 @@ pub struct OpenDialogEvent {
     pub title: Option<String>,
     pub description: Option<String>,
     pub action: Option<String>,
+    pub secondary_action: Option<String>,
     pub cancel: Option<String>,
     pub variant: DialogVariant,
     pub has_close_button: bool,
@@ -111,6 +121,7 @@ impl OpenDialogEvent {
             title: Some(title.into()),
             description: None,
             action: Some(action.into()),
+            secondary_action: None,
             cancel: Some("Cancel".into()),
             variant: DialogVariant::Default,
             has_close_button: true,
@@ -126,6 +137,11 @@ impl OpenDialogEvent {
         self
     }
 
+    pub fn with_secondary_action(mut self, secondary_action: impl Into<String>) -> Self {
+        self.secondary_action = Some(secondary_action.into());
+        self
+    }
+
     pub fn with_variant(mut self, variant: DialogVariant) -> Self {
         self.variant = variant;
         self
@@ -281,7 +297,8 @@ fn spawn_dialog(commands: &mut Commands, asset_server: &AssetServer, event: &Ope
         .id();
 
     let has_header = event.title.is_some() || event.description.is_some();
-    let has_footer = event.action.is_some() || event.cancel.is_some();
+    let has_footer =
+        event.action.is_some() || event.secondary_action.is_some() || event.cancel.is_some();
 
     let header_id = if has_header {
         let mut header = commands.spawn((
@@ -337,6 +354,13 @@ fn spawn_dialog(commands: &mut Commands, asset_server: &AssetServer, event: &Ope
             footer.with_child((DialogCancelButton, button(ButtonProps::new(cancel))));
         }
 
+        if let Some(secondary_action) = &event.secondary_action {
+            footer.with_child((
+                DialogSecondaryActionButton,
+                button(ButtonProps::new(secondary_action)),
+            ));
+        }
+
         if let Some(action) = &event.action {
             footer.with_child((
                 DialogActionButton,
@@ -710,6 +734,26 @@ fn on_action_button_click(
     }
 }
 
+fn on_secondary_action_button_click(
+    event: On<ButtonClickEvent>,
+    secondary_buttons: Query<&ChildOf, With<DialogSecondaryActionButton>>,
+    parents: Query<&ChildOf>,
+    dialogs: Query<(Entity, &DialogVisual), (With<EditorDialog>, Without<DespawningDialog>)>,
+    mut commands: Commands,
+) {
+    let Ok(button_parent) = secondary_buttons.get(event.entity) else {
+        return;
+    };
+
+    if let Some(dialog_entity) =
+        find_and_dismiss(button_parent.parent(), &parents, &dialogs, &mut commands)
+    {
+        commands.trigger(DialogSecondaryActionEvent {
+            entity: dialog_entity,
+        });
+    }
+}
+
 fn on_cancel_button_click(
     event: On<ButtonClickEvent>,
     cancel_buttons: Query<&ChildOf, With<DialogCancelButton>>,
@@ -1,12 +1,13 @@
 pub mod materials;
 
-use bevy::picking::events::Click;
+use bevy::input_focus::InputFocus;
+use bevy::picking::events::{Click, Press};
 use bevy::picking::hover::Hovered;
 use bevy::picking::pointer::PointerButton;
 use bevy::picking::prelude::Pickable;
 use bevy::prelude::*;
 use bevy::ui::UiGlobalTransform;
-use bevy_sprinkles::prelude::{GradientStop, ParticleGradient};
+use bevy_sprinkles::prelude::{GradientStop, LifetimePreset, ParticleGradient};
 
 pub use materials::GradientMaterial;
 
@@ -21,6 +22,7 @@ use crate::ui::widgets::button::{
 use crate::ui::widgets::color_picker::{
     ColorPickerChangeEvent, ColorPickerCommitEvent, ColorPickerProps, color_picker,
 };
+use crate::ui::widgets::combobox::{ComboBoxChangeEvent, combobox_with_label};
 use crate::ui::widgets::cursor::{ActiveCursor, HoverCursor};
 use crate::ui::widgets::panel_section::{PanelSectionProps, panel_section};
 use crate::ui::widgets::popover::{
@@ -61,6 +63,8 @@ pub fn plugin(app: &mut App) {
         .add_observer(handle_stop_color_change)
         .add_observer(handle_stop_color_commit)
         .add_observer(handle_redistribute_click)
+        .add_observer(handle_reverse_click)
+        .add_observer(handle_preset_change)
         .add_observer(handle_delete_menu_click)
         .add_observer(handle_handle_color_change)
         .add_observer(handle_handle_color_commit)
@@ -80,6 +84,7 @@ pub fn plugin(app: &mut App) {
                 handle_bar_right_click,
                 handle_handle_right_click,
                 respawn_stops_on_change,
+                handle_stop_keys,
             ),
         );
 }
@@ -253,6 +258,12 @@ stop_ref_component!(HandleColorPicker);
 #[derive(Component)]
 struct RedistributeOption(Entity);
 
+#[derive(Component)]
+struct ReverseOption(Entity);
+
+#[derive(Component)]
+struct PresetComboBox(Entity);
+
 stop_ref_component!(DeleteMenuOption);
 
 #[derive(Component, Default)]
@@ -261,6 +272,13 @@ struct Dragging;
 #[derive(Component)]
 struct JustDragged;
 
+/// Marks the stop handle that keyboard edits apply to.
+#[derive(Component)]
+struct SelectedStop;
+
+/// Distance a stop moves per arrow key press, as a fraction of the gradient.
+const NUDGE_STEP: f32 = 0.01;
+
 fn setup_gradient_edit(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -482,6 +500,12 @@ fn setup_gradient_edit_content(
             continue;
         };
 
+        let presets: Vec<_> = LifetimePreset::ALL.iter().map(|p| p.name()).collect();
+        commands.entity(content_entity).with_child((
+            PresetComboBox(edit_entity),
+            combobox_with_label(presets, "Presets"),
+        ));
+
         let bar_entity = commands
             .spawn((
                 GradientBar(edit_entity),
@@ -722,6 +746,7 @@ fn spawn_stop_handles(
                 spawn_handle_arrow(handle);
                 spawn_handle_square(handle, stop.color);
             })
+            .observe(on_handle_select)
             .observe(on_handle_click)
             .observe(on_handle_drag_start)
             .observe(on_handle_drag)
@@ -843,6 +868,20 @@ fn on_handle_click(
     });
 }
 
+fn on_handle_select(
+    event: On<Pointer<Press>>,
+    mut commands: Commands,
+    selected: Query<Entity, With<SelectedStop>>,
+) {
+    if event.button != PointerButton::Primary {
+        return;
+    }
+    for entity in &selected {
+        commands.entity(entity).remove::<SelectedStop>();
+    }
+    commands.entity(event.event_target()).insert(SelectedStop);
+}
+
 fn on_handle_drag_start(
     event: On<Pointer<DragStart>>,
     mut commands: Commands,
@@ -1098,11 +1137,15 @@ fn update_stop_position_inputs(
 
 fn update_handle_colors(
     mut removed_dragging: RemovedComponents<Dragging>,
+    mut removed_selected: RemovedComponents<SelectedStop>,
     handles: Query<
-        (Entity, &Hovered, Has<Dragging>),
-        (With<StopHandle>, Or<(Changed<Hovered>, Added<Dragging>)>),
+        (Entity, &Hovered, Has<Dragging>, Has<SelectedStop>),
+        (
+            With<StopHandle>,
+            Or<(Changed<Hovered>, Added<Dragging>, Added<SelectedStop>)>,
+        ),
     >,
-    handles_all: Query<(Entity, &Hovered), With<StopHandle>>,
+    handles_all: Query<(&Hovered, Has<Dragging>, Has<SelectedStop>), With<StopHandle>>,
     mut squares: Query<(&ChildOf, &mut BackgroundColor), With<StopHandleSquare>>,
     mut arrows: Query<
         (&ChildOf, &mut BackgroundColor),
@@ -1110,32 +1153,29 @@ fn update_handle_colors(
     >,
     children_query: Query<&Children>,
 ) {
-    let removed: Vec<Entity> = removed_dragging.read().collect();
+    let removed: Vec<Entity> = removed_dragging
+        .read()
+        .chain(removed_selected.read())
+        .collect();
 
     let mut updates: Vec<(Entity, Srgba)> = Vec::new();
 
-    for (entity, hovered, is_dragging) in &handles {
+    for (entity, hovered, is_dragging, is_selected) in &handles {
         if removed.contains(&entity) {
             continue;
         }
-        let color = if is_dragging {
-            PRIMARY_COLOR
-        } else if hovered.get() {
-            PRIMARY_COLOR.lighter(0.1)
-        } else {
-            BORDER_COLOR
-        };
-        updates.push((entity, color));
+        updates.push((
+            entity,
+            handle_color(hovered.get(), is_dragging || is_selected),
+        ));
     }
 
     for entity in removed {
-        if let Ok((_, hovered)) = handles_all.get(entity) {
-            let color = if hovered.get() {
-                PRIMARY_COLOR.lighter(0.1)
-            } else {
-                BORDER_COLOR
-            };
-            updates.push((entity, color));
+        if let Ok((hovered, is_dragging, is_selected)) = handles_all.get(entity) {
+            updates.push((
+                entity,
+                handle_color(hovered.get(), is_dragging || is_selected),
+            ));
         }
     }
 
@@ -1159,6 +1199,61 @@ fn update_handle_colors(
     }
 }
 
+fn handle_color(hovered: bool, active: bool) -> Srgba {
+    if active {
+        PRIMARY_COLOR
+    } else if hovered {
+        PRIMARY_COLOR.lighter(0.1)
+    } else {
+        BORDER_COLOR
+    }
+}
+
+// Delete removes the selected stop, left and right nudge it (ten steps with shift held)
+fn handle_stop_keys(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    input_focus: Res<InputFocus>,
+    selected: Query<&StopHandle, With<SelectedStop>>,
+    mut states: Query<&mut GradientEditState>,
+) {
+    if input_focus.0.is_some() {
+        return;
+    }
+    let Ok(handle) = selected.single() else {
+        return;
+    };
+    let Ok(mut state) = states.get_mut(handle.gradient_edit) else {
+        return;
+    };
+
+    if keyboard.any_just_pressed([KeyCode::Delete, KeyCode::Backspace]) {
+        if state.gradient.remove_stop(handle.index) {
+            trigger_gradient_events(&mut commands, handle.gradient_edit, &state.gradient);
+        }
+        return;
+    }
+
+    let mut direction = 0.0;
+    if keyboard.just_pressed(KeyCode::ArrowLeft) {
+        direction -= 1.0;
+    }
+    if keyboard.just_pressed(KeyCode::ArrowRight) {
+        direction += 1.0;
+    }
+    if direction == 0.0 {
+        return;
+    }
+
+    let step = if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        NUDGE_STEP * 10.0
+    } else {
+        NUDGE_STEP
+    };
+    state.gradient.nudge_stop(handle.index, direction * step);
+    trigger_gradient_events(&mut commands, handle.gradient_edit, &state.gradient);
+}
+
 fn handle_bar_right_click(
     mut commands: Commands,
     mouse: Res<ButtonInput<MouseButton>>,
@@ -1228,7 +1323,11 @@ fn handle_bar_right_click(
             left_color[3] + (right_color[3] - left_color[3]) * t,
         ];
 
-        let new_stop = GradientStop { color, position };
+        let new_stop = GradientStop {
+            color,
+            position,
+            midpoint: 0.5,
+        };
 
         let insert_idx = state
             .gradient
@@ -1292,6 +1391,15 @@ fn handle_handle_right_click(
                 ),
             ));
 
+            parent.spawn((
+                ReverseOption(handle.gradient_edit),
+                button(
+                    ButtonProps::new("Reverse stops")
+                        .with_variant(ButtonVariant::Ghost)
+                        .align_left(),
+                ),
+            ));
+
             parent.spawn((
                 Node {
                     width: percent(100),
@@ -1363,7 +1471,11 @@ fn handle_add_stop_click(
         ];
     }
 
-    let new_stop = GradientStop { color, position };
+    let new_stop = GradientStop {
+        color,
+        position,
+        midpoint: 0.5,
+    };
 
     let insert_idx = state
         .gradient
@@ -1413,15 +1525,34 @@ fn handle_redistribute_click(
         return;
     };
 
-    let count = state.gradient.stops.len();
-    if count < 2 {
+    if state.gradient.stops.len() < 2 {
         return;
     }
 
-    for (i, stop) in state.gradient.stops.iter_mut().enumerate() {
-        stop.position = i as f32 / (count - 1) as f32;
+    state.gradient.distribute_evenly();
+    trigger_gradient_events(&mut commands, option.0, &state.gradient);
+
+    for menu in &menus {
+        commands.entity(menu).try_despawn();
     }
+}
 
+fn handle_reverse_click(
+    trigger: On<ButtonClickEvent>,
+    mut commands: Commands,
+    reverse_options: Query<&ReverseOption>,
+    mut states: Query<&mut GradientEditState>,
+    menus: Query<Entity, With<HandleMenu>>,
+) {
+    let Ok(option) = reverse_options.get(trigger.entity) else {
+        return;
+    };
+
+    let Ok(mut state) = states.get_mut(option.0) else {
+        return;
+    };
+
+    state.gradient = state.gradient.reversed();
     trigger_gradient_events(&mut commands, option.0, &state.gradient);
 
     for menu in &menus {
@@ -1429,6 +1560,36 @@ fn handle_redistribute_click(
     }
 }
 
+fn handle_preset_change(
+    trigger: On<ComboBoxChangeEvent>,
+    mut commands: Commands,
+    preset_boxes: Query<&PresetComboBox>,
+    mut states: Query<&mut GradientEditState>,
+) {
+    let Ok(preset_box) = preset_boxes.get(trigger.entity) else {
+        return;
+    };
+    let Some(preset) = LifetimePreset::ALL.get(trigger.selected) else {
+        return;
+    };
+    let Ok(mut state) = states.get_mut(preset_box.0) else {
+        return;
+    };
+
+    // keep the color of the first stop so presets only reshape the alpha
+    let [r, g, b, _] = state
+        .gradient
+        .stops
+        .first()
+        .map_or([1.0; 4], |stop| stop.color);
+    let previous = std::mem::replace(
+        &mut state.gradient,
+        ParticleGradient::from_preset(*preset, [r, g, b]),
+    );
+    state.gradient.hdr = previous.hdr;
+    trigger_gradient_events(&mut commands, preset_box.0, &state.gradient);
+}
+
 fn handle_delete_menu_click(
     trigger: On<ButtonClickEvent>,
     mut commands: Commands,
@@ -6,7 +6,7 @@ use super::curve_edit::{CurveEditProps, curve_edit};
 use super::gradient_edit::{GradientEditProps, gradient_edit};
 use super::text_edit::{TextEditPrefix, TextEditProps, text_edit};
 use super::vector_edit::{VectorEditProps, VectorSuffixes, vector_edit};
-use crate::ui::components::binding::FieldBinding;
+use crate::ui::components::binding::{AngleField, FieldBinding};
 use crate::ui::components::inspector::{ComboBoxOption, FieldKind, path_to_label};
 
 pub fn plugin(app: &mut App) {
@@ -23,6 +23,7 @@ pub struct InspectorFieldProps {
     min: Option<f32>,
     max: Option<f32>,
     combobox_options: Option<Vec<ComboBoxOptionData>>,
+    angle: bool,
 }
 
 impl InspectorFieldProps {
@@ -37,6 +38,7 @@ impl InspectorFieldProps {
             min: None,
             max: None,
             combobox_options: None,
+            angle: false,
         }
     }
 
@@ -45,11 +47,22 @@ impl InspectorFieldProps {
         self
     }
 
+    /// Marks the field as an angle stored in degrees, shown in the editor's angle unit.
+    pub fn angle(mut self) -> Self {
+        self.angle = true;
+        self
+    }
+
     pub fn u32(mut self) -> Self {
         self.kind = FieldKind::U32;
         self
     }
 
+    pub fn i32(mut self) -> Self {
+        self.kind = FieldKind::I32;
+        self
+    }
+
     pub fn u32_or_empty(mut self) -> Self {
         self.kind = FieldKind::U32OrEmpty;
         self
@@ -137,6 +150,9 @@ impl InspectorFieldProps {
         if self.suffix.is_some() {
             return self.suffix.as_deref();
         }
+        if self.angle {
+            return Some("°");
+        }
         match self.kind {
             FieldKind::F32Percent => Some("%"),
             _ => None,
@@ -173,7 +189,7 @@ impl InspectorFieldProps {
     fn is_integer(&self) -> bool {
         matches!(
             self.kind,
-            FieldKind::U32 | FieldKind::U32OrEmpty | FieldKind::OptionalU32
+            FieldKind::U32 | FieldKind::U32OrEmpty | FieldKind::OptionalU32 | FieldKind::I32
         )
     }
 }
@@ -207,7 +223,10 @@ pub fn spawn_inspector_field(
             vec_props = vec_props.with_max(max as f64);
         }
 
-        spawner.spawn((field, vector_edit(vec_props)));
+        let mut entity = spawner.spawn((field, vector_edit(vec_props)));
+        if props.angle {
+            entity.insert(AngleField);
+        }
         return;
     }
 
@@ -261,7 +280,10 @@ pub fn spawn_inspector_field(
         text_props = text_props.allow_empty();
     }
 
-    spawner.spawn((field, text_edit(text_props)));
+    let mut entity = spawner.spawn((field, text_edit(text_props)));
+    if props.angle {
+        entity.insert(AngleField);
+    }
 }
 
 fn combobox_data_to_options(data: &[ComboBoxOptionData]) -> Vec<ComboBoxOption> {
@@ -2,7 +2,9 @@ use bevy::prelude::*;
 
 use crate::ui::icons::{ICON_ADD, ICON_ARROW_DOWN};
 use crate::ui::tokens::{BORDER_COLOR, FONT_PATH, TEXT_DISPLAY_COLOR, TEXT_SIZE};
-use crate::ui::widgets::button::{ButtonClickEvent, ButtonVariant, IconButtonProps, icon_button};
+use crate::ui::widgets::button::{
+    ButtonClickEvent, ButtonProps, ButtonVariant, IconButtonProps, button, icon_button,
+};
 
 pub fn plugin(app: &mut App) {
     app.add_systems(Update, setup_panel_section_buttons);
@@ -20,6 +22,24 @@ struct PanelSectionButtonsContainer;
 #[derive(Component)]
 pub struct PanelSectionAddButton(pub Entity);
 
+#[derive(Component)]
+pub struct PanelSectionPasteButton(pub Entity);
+
+/// Triggered on the section entity when its paste button is clicked.
+#[derive(EntityEvent)]
+pub struct PanelSectionPasteEvent {
+    pub entity: Entity,
+}
+
+#[derive(Component)]
+pub struct PanelSectionImportButton(pub Entity);
+
+/// Triggered on the section entity when its import button is clicked.
+#[derive(EntityEvent)]
+pub struct PanelSectionImportEvent {
+    pub entity: Entity,
+}
+
 #[derive(Component)]
 struct PanelSectionCollapseButton(Entity);
 
@@ -29,6 +49,8 @@ struct Collapsed(bool);
 #[derive(Component)]
 struct PanelSectionState {
     has_add_button: bool,
+    has_paste_button: bool,
+    has_import_button: bool,
     collapsible: bool,
 }
 
@@ -53,6 +75,8 @@ pub struct PanelSectionProps {
     pub title: String,
     pub size: PanelSectionSize,
     pub has_add_button: bool,
+    pub has_paste_button: bool,
+    pub has_import_button: bool,
     pub collapsible: bool,
 }
 
@@ -74,6 +98,16 @@ impl PanelSectionProps {
         self
     }
 
+    pub fn with_paste_button(mut self) -> Self {
+        self.has_paste_button = true;
+        self
+    }
+
+    pub fn with_import_button(mut self) -> Self {
+        self.has_import_button = true;
+        self
+    }
+
     pub fn collapsible(mut self) -> Self {
         self.collapsible = true;
         self
@@ -85,6 +119,8 @@ pub fn panel_section(props: PanelSectionProps, asset_server: &AssetServer) -> im
         title,
         size,
         has_add_button,
+        has_paste_button,
+        has_import_button,
         collapsible,
     } = props;
     let font: Handle<Font> = asset_server.load(FONT_PATH);
@@ -103,6 +139,8 @@ pub fn panel_section(props: PanelSectionProps, asset_server: &AssetServer) -> im
         BorderColor::all(BORDER_COLOR),
         PanelSectionState {
             has_add_button,
+            has_paste_button,
+            has_import_button,
             collapsible,
         },
         children![(
@@ -157,6 +195,28 @@ fn setup_panel_section_buttons(
             continue;
         }
 
+        if state.has_paste_button {
+            let paste_entity = commands
+                .spawn((
+                    PanelSectionPasteButton(section_entity),
+                    button(ButtonProps::new("Paste").with_variant(ButtonVariant::Ghost)),
+                ))
+                .observe(on_paste_click)
+                .id();
+            commands.entity(container_entity).add_child(paste_entity);
+        }
+
+        if state.has_import_button {
+            let import_entity = commands
+                .spawn((
+                    PanelSectionImportButton(section_entity),
+                    button(ButtonProps::new("Import").with_variant(ButtonVariant::Ghost)),
+                ))
+                .observe(on_import_click)
+                .id();
+            commands.entity(container_entity).add_child(import_entity);
+        }
+
         if state.has_add_button {
             let add_entity = commands
                 .spawn((
@@ -204,6 +264,32 @@ fn on_add_click(
     });
 }
 
+fn on_paste_click(
+    event: On<ButtonClickEvent>,
+    paste_buttons: Query<&PanelSectionPasteButton>,
+    mut commands: Commands,
+) {
+    let Ok(paste_button) = paste_buttons.get(event.entity) else {
+        return;
+    };
+    commands.trigger(PanelSectionPasteEvent {
+        entity: paste_button.0,
+    });
+}
+
+fn on_import_click(
+    event: On<ButtonClickEvent>,
+    import_buttons: Query<&PanelSectionImportButton>,
+    mut commands: Commands,
+) {
+    let Ok(import_button) = import_buttons.get(event.entity) else {
+        return;
+    };
+    commands.trigger(PanelSectionImportEvent {
+        entity: import_button.0,
+    });
+}
+
 fn on_collapse_click(
     event: On<ButtonClickEvent>,
     collapse_buttons: Query<&PanelSectionCollapseButton>,
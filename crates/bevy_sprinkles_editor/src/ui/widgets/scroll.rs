@@ -2,6 +2,7 @@ use bevy::color::palettes::tailwind;
 use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::picking::hover::{HoverMap, Hovered};
 use bevy::prelude::*;
+use bevy::ui::UiGlobalTransform;
 
 const SCROLL_SPEED: f32 = 24.0;
 
@@ -11,7 +12,8 @@ const SCROLLBAR_MARGIN: f32 = 3.0;
 
 pub fn plugin(app: &mut App) {
     app.add_systems(Update, (send_scroll_events, update_scrollbar))
-        .add_observer(on_scroll_handler);
+        .add_observer(on_scroll_handler)
+        .add_observer(on_scroll_to_entity);
 }
 
 #[derive(EntityEvent, Debug)]
@@ -21,6 +23,13 @@ pub struct Scroll {
     pub delta: Vec2,
 }
 
+/// Scrolls the container `entity` vertically so that `target` sits at its top edge.
+#[derive(EntityEvent, Debug)]
+pub struct ScrollToEntity {
+    pub entity: Entity,
+    pub target: Entity,
+}
+
 #[derive(Component)]
 pub struct Scrollbar {
     pub container: Entity,
@@ -96,6 +105,28 @@ fn on_scroll_handler(
     }
 }
 
+pub(crate) fn on_scroll_to_entity(
+    event: On<ScrollToEntity>,
+    mut containers: Query<(&mut ScrollPosition, &ComputedNode, &UiGlobalTransform)>,
+    targets: Query<(&ComputedNode, &UiGlobalTransform)>,
+) {
+    let Ok((target_computed, target_transform)) = targets.get(event.target) else {
+        return;
+    };
+    let Ok((mut scroll_position, computed, transform)) = containers.get_mut(event.entity) else {
+        return;
+    };
+
+    let top_edge = |computed: &ComputedNode, transform: &UiGlobalTransform| {
+        (transform.translation.y - computed.size().y * 0.5) * computed.inverse_scale_factor()
+    };
+    let offset = top_edge(target_computed, target_transform) - top_edge(computed, transform);
+
+    let max_offset =
+        (computed.content_size().y - computed.size().y) * computed.inverse_scale_factor();
+    scroll_position.y = (scroll_position.y + offset).clamp(0., max_offset.max(0.));
+}
+
 fn update_scrollbar(
     containers: Query<(&Hovered, &ScrollPosition, &ComputedNode)>,
     mut scrollbars: Query<(&Scrollbar, &mut Node, &mut Visibility)>,
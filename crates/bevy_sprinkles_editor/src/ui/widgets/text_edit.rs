@@ -44,7 +44,10 @@ pub fn plugin(app: &mut App) {
                 handle_clamp_on_unfocus,
             ),
         )
-        .add_systems(PostUpdate, (apply_default_value, handle_suffix).chain());
+        .add_systems(
+            PostUpdate,
+            (apply_default_value, update_suffix_text, handle_suffix).chain(),
+        );
 }
 
 #[derive(Component)]
@@ -81,8 +84,9 @@ impl Default for TextEditPrefix {
     }
 }
 
+/// Text shown after the value of a text edit. Changing it updates the shown suffix.
 #[derive(Component)]
-struct TextEditSuffix(String);
+pub struct TextEditSuffix(pub String);
 
 #[derive(Component)]
 struct TextEditSuffixNode(Entity);
@@ -530,6 +534,19 @@ fn apply_default_value(
     }
 }
 
+fn update_suffix_text(
+    text_edits: Query<(Entity, &TextEditSuffix), Changed<TextEditSuffix>>,
+    mut suffix_nodes: Query<(&TextEditSuffixNode, &mut Text)>,
+) {
+    for (entity, suffix) in &text_edits {
+        for (link, mut text) in &mut suffix_nodes {
+            if link.0 == entity && text.0 != suffix.0 {
+                text.0 = suffix.0.clone();
+            }
+        }
+    }
+}
+
 fn handle_suffix(
     focus: Res<InputFocus>,
     text_edits: Query<
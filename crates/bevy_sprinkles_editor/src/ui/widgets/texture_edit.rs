@@ -827,6 +827,11 @@ fn extract_texture_ref_from_reflect(value: &dyn PartialReflect) -> Option<Textur
             let path = field.try_downcast_ref::<String>()?.clone();
             Some(TextureRef::Local(path))
         }
+        "Custom" => {
+            let field = texture_ref_enum.field_at(0)?;
+            let key = field.try_downcast_ref::<String>()?.clone();
+            Some(TextureRef::Custom(key))
+        }
         _ => None,
     }
 }
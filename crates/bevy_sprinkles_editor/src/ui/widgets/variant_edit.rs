@@ -733,12 +733,14 @@ fn spawn_field_widget(
             commands.spawn((binding, text_edit(props))).id()
         }
 
-        FieldKind::U32 | FieldKind::U32OrEmpty | FieldKind::OptionalU32 => commands
-            .spawn((
-                binding,
-                text_edit(TextEditProps::default().with_label(label).numeric_i32()),
-            ))
-            .id(),
+        FieldKind::U32 | FieldKind::U32OrEmpty | FieldKind::OptionalU32 | FieldKind::I32 => {
+            commands
+                .spawn((
+                    binding,
+                    text_edit(TextEditProps::default().with_label(label).numeric_i32()),
+                ))
+                .id()
+        }
 
         FieldKind::Bool => commands
             .spawn((binding, checkbox(CheckboxProps::new(label), asset_server)))
@@ -1,12 +1,13 @@
-use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, TAU};
 use std::ops::Range;
 
 use bevy::asset::RenderAssetUsages;
 use bevy::camera::RenderTarget;
+use bevy::camera::visibility::RenderLayers;
 use bevy::color::palettes::tailwind::{ZINC_200, ZINC_950};
 use bevy::image::{ImageAddressMode, ImageSamplerDescriptor};
 use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll};
-use bevy::math::Affine2;
+use bevy::math::{Affine2, Affine3A};
 use bevy::picking::hover::Hovered;
 use bevy::post_process::bloom::Bloom;
 use bevy::prelude::*;
@@ -17,6 +18,7 @@ use crate::state::{
     EditorState, Inspectable, PlaybackPlayEvent, PlaybackResetEvent, PlaybackSeekEvent,
 };
 use crate::ui::components::seekbar::SeekbarDragState;
+use crate::ui::components::thumbnails::thumbnail_layers;
 use crate::ui::components::viewport::EditorViewport;
 use crate::ui::tokens::PRIMARY_COLOR;
 
@@ -30,6 +32,9 @@ const ORBIT_TARGET: Vec3 = Vec3::ZERO;
 const FLOOR_SIZE: f32 = 192.0;
 const FLOOR_TILE_SIZE: f32 = 2.0;
 
+const SHAPE_GIZMO_SEGMENTS: usize = 32;
+const POINT_GIZMO_SIZE: f32 = 0.1;
+
 #[derive(Component)]
 pub struct EditorCamera;
 
@@ -100,6 +105,7 @@ pub fn setup_camera(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
             ..default()
         },
         Transform::from_rotation(Quat::from_euler(EulerRot::XYZ, -FRAC_PI_4, 0.0, -FRAC_PI_4)),
+        RenderLayers::default().union(&thumbnail_layers()),
     ));
 }
 
@@ -246,9 +252,7 @@ pub fn spawn_preview_particle_system(
     }
 
     commands.spawn((
-        ParticleSystem3D {
-            handle: handle.clone(),
-        },
+        ParticleSystem3D::new(handle.clone()),
         Transform::default(),
         Visibility::default(),
         EditorParticlePreview,
@@ -448,6 +452,40 @@ pub fn handle_playback_seek_event(
     }
 }
 
+/// Disables the preview's muted colliders, leaving [`ColliderData::enabled`] untouched.
+///
+/// Runs after the colliders are synced from the asset, which restores their own flag.
+pub fn apply_muted_colliders(
+    editor_state: Res<EditorState>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    preview_systems: Query<(), With<EditorParticlePreview>>,
+    mut colliders: Query<(&ColliderEntity, &mut ParticlesCollider3D)>,
+) {
+    let Some(asset) = editor_state
+        .current_project
+        .as_ref()
+        .and_then(|handle| assets.get(handle))
+    else {
+        return;
+    };
+
+    for (collider_entity, mut collider) in &mut colliders {
+        if !preview_systems.contains(collider_entity.parent_system) {
+            continue;
+        }
+        let Some(data) = asset.colliders.get(collider_entity.collider_index) else {
+            continue;
+        };
+        let muted = editor_state
+            .muted_colliders
+            .contains(&collider_entity.collider_index);
+        let enabled = data.enabled && !muted;
+        if collider.enabled != enabled {
+            collider.enabled = enabled;
+        }
+    }
+}
+
 pub fn draw_collider_gizmos(
     mut gizmos: Gizmos,
     colliders: Query<(&ParticlesCollider3D, &ColliderEntity, &Transform)>,
@@ -488,6 +526,126 @@ pub fn draw_collider_gizmos(
     }
 }
 
+/// Returns the points of the ellipse around `center` with the given half axes.
+fn ellipse_points(center: Vec3, u: Vec3, v: Vec3) -> Vec<Vec3> {
+    (0..=SHAPE_GIZMO_SEGMENTS)
+        .map(|i| {
+            let theta = TAU * i as f32 / SHAPE_GIZMO_SEGMENTS as f32;
+            center + u * theta.cos() + v * theta.sin()
+        })
+        .collect()
+}
+
+pub fn draw_emission_shape_gizmos(
+    mut gizmos: Gizmos,
+    preview_systems: Query<&ParticleSystem3D, With<EditorParticlePreview>>,
+    emitters: Query<(&EmitterEntity, &EmitterRuntime, &GlobalTransform)>,
+    assets: Res<Assets<ParticleSystemAsset>>,
+    editor_state: Res<EditorState>,
+) {
+    let Some(inspected_index) = editor_state
+        .inspecting
+        .as_ref()
+        .filter(|i| i.kind == Inspectable::Emitter)
+        .map(|i| i.index as usize)
+    else {
+        return;
+    };
+
+    for (emitter, runtime, global_transform) in &emitters {
+        if runtime.emitter_index != inspected_index {
+            continue;
+        }
+        let Ok(particle_system) = preview_systems.get(emitter.parent_system) else {
+            continue;
+        };
+        let Some(asset) = assets.get(&particle_system.handle) else {
+            continue;
+        };
+        let Some(emitter_data) = asset.emitters.get(inspected_index) else {
+            continue;
+        };
+
+        let color = if emitter_data.enabled {
+            PRIMARY_COLOR
+        } else {
+            ZINC_200
+        };
+        // mirrors where `get_emission_offset` in `particle_simulate.wgsl` places particles
+        let emission = &emitter_data.emission;
+        let shape_affine = global_transform.affine()
+            * Affine3A::from_scale(asset.mirror_scale())
+            * Affine3A::from_scale_rotation_translation(
+                emission.scale,
+                Quat::IDENTITY,
+                emission.offset,
+            );
+        let mut draw_loop = |points: Vec<Vec3>| {
+            gizmos.linestrip(
+                points.into_iter().map(|p| shape_affine.transform_point3(p)),
+                color,
+            );
+        };
+
+        match emission.shape {
+            EmissionShape::Point => {
+                for axis in [Vec3::X, Vec3::Y, Vec3::Z] {
+                    draw_loop(vec![-axis * POINT_GIZMO_SIZE, axis * POINT_GIZMO_SIZE]);
+                }
+            }
+            EmissionShape::Sphere { radius } | EmissionShape::SphereSurface { radius } => {
+                for (u, v) in [(Vec3::X, Vec3::Y), (Vec3::Y, Vec3::Z), (Vec3::Z, Vec3::X)] {
+                    draw_loop(ellipse_points(Vec3::ZERO, u * radius, v * radius));
+                }
+            }
+            EmissionShape::Box { extents, .. } => {
+                let corner = |x: f32, y: f32, z: f32| Vec3::new(x, y, z) * extents;
+                for z in [-1.0, 1.0] {
+                    draw_loop(vec![
+                        corner(-1.0, -1.0, z),
+                        corner(1.0, -1.0, z),
+                        corner(1.0, 1.0, z),
+                        corner(-1.0, 1.0, z),
+                        corner(-1.0, -1.0, z),
+                    ]);
+                }
+                for (x, y) in [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)] {
+                    draw_loop(vec![corner(x, y, -1.0), corner(x, y, 1.0)]);
+                }
+            }
+            EmissionShape::Ring {
+                axis,
+                height,
+                radius,
+                inner_radius,
+                ..
+            } => {
+                // the ring lies in the XY plane, rotated so its Z axis points along `axis`
+                let rotation = Quat::from_rotation_arc(Vec3::Z, axis.normalize_or(Vec3::Z));
+                let (u, v, w) = (rotation * Vec3::X, rotation * Vec3::Y, rotation * Vec3::Z);
+                let half_height = height * 0.5;
+                let heights: &[f32] = if height == 0.0 {
+                    &[0.0]
+                } else {
+                    &[-half_height, half_height]
+                };
+                for &h in heights {
+                    draw_loop(ellipse_points(w * h, u * radius, v * radius));
+                    if inner_radius > 0.0 {
+                        draw_loop(ellipse_points(w * h, u * inner_radius, v * inner_radius));
+                    }
+                }
+                if height != 0.0 {
+                    for side in [u, v, -u, -v] {
+                        let edge = side * radius;
+                        draw_loop(vec![edge - w * half_height, edge + w * half_height]);
+                    }
+                }
+            }
+        }
+    }
+}
+
 pub fn sync_playback_state(
     assets: Res<Assets<ParticleSystemAsset>>,
     drag_state: Query<&SeekbarDragState>,
@@ -574,3 +732,72 @@ pub fn sync_playback_state(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::gizmos::GizmoPlugin;
+
+    use super::*;
+    use crate::test_utils::{inspect, open_project, test_app};
+
+    #[test]
+    fn test_emission_shape_gizmos_draw_every_shape() {
+        let shapes = [
+            EmissionShape::Point,
+            EmissionShape::Sphere { radius: 1.0 },
+            EmissionShape::SphereSurface { radius: 1.0 },
+            EmissionShape::Box {
+                extents: Vec3::new(1.0, 2.0, 0.5),
+                surface_only: true,
+            },
+            EmissionShape::Ring {
+                axis: Vec3::Y,
+                height: 0.5,
+                radius: 2.0,
+                inner_radius: 1.0,
+                radial_bias: 0.0,
+            },
+            // a flat ring with an axis that can't be normalized
+            EmissionShape::Ring {
+                axis: Vec3::ZERO,
+                height: 0.0,
+                radius: 2.0,
+                inner_radius: 0.0,
+                radial_bias: 0.0,
+            },
+        ];
+        let mut app = test_app();
+        app.add_plugins(GizmoPlugin);
+        let emitters = shapes
+            .iter()
+            .map(|shape| {
+                let mut emitter = EmitterData::default();
+                emitter.emission.shape = *shape;
+                emitter
+            })
+            .collect();
+        let handle = open_project(&mut app, emitters, vec![]);
+
+        let preview = app
+            .world_mut()
+            .spawn((ParticleSystem3D::new(handle), EditorParticlePreview))
+            .id();
+        for index in 0..shapes.len() {
+            app.world_mut().spawn((
+                EmitterEntity {
+                    parent_system: preview,
+                },
+                EmitterRuntime::new(index, None),
+                GlobalTransform::default(),
+            ));
+        }
+
+        for index in 0..shapes.len() {
+            inspect(&mut app, Inspectable::Emitter, index as u8);
+            app.world_mut()
+                .run_system_once(draw_emission_shape_gizmos)
+                .unwrap();
+        }
+    }
+}